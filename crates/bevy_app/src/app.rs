@@ -1,11 +1,11 @@
 use crate::{
-    First, Main, MainSchedulePlugin, PlaceholderPlugin, Plugin, Plugins, PluginsState, SubApp,
-    SubApps,
+    First, Last, Main, MainSchedulePlugin, PlaceholderPlugin, Plugin, Plugins, PluginsState,
+    SubApp, SubApps,
 };
 pub use bevy_derive::AppLabel;
 use bevy_ecs::{
-    component::RequiredComponentsError,
-    event::{event_update_system, EventCursor},
+    component::{Immutable, Mutable, RequiredComponentsError},
+    event::{event_update_system, persistent_event_update_system, EventCursor, PersistentEvents},
     intern::Interned,
     prelude::*,
     schedule::{ScheduleBuildSettings, ScheduleLabel},
@@ -347,6 +347,38 @@ impl App {
         self
     }
 
+    /// Initializes `T` event handling with a [`PersistentEvents::<T>`] resource, which keeps
+    /// the events until every [`PersistentEventReader<T>`] has read them, and schedules a
+    /// [`persistent_event_update_system`] in [`First`] to drop the read events.
+    ///
+    /// At most [`PersistentEvents::DEFAULT_MAX_LEN`] events are kept, even if nothing reads them.
+    /// Use [`PersistentEvents::set_max_len`] on the resource to change this.
+    ///
+    /// [`PersistentEventReader<T>`]: bevy_ecs::event::PersistentEventReader
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// # use bevy_app::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// # #[derive(Event)]
+    /// # struct MyEvent;
+    /// # let mut app = App::new();
+    /// #
+    /// app.add_persistent_event::<MyEvent>();
+    /// ```
+    pub fn add_persistent_event<T>(&mut self) -> &mut Self
+    where
+        T: Event,
+    {
+        if !self.world().contains_resource::<PersistentEvents<T>>() {
+            self.init_resource::<PersistentEvents<T>>()
+                .add_systems(First, persistent_event_update_system::<T>);
+        }
+        self
+    }
+
     /// Inserts the [`Resource`] into the app, overwriting any existing resource of the same type.
     ///
     /// There is also an [`init_resource`](Self::init_resource) for resources that have
@@ -754,6 +786,31 @@ impl App {
         self
     }
 
+    /// Registers a [`ComponentIndex`](bevy_ecs::index::ComponentIndex) for `T`, which maps each
+    /// value of `T` to the entities that have it.
+    ///
+    /// See [`World::register_index`] for details.
+    pub fn register_index<T: Component<Mutability = Immutable> + Ord + Clone>(
+        &mut self,
+    ) -> &mut Self {
+        self.world_mut().register_index::<T>();
+        self
+    }
+
+    /// Triggers [`OnMutate`] for every `T` component that was mutated during the frame, in the
+    /// [`Last`] schedule.
+    ///
+    /// See [`World::track_mutations`] for details.
+    pub fn track_mutations<T: Component<Mutability = Mutable>>(&mut self) -> &mut Self {
+        let world = self.world_mut();
+        world.track_mutations::<T>();
+        if !world.contains_resource::<TriggerMutationsAdded>() {
+            world.insert_resource(TriggerMutationsAdded);
+            self.add_systems(Last, World::trigger_mutations);
+        }
+        self
+    }
+
     /// Registers the given component `R` as a [required component] for `T`.
     ///
     /// When `T` is added to an entity, `R` and its own required components will also be added
@@ -1290,6 +1347,10 @@ impl App {
     }
 }
 
+/// Marks that [`App::track_mutations`] added [`World::trigger_mutations`] to [`Last`].
+#[derive(Resource)]
+struct TriggerMutationsAdded;
+
 type RunnerFn = Box<dyn FnOnce(App) -> AppExit>;
 
 fn run_once(mut app: App) -> AppExit {
@@ -1384,14 +1445,15 @@ mod tests {
         component::Component,
         entity::Entity,
         event::{Event, EventWriter, Events},
+        observer::Trigger,
         query::With,
         removal_detection::RemovedComponents,
         schedule::{IntoSystemConfigs, ScheduleLabel},
         system::{Commands, Query, Resource},
-        world::{FromWorld, World},
+        world::{FromWorld, OnMutate, World},
     };
 
-    use crate::{App, AppExit, Plugin, SubApp, Update};
+    use crate::{App, AppExit, Last, Plugin, SubApp, Update};
 
     struct PluginA;
     impl Plugin for PluginA {
@@ -1784,4 +1846,106 @@ mod tests {
         assert_eq!(test_events.len(), 2); // Events are double-buffered, so we see 2 + 0 = 2
         assert_eq!(test_events.iter_current_update_events().count(), 0);
     }
+
+    #[test]
+    fn persistent_events_are_kept_for_fixed_readers() {
+        use bevy_ecs::{
+            event::{PersistentEventReader, PersistentEvents},
+            system::Local,
+        };
+
+        #[derive(Event)]
+        struct TestEvent;
+
+        #[derive(Resource, Default)]
+        struct Read(usize);
+
+        let mut app = App::new();
+        app.add_persistent_event::<TestEvent>()
+            .init_resource::<Read>()
+            .add_systems(
+                Update,
+                (|mut reader: PersistentEventReader<TestEvent>, mut read: ResMut<Read>| {
+                    read.0 += reader.read().count();
+                })
+                .run_if(|mut frame: Local<usize>| {
+                    *frame += 1;
+                    *frame % 4 == 0
+                }),
+            );
+
+        for _ in 0..3 {
+            app.world_mut()
+                .resource_mut::<PersistentEvents<TestEvent>>()
+                .send(TestEvent);
+            app.update();
+        }
+        assert_eq!(app.world().resource::<Read>().0, 0);
+        assert_eq!(
+            app.world().resource::<PersistentEvents<TestEvent>>().len(),
+            3
+        );
+
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<Read>().0, 3);
+        assert!(app
+            .world()
+            .resource::<PersistentEvents<TestEvent>>()
+            .is_empty());
+    }
+
+    #[test]
+    fn unread_persistent_events_are_evicted() {
+        use bevy_ecs::event::PersistentEvents;
+
+        #[derive(Event)]
+        struct TestEvent;
+
+        let mut app = App::new();
+        app.add_persistent_event::<TestEvent>();
+        let max_len = PersistentEvents::<TestEvent>::DEFAULT_MAX_LEN;
+        for _ in 0..3 {
+            app.world_mut()
+                .resource_mut::<PersistentEvents<TestEvent>>()
+                .send_batch((0..max_len).map(|_| TestEvent));
+            app.update();
+        }
+        let events = app.world().resource::<PersistentEvents<TestEvent>>();
+        assert_eq!(events.len(), max_len);
+        assert_eq!(events.oldest_event_count(), 2 * max_len);
+    }
+
+    #[test]
+    fn track_mutations_adds_system_once() {
+        #[derive(Component)]
+        struct A(u32);
+
+        #[derive(Resource, Default)]
+        struct Mutations(u32);
+
+        let mut app = App::new();
+        // Tracking through the world first still adds the system.
+        app.world_mut().track_mutations::<A>();
+        app.track_mutations::<A>()
+            .track_mutations::<A>()
+            .init_resource::<Mutations>()
+            .add_observer(
+                |_: Trigger<OnMutate, A>, mut mutations: ResMut<Mutations>| {
+                    mutations.0 += 1;
+                },
+            )
+            .add_systems(Update, |mut query: Query<&mut A>| {
+                for mut a in &mut query {
+                    a.0 += 1;
+                }
+            });
+        app.world_mut().spawn(A(0));
+        app.update();
+        app.update();
+        assert_eq!(app.world().resource::<Mutations>().0, 2);
+        let systems = app.get_schedule(Last).unwrap().systems_len();
+        app.track_mutations::<A>();
+        assert_eq!(app.get_schedule(Last).unwrap().systems_len(), systems);
+    }
 }
@@ -0,0 +1,69 @@
+use bevy_ecs::{
+    prelude::*,
+    world::{AsyncWorld, World},
+};
+
+use crate::{App, Last, Plugin};
+
+/// Adds an [`AsyncWorld`] handle to the [`App`], and applies the accesses it queued at the end
+/// of every frame, in the [`Last`] schedule.
+///
+/// ```
+/// # use bevy_app::{App, AsyncWorldPlugin};
+/// # use bevy_ecs::world::AsyncWorld;
+/// let mut app = App::new();
+/// app.add_plugins(AsyncWorldPlugin);
+///
+/// let async_world = app.world().resource::<AsyncWorld>().clone();
+/// ```
+#[derive(Default)]
+pub struct AsyncWorldPlugin;
+
+impl Plugin for AsyncWorldPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<AsyncWorld>()
+            .add_systems(Last, World::apply_async_requests.run_if(has_async_requests));
+    }
+}
+
+fn has_async_requests(async_world: Option<Res<AsyncWorld>>) -> bool {
+    async_world.is_some_and(|async_world| async_world.pending_requests() > 0)
+}
+
+#[cfg(test)]
+mod tests {
+    use alloc::boxed::Box;
+
+    use bevy_ecs::prelude::*;
+    use bevy_ecs::world::AsyncWorld;
+    use bevy_tasks::{block_on, poll_once};
+
+    use crate::{App, AsyncWorldPlugin, Update};
+
+    #[test]
+    fn async_world_requests_are_applied_once_per_update() {
+        #[derive(Resource, Default)]
+        struct Frames(u32);
+
+        let mut app = App::new();
+        app.add_plugins(AsyncWorldPlugin)
+            .init_resource::<Frames>()
+            .add_systems(Update, |mut frames: ResMut<Frames>| frames.0 += 1);
+
+        let async_world = app.world().resource::<AsyncWorld>().clone();
+        let mut script = Box::pin(async move {
+            let start = async_world.run(|world| world.resource::<Frames>().0).await;
+            async_world.next_sync_point().await;
+            let end = async_world.run(|world| world.resource::<Frames>().0).await;
+            (start, end)
+        });
+
+        let frames = loop {
+            if let Some(frames) = block_on(poll_once(&mut script)) {
+                break frames;
+            }
+            app.update();
+        };
+        assert_eq!(frames, (1, 3));
+    }
+}
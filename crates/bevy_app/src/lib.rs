@@ -12,6 +12,7 @@
 extern crate alloc;
 
 mod app;
+mod async_world;
 mod main_schedule;
 mod panic_handler;
 mod plugin;
@@ -22,6 +23,7 @@ mod sub_app;
 mod terminal_ctrl_c_handler;
 
 pub use app::*;
+pub use async_world::*;
 pub use main_schedule::*;
 pub use panic_handler::*;
 pub use plugin::*;
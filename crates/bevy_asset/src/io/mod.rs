@@ -12,6 +12,8 @@ pub mod embedded;
 pub mod file;
 pub mod gated;
 pub mod memory;
+pub mod overlay;
+pub mod pack;
 pub mod processor_gated;
 #[cfg(target_arch = "wasm32")]
 pub mod wasm;
@@ -430,6 +432,12 @@ pub trait AssetWriter: Send + Sync + 'static {
             Ok(())
         }
     }
+    /// Persists the changes made so far, for writers that don't do it as they go. This is called
+    /// by the [`AssetProcessor`](crate::processor::AssetProcessor) whenever it finishes processing
+    /// assets.
+    fn flush(&self) -> impl ConditionalSendFuture<Output = Result<(), AssetWriterError>> {
+        async { Ok(()) }
+    }
 }
 
 /// Equivalent to an [`AssetWriter`] but using boxed futures, necessary eg. when using a `dyn AssetWriter`,
@@ -498,6 +506,8 @@ pub trait ErasedAssetWriter: Send + Sync + 'static {
         path: &'a Path,
         bytes: &'a [u8],
     ) -> BoxedFuture<'a, Result<(), AssetWriterError>>;
+    /// Persists the changes made so far, for writers that don't do it as they go.
+    fn flush(&self) -> BoxedFuture<'_, Result<(), AssetWriterError>>;
 }
 
 impl<T: AssetWriter> ErasedAssetWriter for T {
@@ -571,6 +581,9 @@ impl<T: AssetWriter> ErasedAssetWriter for T {
     ) -> BoxedFuture<'a, Result<(), AssetWriterError>> {
         Box::pin(Self::write_meta_bytes(self, path, bytes))
     }
+    fn flush(&self) -> BoxedFuture<'_, Result<(), AssetWriterError>> {
+        Box::pin(Self::flush(self))
+    }
 }
 
 /// An "asset source change event" that occurs whenever asset (or asset metadata) is created/added/removed
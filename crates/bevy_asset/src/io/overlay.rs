@@ -0,0 +1,504 @@
+//! Stacks several asset readers on top of each other, so that assets of the upper layers shadow
+//! the assets with the same path in the lower layers.
+//!
+//! This makes it possible for mods and patches to replace the assets of a game without the game
+//! knowing which assets are replaced. Layers are usually added to an existing source with
+//! [`AssetSourceBuilder::with_overlay`], or with
+//! [`AssetApp::register_asset_overlay`](crate::AssetApp::register_asset_overlay) before the
+//! [`AssetPlugin`](crate::AssetPlugin) is added:
+//!
+//! ```no_run
+//! # use bevy_app::prelude::*;
+//! # use bevy_asset::{prelude::*, io::{AssetSource, AssetSourceId}};
+//! let mut app = App::new();
+//! // Assets in the `mods/hd_textures` folder replace the default assets with the same path.
+//! app.register_asset_overlay(
+//!     AssetSourceId::Default,
+//!     AssetSource::build().with_reader(AssetSource::get_default_reader(
+//!         "mods/hd_textures".to_string(),
+//!     )),
+//! )
+//! .add_plugins(AssetPlugin::default());
+//! ```
+
+use crate::io::{
+    AssetReader, AssetReaderError, AssetSourceBuilder, AssetSourceEvent, AssetWatcher,
+    ErasedAssetReader, PathStream, Reader,
+};
+use alloc::{boxed::Box, sync::Arc};
+use bevy_utils::HashSet;
+use crossbeam_channel::Sender;
+use futures_lite::{stream, StreamExt};
+use parking_lot::Mutex;
+use std::path::{Path, PathBuf};
+
+/// An [`AssetReader`] reading assets from the first of its layers containing them.
+///
+/// - Reads return the asset of the first layer containing it.
+/// - The metadata of an asset is read from the layer its asset is read from, so that the
+///   metadata of a shadowed asset is never used for the asset replacing it.
+/// - Directory listings merge the entries of every layer.
+///
+/// Errors other than [`AssetReaderError::NotFound`] are returned immediately, without trying
+/// the next layers.
+#[derive(Default)]
+pub struct OverlayAssetReader {
+    layers: Vec<Box<dyn ErasedAssetReader>>,
+}
+
+impl OverlayAssetReader {
+    /// Creates a reader stacking `layers`, from the highest priority to the lowest.
+    pub fn new(layers: Vec<Box<dyn ErasedAssetReader>>) -> Self {
+        Self { layers }
+    }
+
+    /// Adds `layer` below the existing layers, so that it has the lowest priority.
+    pub fn with_layer(mut self, layer: impl AssetReader) -> Self {
+        self.layers.push(Box::new(layer));
+        self
+    }
+
+    /// Returns the layers of this reader, from the highest priority to the lowest.
+    pub fn layers(&self) -> &[Box<dyn ErasedAssetReader>] {
+        &self.layers
+    }
+}
+
+impl AssetReader for OverlayAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        for layer in &self.layers {
+            match layer.read(path).await {
+                Err(AssetReaderError::NotFound(_)) => continue,
+                result => return result,
+            }
+        }
+        Err(AssetReaderError::NotFound(path.to_owned()))
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        for layer in &self.layers {
+            match layer.read_meta(path).await {
+                Err(AssetReaderError::NotFound(_)) => {}
+                result => return result,
+            }
+            // This layer doesn't have metadata for the asset, but if it has the asset, the
+            // metadata of the lower layers doesn't apply to it.
+            match layer.read(path).await {
+                Err(AssetReaderError::NotFound(_)) => {}
+                Err(err) => return Err(err),
+                Ok(_) => break,
+            }
+        }
+        Err(AssetReaderError::NotFound(path.to_owned()))
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        let mut found = false;
+        let mut seen = HashSet::<PathBuf>::default();
+        let mut entries = Vec::new();
+        for layer in &self.layers {
+            let stream = match layer.read_directory(path).await {
+                Ok(stream) => stream,
+                Err(AssetReaderError::NotFound(_)) => continue,
+                Err(err) => return Err(err),
+            };
+            found = true;
+            let layer_entries = stream.collect::<Vec<_>>().await;
+            entries.extend(
+                layer_entries
+                    .into_iter()
+                    .filter(|entry| seen.insert(entry.clone())),
+            );
+        }
+        if !found {
+            return Err(AssetReaderError::NotFound(path.to_owned()));
+        }
+        Ok(Box::new(stream::iter(entries)))
+    }
+
+    async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        let mut result = Err(AssetReaderError::NotFound(path.to_owned()));
+        for layer in &self.layers {
+            match layer.is_directory(path).await {
+                Ok(true) => return Ok(true),
+                Ok(false) => result = Ok(false),
+                Err(AssetReaderError::NotFound(_)) => {}
+                Err(err) => return Err(err),
+            }
+        }
+        result
+    }
+}
+
+/// Keeps the [`AssetWatcher`]s of the layers of an overlaid source alive.
+struct OverlayAssetWatcher {
+    _watchers: Vec<Box<dyn AssetWatcher>>,
+}
+
+impl AssetWatcher for OverlayAssetWatcher {}
+
+impl AssetSourceBuilder {
+    /// Puts the readers of `layer` on top of the readers of this source, so that its assets
+    /// shadow the assets with the same path. See [`OverlayAssetReader`] for how the layers are
+    /// read.
+    ///
+    /// - The processed reader of `layer`, if any, is put on top of the processed reader of this
+    ///   source.
+    /// - The watchers of both sources are kept, and the events of all layers are forwarded. As
+    ///   the assets of a layer can be shadowed, removals of assets that another layer still
+    ///   provides are forwarded as modifications.
+    /// - The writers of this source are kept, and the writers of `layer` are ignored, so that
+    ///   overlays are never modified.
+    ///
+    /// Calling this again adds a layer above the previous ones.
+    pub fn with_overlay(mut self, mut layer: AssetSourceBuilder) -> Self {
+        let (reader, merged_reader) = overlay_readers(layer.reader.take(), self.reader.take());
+        let (processed_reader, merged_processed_reader) =
+            overlay_readers(layer.processed_reader.take(), self.processed_reader.take());
+        self.reader = reader;
+        self.processed_reader = processed_reader;
+        self.watcher = overlay_watchers(layer.watcher.take(), self.watcher.take(), merged_reader);
+        self.processed_watcher = overlay_watchers(
+            layer.processed_watcher.take(),
+            self.processed_watcher.take(),
+            merged_processed_reader,
+        );
+        self.watch_warning = self.watch_warning.or(layer.watch_warning);
+        self.processed_watch_warning = self
+            .processed_watch_warning
+            .or(layer.processed_watch_warning);
+        self
+    }
+}
+
+type ReaderBuilder = Box<dyn FnMut() -> Box<dyn ErasedAssetReader> + Send + Sync>;
+
+type SharedReaderBuilder = Arc<Mutex<ReaderBuilder>>;
+
+type WatcherBuilder =
+    Box<dyn FnMut(Sender<AssetSourceEvent>) -> Option<Box<dyn AssetWatcher>> + Send + Sync>;
+
+/// Returns the builder of the reader stacking `top` on `bottom`. When both layers have a reader,
+/// the builder of the merged reader is also returned, so that the watchers can use it.
+fn overlay_readers(
+    top: Option<ReaderBuilder>,
+    bottom: Option<ReaderBuilder>,
+) -> (Option<ReaderBuilder>, Option<SharedReaderBuilder>) {
+    match (top, bottom) {
+        (Some(mut top), Some(mut bottom)) => {
+            let merged: ReaderBuilder =
+                Box::new(move || Box::new(OverlayAssetReader::new(vec![top(), bottom()])));
+            let merged = Arc::new(Mutex::new(merged));
+            let shared = merged.clone();
+            (Some(Box::new(move || (shared.lock())())), Some(merged))
+        }
+        (top, bottom) => (top.or(bottom), None),
+    }
+}
+
+fn overlay_watchers(
+    top: Option<WatcherBuilder>,
+    bottom: Option<WatcherBuilder>,
+    merged_reader: Option<SharedReaderBuilder>,
+) -> Option<WatcherBuilder> {
+    let (mut top, mut bottom) = match (top, bottom) {
+        (None, None) => return None,
+        // Without several readers, no asset is shadowed and the events can be sent directly.
+        (top, bottom) if merged_reader.is_none() && (top.is_none() || bottom.is_none()) => {
+            return top.or(bottom)
+        }
+        layers => layers,
+    };
+    Some(Box::new(move |sender| {
+        let sender = match &merged_reader {
+            Some(merged_reader) => check_removed_paths(sender, (merged_reader.lock())()),
+            None => sender,
+        };
+        let watchers = [
+            top.as_mut().and_then(|top| top(sender.clone())),
+            bottom.as_mut().and_then(|bottom| bottom(sender)),
+        ]
+        .into_iter()
+        .flatten()
+        .collect::<Vec<_>>();
+        if watchers.is_empty() {
+            None
+        } else {
+            Some(Box::new(OverlayAssetWatcher {
+                _watchers: watchers,
+            }))
+        }
+    }))
+}
+
+/// Returns a sender forwarding the events of the layers to `sender`, once `reader` has checked
+/// them. The events are forwarded from another thread, which stops when the returned sender and
+/// its clones are dropped.
+///
+/// A removal from one layer doesn't remove the asset if another layer still provides it: the
+/// asset now comes from that layer, so the removal is forwarded as a modification instead.
+/// Removed folders that another layer still provides are added again after their removal.
+#[cfg(not(target_arch = "wasm32"))]
+fn check_removed_paths(
+    sender: Sender<AssetSourceEvent>,
+    reader: Box<dyn ErasedAssetReader>,
+) -> Sender<AssetSourceEvent> {
+    let (layer_sender, receiver) = crossbeam_channel::unbounded();
+    std::thread::spawn(move || {
+        for event in receiver {
+            for event in bevy_tasks::block_on(check_removed_path(&*reader, event)) {
+                if sender.send(event).is_err() {
+                    return;
+                }
+            }
+        }
+    });
+    layer_sender
+}
+
+/// Threads are not available on `wasm32`, so the events are forwarded unchecked.
+#[cfg(target_arch = "wasm32")]
+fn check_removed_paths(
+    sender: Sender<AssetSourceEvent>,
+    _reader: Box<dyn ErasedAssetReader>,
+) -> Sender<AssetSourceEvent> {
+    sender
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+async fn check_removed_path(
+    reader: &dyn ErasedAssetReader,
+    event: AssetSourceEvent,
+) -> Vec<AssetSourceEvent> {
+    let is_directory = |path| async move { matches!(reader.is_directory(path).await, Ok(true)) };
+    match event {
+        AssetSourceEvent::RemovedAsset(path) if reader.read(&path).await.is_ok() => {
+            vec![AssetSourceEvent::ModifiedAsset(path)]
+        }
+        AssetSourceEvent::RemovedMeta(path) if reader.read_meta(&path).await.is_ok() => {
+            vec![AssetSourceEvent::ModifiedMeta(path)]
+        }
+        AssetSourceEvent::RenamedAsset { old, new } if reader.read(&old).await.is_ok() => {
+            vec![
+                AssetSourceEvent::ModifiedAsset(old),
+                AssetSourceEvent::AddedAsset(new),
+            ]
+        }
+        // The removed folder is processed again from the layers that still provide it.
+        AssetSourceEvent::RemovedFolder(path) if is_directory(&path).await => vec![
+            AssetSourceEvent::RemovedFolder(path.clone()),
+            AssetSourceEvent::AddedFolder(path),
+        ],
+        AssetSourceEvent::RenamedFolder { old, new } if is_directory(&old).await => vec![
+            AssetSourceEvent::RenamedFolder {
+                old: old.clone(),
+                new,
+            },
+            AssetSourceEvent::AddedFolder(old),
+        ],
+        AssetSourceEvent::RemovedUnknown { path, is_meta } => {
+            if is_directory(&path).await {
+                vec![
+                    AssetSourceEvent::RemovedFolder(path.clone()),
+                    AssetSourceEvent::AddedFolder(path),
+                ]
+            } else if is_meta && reader.read_meta(&path).await.is_ok() {
+                vec![AssetSourceEvent::ModifiedMeta(path)]
+            } else if !is_meta && reader.read(&path).await.is_ok() {
+                vec![AssetSourceEvent::ModifiedAsset(path)]
+            } else {
+                vec![AssetSourceEvent::RemovedUnknown { path, is_meta }]
+            }
+        }
+        event => vec![event],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::OverlayAssetWatcher;
+    use crate::io::{
+        memory::{Dir, MemoryAssetReader},
+        overlay::OverlayAssetReader,
+        AssetReader, AssetReaderError, AssetSourceBuilder, AssetSourceEvent, AssetWatcher,
+    };
+    use bevy_tasks::block_on;
+    use core::time::Duration;
+    use crossbeam_channel::Receiver;
+    use futures_lite::{AsyncReadExt, StreamExt};
+    use std::path::{Path, PathBuf};
+
+    fn reader(dirs: &[&Dir]) -> OverlayAssetReader {
+        dirs.iter()
+            .fold(OverlayAssetReader::default(), |reader, dir| {
+                reader.with_layer(MemoryAssetReader {
+                    root: (*dir).clone(),
+                })
+            })
+    }
+
+    fn read_text(reader: &OverlayAssetReader, path: &str) -> Option<String> {
+        block_on(async {
+            let mut bytes = Vec::new();
+            let mut asset = reader.read(Path::new(path)).await.ok()?;
+            asset.read_to_end(&mut bytes).await.unwrap();
+            Some(String::from_utf8(bytes).unwrap())
+        })
+    }
+
+    /// Receives `count` events, which are forwarded from another thread.
+    fn receive(receiver: &Receiver<AssetSourceEvent>, count: usize) -> Vec<AssetSourceEvent> {
+        (0..count)
+            .map(|_| receiver.recv_timeout(Duration::from_secs(10)).unwrap())
+            .collect()
+    }
+
+    fn watcher(
+        events: Vec<AssetSourceEvent>,
+    ) -> impl FnMut(crossbeam_channel::Sender<AssetSourceEvent>) -> Option<Box<dyn AssetWatcher>>
+    {
+        move |sender| {
+            for event in events.clone() {
+                sender.send(event).unwrap();
+            }
+            Some(Box::new(OverlayAssetWatcher {
+                _watchers: Vec::new(),
+            }))
+        }
+    }
+
+    #[test]
+    fn first_layer_wins() {
+        let patch = Dir::default();
+        let base = Dir::default();
+        patch.insert_asset_text(Path::new("a.txt"), "patched");
+        base.insert_asset_text(Path::new("a.txt"), "base");
+        base.insert_asset_text(Path::new("b.txt"), "base");
+        let reader = reader(&[&patch, &base]);
+
+        assert_eq!(read_text(&reader, "a.txt").as_deref(), Some("patched"));
+        assert_eq!(read_text(&reader, "b.txt").as_deref(), Some("base"));
+        assert_eq!(read_text(&reader, "c.txt"), None);
+    }
+
+    #[test]
+    fn meta_comes_from_the_asset_layer() {
+        let patch = Dir::default();
+        let base = Dir::default();
+        patch.insert_asset_text(Path::new("a.txt"), "patched");
+        base.insert_asset_text(Path::new("a.txt"), "base");
+        base.insert_meta_text(Path::new("a.txt"), "base meta");
+        base.insert_asset_text(Path::new("b.txt"), "base");
+        base.insert_meta_text(Path::new("b.txt"), "base meta");
+        let reader = reader(&[&patch, &base]);
+
+        block_on(async {
+            assert!(matches!(
+                reader.read_meta_bytes(Path::new("a.txt")).await,
+                Err(AssetReaderError::NotFound(_))
+            ));
+            assert_eq!(
+                reader.read_meta_bytes(Path::new("b.txt")).await.unwrap(),
+                b"base meta"
+            );
+        });
+    }
+
+    #[test]
+    fn directories_are_merged() {
+        let patch = Dir::default();
+        let base = Dir::default();
+        patch.insert_asset_text(Path::new("x/a.txt"), "patched");
+        patch.insert_asset_text(Path::new("y/c.txt"), "patched");
+        base.insert_asset_text(Path::new("x/a.txt"), "base");
+        base.insert_asset_text(Path::new("x/b.txt"), "base");
+        let reader = reader(&[&patch, &base]);
+
+        block_on(async {
+            let mut entries = reader
+                .read_directory(Path::new("x"))
+                .await
+                .unwrap()
+                .collect::<Vec<_>>()
+                .await;
+            entries.sort();
+            assert_eq!(
+                entries,
+                [PathBuf::from("x/a.txt"), PathBuf::from("x/b.txt")]
+            );
+            assert!(reader.is_directory(Path::new("y")).await.unwrap());
+            assert!(!reader.is_directory(Path::new("x/b.txt")).await.unwrap());
+            assert!(reader.read_directory(Path::new("z")).await.is_err());
+        });
+    }
+
+    #[test]
+    fn builder_forwards_events_of_all_layers() {
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        let mut builder = AssetSourceBuilder::default()
+            .with_reader(|| Box::new(MemoryAssetReader::default()))
+            .with_watcher(watcher(vec![AssetSourceEvent::ModifiedAsset(
+                "base.txt".into(),
+            )]))
+            .with_overlay(
+                AssetSourceBuilder::default()
+                    .with_reader(|| Box::new(MemoryAssetReader::default()))
+                    .with_watcher(watcher(vec![AssetSourceEvent::ModifiedAsset(
+                        "patch.txt".into(),
+                    )])),
+            );
+
+        assert!(builder.reader.as_mut().is_some());
+        assert!(builder.watcher.as_mut().unwrap()(sender).is_some());
+        assert_eq!(
+            receive(&receiver, 2),
+            [
+                AssetSourceEvent::ModifiedAsset("patch.txt".into()),
+                AssetSourceEvent::ModifiedAsset("base.txt".into()),
+            ]
+        );
+    }
+
+    #[test]
+    fn removals_of_shadowed_paths_are_modifications() {
+        let base = Dir::default();
+        base.insert_asset_text(Path::new("a.txt"), "base");
+        base.insert_meta_text(Path::new("a.txt"), "base meta");
+        base.insert_asset_text(Path::new("x/b.txt"), "base");
+        let root = base.clone();
+        let (sender, receiver) = crossbeam_channel::unbounded();
+        let mut builder = AssetSourceBuilder::default()
+            .with_reader(move || Box::new(MemoryAssetReader { root: root.clone() }))
+            .with_overlay(
+                AssetSourceBuilder::default()
+                    .with_reader(|| Box::new(MemoryAssetReader::default()))
+                    .with_watcher(watcher(vec![
+                        AssetSourceEvent::RemovedAsset("a.txt".into()),
+                        AssetSourceEvent::RemovedMeta("a.txt".into()),
+                        AssetSourceEvent::RemovedAsset("c.txt".into()),
+                        AssetSourceEvent::RenamedAsset {
+                            old: "a.txt".into(),
+                            new: "d.txt".into(),
+                        },
+                        AssetSourceEvent::RemovedFolder("x".into()),
+                    ])),
+            );
+
+        assert!(builder.watcher.as_mut().unwrap()(sender).is_some());
+        assert_eq!(
+            receive(&receiver, 7),
+            [
+                AssetSourceEvent::ModifiedAsset("a.txt".into()),
+                AssetSourceEvent::ModifiedMeta("a.txt".into()),
+                AssetSourceEvent::RemovedAsset("c.txt".into()),
+                AssetSourceEvent::ModifiedAsset("a.txt".into()),
+                AssetSourceEvent::AddedAsset("d.txt".into()),
+                AssetSourceEvent::RemovedFolder("x".into()),
+                AssetSourceEvent::AddedFolder("x".into()),
+            ]
+        );
+    }
+}
@@ -0,0 +1,999 @@
+//! Stores the assets of a source in a single indexed pack file, instead of many loose files.
+//!
+//! An [`AssetPack`] can be read with a [`PackAssetReader`] and written with a
+//! [`PackAssetWriter`], which share the pack through an [`Arc`]. This makes it possible for the
+//! [`AssetProcessor`](crate::processor::AssetProcessor) to write its processed output into a pack,
+//! using [`AssetSourceBuilder::with_processed_pack`], so that release builds read every processed
+//! asset from one file:
+//!
+//! ```no_run
+//! # use bevy_app::prelude::*;
+//! # use bevy_asset::{prelude::*, io::{AssetSource, AssetSourceBuilder, pack::{AssetPack, PackAssetReader}}};
+//! # use std::sync::Arc;
+//! let pack = Arc::new(AssetPack::open("assets.pack").unwrap());
+//! App::new().register_asset_source(
+//!     "packed",
+//!     AssetSourceBuilder::default()
+//!         .with_reader(move || Box::new(PackAssetReader::new(pack.clone()))),
+//! );
+//! ```
+//!
+//! # Format
+//!
+//! A pack starts with the bytes of its entries, followed by its index, and ends with a footer.
+//! All integers are little-endian.
+//! - Each index entry is a kind byte (`0` for an asset, `1` for asset metadata and `2` for an
+//!   empty directory), the length of the path as a `u32`, the path in UTF-8 with `/` separators,
+//!   then the offset and the length of the entry bytes as `u64`s.
+//! - The index starts with the number of entries as a `u32`.
+//! - The footer is the offset of the index as a `u64`, followed by [`AssetPack::MAGIC`].
+//!
+//! Writing to a pack appends the new bytes, and the index is only written when the pack is
+//! [flushed](AssetPack::flush) or dropped. The processor flushes its processed writers whenever it
+//! finishes processing assets. Pack files are written through a copy of them, with a
+//! `.tmp` extension appended, which replaces them once its index is written, so that they always
+//! hold a valid pack. The bytes of removed or replaced entries are not reclaimed.
+
+use crate::io::{
+    AssetReader, AssetReaderError, AssetSourceBuilder, AssetWriter, AssetWriterError, PathStream,
+    Reader, VecReader, Writer,
+};
+use alloc::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+use bevy_utils::tracing::error;
+use core::{
+    pin::Pin,
+    task::{Context, Poll},
+};
+use futures_io::AsyncWrite;
+use futures_lite::stream;
+use parking_lot::{Mutex, RwLock};
+use std::{
+    io::{Error, ErrorKind},
+    path::{Component, Path, PathBuf},
+};
+
+/// The location of the bytes of an entry in an [`AssetPack`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct PackEntry {
+    offset: u64,
+    len: u64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum PackEntryKind {
+    Asset = 0,
+    Meta = 1,
+    Directory = 2,
+}
+
+#[derive(Default, Debug)]
+struct PackIndex {
+    assets: BTreeMap<PathBuf, PackEntry>,
+    metas: BTreeMap<PathBuf, PackEntry>,
+    /// Empty directories created with [`AssetWriter::create_directory`]. Other directories are
+    /// implied by the paths of the entries.
+    directories: BTreeSet<PathBuf>,
+    /// The end of the entry bytes, where the next entry is appended.
+    end: u64,
+    /// Whether the index changed since it was last written.
+    dirty: bool,
+}
+
+impl PackIndex {
+    fn entries(&self, kind: PackEntryKind) -> &BTreeMap<PathBuf, PackEntry> {
+        match kind {
+            PackEntryKind::Meta => &self.metas,
+            _ => &self.assets,
+        }
+    }
+
+    fn entries_mut(&mut self, kind: PackEntryKind) -> &mut BTreeMap<PathBuf, PackEntry> {
+        match kind {
+            PackEntryKind::Meta => &mut self.metas,
+            _ => &mut self.assets,
+        }
+    }
+
+    fn paths(&self) -> impl Iterator<Item = &PathBuf> {
+        self.assets
+            .keys()
+            .chain(self.metas.keys())
+            .chain(self.directories.iter())
+    }
+
+    fn is_directory(&self, path: &Path) -> bool {
+        path.as_os_str().is_empty()
+            || self
+                .paths()
+                .any(|entry| entry != path && entry.starts_with(path))
+            || self.directories.contains(path)
+    }
+
+    fn remove_in_directory(&mut self, path: &Path) {
+        let in_directory = |entry: &PathBuf| !entry.starts_with(path);
+        self.assets.retain(|entry, _| in_directory(entry));
+        self.metas.retain(|entry, _| in_directory(entry));
+        self.directories.retain(in_directory);
+        self.dirty = true;
+    }
+
+    fn encode(&self) -> Vec<u8> {
+        let entries = self
+            .assets
+            .iter()
+            .map(|(path, entry)| (PackEntryKind::Asset, path, *entry))
+            .chain(
+                self.metas
+                    .iter()
+                    .map(|(path, entry)| (PackEntryKind::Meta, path, *entry)),
+            )
+            .chain(self.directories.iter().map(|path| {
+                (
+                    PackEntryKind::Directory,
+                    path,
+                    PackEntry { offset: 0, len: 0 },
+                )
+            }));
+
+        let mut bytes = Vec::new();
+        let count = self.assets.len() + self.metas.len() + self.directories.len();
+        bytes.extend_from_slice(&(count as u32).to_le_bytes());
+        for (kind, path, entry) in entries {
+            let path = encode_path(path);
+            bytes.push(kind as u8);
+            bytes.extend_from_slice(&(path.len() as u32).to_le_bytes());
+            bytes.extend_from_slice(path.as_bytes());
+            bytes.extend_from_slice(&entry.offset.to_le_bytes());
+            bytes.extend_from_slice(&entry.len.to_le_bytes());
+        }
+        bytes.extend_from_slice(&self.end.to_le_bytes());
+        bytes.extend_from_slice(AssetPack::MAGIC);
+        bytes
+    }
+
+    fn decode(mut bytes: &[u8], end: u64) -> Result<Self, Error> {
+        fn take<'a>(bytes: &mut &'a [u8], len: usize) -> Result<&'a [u8], Error> {
+            if bytes.len() < len {
+                return Err(invalid_pack("truncated index"));
+            }
+            let (taken, rest) = bytes.split_at(len);
+            *bytes = rest;
+            Ok(taken)
+        }
+        fn take_u32(bytes: &mut &[u8]) -> Result<u32, Error> {
+            Ok(u32::from_le_bytes(take(bytes, 4)?.try_into().unwrap()))
+        }
+        fn take_u64(bytes: &mut &[u8]) -> Result<u64, Error> {
+            Ok(u64::from_le_bytes(take(bytes, 8)?.try_into().unwrap()))
+        }
+
+        let mut index = PackIndex {
+            end,
+            ..Default::default()
+        };
+        for _ in 0..take_u32(&mut bytes)? {
+            let kind = take(&mut bytes, 1)?[0];
+            let path_len = take_u32(&mut bytes)? as usize;
+            let path = core::str::from_utf8(take(&mut bytes, path_len)?)
+                .map_err(|_| invalid_pack("path is not UTF-8"))?;
+            let path = decode_path(path);
+            let entry = PackEntry {
+                offset: take_u64(&mut bytes)?,
+                len: take_u64(&mut bytes)?,
+            };
+            if entry.offset.saturating_add(entry.len) > end {
+                return Err(invalid_pack("entry is out of bounds"));
+            }
+            match kind {
+                0 => index.assets.insert(path, entry),
+                1 => index.metas.insert(path, entry),
+                2 => {
+                    index.directories.insert(path);
+                    None
+                }
+                _ => return Err(invalid_pack("unknown entry kind")),
+            };
+        }
+        Ok(index)
+    }
+}
+
+fn encode_path(path: &Path) -> String {
+    path.components()
+        .filter_map(|component| match component {
+            Component::Normal(name) => Some(name.to_string_lossy()),
+            _ => None,
+        })
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn decode_path(path: &str) -> PathBuf {
+    path.split('/').filter(|name| !name.is_empty()).collect()
+}
+
+fn normalize(path: &Path) -> PathBuf {
+    decode_path(&encode_path(path))
+}
+
+fn invalid_pack(reason: &str) -> Error {
+    Error::new(
+        ErrorKind::InvalidData,
+        format!("invalid asset pack: {reason}"),
+    )
+}
+
+/// The bytes of an [`AssetPack`].
+enum PackStorage {
+    Memory(Vec<u8>),
+    #[cfg(not(target_arch = "wasm32"))]
+    File {
+        path: PathBuf,
+        file: std::fs::File,
+        /// The copy of the file written to since the pack was last flushed, if any.
+        pending: Option<std::fs::File>,
+    },
+}
+
+impl PackStorage {
+    #[cfg(not(target_arch = "wasm32"))]
+    fn file(path: &Path, file: std::fs::File) -> Self {
+        PackStorage::File {
+            path: path.to_owned(),
+            file,
+            pending: None,
+        }
+    }
+
+    fn len(&mut self) -> Result<u64, Error> {
+        match self {
+            PackStorage::Memory(bytes) => Ok(bytes.len() as u64),
+            #[cfg(not(target_arch = "wasm32"))]
+            PackStorage::File { file, .. } => Ok(file.metadata()?.len()),
+        }
+    }
+
+    fn read_at(&mut self, offset: u64, len: u64) -> Result<Vec<u8>, Error> {
+        match self {
+            PackStorage::Memory(bytes) => usize::try_from(offset)
+                .ok()
+                .zip(usize::try_from(offset + len).ok())
+                .and_then(|(start, end)| bytes.get(start..end))
+                .map(<[u8]>::to_vec)
+                .ok_or_else(|| invalid_pack("entry is out of bounds")),
+            #[cfg(not(target_arch = "wasm32"))]
+            PackStorage::File { file, pending, .. } => {
+                use std::io::{Read, Seek, SeekFrom};
+                let file = pending.as_mut().unwrap_or(file);
+                let mut bytes = vec![0; len as usize];
+                file.seek(SeekFrom::Start(offset))?;
+                file.read_exact(&mut bytes)?;
+                Ok(bytes)
+            }
+        }
+    }
+
+    /// Writes the bytes of an entry at `end`, the end of the entry bytes.
+    fn write_entry(&mut self, end: u64, bytes: &[u8]) -> Result<(), Error> {
+        match self {
+            PackStorage::Memory(memory) => {
+                memory.truncate(end as usize);
+                memory.extend_from_slice(bytes);
+                Ok(())
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            PackStorage::File {
+                path,
+                file,
+                pending,
+            } => {
+                use std::io::{Seek, SeekFrom, Write};
+                let pending = Self::pending_file(path, file, pending, end)?;
+                pending.seek(SeekFrom::Start(end))?;
+                pending.write_all(bytes)
+            }
+        }
+    }
+
+    /// Writes `index` after the entry bytes ending at `end`, replacing the previous index.
+    fn write_index(&mut self, end: u64, index: &[u8]) -> Result<(), Error> {
+        match self {
+            PackStorage::Memory(memory) => {
+                memory.truncate(end as usize);
+                memory.extend_from_slice(index);
+                Ok(())
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            PackStorage::File {
+                path,
+                file,
+                pending,
+            } => {
+                use std::io::{Seek, SeekFrom, Write};
+                let written = Self::pending_file(path, file, pending, end)?;
+                written.set_len(end)?;
+                written.seek(SeekFrom::Start(end))?;
+                written.write_all(index)?;
+                written.sync_all()?;
+                std::fs::rename(pending_path(path), &*path)?;
+                *file = pending.take().unwrap();
+                Ok(())
+            }
+        }
+    }
+
+    /// Returns the copy of the pack file that is written to, creating it from the entry bytes
+    /// ending at `end` if the pack was flushed since it was last written to.
+    #[cfg(not(target_arch = "wasm32"))]
+    fn pending_file<'a>(
+        path: &Path,
+        file: &mut std::fs::File,
+        pending: &'a mut Option<std::fs::File>,
+        end: u64,
+    ) -> Result<&'a mut std::fs::File, Error> {
+        use std::io::{Read, Seek, SeekFrom};
+        if pending.is_none() {
+            let mut copy = std::fs::OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .truncate(true)
+                .open(pending_path(path))?;
+            file.seek(SeekFrom::Start(0))?;
+            std::io::copy(&mut file.by_ref().take(end), &mut copy)?;
+            *pending = Some(copy);
+        }
+        Ok(pending.as_mut().unwrap())
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+fn pending_path(path: &Path) -> PathBuf {
+    let mut pending = path.as_os_str().to_owned();
+    pending.push(".tmp");
+    pending.into()
+}
+
+/// A single file storing assets and their metadata at virtual paths, with random access to
+/// each of them.
+///
+/// See the [module documentation](self) for how to use it and its format.
+pub struct AssetPack {
+    storage: Mutex<PackStorage>,
+    index: RwLock<PackIndex>,
+}
+
+impl Default for AssetPack {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl core::fmt::Debug for AssetPack {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let index = self.index.read();
+        f.debug_struct("AssetPack")
+            .field("assets", &index.assets.len())
+            .field("metas", &index.metas.len())
+            .finish()
+    }
+}
+
+impl AssetPack {
+    /// The bytes ending every pack.
+    pub const MAGIC: &'static [u8; 8] = b"BEVYPAK1";
+
+    const FOOTER_LEN: u64 = 16;
+
+    /// Creates an empty pack stored in memory.
+    pub fn new() -> Self {
+        Self {
+            storage: Mutex::new(PackStorage::Memory(Vec::new())),
+            index: RwLock::new(PackIndex {
+                dirty: true,
+                ..Default::default()
+            }),
+        }
+    }
+
+    /// Reads a pack from its bytes, for example included in the executable with
+    /// [`include_bytes`].
+    pub fn from_bytes(bytes: impl Into<Vec<u8>>) -> Result<Self, Error> {
+        Self::from_storage(PackStorage::Memory(bytes.into()))
+    }
+
+    /// Opens the pack file at `path` for reading.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn open(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        Self::from_storage(PackStorage::file(path, std::fs::File::open(path)?))
+    }
+
+    /// Opens the pack file at `path` for reading and writing, creating an empty pack if the file
+    /// does not exist or is empty.
+    ///
+    /// Returns an error if the file is not a valid pack, instead of replacing it.
+    #[cfg(not(target_arch = "wasm32"))]
+    pub fn open_or_create(path: impl AsRef<Path>) -> Result<Self, Error> {
+        let path = path.as_ref();
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let file = std::fs::OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        let mut storage = PackStorage::file(path, file);
+        if storage.len()? == 0 {
+            return Ok(Self {
+                storage: Mutex::new(storage),
+                index: RwLock::new(PackIndex {
+                    dirty: true,
+                    ..Default::default()
+                }),
+            });
+        }
+        Self::from_storage(storage)
+    }
+
+    fn from_storage(mut storage: PackStorage) -> Result<Self, Error> {
+        let index = Self::read_index(&mut storage)?;
+        Ok(Self {
+            storage: Mutex::new(storage),
+            index: RwLock::new(index),
+        })
+    }
+
+    fn read_index(storage: &mut PackStorage) -> Result<PackIndex, Error> {
+        let len = storage.len()?;
+        if len < Self::FOOTER_LEN {
+            return Err(invalid_pack("missing footer"));
+        }
+        let footer = storage.read_at(len - Self::FOOTER_LEN, Self::FOOTER_LEN)?;
+        if &footer[8..] != Self::MAGIC {
+            return Err(invalid_pack("wrong magic bytes"));
+        }
+        let end = u64::from_le_bytes(footer[..8].try_into().unwrap());
+        if end > len - Self::FOOTER_LEN {
+            return Err(invalid_pack("index is out of bounds"));
+        }
+        let index = storage.read_at(end, len - Self::FOOTER_LEN - end)?;
+        PackIndex::decode(&index, end)
+    }
+
+    /// Returns the paths of the assets in the pack.
+    pub fn asset_paths(&self) -> Vec<PathBuf> {
+        self.index.read().assets.keys().cloned().collect()
+    }
+
+    /// Returns true if the pack contains an asset at `path`.
+    pub fn contains_asset(&self, path: &Path) -> bool {
+        self.index.read().assets.contains_key(&normalize(path))
+    }
+
+    /// Returns the bytes of the asset at `path`.
+    pub fn read_asset(&self, path: &Path) -> Result<Vec<u8>, AssetReaderError> {
+        self.read_entry(PackEntryKind::Asset, path)
+    }
+
+    /// Returns the bytes of the metadata of the asset at `path`.
+    pub fn read_meta(&self, path: &Path) -> Result<Vec<u8>, AssetReaderError> {
+        self.read_entry(PackEntryKind::Meta, path)
+    }
+
+    fn read_entry(&self, kind: PackEntryKind, path: &Path) -> Result<Vec<u8>, AssetReaderError> {
+        let entry = self
+            .index
+            .read()
+            .entries(kind)
+            .get(&normalize(path))
+            .copied()
+            .ok_or_else(|| AssetReaderError::NotFound(path.to_owned()))?;
+        Ok(self.storage.lock().read_at(entry.offset, entry.len)?)
+    }
+
+    /// Stores `bytes` as the asset at `path`, replacing any previous asset.
+    pub fn write_asset(&self, path: &Path, bytes: &[u8]) -> Result<(), Error> {
+        self.write_entry(PackEntryKind::Asset, path, bytes)
+    }
+
+    /// Stores `bytes` as the metadata of the asset at `path`, replacing any previous metadata.
+    pub fn write_meta(&self, path: &Path, bytes: &[u8]) -> Result<(), Error> {
+        self.write_entry(PackEntryKind::Meta, path, bytes)
+    }
+
+    fn write_entry(&self, kind: PackEntryKind, path: &Path, bytes: &[u8]) -> Result<(), Error> {
+        let mut index = self.index.write();
+        let entry = PackEntry {
+            offset: index.end,
+            len: bytes.len() as u64,
+        };
+        self.storage.lock().write_entry(entry.offset, bytes)?;
+        index.end += entry.len;
+        index.entries_mut(kind).insert(normalize(path), entry);
+        index.dirty = true;
+        Ok(())
+    }
+
+    /// Writes the index of the pack, if it changed since it was last written. Pack files are
+    /// replaced with the written copy of them at once.
+    ///
+    /// This is also done when the pack is dropped, but errors are then only logged.
+    pub fn flush(&self) -> Result<(), Error> {
+        let mut index = self.index.write();
+        if index.dirty {
+            self.storage
+                .lock()
+                .write_index(index.end, &index.encode())?;
+            index.dirty = false;
+        }
+        Ok(())
+    }
+
+    /// Returns the bytes of the pack, if it is stored in memory.
+    pub fn to_bytes(&self) -> Option<Vec<u8>> {
+        let index = self.index.read();
+        match &*self.storage.lock() {
+            PackStorage::Memory(bytes) => {
+                let mut bytes = bytes[..index.end as usize].to_vec();
+                bytes.extend_from_slice(&index.encode());
+                Some(bytes)
+            }
+            #[cfg(not(target_arch = "wasm32"))]
+            PackStorage::File { .. } => None,
+        }
+    }
+}
+
+impl Drop for AssetPack {
+    fn drop(&mut self) {
+        if let Err(err) = self.flush() {
+            error!("Failed to write the index of an asset pack: {err}");
+        }
+    }
+}
+
+/// An [`AssetReader`] reading the assets of an [`AssetPack`].
+#[derive(Clone, Debug)]
+pub struct PackAssetReader {
+    pack: Arc<AssetPack>,
+}
+
+impl PackAssetReader {
+    /// Creates a reader for the assets of `pack`.
+    pub fn new(pack: Arc<AssetPack>) -> Self {
+        Self { pack }
+    }
+}
+
+impl AssetReader for PackAssetReader {
+    async fn read<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        self.pack.read_asset(path).map(VecReader::new)
+    }
+
+    async fn read_meta<'a>(&'a self, path: &'a Path) -> Result<impl Reader + 'a, AssetReaderError> {
+        self.pack.read_meta(path).map(VecReader::new)
+    }
+
+    async fn read_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<Box<PathStream>, AssetReaderError> {
+        let directory = normalize(path);
+        let index = self.pack.index.read();
+        if !index.is_directory(&directory) {
+            return Err(AssetReaderError::NotFound(path.to_owned()));
+        }
+        // Like loose files, metadata is not listed.
+        let mut children = BTreeSet::new();
+        for entry in index.assets.keys().chain(index.directories.iter()) {
+            if let Ok(relative) = entry.strip_prefix(&directory) {
+                if let Some(child) = relative.components().next() {
+                    children.insert(directory.join(child));
+                }
+            }
+        }
+        Ok(Box::new(stream::iter(children)))
+    }
+
+    async fn is_directory<'a>(&'a self, path: &'a Path) -> Result<bool, AssetReaderError> {
+        Ok(self.pack.index.read().is_directory(&normalize(path)))
+    }
+}
+
+/// An [`AssetWriter`] writing assets into an [`AssetPack`].
+///
+/// Written assets are stored when their [`Writer`] is flushed, closed or dropped, and the index of
+/// the pack is written when the writer itself is [flushed](AssetWriter::flush).
+#[derive(Clone, Debug)]
+pub struct PackAssetWriter {
+    pack: Arc<AssetPack>,
+}
+
+impl PackAssetWriter {
+    /// Creates a writer for the assets of `pack`.
+    pub fn new(pack: Arc<AssetPack>) -> Self {
+        Self { pack }
+    }
+
+    fn rename_entry(
+        &self,
+        kind: PackEntryKind,
+        old_path: &Path,
+        new_path: &Path,
+    ) -> Result<(), AssetWriterError> {
+        let mut index = self.pack.index.write();
+        let entries = index.entries_mut(kind);
+        let entry = entries
+            .remove(&normalize(old_path))
+            .ok_or_else(|| not_found(old_path))?;
+        entries.insert(normalize(new_path), entry);
+        index.dirty = true;
+        Ok(())
+    }
+
+    fn remove_entry(&self, kind: PackEntryKind, path: &Path) -> Result<(), AssetWriterError> {
+        let mut index = self.pack.index.write();
+        index
+            .entries_mut(kind)
+            .remove(&normalize(path))
+            .ok_or_else(|| not_found(path))?;
+        index.dirty = true;
+        Ok(())
+    }
+}
+
+fn not_found(path: &Path) -> Error {
+    Error::new(ErrorKind::NotFound, format!("{path:?} is not in the pack"))
+}
+
+/// A [`Writer`] buffering the bytes of an entry, and storing them in the pack when flushed.
+struct PackEntryWriter {
+    pack: Arc<AssetPack>,
+    kind: PackEntryKind,
+    path: PathBuf,
+    bytes: Vec<u8>,
+    stored: bool,
+}
+
+impl PackEntryWriter {
+    fn store(&mut self) -> Result<(), Error> {
+        if !self.stored {
+            self.pack.write_entry(self.kind, &self.path, &self.bytes)?;
+            self.stored = true;
+        }
+        Ok(())
+    }
+}
+
+impl AsyncWrite for PackEntryWriter {
+    fn poll_write(
+        self: Pin<&mut Self>,
+        _cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<std::io::Result<usize>> {
+        let this = self.get_mut();
+        this.bytes.extend_from_slice(buf);
+        this.stored = false;
+        Poll::Ready(Ok(buf.len()))
+    }
+
+    fn poll_flush(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(self.get_mut().store())
+    }
+
+    fn poll_close(self: Pin<&mut Self>, _cx: &mut Context<'_>) -> Poll<std::io::Result<()>> {
+        Poll::Ready(self.get_mut().store())
+    }
+}
+
+impl Drop for PackEntryWriter {
+    fn drop(&mut self) {
+        if let Err(err) = self.store() {
+            error!("Failed to write {:?} to an asset pack: {err}", self.path);
+        }
+    }
+}
+
+impl AssetWriter for PackAssetWriter {
+    async fn write<'a>(&'a self, path: &'a Path) -> Result<Box<Writer>, AssetWriterError> {
+        Ok(Box::new(PackEntryWriter {
+            pack: self.pack.clone(),
+            kind: PackEntryKind::Asset,
+            path: path.to_owned(),
+            bytes: Vec::new(),
+            stored: false,
+        }))
+    }
+
+    async fn write_meta<'a>(&'a self, path: &'a Path) -> Result<Box<Writer>, AssetWriterError> {
+        Ok(Box::new(PackEntryWriter {
+            pack: self.pack.clone(),
+            kind: PackEntryKind::Meta,
+            path: path.to_owned(),
+            bytes: Vec::new(),
+            stored: false,
+        }))
+    }
+
+    async fn remove<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.remove_entry(PackEntryKind::Asset, path)
+    }
+
+    async fn remove_meta<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.remove_entry(PackEntryKind::Meta, path)
+    }
+
+    async fn rename<'a>(
+        &'a self,
+        old_path: &'a Path,
+        new_path: &'a Path,
+    ) -> Result<(), AssetWriterError> {
+        self.rename_entry(PackEntryKind::Asset, old_path, new_path)
+    }
+
+    async fn rename_meta<'a>(
+        &'a self,
+        old_path: &'a Path,
+        new_path: &'a Path,
+    ) -> Result<(), AssetWriterError> {
+        self.rename_entry(PackEntryKind::Meta, old_path, new_path)
+    }
+
+    async fn create_directory<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        let path = normalize(path);
+        let mut index = self.pack.index.write();
+        if !index.is_directory(&path) {
+            index.directories.insert(path);
+            index.dirty = true;
+        }
+        Ok(())
+    }
+
+    async fn remove_directory<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        self.pack
+            .index
+            .write()
+            .remove_in_directory(&normalize(path));
+        Ok(())
+    }
+
+    async fn remove_empty_directory<'a>(&'a self, path: &'a Path) -> Result<(), AssetWriterError> {
+        let path = normalize(path);
+        let mut index = self.pack.index.write();
+        if index
+            .assets
+            .keys()
+            .chain(index.metas.keys())
+            .any(|entry| entry.starts_with(&path))
+        {
+            return Err(Error::other(format!("{path:?} is not an empty directory")).into());
+        }
+        index.remove_in_directory(&path);
+        Ok(())
+    }
+
+    async fn remove_assets_in_directory<'a>(
+        &'a self,
+        path: &'a Path,
+    ) -> Result<(), AssetWriterError> {
+        let path = normalize(path);
+        let mut index = self.pack.index.write();
+        index.remove_in_directory(&path);
+        if !path.as_os_str().is_empty() {
+            index.directories.insert(path);
+        }
+        Ok(())
+    }
+
+    async fn flush(&self) -> Result<(), AssetWriterError> {
+        Ok(self.pack.flush()?)
+    }
+}
+
+impl AssetSourceBuilder {
+    /// Stores the processed assets of this source in `pack`, by using a [`PackAssetReader`] and a
+    /// [`PackAssetWriter`] of it as the processed reader and writer.
+    ///
+    /// The processed source then doesn't have a watcher.
+    pub fn with_processed_pack(self, pack: Arc<AssetPack>) -> Self {
+        let writer_pack = pack.clone();
+        self.with_processed_reader(move || Box::new(PackAssetReader::new(pack.clone())))
+            .with_processed_writer(move |_| {
+                Some(Box::new(PackAssetWriter::new(writer_pack.clone())))
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_tasks::block_on;
+    use futures_lite::StreamExt;
+
+    fn read_directory(reader: &PackAssetReader, path: &str) -> Vec<PathBuf> {
+        block_on(async {
+            reader
+                .read_directory(Path::new(path))
+                .await
+                .unwrap()
+                .collect()
+                .await
+        })
+    }
+
+    #[test]
+    fn read_written_assets() {
+        let pack = Arc::new(AssetPack::new());
+        let writer = PackAssetWriter::new(pack.clone());
+        let reader = PackAssetReader::new(pack.clone());
+        block_on(async {
+            writer.write_bytes(Path::new("a.txt"), b"a").await.unwrap();
+            writer
+                .write_meta_bytes(Path::new("a.txt"), b"meta")
+                .await
+                .unwrap();
+            writer
+                .write_bytes(Path::new("x/y/b.txt"), b"b")
+                .await
+                .unwrap();
+            // Replacing an asset.
+            writer.write_bytes(Path::new("a.txt"), b"aa").await.unwrap();
+
+            let mut bytes = Vec::new();
+            let mut asset = reader.read(Path::new("a.txt")).await.unwrap();
+            asset.read_to_end(&mut bytes).await.unwrap();
+            assert_eq!(bytes, b"aa");
+            assert_eq!(
+                reader.read_meta_bytes(Path::new("a.txt")).await.unwrap(),
+                b"meta"
+            );
+            assert!(matches!(
+                reader.read(Path::new("missing.txt")).await,
+                Err(AssetReaderError::NotFound(_))
+            ));
+            assert!(reader.is_directory(Path::new("x")).await.unwrap());
+            assert!(!reader.is_directory(Path::new("a.txt")).await.unwrap());
+        });
+        assert_eq!(
+            read_directory(&reader, ""),
+            [PathBuf::from("a.txt"), PathBuf::from("x")]
+        );
+        assert_eq!(read_directory(&reader, "x/y"), [PathBuf::from("x/y/b.txt")]);
+    }
+
+    #[test]
+    fn roundtrip_bytes() {
+        let pack = Arc::new(AssetPack::new());
+        let writer = PackAssetWriter::new(pack.clone());
+        block_on(async {
+            writer
+                .write_bytes(Path::new("x/b.txt"), b"b")
+                .await
+                .unwrap();
+            writer
+                .write_meta_bytes(Path::new("x/b.txt"), b"meta")
+                .await
+                .unwrap();
+            writer.create_directory(Path::new("empty")).await.unwrap();
+            writer
+                .rename(Path::new("x/b.txt"), Path::new("x/c.txt"))
+                .await
+                .unwrap();
+        });
+
+        let pack = AssetPack::from_bytes(pack.to_bytes().unwrap()).unwrap();
+        assert_eq!(pack.asset_paths(), [PathBuf::from("x/c.txt")]);
+        assert_eq!(pack.read_asset(Path::new("x/c.txt")).unwrap(), b"b");
+        assert_eq!(pack.read_meta(Path::new("x/b.txt")).unwrap(), b"meta");
+        let reader = PackAssetReader::new(Arc::new(pack));
+        assert_eq!(
+            read_directory(&reader, ""),
+            [PathBuf::from("empty"), PathBuf::from("x")]
+        );
+
+        assert!(AssetPack::from_bytes(b"not a pack".to_vec()).is_err());
+    }
+
+    #[test]
+    fn remove_directories() {
+        let pack = Arc::new(AssetPack::new());
+        let writer = PackAssetWriter::new(pack.clone());
+        let reader = PackAssetReader::new(pack.clone());
+        block_on(async {
+            writer
+                .write_bytes(Path::new("x/a.txt"), b"a")
+                .await
+                .unwrap();
+            writer
+                .write_bytes(Path::new("x/y/b.txt"), b"b")
+                .await
+                .unwrap();
+            assert!(writer.remove_empty_directory(Path::new("x")).await.is_err());
+
+            writer
+                .remove_assets_in_directory(Path::new("x"))
+                .await
+                .unwrap();
+            assert!(reader.is_directory(Path::new("x")).await.unwrap());
+            assert!(!reader.is_directory(Path::new("x/y")).await.unwrap());
+
+            writer.remove_empty_directory(Path::new("x")).await.unwrap();
+            assert!(!reader.is_directory(Path::new("x")).await.unwrap());
+            assert!(writer.remove(Path::new("x/a.txt")).await.is_err());
+        });
+    }
+
+    #[test]
+    fn file_pack() {
+        let dir = std::env::temp_dir().join(format!("bevy_asset_pack_{}", std::process::id()));
+        let path = dir.join("assets.pack");
+        {
+            let pack = Arc::new(AssetPack::open_or_create(&path).unwrap());
+            block_on(PackAssetWriter::new(pack.clone()).write_bytes(Path::new("a.txt"), b"a"))
+                .unwrap();
+        }
+        {
+            let pack = Arc::new(AssetPack::open_or_create(&path).unwrap());
+            block_on(PackAssetWriter::new(pack.clone()).write_bytes(Path::new("b.txt"), b"b"))
+                .unwrap();
+            assert_eq!(pack.read_asset(Path::new("b.txt")).unwrap(), b"b");
+            // The file keeps its previous index until the pack is flushed.
+            let previous = AssetPack::open(&path).unwrap();
+            assert_eq!(previous.asset_paths(), [PathBuf::from("a.txt")]);
+            pack.flush().unwrap();
+        }
+        let pack = AssetPack::open(&path).unwrap();
+        assert_eq!(pack.read_asset(Path::new("a.txt")).unwrap(), b"a");
+        assert_eq!(pack.read_asset(Path::new("b.txt")).unwrap(), b"b");
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[cfg(all(not(target_arch = "wasm32"), feature = "multi_threaded"))]
+    #[test]
+    fn process_into_pack() {
+        use crate::{
+            meta::ProcessedInfoMinimal,
+            processor::tests::{process_packs, UppercaseText},
+        };
+
+        let source = Arc::new(AssetPack::new());
+        source.write_asset(Path::new("a.up.txt"), b"a").unwrap();
+        source.write_asset(Path::new("x/b.txt"), b"b").unwrap();
+        let dir =
+            std::env::temp_dir().join(format!("bevy_asset_processed_pack_{}", std::process::id()));
+        let path = dir.join("processed.pack");
+        let processed = Arc::new(AssetPack::open_or_create(&path).unwrap());
+        let uppercase = UppercaseText::default();
+        process_packs(&source, &processed, &uppercase, |_| {});
+        assert_eq!(uppercase.runs(), 1);
+
+        // The processor flushes the pack once it is done, while it is still in use.
+        let processed = AssetPack::open(&path).unwrap();
+        assert_eq!(processed.read_asset(Path::new("a.up.txt")).unwrap(), b"A");
+        assert_eq!(processed.read_asset(Path::new("x/b.txt")).unwrap(), b"b");
+        let meta = processed.read_meta(Path::new("a.up.txt")).unwrap();
+        let meta: ProcessedInfoMinimal = ron::de::from_bytes(&meta).unwrap();
+        assert!(meta.processed_info.is_some());
+        // The default meta of the source assets is written next to them.
+        assert!(source.read_meta(Path::new("a.up.txt")).is_ok());
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+
+    #[test]
+    fn open_or_create_keeps_invalid_files() {
+        let dir =
+            std::env::temp_dir().join(format!("bevy_asset_invalid_pack_{}", std::process::id()));
+        let path = dir.join("assets.pack");
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(&path, b"not a pack").unwrap();
+        assert!(AssetPack::open_or_create(&path).is_err());
+        assert_eq!(std::fs::read(&path).unwrap(), b"not a pack");
+        std::fs::remove_dir_all(dir).unwrap();
+    }
+}
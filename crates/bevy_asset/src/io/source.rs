@@ -314,6 +314,7 @@ impl AssetSourceBuilder {
 pub struct AssetSourceBuilders {
     sources: HashMap<CowArc<'static, str>, AssetSourceBuilder>,
     default: Option<AssetSourceBuilder>,
+    overlays: Vec<(AssetSourceId<'static>, AssetSourceBuilder)>,
 }
 
 impl AssetSourceBuilders {
@@ -340,9 +341,28 @@ impl AssetSourceBuilders {
         }
     }
 
+    /// Puts `layer` on top of the source with the given `id`, using [`AssetSourceBuilder::with_overlay`].
+    ///
+    /// Unlike [`AssetSourceBuilders::get_mut`], this works for sources that are inserted later, such as the default source,
+    /// as overlays are applied when the sources are built. Overlays inserted later are put on top of the previous ones.
+    pub fn insert_overlay(
+        &mut self,
+        id: impl Into<AssetSourceId<'static>>,
+        layer: AssetSourceBuilder,
+    ) {
+        self.overlays.push((AssetSourceId::from_static(id), layer));
+    }
+
     /// Builds a new [`AssetSources`] collection. If `watch` is true, the unprocessed sources will watch for changes.
     /// If `watch_processed` is true, the processed sources will watch for changes.
     pub fn build_sources(&mut self, watch: bool, watch_processed: bool) -> AssetSources {
+        for (id, layer) in core::mem::take(&mut self.overlays) {
+            match self.get_mut(&id) {
+                Some(source) => *source = core::mem::take(source).with_overlay(layer),
+                None => error!("Cannot overlay {id}, as it is not registered"),
+            }
+        }
+
         let mut sources = <HashMap<_, _>>::default();
         for (id, source) in &mut self.sources {
             if let Some(data) = source.build(
@@ -201,7 +201,7 @@ pub use ron;
 
 use crate::{
     io::{embedded::EmbeddedAssetRegistry, AssetSourceBuilder, AssetSourceBuilders, AssetSourceId},
-    processor::{AssetProcessor, Process},
+    processor::{AssetProcessor, Process, ProcessedAssetCache},
 };
 use alloc::sync::Arc;
 use bevy_app::{App, Last, Plugin, PreUpdate};
@@ -244,6 +244,10 @@ pub struct AssetPlugin {
     pub mode: AssetMode,
     /// How/If asset meta files should be checked.
     pub meta_check: AssetMetaCheck,
+    /// The maximum number of asset loads that can be in flight at the same time, or [`None`] for no limit.
+    ///
+    /// See [`AssetServer::set_max_in_flight_loads`].
+    pub max_in_flight_loads: Option<usize>,
 }
 
 /// Controls whether or not assets are pre-processed before being loaded.
@@ -297,6 +301,7 @@ impl Default for AssetPlugin {
             processed_file_path: Self::DEFAULT_PROCESSED_FILE_PATH.to_string(),
             watch_for_changes_override: None,
             meta_check: AssetMetaCheck::default(),
+            max_in_flight_loads: None,
         }
     }
 }
@@ -371,6 +376,9 @@ impl Plugin for AssetPlugin {
                 }
             }
         }
+        app.world()
+            .resource::<AssetServer>()
+            .set_max_in_flight_loads(self.max_in_flight_loads);
         app.insert_resource(embedded)
             .init_asset::<LoadedFolder>()
             .init_asset::<LoadedUntypedAsset>()
@@ -467,8 +475,21 @@ pub trait AssetApp {
         id: impl Into<AssetSourceId<'static>>,
         source: AssetSourceBuilder,
     ) -> &mut Self;
+    /// Puts the given [`AssetSourceBuilder`] on top of the asset source with the given `id`, so that its assets
+    /// shadow the assets with the same path. See [`AssetSourceBuilder::with_overlay`].
+    ///
+    /// This can be used for the default source, even though it is only created by [`AssetPlugin`]. Like asset sources,
+    /// overlays must be registered before adding [`AssetPlugin`] to your application.
+    fn register_asset_overlay(
+        &mut self,
+        id: impl Into<AssetSourceId<'static>>,
+        layer: AssetSourceBuilder,
+    ) -> &mut Self;
     /// Sets the default asset processor for the given `extension`.
     fn set_default_asset_processor<P: Process>(&mut self, extension: &str) -> &mut Self;
+    /// Sets the [`ProcessedAssetCache`] of the [`App`]'s [`AssetProcessor`], which processed assets are fetched from
+    /// instead of being processed again. See [`AssetProcessor::set_cache`].
+    fn set_processed_asset_cache(&mut self, cache: impl ProcessedAssetCache) -> &mut Self;
     /// Initializes the given loader in the [`App`]'s [`AssetServer`].
     fn init_asset_loader<L: AssetLoader + FromWorld>(&mut self) -> &mut Self;
     /// Initializes the given [`Asset`] in the [`App`] by:
@@ -526,6 +547,22 @@ impl AssetApp for App {
         self
     }
 
+    fn register_asset_overlay(
+        &mut self,
+        id: impl Into<AssetSourceId<'static>>,
+        layer: AssetSourceBuilder,
+    ) -> &mut Self {
+        let id = AssetSourceId::from_static(id);
+        if self.world().get_resource::<AssetServer>().is_some() {
+            error!("The overlay of {} must be registered before `AssetPlugin` (typically added as part of `DefaultPlugins`)", id);
+        }
+
+        self.world_mut()
+            .get_resource_or_init::<AssetSourceBuilders>()
+            .insert_overlay(id, layer);
+        self
+    }
+
     fn set_default_asset_processor<P: Process>(&mut self, extension: &str) -> &mut Self {
         if let Some(asset_processor) = self.world().get_resource::<AssetProcessor>() {
             asset_processor.set_default_processor::<P>(extension);
@@ -533,6 +570,13 @@ impl AssetApp for App {
         self
     }
 
+    fn set_processed_asset_cache(&mut self, cache: impl ProcessedAssetCache) -> &mut Self {
+        if let Some(asset_processor) = self.world().get_resource::<AssetProcessor>() {
+            asset_processor.set_cache(cache);
+        }
+        self
+    }
+
     fn init_asset_loader<L: AssetLoader + FromWorld>(&mut self) -> &mut Self {
         let loader = L::from_world(self.world_mut());
         self.register_asset_loader(loader)
@@ -616,8 +660,8 @@ mod tests {
             AssetReader, AssetReaderError, AssetSource, AssetSourceId, Reader,
         },
         loader::{AssetLoader, LoadContext},
-        Asset, AssetApp, AssetEvent, AssetId, AssetLoadError, AssetLoadFailedEvent, AssetPath,
-        AssetPlugin, AssetServer, Assets,
+        Asset, AssetApp, AssetDependencyKind, AssetEvent, AssetId, AssetLoadError,
+        AssetLoadFailedEvent, AssetPath, AssetPlugin, AssetServer, Assets, LoadPriority,
     };
     use alloc::sync::Arc;
     use bevy_app::{App, Update};
@@ -1730,6 +1774,169 @@ mod tests {
         });
     }
 
+    #[test]
+    fn load_priorities_and_budget() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        let dir = Dir::default();
+        let a_path = "a.cool.ron";
+        let b_path = "b.cool.ron";
+        let c_path = "c.cool.ron";
+        for path in [a_path, b_path, c_path] {
+            dir.insert_asset_text(Path::new(path), SIMPLE_TEXT);
+        }
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        asset_server.set_max_in_flight_loads(Some(1));
+
+        let a: Handle<CoolText> = asset_server.load(a_path);
+        let b: Handle<CoolText> = asset_server.load_with_priority(b_path, LoadPriority::LOW);
+        let c: Handle<CoolText> = asset_server.load(c_path);
+        assert!(!asset_server.is_load_queued(&a));
+        assert_eq!(asset_server.in_flight_load_count(), 1);
+        assert_eq!(asset_server.queued_load_count(), 2);
+        assert!(asset_server.set_load_priority(&c, LoadPriority::HIGH));
+
+        // `c` has a higher priority than `b`, so it is started when `a` is loaded.
+        gate_opener.open(a_path);
+        run_app_until(&mut app, |world| {
+            get::<CoolText>(world, a.id())?;
+            (!asset_server.is_load_queued(&c)).then_some(())
+        });
+        assert!(asset_server.is_load_queued(&b));
+        assert!(!asset_server.set_load_priority(&c, LoadPriority::LOW));
+
+        gate_opener.open(c_path);
+        gate_opener.open(b_path);
+        run_app_until(&mut app, |world| {
+            get::<CoolText>(world, b.id())?;
+            get::<CoolText>(world, c.id())?;
+            (asset_server.in_flight_load_count() == 0).then_some(())
+        });
+    }
+
+    #[test]
+    fn dropping_handles_cancels_queued_loads() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        let dir = Dir::default();
+        let a_path = "a.cool.ron";
+        let b_path = "b.cool.ron";
+        dir.insert_asset_text(Path::new(a_path), SIMPLE_TEXT);
+        dir.insert_asset_text(Path::new(b_path), SIMPLE_TEXT);
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        asset_server.set_max_in_flight_loads(Some(1));
+
+        let a: Handle<CoolText> = asset_server.load(a_path);
+        let b_id = asset_server.load::<CoolText>(b_path).id();
+        // the handle drop is processed when the assets are tracked
+        app.update();
+        assert!(!asset_server.is_load_queued(b_id));
+        assert_eq!(asset_server.queued_load_count(), 0);
+
+        gate_opener.open(a_path);
+        run_app_until(&mut app, |world| {
+            get::<CoolText>(world, a.id())?;
+            (asset_server.in_flight_load_count() == 0).then_some(())
+        });
+        assert!(asset_server.get_load_state(b_id).is_none());
+        assert!(asset_server.get_handle::<CoolText>(b_path).is_none());
+    }
+
+    #[test]
+    fn dependency_graph() {
+        // The particular usage of GatedReader in this test will cause deadlocking if running single-threaded
+        #[cfg(not(feature = "multi_threaded"))]
+        panic!("This test requires the \"multi_threaded\" feature, otherwise it will deadlock.\ncargo test --package bevy_asset --features multi_threaded");
+
+        let dir = Dir::default();
+        let a_path = "a.cool.ron";
+        let a_ron = r#"
+(
+    text: "a",
+    dependencies: ["b.cool.ron"],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#;
+        let b_path = "b.cool.ron";
+        let b_ron = r#"
+(
+    text: "b",
+    dependencies: ["c.cool.ron"],
+    embedded_dependencies: [],
+    sub_texts: [],
+)"#;
+        let c_path = "c.cool.ron";
+        dir.insert_asset_text(Path::new(a_path), a_ron);
+        dir.insert_asset_text(Path::new(b_path), b_ron);
+        dir.insert_asset_text(Path::new(c_path), SIMPLE_TEXT);
+
+        let (mut app, gate_opener) = test_app(dir);
+        app.init_asset::<CoolText>()
+            .init_asset::<SubText>()
+            .register_asset_loader(CoolTextLoader);
+        let asset_server = app.world().resource::<AssetServer>().clone();
+        let a: Handle<CoolText> = asset_server.load(a_path);
+        gate_opener.open(a_path);
+        gate_opener.open(b_path);
+        gate_opener.open(c_path);
+        run_app_until(&mut app, |_| {
+            asset_server.is_loaded_with_dependencies(&a).then_some(())
+        });
+
+        let graph = asset_server.get_path_dependency_graph(b_path).unwrap();
+        assert_eq!(graph.nodes.len(), 3);
+        let index = |path: &str| {
+            graph
+                .nodes
+                .iter()
+                .position(|node| node.path == Some(AssetPath::from(path)))
+                .unwrap()
+        };
+        let (a_index, b_index, c_index) = (index(a_path), index(b_path), index(c_path));
+        assert_eq!(graph.roots, vec![b_index]);
+        let mut edges: Vec<_> = graph
+            .edges
+            .iter()
+            .map(|edge| (edge.dependent, edge.dependency, edge.kind))
+            .collect();
+        edges.sort_by_key(|(dependent, ..)| *dependent != a_index);
+        assert_eq!(
+            edges,
+            vec![
+                (a_index, b_index, AssetDependencyKind::Asset),
+                (b_index, c_index, AssetDependencyKind::Asset),
+            ]
+        );
+        for node in &graph.nodes {
+            assert_eq!(node.loader, Some(core::any::type_name::<CoolTextLoader>()));
+            assert!(node.load_state.is_loaded());
+            assert!(node.recursive_dependency_load_state.is_loaded());
+        }
+        assert!(graph.to_dot().starts_with("digraph assets {"));
+
+        let graph = asset_server.get_dependency_graph(&a).unwrap();
+        assert_eq!(graph.nodes[graph.roots[0]].id, a.id().untyped());
+        assert_eq!(graph.dependencies(graph.roots[0]).count(), 1);
+        assert_eq!(graph.dependents(graph.roots[0]).count(), 0);
+        assert!(asset_server
+            .get_path_dependency_graph("missing.cool.ron")
+            .is_none());
+    }
+
     #[test]
     fn ignore_system_ambiguities_on_assets() {
         let mut app = App::new();
@@ -5,7 +5,7 @@ use crate::{
     io::Reader,
     meta::{meta_transform_settings, AssetMetaDyn, MetaTransform, Settings},
     Asset, AssetLoadError, AssetPath, ErasedAssetLoader, ErasedLoadedAsset, Handle, LoadContext,
-    LoadDirectError, LoadedAsset, LoadedUntypedAsset, UntypedHandle,
+    LoadDirectError, LoadPriority, LoadedAsset, LoadedUntypedAsset, UntypedHandle,
 };
 use alloc::sync::Arc;
 use core::any::TypeId;
@@ -305,9 +305,12 @@ impl NestedLoader<'_, '_, StaticTyped, Deferred> {
     pub fn load<'c, A: Asset>(self, path: impl Into<AssetPath<'c>>) -> Handle<A> {
         let path = path.into().to_owned();
         let handle = if self.load_context.should_load_dependencies {
-            self.load_context
-                .asset_server
-                .load_with_meta_transform(path, self.meta_transform, ())
+            self.load_context.asset_server.load_with_meta_transform(
+                path,
+                self.meta_transform,
+                LoadPriority::NORMAL,
+                (),
+            )
         } else {
             self.load_context
                 .asset_server
@@ -335,6 +338,7 @@ impl NestedLoader<'_, '_, DynamicTyped, Deferred> {
                     path,
                     self.typing.asset_type_id,
                     self.meta_transform,
+                    LoadPriority::NORMAL,
                     (),
                 )
         } else {
@@ -361,7 +365,11 @@ impl NestedLoader<'_, '_, UnknownTyped, Deferred> {
         let handle = if self.load_context.should_load_dependencies {
             self.load_context
                 .asset_server
-                .load_unknown_type_with_meta_transform(path, self.meta_transform)
+                .load_unknown_type_with_meta_transform(
+                    path,
+                    self.meta_transform,
+                    LoadPriority::NORMAL,
+                )
         } else {
             self.load_context
                 .asset_server
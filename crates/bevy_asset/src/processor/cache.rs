@@ -0,0 +1,318 @@
+use crate::{
+    io::{AssetReaderError, MissingProcessedAssetReaderError},
+    meta::{AssetHash, META_FORMAT_VERSION},
+};
+use alloc::{string::String, vec::Vec};
+use bevy_utils::{BoxedFuture, ConditionalSendFuture};
+use core::fmt::Write;
+use thiserror::Error;
+
+#[cfg(not(target_arch = "wasm32"))]
+pub use file::*;
+
+/// The key of an entry in a [`ProcessedAssetCache`].
+///
+/// Keys are derived from the hashes of everything that determines the output of an
+/// [`AssetProcessor`](crate::processor::AssetProcessor), so that the same key always refers to the
+/// same bytes, whichever machine computed them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct ProcessedAssetCacheKey(pub AssetHash);
+
+impl ProcessedAssetCacheKey {
+    /// The key of the processed output of an asset, given the hash of its source bytes, the hash
+    /// of its .meta (which contains the processor and its settings) and the full hashes of its
+    /// process dependencies, in the order they were used.
+    pub fn output(
+        source_hash: AssetHash,
+        settings_hash: AssetHash,
+        dependency_hashes: impl IntoIterator<Item = AssetHash>,
+    ) -> Self {
+        let mut hasher = Self::hasher(b"output", source_hash, settings_hash);
+        for hash in dependency_hashes {
+            hasher.update(&hash);
+        }
+        Self(*hasher.finalize().as_bytes())
+    }
+
+    /// The key of the list of process dependencies of an asset, which is needed to compute the key
+    /// of its [output](Self::output) before processing it.
+    pub fn dependencies(source_hash: AssetHash, settings_hash: AssetHash) -> Self {
+        let hasher = Self::hasher(b"dependencies", source_hash, settings_hash);
+        Self(*hasher.finalize().as_bytes())
+    }
+
+    fn hasher(kind: &[u8], source_hash: AssetHash, settings_hash: AssetHash) -> blake3::Hasher {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(META_FORMAT_VERSION.as_bytes());
+        hasher.update(kind);
+        hasher.update(&source_hash);
+        hasher.update(&settings_hash);
+        hasher
+    }
+
+    /// Returns the key as a lowercase hexadecimal string.
+    pub fn to_hex(&self) -> String {
+        self.0
+            .iter()
+            .fold(String::with_capacity(64), |mut hex, byte| {
+                let _ = write!(hex, "{byte:02x}");
+                hex
+            })
+    }
+}
+
+/// Errors that occur while reading or writing a [`ProcessedAssetCache`].
+#[derive(Error, Debug)]
+pub enum ProcessedAssetCacheError {
+    /// An IO error occurred.
+    #[error("encountered an io error while accessing the processed asset cache: {0}")]
+    Io(#[from] std::io::Error),
+}
+
+/// An error that prevents a processed asset from being written to a [`ProcessedAssetCache`].
+#[derive(Error, Debug)]
+pub(crate) enum WriteProcessedAssetCacheError {
+    #[error(transparent)]
+    MissingProcessedAssetReader(#[from] MissingProcessedAssetReaderError),
+    #[error(transparent)]
+    AssetReader(#[from] AssetReaderError),
+    #[error(transparent)]
+    Cache(#[from] ProcessedAssetCacheError),
+}
+
+/// A content-addressed store of processed assets, which the
+/// [`AssetProcessor`](crate::processor::AssetProcessor) checks before running a [`Process`]
+/// implementation, and fills in after running one.
+///
+/// A cache can be shared between machines, so that an asset processed by one of them does not
+/// have to be processed again by the others. Entries are never invalidated: a change to an input
+/// of the processor results in a different [`ProcessedAssetCacheKey`].
+///
+/// See [`AssetProcessor::set_cache`](crate::processor::AssetProcessor::set_cache) and
+/// [`FileProcessedAssetCache`].
+///
+/// [`Process`]: crate::processor::Process
+pub trait ProcessedAssetCache: Send + Sync + 'static {
+    /// Reads the bytes stored at `key`, or returns [`None`] if there are none.
+    fn read<'a>(
+        &'a self,
+        key: &'a ProcessedAssetCacheKey,
+    ) -> impl ConditionalSendFuture<Output = Result<Option<Vec<u8>>, ProcessedAssetCacheError>>;
+    /// Stores `bytes` at `key`. As keys are content-addressed, existing bytes do not need to be
+    /// replaced.
+    fn write<'a>(
+        &'a self,
+        key: &'a ProcessedAssetCacheKey,
+        bytes: &'a [u8],
+    ) -> impl ConditionalSendFuture<Output = Result<(), ProcessedAssetCacheError>>;
+}
+
+/// Equivalent to a [`ProcessedAssetCache`] but using boxed futures, necessary eg. when using a
+/// `dyn ProcessedAssetCache`, as [`ProcessedAssetCache`] isn't currently object safe.
+pub trait ErasedProcessedAssetCache: Send + Sync + 'static {
+    /// Reads the bytes stored at `key`, or returns [`None`] if there are none.
+    fn read<'a>(
+        &'a self,
+        key: &'a ProcessedAssetCacheKey,
+    ) -> BoxedFuture<'a, Result<Option<Vec<u8>>, ProcessedAssetCacheError>>;
+    /// Stores `bytes` at `key`.
+    fn write<'a>(
+        &'a self,
+        key: &'a ProcessedAssetCacheKey,
+        bytes: &'a [u8],
+    ) -> BoxedFuture<'a, Result<(), ProcessedAssetCacheError>>;
+}
+
+impl<T: ProcessedAssetCache> ErasedProcessedAssetCache for T {
+    fn read<'a>(
+        &'a self,
+        key: &'a ProcessedAssetCacheKey,
+    ) -> BoxedFuture<'a, Result<Option<Vec<u8>>, ProcessedAssetCacheError>> {
+        Box::pin(Self::read(self, key))
+    }
+    fn write<'a>(
+        &'a self,
+        key: &'a ProcessedAssetCacheKey,
+        bytes: &'a [u8],
+    ) -> BoxedFuture<'a, Result<(), ProcessedAssetCacheError>> {
+        Box::pin(Self::write(self, key, bytes))
+    }
+}
+
+/// The processed asset and .meta bytes stored in a [`ProcessedAssetCache`] entry.
+pub(crate) struct CachedProcessedAsset {
+    pub(crate) meta: Vec<u8>,
+    pub(crate) asset: Vec<u8>,
+}
+
+impl CachedProcessedAsset {
+    /// Encodes the entry as the length of the meta, as a little-endian `u64`, followed by the meta
+    /// and the asset.
+    pub(crate) fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes = Vec::with_capacity(8 + self.meta.len() + self.asset.len());
+        bytes.extend_from_slice(&(self.meta.len() as u64).to_le_bytes());
+        bytes.extend_from_slice(&self.meta);
+        bytes.extend_from_slice(&self.asset);
+        bytes
+    }
+
+    pub(crate) fn from_bytes(bytes: &[u8]) -> Option<Self> {
+        let (meta_len, rest) = bytes.split_first_chunk::<8>()?;
+        let meta_len = usize::try_from(u64::from_le_bytes(*meta_len)).ok()?;
+        if meta_len > rest.len() {
+            return None;
+        }
+        let (meta, asset) = rest.split_at(meta_len);
+        Some(Self {
+            meta: meta.to_vec(),
+            asset: asset.to_vec(),
+        })
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+mod file {
+    use super::{ProcessedAssetCache, ProcessedAssetCacheError, ProcessedAssetCacheKey};
+    use crate::io::file::FileAssetReader;
+    use alloc::{format, vec::Vec};
+    use core::sync::atomic::{AtomicU64, Ordering};
+    use std::{
+        io::ErrorKind,
+        path::{Path, PathBuf},
+    };
+
+    /// A [`ProcessedAssetCache`] that stores each entry in a file of a local directory, which can
+    /// be a folder shared between machines.
+    ///
+    /// Entries are written to a temporary file first, then renamed, so that several processors
+    /// can safely use the same directory at the same time.
+    pub struct FileProcessedAssetCache {
+        root_path: PathBuf,
+    }
+
+    impl FileProcessedAssetCache {
+        /// Creates a new [`FileProcessedAssetCache`] storing its entries in the directory at
+        /// `path`. Relative paths are resolved from the same base path as the
+        /// [`FileAssetReader`].
+        pub fn new<P: AsRef<Path>>(path: P) -> Self {
+            Self {
+                root_path: FileAssetReader::get_base_path().join(path.as_ref()),
+            }
+        }
+
+        /// Returns the directory where entries are stored.
+        pub fn root_path(&self) -> &Path {
+            &self.root_path
+        }
+
+        /// Returns the path of the file storing the entry with the given `key`. Entries are split
+        /// into subdirectories by the first byte of their key.
+        pub fn entry_path(&self, key: &ProcessedAssetCacheKey) -> PathBuf {
+            let hex = key.to_hex();
+            self.root_path.join(&hex[..2]).join(&hex[2..])
+        }
+    }
+
+    impl ProcessedAssetCache for FileProcessedAssetCache {
+        async fn read<'a>(
+            &'a self,
+            key: &'a ProcessedAssetCacheKey,
+        ) -> Result<Option<Vec<u8>>, ProcessedAssetCacheError> {
+            match async_fs::read(self.entry_path(key)).await {
+                Ok(bytes) => Ok(Some(bytes)),
+                Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+                Err(err) => Err(err.into()),
+            }
+        }
+
+        async fn write<'a>(
+            &'a self,
+            key: &'a ProcessedAssetCacheKey,
+            bytes: &'a [u8],
+        ) -> Result<(), ProcessedAssetCacheError> {
+            static TEMP_FILE_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+            let path = self.entry_path(key);
+            if async_fs::metadata(&path).await.is_ok() {
+                return Ok(());
+            }
+            if let Some(parent) = path.parent() {
+                async_fs::create_dir_all(parent).await?;
+            }
+            let temp_path = path.with_extension(format!(
+                "{}-{}.tmp",
+                std::process::id(),
+                TEMP_FILE_COUNTER.fetch_add(1, Ordering::Relaxed)
+            ));
+            async_fs::write(&temp_path, bytes).await?;
+            if let Err(err) = async_fs::rename(&temp_path, &path).await {
+                let _ = async_fs::remove_file(&temp_path).await;
+                return Err(err.into());
+            }
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(not(target_arch = "wasm32"))]
+    use super::FileProcessedAssetCache;
+    use super::{CachedProcessedAsset, ProcessedAssetCache, ProcessedAssetCacheKey};
+
+    #[test]
+    fn keys_depend_on_every_input() {
+        let output = ProcessedAssetCacheKey::output([1; 32], [2; 32], [[3; 32]]);
+        assert_eq!(
+            output,
+            ProcessedAssetCacheKey::output([1; 32], [2; 32], [[3; 32]])
+        );
+        assert_ne!(
+            output,
+            ProcessedAssetCacheKey::output([1; 32], [2; 32], [[4; 32]])
+        );
+        assert_ne!(output, ProcessedAssetCacheKey::output([1; 32], [2; 32], []));
+        assert_ne!(
+            output,
+            ProcessedAssetCacheKey::output([2; 32], [1; 32], [[3; 32]])
+        );
+        assert_ne!(
+            ProcessedAssetCacheKey::output([1; 32], [2; 32], []),
+            ProcessedAssetCacheKey::dependencies([1; 32], [2; 32])
+        );
+        assert_eq!(output.to_hex().len(), 64);
+    }
+
+    #[test]
+    fn cached_asset_round_trip() {
+        let cached = CachedProcessedAsset {
+            meta: b"(meta)".to_vec(),
+            asset: b"asset".to_vec(),
+        };
+        let decoded = CachedProcessedAsset::from_bytes(&cached.to_bytes()).unwrap();
+        assert_eq!(decoded.meta, cached.meta);
+        assert_eq!(decoded.asset, cached.asset);
+        assert!(CachedProcessedAsset::from_bytes(&[7, 0, 0, 0, 0, 0, 0, 0, 1]).is_none());
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    #[test]
+    fn file_cache() {
+        let root =
+            std::env::temp_dir().join(format!("bevy_asset_processed_cache_{}", std::process::id()));
+        let cache = FileProcessedAssetCache::new(&root);
+        let key = ProcessedAssetCacheKey::dependencies([5; 32], [6; 32]);
+
+        bevy_tasks::block_on(async {
+            assert!(cache.read(&key).await.unwrap().is_none());
+            cache.write(&key, b"bytes").await.unwrap();
+            assert_eq!(cache.read(&key).await.unwrap().unwrap(), b"bytes");
+            // entries are content-addressed, so they are not replaced
+            cache.write(&key, b"other").await.unwrap();
+            assert_eq!(cache.read(&key).await.unwrap().unwrap(), b"bytes");
+        });
+        assert!(cache.entry_path(&key).starts_with(&root));
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -70,7 +70,7 @@ const ENTRY_END: &str = "End ";
 const UNRECOVERABLE_ERROR: &str = "UnrecoverableError";
 
 impl ProcessorTransactionLog {
-    fn full_log_path() -> PathBuf {
+    pub(crate) fn full_log_path() -> PathBuf {
         #[cfg(not(target_arch = "wasm32"))]
         let base_path = crate::io::file::get_base_path();
         #[cfg(target_arch = "wasm32")]
@@ -37,9 +37,11 @@
 //!
 //! In most cases, [`LoadTransformAndSave`] should be sufficient.
 
+mod cache;
 mod log;
 mod process;
 
+pub use cache::*;
 pub use log::*;
 pub use process::*;
 
@@ -102,6 +104,8 @@ pub struct AssetProcessorData {
     processors: RwLock<HashMap<&'static str, Arc<dyn ErasedProcessor>>>,
     /// Default processors for file extensions
     default_processors: RwLock<HashMap<Box<str>, &'static str>>,
+    /// The cache processed assets are fetched from instead of being processed again, if any.
+    cache: RwLock<Option<Arc<dyn ErasedProcessedAssetCache>>>,
     state: async_lock::RwLock<ProcessorState>,
     sources: AssetSources,
     initialized_sender: async_broadcast::Sender<()>,
@@ -473,6 +477,17 @@ impl AssetProcessor {
 
     async fn finish_processing_assets(&self) {
         self.try_reprocessing_queued().await;
+        // Some writers, like those of asset packs, only persist their changes when flushed.
+        for source in self.sources().iter_processed() {
+            if let Ok(processed_writer) = source.processed_writer() {
+                if let Err(err) = processed_writer.flush().await {
+                    error!(
+                        "Failed to flush the processed assets of source {}: {err}",
+                        source.id()
+                    );
+                }
+            }
+        }
         // clean up metadata in asset server
         self.server.data.infos.write().consume_handle_drop_events();
         self.set_state(ProcessorState::Finished).await;
@@ -529,6 +544,18 @@ impl AssetProcessor {
         process_plans.insert(core::any::type_name::<P>(), Arc::new(processor));
     }
 
+    /// Sets the [`ProcessedAssetCache`] that processed assets are fetched from, instead of running their [`Process`]
+    /// implementation again when an asset with the same source, settings and process dependencies was already processed.
+    /// Newly processed assets are written to the cache.
+    pub fn set_cache(&self, cache: impl ProcessedAssetCache) {
+        *self.data.cache.write() = Some(Arc::new(cache));
+    }
+
+    /// Returns the [`ProcessedAssetCache`] set with [`AssetProcessor::set_cache`], if any.
+    pub fn cache(&self) -> Option<Arc<dyn ErasedProcessedAssetCache>> {
+        self.data.cache.read().clone()
+    }
+
     /// Set the default processor for the given `extension`. Make sure `P` is registered with [`AssetProcessor::register_processor`].
     pub fn set_default_processor<P: Process>(&self, extension: &str) {
         let mut default_processors = self.data.default_processors.write();
@@ -871,7 +898,28 @@ impl AssetProcessor {
         // Directly writing to the asset destination in the processor necessitates this behavior
         // TODO: this class of failure can be recovered via re-processing + smarter log validation that allows for duplicate transactions in the event of failures
         self.log_begin_processing(asset_path).await;
-        if let Some(processor) = processor {
+        let cache = processor.as_ref().and_then(|_| self.cache());
+        let source_hash = *blake3::hash(&asset_bytes).as_bytes();
+        let settings_hash = *blake3::hash(&meta_bytes).as_bytes();
+        let cached = match &cache {
+            Some(cache) => {
+                self.read_cached(&**cache, asset_path, source_hash, settings_hash)
+                    .await
+            }
+            None => None,
+        };
+        if let Some((cached, processed_info)) = cached {
+            debug!("Fetched processed {:?} from the cache", asset_path);
+            processed_writer
+                .write_bytes(path, &cached.asset)
+                .await
+                .map_err(writer_err)?;
+            processed_writer
+                .write_meta_bytes(path, &cached.meta)
+                .await
+                .map_err(writer_err)?;
+            new_processed_info = processed_info;
+        } else if let Some(processor) = processor {
             let mut writer = processed_writer.write(path).await.map_err(writer_err)?;
             let mut processed_meta = {
                 let mut context =
@@ -903,6 +951,18 @@ impl AssetProcessor {
                 .write_meta_bytes(path, &meta_bytes)
                 .await
                 .map_err(writer_err)?;
+            if let Some(cache) = &cache {
+                self.write_cached(
+                    &**cache,
+                    source,
+                    asset_path,
+                    source_hash,
+                    settings_hash,
+                    &new_processed_info,
+                    meta_bytes,
+                )
+                .await;
+            }
         } else {
             processed_writer
                 .write_bytes(path, &asset_bytes)
@@ -920,6 +980,136 @@ impl AssetProcessor {
         Ok(ProcessResult::Processed(new_processed_info))
     }
 
+    /// Fetches the processed asset and its [`ProcessedInfo`] from the `cache`, once the process dependencies it was
+    /// processed with are processed. Returns [`None`] if the asset must be processed.
+    async fn read_cached(
+        &self,
+        cache: &dyn ErasedProcessedAssetCache,
+        asset_path: &AssetPath<'static>,
+        source_hash: AssetHash,
+        settings_hash: AssetHash,
+    ) -> Option<(CachedProcessedAsset, ProcessedInfo)> {
+        let read = |key| async move {
+            match cache.read(&key).await {
+                Ok(bytes) => bytes,
+                Err(err) => {
+                    warn!(
+                        "Failed to read {:?} from the processed asset cache: {err}",
+                        asset_path
+                    );
+                    None
+                }
+            }
+        };
+
+        let dependencies_key = ProcessedAssetCacheKey::dependencies(source_hash, settings_hash);
+        let dependencies_bytes = read(dependencies_key).await?;
+        let Ok(dependencies) = ron::de::from_bytes::<Vec<AssetPath<'static>>>(&dependencies_bytes)
+        else {
+            warn!(
+                "The process dependencies of {:?} in the processed asset cache are invalid",
+                asset_path
+            );
+            return None;
+        };
+        let mut dependency_hashes = Vec::with_capacity(dependencies.len());
+        for dependency in dependencies {
+            let dependency = dependency.without_label().into_owned();
+            if self.data.wait_until_processed(dependency.clone()).await != ProcessStatus::Processed
+            {
+                return None;
+            }
+            let infos = self.data.asset_infos.read().await;
+            let full_hash = infos.get(&dependency)?.processed_info.as_ref()?.full_hash;
+            dependency_hashes.push(full_hash);
+        }
+
+        let output_key =
+            ProcessedAssetCacheKey::output(source_hash, settings_hash, dependency_hashes);
+        let Some(cached) = CachedProcessedAsset::from_bytes(&read(output_key).await?) else {
+            warn!(
+                "The processed {:?} in the processed asset cache is invalid",
+                asset_path
+            );
+            return None;
+        };
+        let Some(processed_info) = ron::de::from_bytes::<ProcessedInfoMinimal>(&cached.meta)
+            .ok()
+            .and_then(|minimal| minimal.processed_info)
+        else {
+            warn!(
+                "The processed {:?} in the processed asset cache has invalid meta",
+                asset_path
+            );
+            return None;
+        };
+        Some((cached, processed_info))
+    }
+
+    /// Writes the asset that was just processed to the `cache`, along with the process dependencies needed to find it.
+    /// Failures are logged, as they do not prevent the asset from being used.
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "Internal helper sharing the state of `process_asset_internal`."
+    )]
+    async fn write_cached(
+        &self,
+        cache: &dyn ErasedProcessedAssetCache,
+        source: &AssetSource,
+        asset_path: &AssetPath<'static>,
+        source_hash: AssetHash,
+        settings_hash: AssetHash,
+        processed_info: &ProcessedInfo,
+        meta: Vec<u8>,
+    ) {
+        let result = async {
+            let mut asset = Vec::new();
+            source
+                .processed_reader()?
+                .read(asset_path.path())
+                .await?
+                .read_to_end(&mut asset)
+                .await
+                .map_err(|err| AssetReaderError::Io(err.into()))?;
+            let dependencies: Vec<_> = processed_info
+                .process_dependencies
+                .iter()
+                .map(|dependency| dependency.path.clone())
+                .collect();
+            let dependencies_bytes = ron::ser::to_string(&dependencies)
+                .expect("asset paths should always be serializable");
+            let output_key = ProcessedAssetCacheKey::output(
+                source_hash,
+                settings_hash,
+                processed_info
+                    .process_dependencies
+                    .iter()
+                    .map(|dependency| dependency.full_hash),
+            );
+            // The output is written first, so that it is available once its dependencies can be found.
+            cache
+                .write(
+                    &output_key,
+                    &CachedProcessedAsset { meta, asset }.to_bytes(),
+                )
+                .await?;
+            cache
+                .write(
+                    &ProcessedAssetCacheKey::dependencies(source_hash, settings_hash),
+                    dependencies_bytes.as_bytes(),
+                )
+                .await?;
+            Ok::<_, WriteProcessedAssetCacheError>(())
+        }
+        .await;
+        if let Err(err) = result {
+            warn!(
+                "Failed to write {:?} to the processed asset cache: {err}",
+                asset_path
+            );
+        }
+    }
+
     async fn validate_transaction_log_and_recover(&self) {
         if let Err(err) = ProcessorTransactionLog::validate().await {
             let state_is_valid = match err {
@@ -1027,6 +1217,7 @@ impl AssetProcessorData {
             processors: Default::default(),
             asset_infos: Default::default(),
             default_processors: Default::default(),
+            cache: Default::default(),
         }
     }
 
@@ -1422,3 +1613,173 @@ pub enum InitializeError {
     #[error("Failed to validate asset log: {0}")]
     ValidateLogError(#[from] ValidateLogError),
 }
+
+#[cfg(all(test, not(target_arch = "wasm32"), feature = "multi_threaded"))]
+pub(crate) mod tests {
+    use super::{AssetProcessor, FileProcessedAssetCache, Process, ProcessContext, ProcessError};
+    use crate::{
+        self as bevy_asset,
+        io::{
+            pack::{AssetPack, PackAssetReader, PackAssetWriter},
+            AssetSourceBuilder, AssetSourceBuilders, AssetSourceId, AssetWriterError, Reader,
+            Writer,
+        },
+        loader::{AssetLoader, LoadContext},
+        meta::{AssetAction, AssetMeta},
+        processor::ProcessorTransactionLog,
+        Asset,
+    };
+    use alloc::sync::Arc;
+    use bevy_reflect::TypePath;
+    use bevy_tasks::IoTaskPool;
+    use core::sync::atomic::{AtomicUsize, Ordering};
+    use futures_lite::AsyncWriteExt;
+    use std::{
+        path::Path,
+        sync::{Mutex, PoisonError},
+    };
+
+    #[derive(Asset, TypePath)]
+    pub(crate) struct Text(pub(crate) String);
+
+    /// Loads the first line of a text file, followed by the text of the assets at the paths on
+    /// its next lines.
+    #[derive(Default)]
+    pub(crate) struct TextLoader;
+
+    impl AssetLoader for TextLoader {
+        type Asset = Text;
+        type Settings = ();
+        type Error = std::io::Error;
+
+        async fn load(
+            &self,
+            reader: &mut dyn Reader,
+            _settings: &(),
+            load_context: &mut LoadContext<'_>,
+        ) -> Result<Text, std::io::Error> {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes).await?;
+            let bytes = String::from_utf8_lossy(&bytes).into_owned();
+            let mut lines = bytes.lines();
+            let mut text = lines.next().unwrap_or_default().to_owned();
+            for path in lines {
+                let bytes = load_context
+                    .read_asset_bytes(path.to_owned())
+                    .await
+                    .map_err(std::io::Error::other)?;
+                text.push_str(&String::from_utf8_lossy(&bytes));
+            }
+            Ok(Text(text))
+        }
+
+        fn extensions(&self) -> &[&str] {
+            &["txt"]
+        }
+    }
+
+    /// Uppercases text loaded with [`TextLoader`], counting how many times it ran.
+    #[derive(Default, Clone)]
+    pub(crate) struct UppercaseText(pub(crate) Arc<AtomicUsize>);
+
+    impl UppercaseText {
+        pub(crate) fn runs(&self) -> usize {
+            self.0.load(Ordering::Relaxed)
+        }
+    }
+
+    impl Process for UppercaseText {
+        type Settings = ();
+        type OutputLoader = TextLoader;
+
+        async fn process(
+            &self,
+            context: &mut ProcessContext<'_>,
+            _meta: AssetMeta<(), Self>,
+            writer: &mut Writer,
+        ) -> Result<(), ProcessError> {
+            self.0.fetch_add(1, Ordering::Relaxed);
+            let loader_meta = AssetMeta::<TextLoader, ()>::new(AssetAction::Load {
+                loader: core::any::type_name::<TextLoader>().to_string(),
+                settings: (),
+            });
+            let loaded = context.load_source_asset(loader_meta).await?;
+            let text = loaded.get::<Text>().unwrap().0.to_uppercase();
+            writer
+                .write_all(text.as_bytes())
+                .await
+                .map_err(|err| ProcessError::AssetWriterError {
+                    path: context.path().clone(),
+                    err: AssetWriterError::Io(err),
+                })
+        }
+    }
+
+    /// Serializes the processors run by tests, as they share their transaction log.
+    static PROCESSOR_LOCK: Mutex<()> = Mutex::new(());
+
+    /// Processes the assets of the `source` pack into the `processed` pack, using `uppercase` for
+    /// `.up.txt` assets. The processor can be further set up with `configure`.
+    pub(crate) fn process_packs(
+        source: &Arc<AssetPack>,
+        processed: &Arc<AssetPack>,
+        uppercase: &UppercaseText,
+        configure: impl FnOnce(&AssetProcessor),
+    ) {
+        let reader_pack = source.clone();
+        let writer_pack = source.clone();
+        let mut sources = AssetSourceBuilders::default();
+        sources.insert(
+            AssetSourceId::Default,
+            AssetSourceBuilder::default()
+                .with_reader(move || Box::new(PackAssetReader::new(reader_pack.clone())))
+                .with_writer(move |_| Some(Box::new(PackAssetWriter::new(writer_pack.clone()))))
+                .with_processed_pack(processed.clone()),
+        );
+        let processor = AssetProcessor::new(&mut sources);
+        processor.server().register_loader(TextLoader);
+        processor.register_processor(uppercase.clone());
+        processor.set_default_processor::<UppercaseText>("up.txt");
+        configure(&processor);
+
+        let _lock = PROCESSOR_LOCK
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner);
+        IoTaskPool::get_or_init(Default::default);
+        processor.process_assets();
+        let log_path = ProcessorTransactionLog::full_log_path();
+        let _ = std::fs::remove_file(&log_path);
+        let _ = std::fs::remove_dir(log_path.parent().unwrap());
+    }
+
+    #[test]
+    fn cached_assets_are_not_processed_again() {
+        let source = Arc::new(AssetPack::new());
+        source
+            .write_asset(Path::new("a.up.txt"), b"a\ndep.txt")
+            .unwrap();
+        source.write_asset(Path::new("dep.txt"), b"b").unwrap();
+        let root =
+            std::env::temp_dir().join(format!("bevy_asset_processor_cache_{}", std::process::id()));
+        let uppercase = UppercaseText::default();
+        let process = || {
+            let processed = Arc::new(AssetPack::new());
+            process_packs(&source, &processed, &uppercase, |processor| {
+                processor.set_cache(FileProcessedAssetCache::new(&root));
+            });
+            processed.read_asset(Path::new("a.up.txt")).unwrap()
+        };
+
+        assert_eq!(process(), b"AB");
+        assert_eq!(uppercase.runs(), 1);
+        // Processing into an empty destination again fetches the asset from the cache.
+        assert_eq!(process(), b"AB");
+        assert_eq!(uppercase.runs(), 1);
+        // Changing a process dependency invalidates the cached asset.
+        source.write_asset(Path::new("dep.txt"), b"c").unwrap();
+        assert_eq!(process(), b"AC");
+        assert_eq!(uppercase.runs(), 2);
+
+        std::fs::remove_dir_all(root).unwrap();
+    }
+}
@@ -0,0 +1,185 @@
+use crate::{
+    AssetPath, DependencyLoadState, LoadState, RecursiveDependencyLoadState, UntypedAssetId,
+};
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::Write;
+use serde::{ser::SerializeMap, Serialize, Serializer};
+
+/// A snapshot of the dependencies and dependents of one or more assets tracked by the
+/// [`AssetServer`](crate::AssetServer), returned by
+/// [`AssetServer::get_dependency_graph`](crate::AssetServer::get_dependency_graph) and
+/// [`AssetServer::get_path_dependency_graph`](crate::AssetServer::get_path_dependency_graph).
+///
+/// The graph contains the requested root assets, every asset they (recursively) depend on and
+/// every asset that (recursively) depends on them. It can be serialized, for example to JSON, or
+/// exported to the [Graphviz](https://graphviz.org) DOT language with [`Self::to_dot`].
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct AssetDependencyGraph {
+    /// The indices in [`Self::nodes`] of the assets the graph was requested for.
+    pub roots: Vec<usize>,
+    /// The assets in the graph.
+    pub nodes: Vec<AssetGraphNode>,
+    /// The dependencies between the assets in the graph.
+    pub edges: Vec<AssetGraphEdge>,
+}
+
+/// An asset in an [`AssetDependencyGraph`].
+#[derive(Debug, Clone, Serialize)]
+pub struct AssetGraphNode {
+    /// The id of the asset.
+    ///
+    /// It is serialized as its `index` and `generation`, or as its `uuid`, without its type.
+    #[serde(serialize_with = "serialize_id")]
+    pub id: UntypedAssetId,
+    /// The path of the asset, if it was loaded from one.
+    pub path: Option<AssetPath<'static>>,
+    /// The type name of the [`AssetLoader`](crate::AssetLoader) that loaded the asset, if it was
+    /// loaded from a path.
+    pub loader: Option<&'static str>,
+    /// The [`LoadState`] of the asset.
+    #[serde(serialize_with = "serialize_state")]
+    pub load_state: LoadState,
+    /// The [`DependencyLoadState`] of the asset.
+    #[serde(serialize_with = "serialize_state")]
+    pub dependency_load_state: DependencyLoadState,
+    /// The [`RecursiveDependencyLoadState`] of the asset.
+    #[serde(serialize_with = "serialize_state")]
+    pub recursive_dependency_load_state: RecursiveDependencyLoadState,
+    /// The error the asset failed to load with, if any.
+    pub error: Option<String>,
+}
+
+/// A dependency between two assets in an [`AssetDependencyGraph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub struct AssetGraphEdge {
+    /// The index in [`AssetDependencyGraph::nodes`] of the asset that depends on the other one.
+    pub dependent: usize,
+    /// The index in [`AssetDependencyGraph::nodes`] of the asset that is depended on.
+    pub dependency: usize,
+    /// How the asset is depended on.
+    pub kind: AssetDependencyKind,
+}
+
+/// How an asset depends on another one in an [`AssetDependencyGraph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AssetDependencyKind {
+    /// The asset holds a handle to its dependency, which must be loaded for the asset to be
+    /// considered loaded with its dependencies.
+    Asset,
+    /// The [`AssetLoader`](crate::AssetLoader) of the asset read its dependency while loading it.
+    ///
+    /// These dependencies are only tracked when the [`AssetServer`](crate::AssetServer) is
+    /// watching for changes.
+    Loader,
+}
+
+impl AssetDependencyGraph {
+    /// Returns the node of the asset with the given `id`, if it is part of the graph.
+    pub fn node(&self, id: impl Into<UntypedAssetId>) -> Option<&AssetGraphNode> {
+        let id = id.into();
+        self.nodes.iter().find(|node| node.id == id)
+    }
+
+    /// Returns the nodes of the assets the asset at `index` directly depends on.
+    pub fn dependencies(&self, index: usize) -> impl Iterator<Item = &AssetGraphNode> + '_ {
+        self.edges
+            .iter()
+            .filter(move |edge| edge.dependent == index)
+            .map(|edge| &self.nodes[edge.dependency])
+    }
+
+    /// Returns the nodes of the assets that directly depend on the asset at `index`.
+    pub fn dependents(&self, index: usize) -> impl Iterator<Item = &AssetGraphNode> + '_ {
+        self.edges
+            .iter()
+            .filter(move |edge| edge.dependency == index)
+            .map(|edge| &self.nodes[edge.dependent])
+    }
+
+    /// Exports the graph to the [Graphviz](https://graphviz.org) DOT language.
+    ///
+    /// Root assets are drawn with a bold outline, failed assets are filled in red and loader
+    /// dependencies are drawn as dashed edges.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph assets {\n");
+        dot.push_str("\trankdir=LR;\n");
+        dot.push_str("\tnode [shape=box, style=\"rounded,filled\", fillcolor=white];\n");
+        for (index, node) in self.nodes.iter().enumerate() {
+            let mut lines = Vec::new();
+            lines.push(match &node.path {
+                Some(path) => path.to_string(),
+                None => format!("{:?}", node.id),
+            });
+            lines.extend(node.loader.map(String::from));
+            lines.push(String::from(node.load_state.name()));
+            let _ = write!(dot, "\t{index} [label={}", dot_string(&lines));
+            if self.roots.contains(&index) {
+                dot.push_str(", penwidth=2");
+            }
+            if node.error.is_some() {
+                dot.push_str(", fillcolor=\"#ffb3b3\"");
+            }
+            dot.push_str("];\n");
+        }
+        for edge in &self.edges {
+            let _ = write!(dot, "\t{} -> {}", edge.dependent, edge.dependency);
+            if edge.kind == AssetDependencyKind::Loader {
+                dot.push_str(" [style=dashed]");
+            }
+            dot.push_str(";\n");
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// The load states shown in an [`AssetDependencyGraph`] by name.
+trait StateName {
+    fn name(&self) -> &'static str;
+}
+
+macro_rules! impl_state_name {
+    ($($state:ident),*) => {
+        $(impl StateName for $state {
+            fn name(&self) -> &'static str {
+                match self {
+                    Self::NotLoaded => "not_loaded",
+                    Self::Loading => "loading",
+                    Self::Loaded => "loaded",
+                    Self::Failed(_) => "failed",
+                }
+            }
+        })*
+    };
+}
+
+impl_state_name!(LoadState, DependencyLoadState, RecursiveDependencyLoadState);
+
+fn serialize_id<S: Serializer>(id: &UntypedAssetId, serializer: S) -> Result<S::Ok, S::Error> {
+    let mut map = serializer.serialize_map(None)?;
+    match id {
+        UntypedAssetId::Index { index, .. } => {
+            map.serialize_entry("index", &index.index)?;
+            map.serialize_entry("generation", &index.generation)?;
+        }
+        UntypedAssetId::Uuid { uuid, .. } => map.serialize_entry("uuid", uuid)?,
+    }
+    map.end()
+}
+
+fn serialize_state<S: Serializer>(
+    state: &impl StateName,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.serialize_str(state.name())
+}
+
+/// Quotes the given lines as a DOT string, separated by line breaks.
+fn dot_string(lines: &[String]) -> String {
+    let lines: Vec<_> = lines
+        .iter()
+        .map(|line| line.replace('\\', "\\\\").replace('"', "\\\""))
+        .collect();
+    format!("\"{}\"", lines.join("\\n"))
+}
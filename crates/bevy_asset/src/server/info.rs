@@ -1,10 +1,17 @@
+use super::{
+    graph::{AssetDependencyGraph, AssetDependencyKind, AssetGraphEdge, AssetGraphNode},
+    queue::LoadQueue,
+};
 use crate::{
     meta::{AssetHash, MetaTransform},
     Asset, AssetHandleProvider, AssetLoadError, AssetPath, DependencyLoadState, ErasedLoadedAsset,
     Handle, InternalAssetEvent, LoadState, RecursiveDependencyLoadState, StrongHandle,
     UntypedAssetId, UntypedHandle,
 };
-use alloc::sync::{Arc, Weak};
+use alloc::{
+    collections::VecDeque,
+    sync::{Arc, Weak},
+};
 use bevy_ecs::world::World;
 use bevy_tasks::Task;
 use bevy_utils::{tracing::warn, Entry, HashMap, HashSet, TypeIdMap};
@@ -26,6 +33,12 @@ pub(crate) struct AssetInfo {
     failed_rec_dependencies: HashSet<UntypedAssetId>,
     dependents_waiting_on_load: HashSet<UntypedAssetId>,
     dependents_waiting_on_recursive_dep_load: HashSet<UntypedAssetId>,
+    /// The direct dependencies of this asset, set using the value from [`LoadedAsset`].
+    ///
+    /// [`LoadedAsset`]: crate::loader::LoadedAsset
+    dependencies: HashSet<UntypedAssetId>,
+    /// The type name of the loader used to load this asset, if a load was started for it.
+    pub(crate) loader_name: Option<&'static str>,
     /// The asset paths required to load this asset. Hashes will only be set for processed assets.
     /// This is set using the value from [`LoadedAsset`].
     /// This will only be populated if [`AssetInfos::watching_for_changes`] is set to `true` to
@@ -55,6 +68,8 @@ impl AssetInfo {
             loader_dependencies: HashMap::default(),
             dependents_waiting_on_load: HashSet::default(),
             dependents_waiting_on_recursive_dep_load: HashSet::default(),
+            dependencies: HashSet::default(),
+            loader_name: None,
             handle_drops_to_skip: 0,
             waiting_tasks: Vec::new(),
         }
@@ -79,6 +94,8 @@ pub(crate) struct AssetInfos {
     pub(crate) dependency_failed_event_sender:
         TypeIdMap<fn(&mut World, UntypedAssetId, AssetPath<'static>, AssetLoadError)>,
     pub(crate) pending_tasks: HashMap<UntypedAssetId, Task<()>>,
+    /// Loads that were requested but not started yet, because too many loads are in flight.
+    pub(crate) load_queue: LoadQueue,
 }
 
 impl core::fmt::Debug for AssetInfos {
@@ -367,6 +384,126 @@ impl AssetInfos {
         }
     }
 
+    /// Returns the direct dependencies of the asset with the given `id`, including its loader
+    /// dependencies if they are tracked.
+    fn direct_dependencies(
+        &self,
+        id: UntypedAssetId,
+    ) -> impl Iterator<Item = (UntypedAssetId, AssetDependencyKind)> + '_ {
+        let info = self.infos.get(&id);
+        let dependencies = info
+            .into_iter()
+            .flat_map(|info| info.dependencies.iter())
+            .map(|id| (*id, AssetDependencyKind::Asset));
+        let loader_dependencies = info
+            .into_iter()
+            .flat_map(|info| info.loader_dependencies.keys())
+            .flat_map(|path| self.get_path_ids(path))
+            .map(|id| (id, AssetDependencyKind::Loader));
+        dependencies
+            .chain(loader_dependencies)
+            .filter(|(id, _)| self.infos.contains_key(id))
+    }
+
+    /// Builds the graph of the given `roots`, the assets they depend on and the assets that
+    /// depend on them.
+    pub(crate) fn dependency_graph(
+        &self,
+        roots: impl IntoIterator<Item = UntypedAssetId>,
+    ) -> AssetDependencyGraph {
+        let mut graph = AssetDependencyGraph::default();
+        let mut indices = HashMap::<UntypedAssetId, usize>::default();
+        let mut edges = HashSet::<AssetGraphEdge>::default();
+        let mut node_index = |graph: &mut AssetDependencyGraph, id: UntypedAssetId| {
+            *indices.entry(id).or_insert_with(|| {
+                let info = &self.infos[&id];
+                let error = match &info.load_state {
+                    LoadState::Failed(error) => Some(error.to_string()),
+                    _ => None,
+                };
+                graph.nodes.push(AssetGraphNode {
+                    id,
+                    path: info.path.clone(),
+                    loader: info.loader_name,
+                    load_state: info.load_state.clone(),
+                    dependency_load_state: info.dep_load_state.clone(),
+                    recursive_dependency_load_state: info.rec_dep_load_state.clone(),
+                    error,
+                });
+                graph.nodes.len() - 1
+            })
+        };
+        let mut add_edge = |graph: &mut AssetDependencyGraph, edge: AssetGraphEdge| {
+            if edges.insert(edge) {
+                graph.edges.push(edge);
+            }
+        };
+
+        let roots: Vec<_> = roots
+            .into_iter()
+            .filter(|id| self.infos.contains_key(id))
+            .collect();
+        for root in &roots {
+            let index = node_index(&mut graph, *root);
+            if !graph.roots.contains(&index) {
+                graph.roots.push(index);
+            }
+        }
+
+        // Walk down to the dependencies of the roots.
+        let mut visited: HashSet<_> = roots.iter().copied().collect();
+        let mut queue: VecDeque<_> = roots.iter().copied().collect();
+        while let Some(id) = queue.pop_front() {
+            let dependent = node_index(&mut graph, id);
+            for (dependency_id, kind) in self.direct_dependencies(id) {
+                let dependency = node_index(&mut graph, dependency_id);
+                add_edge(
+                    &mut graph,
+                    AssetGraphEdge {
+                        dependent,
+                        dependency,
+                        kind,
+                    },
+                );
+                if visited.insert(dependency_id) {
+                    queue.push_back(dependency_id);
+                }
+            }
+        }
+
+        // Walk up to the dependents of the roots, which are only tracked in the other direction.
+        let mut dependents = HashMap::<UntypedAssetId, Vec<_>>::default();
+        for id in self.infos.keys() {
+            for (dependency_id, kind) in self.direct_dependencies(*id) {
+                dependents
+                    .entry(dependency_id)
+                    .or_default()
+                    .push((*id, kind));
+            }
+        }
+        let mut visited: HashSet<_> = roots.iter().copied().collect();
+        let mut queue: VecDeque<_> = roots.iter().copied().collect();
+        while let Some(id) = queue.pop_front() {
+            let dependency = node_index(&mut graph, id);
+            for (dependent_id, kind) in dependents.get(&id).into_iter().flatten() {
+                let dependent = node_index(&mut graph, *dependent_id);
+                add_edge(
+                    &mut graph,
+                    AssetGraphEdge {
+                        dependent,
+                        dependency,
+                        kind: *kind,
+                    },
+                );
+                if visited.insert(*dependent_id) {
+                    queue.push_back(*dependent_id);
+                }
+            }
+        }
+
+        graph
+    }
+
     /// Returns `true` if the asset should be removed from the collection.
     pub(crate) fn process_handle_drop(&mut self, id: UntypedAssetId) -> bool {
         Self::process_handle_drop_internal(
@@ -375,6 +512,7 @@ impl AssetInfos {
             &mut self.loader_dependents,
             &mut self.living_labeled_assets,
             &mut self.pending_tasks,
+            &mut self.load_queue,
             self.watching_for_changes,
             id,
         )
@@ -394,6 +532,7 @@ impl AssetInfos {
         }
 
         loaded_asset.value.insert(loaded_asset_id, world);
+        let dependencies = loaded_asset.dependencies.clone();
         let mut loading_deps = loaded_asset.dependencies;
         let mut failed_deps = <HashSet<_>>::default();
         let mut dep_error = None;
@@ -490,6 +629,7 @@ impl AssetInfos {
             let info = self
                 .get_mut(loaded_asset_id)
                 .expect("Asset info should always exist at this point");
+            info.dependencies = dependencies;
             info.loading_dependencies = loading_deps;
             info.failed_dependencies = failed_deps;
             info.loading_rec_dependencies = loading_rec_deps;
@@ -673,12 +813,17 @@ impl AssetInfos {
         }
     }
 
+    #[expect(
+        clippy::too_many_arguments,
+        reason = "Arguments needed so that both `process_handle_drop()` and `consume_handle_drop_events()` may share code."
+    )]
     fn process_handle_drop_internal(
         infos: &mut HashMap<UntypedAssetId, AssetInfo>,
         path_to_id: &mut HashMap<AssetPath<'static>, TypeIdMap<UntypedAssetId>>,
         loader_dependents: &mut HashMap<AssetPath<'static>, HashSet<AssetPath<'static>>>,
         living_labeled_assets: &mut HashMap<AssetPath<'static>, HashSet<Box<str>>>,
         pending_tasks: &mut HashMap<UntypedAssetId, Task<()>>,
+        load_queue: &mut LoadQueue,
         watching_for_changes: bool,
         id: UntypedAssetId,
     ) -> bool {
@@ -694,6 +839,7 @@ impl AssetInfos {
         }
 
         pending_tasks.remove(&id);
+        load_queue.remove(id);
 
         let type_id = entry.key().type_id();
 
@@ -738,6 +884,7 @@ impl AssetInfos {
                         &mut self.loader_dependents,
                         &mut self.living_labeled_assets,
                         &mut self.pending_tasks,
+                        &mut self.load_queue,
                         self.watching_for_changes,
                         id.untyped(provider.type_id),
                     );
@@ -1,5 +1,10 @@
+mod graph;
 mod info;
 mod loaders;
+mod queue;
+
+pub use graph::*;
+pub use queue::LoadPriority;
 
 use crate::{
     folder::LoadedFolder,
@@ -23,7 +28,7 @@ use bevy_ecs::prelude::*;
 use bevy_tasks::IoTaskPool;
 use bevy_utils::{
     tracing::{error, info},
-    HashSet,
+    BoxedFuture, HashSet,
 };
 use core::{any::TypeId, future::Future, panic::AssertUnwindSafe, task::Poll};
 use crossbeam_channel::{Receiver, Sender};
@@ -306,7 +311,7 @@ impl AssetServer {
     /// The asset load will fail and an error will be printed to the logs if the asset stored at `path` is not of type `A`.
     #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
     pub fn load<'a, A: Asset>(&self, path: impl Into<AssetPath<'a>>) -> Handle<A> {
-        self.load_with_meta_transform(path, None, ())
+        self.load_with_meta_transform(path, None, LoadPriority::NORMAL, ())
     }
 
     /// Begins loading an [`Asset`] of type `A` stored at `path` while holding a guard item.
@@ -330,7 +335,7 @@ impl AssetServer {
         path: impl Into<AssetPath<'a>>,
         guard: G,
     ) -> Handle<A> {
-        self.load_with_meta_transform(path, None, guard)
+        self.load_with_meta_transform(path, None, LoadPriority::NORMAL, guard)
     }
 
     /// Begins loading an [`Asset`] of type `A` stored at `path`. The given `settings` function will override the asset's
@@ -342,7 +347,12 @@ impl AssetServer {
         path: impl Into<AssetPath<'a>>,
         settings: impl Fn(&mut S) + Send + Sync + 'static,
     ) -> Handle<A> {
-        self.load_with_meta_transform(path, Some(loader_settings_meta_transform(settings)), ())
+        self.load_with_meta_transform(
+            path,
+            Some(loader_settings_meta_transform(settings)),
+            LoadPriority::NORMAL,
+            (),
+        )
     }
 
     /// Begins loading an [`Asset`] of type `A` stored at `path` while holding a guard item.
@@ -361,13 +371,19 @@ impl AssetServer {
         settings: impl Fn(&mut S) + Send + Sync + 'static,
         guard: G,
     ) -> Handle<A> {
-        self.load_with_meta_transform(path, Some(loader_settings_meta_transform(settings)), guard)
+        self.load_with_meta_transform(
+            path,
+            Some(loader_settings_meta_transform(settings)),
+            LoadPriority::NORMAL,
+            guard,
+        )
     }
 
     pub(crate) fn load_with_meta_transform<'a, A: Asset, G: Send + Sync + 'static>(
         &self,
         path: impl Into<AssetPath<'a>>,
         meta_transform: Option<MetaTransform>,
+        priority: LoadPriority,
         guard: G,
     ) -> Handle<A> {
         let path = path.into().into_owned();
@@ -379,7 +395,7 @@ impl AssetServer {
         );
 
         if should_load {
-            self.spawn_load_task(handle.clone().untyped(), path, infos, guard);
+            self.spawn_load_task(handle.clone().untyped(), path, infos, priority, guard);
         }
 
         handle
@@ -390,6 +406,7 @@ impl AssetServer {
         path: impl Into<AssetPath<'a>>,
         type_id: TypeId,
         meta_transform: Option<MetaTransform>,
+        priority: LoadPriority,
         guard: G,
     ) -> UntypedHandle {
         let path = path.into().into_owned();
@@ -403,7 +420,7 @@ impl AssetServer {
         );
 
         if should_load {
-            self.spawn_load_task(handle.clone(), path, infos, guard);
+            self.spawn_load_task(handle.clone(), path, infos, priority, guard);
         }
 
         handle
@@ -414,32 +431,135 @@ impl AssetServer {
         handle: UntypedHandle,
         path: AssetPath<'static>,
         infos: RwLockWriteGuard<AssetInfos>,
+        priority: LoadPriority,
         guard: G,
     ) {
-        // drop the lock on `AssetInfos` before spawning a task that may block on it in single-threaded
-        #[cfg(any(target_arch = "wasm32", not(feature = "multi_threaded")))]
-        drop(infos);
-
-        let owned_handle = handle.clone();
         let server = self.clone();
-        let task = IoTaskPool::get().spawn(async move {
-            if let Err(err) = server
-                .load_internal(Some(owned_handle), path, false, None)
-                .await
+        self.queue_load(handle.id(), priority, infos, move |handle| {
+            Box::pin(async move {
+                if let Err(err) = server.load_internal(Some(handle), path, false, None).await {
+                    error!("{}", err);
+                }
+                drop(guard);
+            })
+        });
+    }
+
+    /// Queues a load of the asset with the given `id`, and starts it right away if the number of loads in flight
+    /// allows it. `start` is given a strong handle to the asset when the load is started.
+    fn queue_load(
+        &self,
+        id: UntypedAssetId,
+        priority: LoadPriority,
+        mut infos: RwLockWriteGuard<AssetInfos>,
+        start: impl FnOnce(UntypedHandle) -> BoxedFuture<'static, ()> + Send + Sync + 'static,
+    ) {
+        infos.load_queue.push(id, priority, start);
+        self.start_queued_loads(infos);
+    }
+
+    /// Starts the queued loads with the highest priority, until the maximum number of loads in flight is reached.
+    pub(crate) fn start_queued_loads<'a>(&'a self, mut infos: RwLockWriteGuard<'a, AssetInfos>) {
+        while let Some((id, start, in_flight)) = infos.load_queue.pop() {
+            // the load was cancelled if all the handles to the asset were dropped
+            let Some(handle) = infos.get_id_handle(id) else {
+                continue;
+            };
+            let server = self.clone();
+            let load = start(handle);
+            let future = async move {
+                load.await;
+                drop(in_flight);
+                if !server.data.infos.read().load_queue.is_empty() {
+                    server.start_queued_loads(server.data.infos.write());
+                }
+            };
+
+            // drop the lock on `AssetInfos` before spawning a task that may block on it in single-threaded
+            #[cfg(any(target_arch = "wasm32", not(feature = "multi_threaded")))]
             {
-                error!("{}", err);
+                drop(infos);
+                IoTaskPool::get().spawn(future).detach();
+                infos = self.data.infos.write();
             }
-            drop(guard);
-        });
 
-        #[cfg(not(any(target_arch = "wasm32", not(feature = "multi_threaded"))))]
-        {
-            let mut infos = infos;
-            infos.pending_tasks.insert(handle.id(), task);
+            #[cfg(not(any(target_arch = "wasm32", not(feature = "multi_threaded"))))]
+            {
+                let task = IoTaskPool::get().spawn(future);
+                infos.pending_tasks.insert(id, task);
+            }
         }
+    }
 
-        #[cfg(any(target_arch = "wasm32", not(feature = "multi_threaded")))]
-        task.detach();
+    /// Begins loading an [`Asset`] of type `A` stored at `path`, like [`AssetServer::load`], with the given `priority`.
+    ///
+    /// The priority is only used when the number of loads in flight is limited by [`AssetServer::set_max_in_flight_loads`],
+    /// to choose which queued load to start first. There is no limit by default, in which case every load starts right
+    /// away and the priority has no effect. It can be changed while the load is queued with
+    /// [`AssetServer::set_load_priority`]. The dependencies of the asset are loaded with [`LoadPriority::NORMAL`].
+    ///
+    /// ```no_run
+    /// # use bevy_asset::{AssetServer, Handle, LoadedUntypedAsset, LoadPriority};
+    /// # use bevy_ecs::prelude::Res;
+    /// # fn setup(asset_server: Res<AssetServer>) {
+    /// # let handle: Handle<LoadedUntypedAsset> =
+    /// asset_server.load_with_priority("player.png", LoadPriority::HIGH);
+    /// # }
+    /// ```
+    #[must_use = "not using the returned strong handle may result in the unexpected release of the asset"]
+    pub fn load_with_priority<'a, A: Asset>(
+        &self,
+        path: impl Into<AssetPath<'a>>,
+        priority: LoadPriority,
+    ) -> Handle<A> {
+        self.load_with_meta_transform(path, None, priority, ())
+    }
+
+    /// Changes the priority of the load of the asset with the given `id`, if it is queued.
+    ///
+    /// Returns `false` if the load is not queued, because it already started, finished, or was never requested.
+    pub fn set_load_priority(&self, id: impl Into<UntypedAssetId>, priority: LoadPriority) -> bool {
+        self.data
+            .infos
+            .write()
+            .load_queue
+            .set_priority(id.into(), priority)
+    }
+
+    /// Returns `true` if the load of the asset with the given `id` is queued, waiting for other loads to finish.
+    ///
+    /// Queued assets are in the [`LoadState::Loading`] state.
+    pub fn is_load_queued(&self, id: impl Into<UntypedAssetId>) -> bool {
+        self.data.infos.read().load_queue.contains(id.into())
+    }
+
+    /// Returns the number of queued loads, waiting for other loads to finish.
+    pub fn queued_load_count(&self) -> usize {
+        self.data.infos.read().load_queue.len()
+    }
+
+    /// Returns the number of loads in flight, started by [`AssetServer::load`] and its variants.
+    pub fn in_flight_load_count(&self) -> usize {
+        self.data.infos.read().load_queue.in_flight()
+    }
+
+    /// Returns the maximum number of loads in flight. See [`AssetServer::set_max_in_flight_loads`].
+    pub fn max_in_flight_loads(&self) -> Option<usize> {
+        self.data.infos.read().load_queue.max_in_flight()
+    }
+
+    /// Limits the number of loads started by [`AssetServer::load`] and its variants that can be in flight at the same
+    /// time, or removes the limit if `max_in_flight_loads` is [`None`], which is the default.
+    ///
+    /// Once the limit is reached, new loads are queued and started by [`LoadPriority`] as loads in flight finish.
+    /// Dropping all the strong handles to an asset whose load is queued cancels the load, and so does dropping them
+    /// while the load is in flight with the `multi_threaded` feature.
+    ///
+    /// Reloads, folder loads and [`AssetServer::add_async`] are not limited.
+    pub fn set_max_in_flight_loads(&self, max_in_flight_loads: Option<usize>) {
+        let mut infos = self.data.infos.write();
+        infos.load_queue.set_max_in_flight(max_in_flight_loads);
+        self.start_queued_loads(infos);
     }
 
     /// Asynchronously load an asset that you do not know the type of statically. If you _do_ know the type of the asset,
@@ -458,6 +578,7 @@ impl AssetServer {
         &self,
         path: impl Into<AssetPath<'a>>,
         meta_transform: Option<MetaTransform>,
+        priority: LoadPriority,
     ) -> Handle<LoadedUntypedAsset> {
         let path = path.into().into_owned();
         let untyped_source = AssetSourceId::Name(match path.source() {
@@ -473,44 +594,36 @@ impl AssetServer {
             meta_transform,
         );
 
-        // drop the lock on `AssetInfos` before spawning a task that may block on it in single-threaded
-        #[cfg(any(target_arch = "wasm32", not(feature = "multi_threaded")))]
-        drop(infos);
-
         if !should_load {
             return handle;
         }
         let id = handle.id().untyped();
 
         let server = self.clone();
-        let task = IoTaskPool::get().spawn(async move {
-            let path_clone = path.clone();
-            match server.load_untyped_async(path).await {
-                Ok(handle) => server.send_asset_event(InternalAssetEvent::Loaded {
-                    id,
-                    loaded_asset: LoadedAsset::new_with_dependencies(
-                        LoadedUntypedAsset { handle },
-                        None,
-                    )
-                    .into(),
-                }),
-                Err(err) => {
-                    error!("{err}");
-                    server.send_asset_event(InternalAssetEvent::Failed {
+        self.queue_load(id, priority, infos, move |_| {
+            Box::pin(async move {
+                let path_clone = path.clone();
+                match server.load_untyped_async(path).await {
+                    Ok(handle) => server.send_asset_event(InternalAssetEvent::Loaded {
                         id,
-                        path: path_clone,
-                        error: err,
-                    });
+                        loaded_asset: LoadedAsset::new_with_dependencies(
+                            LoadedUntypedAsset { handle },
+                            None,
+                        )
+                        .into(),
+                    }),
+                    Err(err) => {
+                        error!("{err}");
+                        server.send_asset_event(InternalAssetEvent::Failed {
+                            id,
+                            path: path_clone,
+                            error: err,
+                        });
+                    }
                 }
-            }
+            })
         });
 
-        #[cfg(not(any(target_arch = "wasm32", not(feature = "multi_threaded"))))]
-        infos.pending_tasks.insert(handle.id().untyped(), task);
-
-        #[cfg(any(target_arch = "wasm32", not(feature = "multi_threaded")))]
-        task.detach();
-
         handle
     }
 
@@ -538,7 +651,7 @@ impl AssetServer {
     /// required to figure out the asset type before a handle can be created.
     #[must_use = "not using the returned strong handle may result in the unexpected release of the assets"]
     pub fn load_untyped<'a>(&self, path: impl Into<AssetPath<'a>>) -> Handle<LoadedUntypedAsset> {
-        self.load_unknown_type_with_meta_transform(path, None)
+        self.load_unknown_type_with_meta_transform(path, None, LoadPriority::NORMAL)
     }
 
     /// Performs an async asset load.
@@ -673,7 +786,7 @@ impl AssetServer {
                     handle.unwrap()
                 };
 
-                self.send_loaded_asset(base_handle.id(), loaded_asset);
+                self.send_loaded_asset(base_handle.id(), loaded_asset, loader.type_name());
                 Ok(final_handle)
             }
             Err(err) => {
@@ -688,10 +801,19 @@ impl AssetServer {
     }
 
     /// Sends a load event for the given `loaded_asset` and does the same recursively for all
-    /// labeled assets.
-    fn send_loaded_asset(&self, id: UntypedAssetId, mut loaded_asset: ErasedLoadedAsset) {
+    /// labeled assets, recording the name of the loader that loaded them.
+    fn send_loaded_asset(
+        &self,
+        id: UntypedAssetId,
+        mut loaded_asset: ErasedLoadedAsset,
+        loader_name: &'static str,
+    ) {
         for (_, labeled_asset) in loaded_asset.labeled_assets.drain() {
-            self.send_loaded_asset(labeled_asset.handle.id(), labeled_asset.asset);
+            self.send_loaded_asset(labeled_asset.handle.id(), labeled_asset.asset, loader_name);
+        }
+
+        if let Some(info) = self.data.infos.write().get_mut(id) {
+            info.loader_name = Some(loader_name);
         }
 
         self.send_asset_event(InternalAssetEvent::Loaded { id, loaded_asset });
@@ -1090,6 +1212,32 @@ impl AssetServer {
         self.data.infos.read().contains_key(id.into())
     }
 
+    /// Returns the [`AssetDependencyGraph`] of the asset with the given `id`: the assets it
+    /// depends on and the assets that depend on it, with their loaders and load states.
+    ///
+    /// Returns [`None`] if the asset is not tracked by the [`AssetServer`].
+    pub fn get_dependency_graph(
+        &self,
+        id: impl Into<UntypedAssetId>,
+    ) -> Option<AssetDependencyGraph> {
+        let graph = self.data.infos.read().dependency_graph([id.into()]);
+        (!graph.roots.is_empty()).then_some(graph)
+    }
+
+    /// Returns the [`AssetDependencyGraph`] of the assets at the given path. There may be more
+    /// than one root asset if the path is used by multiple [`AssetLoader`]s.
+    ///
+    /// Returns [`None`] if no asset at the given path is tracked by the [`AssetServer`].
+    pub fn get_path_dependency_graph<'a>(
+        &self,
+        path: impl Into<AssetPath<'a>>,
+    ) -> Option<AssetDependencyGraph> {
+        let infos = self.data.infos.read();
+        let path = path.into();
+        let graph = infos.dependency_graph(infos.get_path_ids(&path));
+        (!graph.roots.is_empty()).then_some(graph)
+    }
+
     /// Returns an active untyped asset id for the given path, if the asset at the given path has already started loading,
     /// or is still "alive".
     /// Returns the first ID in the event of multiple assets being registered against a single path.
@@ -1596,6 +1744,9 @@ pub fn handle_internal_asset_events(world: &mut World) {
         infos
             .pending_tasks
             .retain(|_, load_task| !load_task.is_finished());
+
+        // start the loads queued while cancelled loads were in flight
+        server.start_queued_loads(infos);
     });
 }
 
@@ -0,0 +1,189 @@
+use crate::{UntypedAssetId, UntypedHandle};
+use alloc::{collections::BinaryHeap, sync::Arc};
+use bevy_utils::{BoxedFuture, HashMap};
+use core::{
+    cmp::Reverse,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// The priority of an asset load, used by the [`AssetServer`](crate::AssetServer) to choose which
+/// queued load to start first when the number of loads in flight is limited.
+///
+/// Loads with a higher priority are started first, and loads with the same priority are started
+/// in the order they were requested. The number of loads in flight is not limited by default, so
+/// loads are then started right away, whatever their priority. See [`AssetServer::load_with_priority`](crate::AssetServer::load_with_priority)
+/// and [`AssetServer::set_max_in_flight_loads`](crate::AssetServer::set_max_in_flight_loads).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct LoadPriority(pub i32);
+
+impl LoadPriority {
+    /// A priority for loads that can wait, such as assets that are far away from the camera.
+    pub const LOW: Self = Self(-100);
+    /// The priority of loads started by [`AssetServer::load`](crate::AssetServer::load) and its
+    /// variants, and of the dependencies of loaded assets.
+    pub const NORMAL: Self = Self(0);
+    /// A priority for loads that are needed as soon as possible.
+    pub const HIGH: Self = Self(100);
+}
+
+/// Starts a queued load, given a strong handle to the asset to load.
+type StartLoad = Box<dyn FnOnce(UntypedHandle) -> BoxedFuture<'static, ()> + Send + Sync>;
+
+struct QueuedLoad {
+    priority: LoadPriority,
+    sequence: u64,
+    start: StartLoad,
+}
+
+/// The asset loads waiting for one of the loads in flight to finish, when their number is limited.
+#[derive(Default)]
+pub(crate) struct LoadQueue {
+    queued: HashMap<UntypedAssetId, QueuedLoad>,
+    /// The queued loads, ordered by priority then by request order. Entries whose priority or
+    /// sequence don't match the load in `queued` anymore are stale, and skipped when popped.
+    order: BinaryHeap<(LoadPriority, Reverse<u64>, UntypedAssetId)>,
+    next_sequence: u64,
+    in_flight: Arc<AtomicUsize>,
+    max_in_flight: Option<usize>,
+}
+
+impl LoadQueue {
+    pub(crate) fn push(
+        &mut self,
+        id: UntypedAssetId,
+        priority: LoadPriority,
+        start: impl FnOnce(UntypedHandle) -> BoxedFuture<'static, ()> + Send + Sync + 'static,
+    ) {
+        let sequence = self.next_sequence;
+        self.next_sequence += 1;
+        self.order.push((priority, Reverse(sequence), id));
+        self.queued.insert(
+            id,
+            QueuedLoad {
+                priority,
+                sequence,
+                start: Box::new(start),
+            },
+        );
+    }
+
+    /// Removes the load of the asset with the given `id` from the queue, if it wasn't started yet.
+    pub(crate) fn remove(&mut self, id: UntypedAssetId) -> bool {
+        let removed = self.queued.remove(&id).is_some();
+        self.remove_stale();
+        removed
+    }
+
+    pub(crate) fn contains(&self, id: UntypedAssetId) -> bool {
+        self.queued.contains_key(&id)
+    }
+
+    pub(crate) fn set_priority(&mut self, id: UntypedAssetId, priority: LoadPriority) -> bool {
+        let Some(load) = self.queued.get_mut(&id) else {
+            return false;
+        };
+        if load.priority != priority {
+            load.priority = priority;
+            self.order.push((priority, Reverse(load.sequence), id));
+            self.remove_stale();
+        }
+        true
+    }
+
+    /// Rebuilds the order of the queued loads once most of its entries are stale.
+    fn remove_stale(&mut self) {
+        if self.order.len() > 2 * self.queued.len() {
+            self.order = self
+                .queued
+                .iter()
+                .map(|(id, load)| (load.priority, Reverse(load.sequence), *id))
+                .collect();
+        }
+    }
+
+    pub(crate) fn is_empty(&self) -> bool {
+        self.queued.is_empty()
+    }
+
+    pub(crate) fn len(&self) -> usize {
+        self.queued.len()
+    }
+
+    pub(crate) fn in_flight(&self) -> usize {
+        self.in_flight.load(Ordering::Acquire)
+    }
+
+    pub(crate) fn max_in_flight(&self) -> Option<usize> {
+        self.max_in_flight
+    }
+
+    pub(crate) fn set_max_in_flight(&mut self, max_in_flight: Option<usize>) {
+        self.max_in_flight = max_in_flight;
+    }
+
+    /// Removes the queued load with the highest priority if another load can be started, and
+    /// counts it as in flight until the returned [`InFlightLoad`] is dropped.
+    pub(crate) fn pop(&mut self) -> Option<(UntypedAssetId, StartLoad, InFlightLoad)> {
+        if self
+            .max_in_flight
+            .is_some_and(|max_in_flight| self.in_flight() >= max_in_flight)
+        {
+            return None;
+        }
+        let (id, load) = loop {
+            let (priority, Reverse(sequence), id) = self.order.pop()?;
+            if self
+                .queued
+                .get(&id)
+                .is_some_and(|load| load.priority == priority && load.sequence == sequence)
+            {
+                break (id, self.queued.remove(&id)?);
+            }
+        };
+        self.in_flight.fetch_add(1, Ordering::AcqRel);
+        Some((id, load.start, InFlightLoad(self.in_flight.clone())))
+    }
+}
+
+/// Counts a load as in flight until it is dropped, when the load finishes or is cancelled.
+pub(crate) struct InFlightLoad(Arc<AtomicUsize>);
+
+impl Drop for InFlightLoad {
+    fn drop(&mut self) {
+        self.0.fetch_sub(1, Ordering::AcqRel);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{LoadPriority, LoadQueue};
+    use crate::UntypedAssetId;
+    use alloc::{boxed::Box, vec::Vec};
+    use core::any::TypeId;
+    use uuid::Uuid;
+
+    fn id(n: u128) -> UntypedAssetId {
+        UntypedAssetId::Uuid {
+            type_id: TypeId::of::<()>(),
+            uuid: Uuid::from_u128(n),
+        }
+    }
+
+    #[test]
+    fn pop_by_priority_then_request_order() {
+        let mut queue = LoadQueue::default();
+        for n in 0..4 {
+            queue.push(id(n), LoadPriority::NORMAL, |_| Box::pin(async {}));
+        }
+        queue.push(id(4), LoadPriority::LOW, |_| Box::pin(async {}));
+        assert!(queue.set_priority(id(2), LoadPriority::HIGH));
+        assert!(queue.set_priority(id(4), LoadPriority::NORMAL));
+        assert!(queue.remove(id(1)));
+        assert!(!queue.set_priority(id(1), LoadPriority::HIGH));
+
+        let order: Vec<_> = core::iter::from_fn(|| queue.pop().map(|(id, ..)| id)).collect();
+        assert_eq!(order, [id(2), id(0), id(3), id(4)]);
+        assert!(queue.is_empty());
+        assert_eq!(queue.in_flight(), 0);
+    }
+}
@@ -44,7 +44,7 @@ impl Plugin for TypeRegistrationPlugin {
     #[cfg_attr(not(feature = "bevy_reflect"), allow(unused_variables))]
     fn build(&self, app: &mut App) {
         #[cfg(feature = "bevy_reflect")]
-        app.register_type::<Name>();
+        app.register_type::<Name>().register_type::<Disabled>();
     }
 }
 
@@ -0,0 +1,135 @@
+use crate::{
+    Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore, RegisterDiagnostic,
+};
+use bevy_app::prelude::*;
+use bevy_ecs::prelude::*;
+use bevy_time::{Real, Time};
+use bevy_utils::Instant;
+
+/// Adds diagnostics about how entities and components are laid out in memory, to spot archetype
+/// fragmentation in running builds.
+///
+/// The following diagnostics are recorded every frame:
+/// - `storage/archetypes`: the number of archetypes.
+/// - `storage/empty_archetypes`: the number of archetypes without entities.
+/// - `storage/archetype_creation_rate`: the number of archetypes created per second.
+/// - `storage/tables`: the number of tables.
+/// - `storage/table_occupancy`: the percentage of the capacity of the tables used by entities.
+/// - `storage/allocated_bytes`: the number of bytes allocated by tables and sparse sets.
+/// - `storage/component_bytes/<name>`: the number of bytes allocated to store each component,
+///   if [`component_bytes`](Self::component_bytes) is enabled.
+///
+/// Computing these walks every archetype, table and sparse set of the world. See
+/// [`World::storage_stats`] for the full statistics.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+pub struct StorageDiagnosticsPlugin {
+    /// Whether to record the number of bytes allocated to store each component.
+    pub component_bytes: bool,
+}
+
+impl Default for StorageDiagnosticsPlugin {
+    fn default() -> Self {
+        Self {
+            component_bytes: true,
+        }
+    }
+}
+
+impl Plugin for StorageDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.register_diagnostic(Diagnostic::new(Self::ARCHETYPE_COUNT))
+            .register_diagnostic(Diagnostic::new(Self::EMPTY_ARCHETYPE_COUNT))
+            .register_diagnostic(Diagnostic::new(Self::ARCHETYPE_CREATION_RATE).with_suffix("/s"))
+            .register_diagnostic(Diagnostic::new(Self::TABLE_COUNT))
+            .register_diagnostic(Diagnostic::new(Self::TABLE_OCCUPANCY).with_suffix("%"))
+            .register_diagnostic(Diagnostic::new(Self::ALLOCATED_BYTES).with_suffix("B"));
+
+        let component_bytes = self.component_bytes;
+        app.add_systems(
+            Update,
+            move |world: &mut World, last_archetype_count: Local<Option<usize>>| {
+                Self::diagnostic_system(world, last_archetype_count, component_bytes);
+            },
+        );
+    }
+}
+
+impl StorageDiagnosticsPlugin {
+    pub const ARCHETYPE_COUNT: DiagnosticPath = DiagnosticPath::const_new("storage/archetypes");
+    pub const EMPTY_ARCHETYPE_COUNT: DiagnosticPath =
+        DiagnosticPath::const_new("storage/empty_archetypes");
+    pub const ARCHETYPE_CREATION_RATE: DiagnosticPath =
+        DiagnosticPath::const_new("storage/archetype_creation_rate");
+    pub const TABLE_COUNT: DiagnosticPath = DiagnosticPath::const_new("storage/tables");
+    pub const TABLE_OCCUPANCY: DiagnosticPath =
+        DiagnosticPath::const_new("storage/table_occupancy");
+    pub const ALLOCATED_BYTES: DiagnosticPath =
+        DiagnosticPath::const_new("storage/allocated_bytes");
+
+    /// Returns the path of the diagnostic with the number of bytes allocated to store the
+    /// component named `name`.
+    pub fn component_bytes_path(name: &str) -> DiagnosticPath {
+        DiagnosticPath::new(format!(
+            "storage/component_bytes/{}",
+            name.replace('/', "_")
+        ))
+    }
+
+    pub fn diagnostic_system(
+        world: &mut World,
+        mut last_archetype_count: Local<Option<usize>>,
+        component_bytes: bool,
+    ) {
+        let stats = world.storage_stats();
+        let delta_seconds = world
+            .get_resource::<Time<Real>>()
+            .map(Time::delta_secs_f64)
+            .unwrap_or_default();
+
+        let mut measurements = vec![
+            (Self::ARCHETYPE_COUNT, stats.archetype_count() as f64),
+            (
+                Self::EMPTY_ARCHETYPE_COUNT,
+                stats.empty_archetype_count() as f64,
+            ),
+            (Self::TABLE_COUNT, stats.tables.len() as f64),
+            (Self::TABLE_OCCUPANCY, stats.table_occupancy() * 100.0),
+            (Self::ALLOCATED_BYTES, stats.allocated_bytes() as f64),
+        ];
+        if let Some(last_archetype_count) = *last_archetype_count {
+            if delta_seconds > 0.0 {
+                let created = stats.archetype_count() - last_archetype_count;
+                measurements.push((
+                    Self::ARCHETYPE_CREATION_RATE,
+                    created as f64 / delta_seconds,
+                ));
+            }
+        }
+        *last_archetype_count = Some(stats.archetype_count());
+
+        if component_bytes {
+            let components = world.components();
+            measurements.extend(stats.component_bytes().into_iter().filter_map(
+                |(component_id, bytes)| {
+                    let name = components.get_name(component_id)?;
+                    Some((Self::component_bytes_path(name), bytes as f64))
+                },
+            ));
+        }
+
+        let time = Instant::now();
+        let mut store = world.resource_mut::<DiagnosticsStore>();
+        for (path, value) in measurements {
+            if store.get(&path).is_none() {
+                store.add(Diagnostic::new(path.clone()).with_suffix("B"));
+            }
+            let diagnostic = store.get_mut(&path).unwrap();
+            if diagnostic.is_enabled {
+                diagnostic.add_measurement(DiagnosticMeasurement { time, value });
+            }
+        }
+    }
+}
@@ -0,0 +1,318 @@
+use crate::{Diagnostic, DiagnosticMeasurement, DiagnosticPath, DiagnosticsStore};
+use alloc::collections::VecDeque;
+use bevy_app::prelude::*;
+use bevy_ecs::{
+    prelude::*,
+    schedule::{ScheduleLabel, ScheduleProfile, ScheduleProfiler},
+};
+use bevy_utils::{tracing::error, HashMap, Instant};
+use core::fmt::Write;
+use std::{
+    path::{Path, PathBuf},
+    thread::ThreadId,
+};
+
+/// Adds diagnostics for the time taken by every system, named system set and schedule, and keeps
+/// a [`SystemProfilerTrace`] that can be exported to the Chrome trace format.
+///
+/// For every schedule run, the following diagnostics are recorded in milliseconds:
+/// - `schedule/<label>`: the wall time of the whole run.
+/// - `schedule/<label>/critical_path`: the run time of the longest chain of dependent systems.
+/// - `schedule/<label>/system/<name>`: the run time of each system that ran.
+/// - `schedule/<label>/set/<name>`: the wall time between the start of the first system and the
+///   end of the last system of each named set.
+///
+/// Only schedules run by the [`SingleThreaded`](bevy_ecs::schedule::ExecutorKind::SingleThreaded)
+/// and [`MultiThreaded`](bevy_ecs::schedule::ExecutorKind::MultiThreaded) executors time their
+/// systems.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+pub struct SystemProfilerDiagnosticsPlugin {
+    /// The number of schedule runs kept in the [`SystemProfilerTrace`].
+    pub max_trace_length: usize,
+    /// If set, the [`SystemProfilerTrace`] is written to this file in the Chrome trace format
+    /// when an [`AppExit`] event is sent.
+    pub trace_path: Option<PathBuf>,
+}
+
+impl Default for SystemProfilerDiagnosticsPlugin {
+    fn default() -> Self {
+        Self {
+            max_trace_length: Self::DEFAULT_MAX_TRACE_LENGTH,
+            trace_path: None,
+        }
+    }
+}
+
+impl Plugin for SystemProfilerDiagnosticsPlugin {
+    fn build(&self, app: &mut App) {
+        app.init_resource::<ScheduleProfiler>()
+            .insert_resource(SystemProfilerTrace::new(self.max_trace_length))
+            .add_systems(Last, Self::diagnostic_system);
+
+        if let Some(path) = self.trace_path.clone() {
+            app.add_systems(
+                Last,
+                (move |trace: Res<SystemProfilerTrace>| {
+                    if let Err(err) = trace.write_chrome_trace(&path) {
+                        error!("Failed to write the system profiler trace to {path:?}: {err}");
+                    }
+                })
+                .run_if(on_event::<AppExit>)
+                .after(Self::diagnostic_system),
+            );
+        }
+    }
+}
+
+impl SystemProfilerDiagnosticsPlugin {
+    pub const DEFAULT_MAX_TRACE_LENGTH: usize = 2000;
+
+    /// Returns the path of the diagnostic with the total run time of the schedule `label`.
+    pub fn schedule_path(label: impl ScheduleLabel) -> DiagnosticPath {
+        DiagnosticPath::new(schedule_prefix(&label.intern()))
+    }
+
+    pub fn diagnostic_system(
+        mut store: ResMut<DiagnosticsStore>,
+        mut profiler: ResMut<ScheduleProfiler>,
+        mut trace: ResMut<SystemProfilerTrace>,
+    ) {
+        for profile in profiler.drain() {
+            let schedule = schedule_prefix(&profile.label());
+            let time = profile.span().end;
+            let mut add = |path: String, duration: bevy_utils::Duration| {
+                let path = DiagnosticPath::new(path);
+                if store.get(&path).is_none() {
+                    store.add(Diagnostic::new(path.clone()).with_suffix("ms"));
+                }
+                let diagnostic = store.get_mut(&path).unwrap();
+                if diagnostic.is_enabled {
+                    diagnostic.add_measurement(DiagnosticMeasurement {
+                        time,
+                        value: duration.as_secs_f64() * 1000.0,
+                    });
+                }
+            };
+
+            add(schedule.clone(), profile.span().duration());
+            add(
+                format!("{schedule}/critical_path"),
+                profile.critical_path_duration(),
+            );
+            for system in profile.systems() {
+                if let Some(span) = system.span() {
+                    let name = path_component(system.name());
+                    add(format!("{schedule}/system/{name}"), span.duration());
+                }
+            }
+            for set in profile.sets() {
+                let name = path_component(set.name());
+                add(format!("{schedule}/set/{name}"), set.duration());
+            }
+
+            trace.push(profile);
+        }
+    }
+}
+
+fn schedule_prefix(label: &dyn core::fmt::Debug) -> String {
+    format!("schedule/{}", path_component(&format!("{label:?}")))
+}
+
+/// Replaces the characters that would split a diagnostic path into several components.
+fn path_component(name: &str) -> String {
+    name.replace('/', "_")
+}
+
+/// The most recent [`ScheduleProfile`]s recorded by the [`SystemProfilerDiagnosticsPlugin`].
+///
+/// The profiles can be exported with [`to_chrome_trace`](Self::to_chrome_trace), and viewed in
+/// `chrome://tracing` or [Perfetto](https://ui.perfetto.dev).
+#[derive(Resource, Debug)]
+pub struct SystemProfilerTrace {
+    origin: Instant,
+    profiles: VecDeque<ScheduleProfile>,
+    max_length: usize,
+}
+
+impl SystemProfilerTrace {
+    /// Creates an empty trace that keeps at most `max_length` schedule runs.
+    pub fn new(max_length: usize) -> Self {
+        Self {
+            origin: Instant::now(),
+            profiles: VecDeque::with_capacity(max_length),
+            max_length,
+        }
+    }
+
+    /// Adds a profile, dropping the oldest one if the trace is full.
+    pub fn push(&mut self, profile: ScheduleProfile) {
+        if self.max_length == 0 {
+            return;
+        }
+        if self.profiles.len() == self.max_length {
+            self.profiles.pop_front();
+        }
+        self.profiles.push_back(profile);
+    }
+
+    /// Returns the profiles in the trace, from oldest to newest.
+    pub fn profiles(&self) -> impl Iterator<Item = &ScheduleProfile> {
+        self.profiles.iter()
+    }
+
+    /// Removes every profile from the trace.
+    pub fn clear(&mut self) {
+        self.profiles.clear();
+    }
+
+    /// Returns the trace as a JSON document in the Chrome trace event format.
+    ///
+    /// Schedules and systems are complete events on the thread they ran on. Systems on the
+    /// critical path of their schedule have `"critical_path": true` in their arguments. System
+    /// sets are async events, since their systems may run on several threads.
+    pub fn to_chrome_trace(&self) -> String {
+        let mut threads = HashMap::<ThreadId, usize>::default();
+        let mut events = Vec::new();
+        let mut thread = |id: ThreadId| {
+            let count = threads.len();
+            *threads.entry(id).or_insert(count)
+        };
+
+        for (id, profile) in self.profiles.iter().enumerate() {
+            let schedule = format!("{:?}", profile.label());
+            let span = profile.span();
+            events.push(format!(
+                r#"{{"name":{},"cat":"schedule","ph":"X","ts":{},"dur":{},"pid":0,"tid":{}}}"#,
+                json_string(&schedule),
+                self.micros(span.start),
+                micros(span.duration()),
+                thread(span.thread),
+            ));
+
+            for (index, system) in profile.systems().iter().enumerate() {
+                let Some(span) = system.span() else {
+                    continue;
+                };
+                events.push(format!(
+                    r#"{{"name":{},"cat":"system","ph":"X","ts":{},"dur":{},"pid":0,"tid":{},"args":{{"schedule":{},"critical_path":{}}}}}"#,
+                    json_string(system.name()),
+                    self.micros(span.start),
+                    micros(span.duration()),
+                    thread(span.thread),
+                    json_string(&schedule),
+                    profile.critical_path().contains(&index),
+                ));
+            }
+
+            for set in profile.sets() {
+                for (phase, time) in [("b", set.start()), ("e", set.end())] {
+                    events.push(format!(
+                        r#"{{"name":{},"cat":"set","ph":"{phase}","id":{id},"ts":{},"pid":0,"tid":0,"args":{{"schedule":{}}}}}"#,
+                        json_string(set.name()),
+                        self.micros(time),
+                        json_string(&schedule),
+                    ));
+                }
+            }
+        }
+
+        for (id, tid) in threads {
+            events.push(format!(
+                r#"{{"name":"thread_name","ph":"M","pid":0,"tid":{tid},"args":{{"name":{}}}}}"#,
+                json_string(&format!("{id:?}")),
+            ));
+        }
+
+        format!(
+            r#"{{"traceEvents":[{}],"displayTimeUnit":"ms"}}"#,
+            events.join(",")
+        )
+    }
+
+    /// Writes the trace to the file at `path` in the Chrome trace event format.
+    pub fn write_chrome_trace(&self, path: impl AsRef<Path>) -> std::io::Result<()> {
+        std::fs::write(path, self.to_chrome_trace())
+    }
+
+    fn micros(&self, time: Instant) -> String {
+        micros(time.saturating_duration_since(self.origin))
+    }
+}
+
+fn micros(duration: bevy_utils::Duration) -> String {
+    format!("{:.3}", duration.as_secs_f64() * 1_000_000.0)
+}
+
+fn json_string(value: &str) -> String {
+    let mut json = String::with_capacity(value.len() + 2);
+    json.push('"');
+    for c in value.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if c.is_control() => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+    json
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{json_string, SystemProfilerTrace};
+    use bevy_ecs::{
+        prelude::*,
+        schedule::{Schedule, ScheduleProfiler},
+    };
+
+    const NAME: &str = "a \"quoted\" \\ name\nwith\t\u{1} control characters\u{7f}";
+
+    #[test]
+    fn json_strings_are_escaped() {
+        let parsed: String = serde_json::from_str(&json_string(NAME)).unwrap();
+        assert_eq!(parsed, NAME);
+    }
+
+    #[test]
+    fn chrome_trace_is_valid_json() {
+        #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Named(&'static str);
+
+        let mut world = World::new();
+        world.init_resource::<ScheduleProfiler>();
+        let mut schedule = Schedule::default();
+        schedule.add_systems(
+            IntoSystem::into_system(|| {})
+                .with_name(NAME)
+                .in_set(Named(NAME)),
+        );
+        schedule.run(&mut world);
+
+        let mut trace = SystemProfilerTrace::new(1);
+        for profile in world.resource_mut::<ScheduleProfiler>().drain() {
+            trace.push(profile);
+        }
+
+        let trace: serde_json::Value = serde_json::from_str(&trace.to_chrome_trace()).unwrap();
+        let events = trace["traceEvents"].as_array().unwrap();
+        let event = |category: &str| {
+            events
+                .iter()
+                .find(|event| event["cat"] == category)
+                .unwrap()
+        };
+        assert_eq!(event("system")["name"], NAME);
+        assert_eq!(event("set")["name"], format!("{:?}", Named(NAME)));
+        assert_eq!(event("schedule")["ph"], "X");
+    }
+}
@@ -9,7 +9,8 @@ use syn::{
     punctuated::Punctuated,
     spanned::Spanned,
     token::{Comma, Paren},
-    DeriveInput, ExprClosure, ExprPath, Ident, LitStr, Path, Result,
+    Data, DataStruct, DeriveInput, ExprClosure, ExprPath, Fields, Ident, LitStr, Member, Path,
+    Result, Type,
 };
 
 pub fn derive_event(input: TokenStream) -> TokenStream {
@@ -64,11 +65,50 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
         Err(e) => return e.into_compile_error().into(),
     };
 
+    let relationship = match derive_relationship(&ast, &attrs, &bevy_ecs_path) {
+        Ok(value) => value,
+        Err(err) => return err.into_compile_error().into(),
+    };
+    let relationship_target = match derive_relationship_target(&ast, &attrs, &bevy_ecs_path) {
+        Ok(value) => value,
+        Err(err) => return err.into_compile_error().into(),
+    };
+
     let storage = storage_path(&bevy_ecs_path, attrs.storage);
 
+    let mut on_insert = attrs.on_insert.clone();
+    let mut on_replace = attrs.on_replace.clone();
+    if attrs.relationship.is_some() {
+        if on_insert.is_some() || on_replace.is_some() {
+            return syn::Error::new(
+                ast.span(),
+                "Custom on_insert and on_replace hooks are not supported as relationships already define them",
+            )
+            .into_compile_error()
+            .into();
+        }
+        on_insert =
+            Some(parse_quote!(<Self as #bevy_ecs_path::relationship::Relationship>::on_insert));
+        on_replace =
+            Some(parse_quote!(<Self as #bevy_ecs_path::relationship::Relationship>::on_replace));
+    }
+    if attrs.relationship_target.is_some() {
+        if on_replace.is_some() {
+            return syn::Error::new(
+                ast.span(),
+                "Custom on_replace hooks are not supported as RelationshipTarget already defines an on_replace hook",
+            )
+            .into_compile_error()
+            .into();
+        }
+        on_replace = Some(
+            parse_quote!(<Self as #bevy_ecs_path::relationship::RelationshipTarget>::on_replace),
+        );
+    }
+
     let on_add = hook_register_function_call(quote! {on_add}, attrs.on_add);
-    let on_insert = hook_register_function_call(quote! {on_insert}, attrs.on_insert);
-    let on_replace = hook_register_function_call(quote! {on_replace}, attrs.on_replace);
+    let on_insert = hook_register_function_call(quote! {on_insert}, on_insert);
+    let on_replace = hook_register_function_call(quote! {on_replace}, on_replace);
     let on_remove = hook_register_function_call(quote! {on_remove}, attrs.on_remove);
 
     ast.generics
@@ -128,11 +168,22 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
     let struct_name = &ast.ident;
     let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
 
-    let mutable_type = attrs
-        .immutable
+    // Relationships are always immutable, so that re-targeting them always goes through their hooks.
+    let mutable_type = (attrs.immutable || attrs.relationship.is_some())
         .then_some(quote! { #bevy_ecs_path::component::Immutable })
         .unwrap_or(quote! { #bevy_ecs_path::component::Mutable });
 
+    let clone_handler = if attrs.relationship_target.is_some() {
+        // The source entities of a cloned target would not point back at the clone.
+        quote! { #bevy_ecs_path::component::ComponentCloneHandler::Ignore }
+    } else {
+        quote! {
+            use #bevy_ecs_path::component::{ComponentCloneViaClone, ComponentCloneBase};
+            (&&&#bevy_ecs_path::component::ComponentCloneSpecializationWrapper::<Self>::default())
+                .get_component_clone_handler()
+        }
+    };
+
     // This puts `register_required` before `register_recursive_requires` to ensure that the constructors of _all_ top
     // level components are initialized first, giving them precedence over recursively defined constructors for the same component type
     TokenStream::from(quote! {
@@ -159,11 +210,13 @@ pub fn derive_component(input: TokenStream) -> TokenStream {
             }
 
             fn get_component_clone_handler() -> #bevy_ecs_path::component::ComponentCloneHandler {
-                use #bevy_ecs_path::component::{ComponentCloneViaClone, ComponentCloneBase};
-                (&&&#bevy_ecs_path::component::ComponentCloneSpecializationWrapper::<Self>::default())
-                    .get_component_clone_handler()
+                #clone_handler
             }
         }
+
+        #relationship
+
+        #relationship_target
     })
 }
 
@@ -206,6 +259,9 @@ pub const ON_REMOVE: &str = "on_remove";
 
 pub const IMMUTABLE: &str = "immutable";
 
+pub const RELATIONSHIP: &str = "relationship";
+pub const RELATIONSHIP_TARGET: &str = "relationship_target";
+
 struct Attrs {
     storage: StorageTy,
     requires: Option<Punctuated<Require, Comma>>,
@@ -214,6 +270,16 @@ struct Attrs {
     on_replace: Option<ExprPath>,
     on_remove: Option<ExprPath>,
     immutable: bool,
+    relationship: Option<Relationship>,
+    relationship_target: Option<RelationshipTarget>,
+}
+
+struct Relationship {
+    relationship_target: Type,
+}
+
+struct RelationshipTarget {
+    relationship: Type,
 }
 
 #[derive(Clone, Copy)]
@@ -245,6 +311,8 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
         on_remove: None,
         requires: None,
         immutable: false,
+        relationship: None,
+        relationship_target: None,
     };
 
     let mut require_paths = HashSet::new();
@@ -297,9 +365,22 @@ fn parse_component_attr(ast: &DeriveInput) -> Result<Attrs> {
             } else {
                 attrs.requires = Some(punctuated);
             }
+        } else if attr.path().is_ident(RELATIONSHIP) {
+            let relationship = attr.parse_args::<Relationship>()?;
+            attrs.relationship = Some(relationship);
+        } else if attr.path().is_ident(RELATIONSHIP_TARGET) {
+            let relationship_target = attr.parse_args::<RelationshipTarget>()?;
+            attrs.relationship_target = Some(relationship_target);
         }
     }
 
+    if attrs.relationship.is_some() && attrs.relationship_target.is_some() {
+        return Err(syn::Error::new(
+            ast.span(),
+            "A component cannot be both a relationship and a relationship target",
+        ));
+    }
+
     Ok(attrs)
 }
 
@@ -337,3 +418,132 @@ fn hook_register_function_call(
 ) -> Option<TokenStream2> {
     function.map(|meta| quote! { hooks. #hook (#meta); })
 }
+
+mod kw {
+    syn::custom_keyword!(relationship_target);
+    syn::custom_keyword!(relationship);
+}
+
+impl Parse for Relationship {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        input.parse::<kw::relationship_target>()?;
+        input.parse::<syn::Token![=]>()?;
+        Ok(Relationship {
+            relationship_target: input.parse::<Type>()?,
+        })
+    }
+}
+
+impl Parse for RelationshipTarget {
+    fn parse(input: syn::parse::ParseStream) -> Result<Self> {
+        input.parse::<kw::relationship>()?;
+        input.parse::<syn::Token![=]>()?;
+        Ok(RelationshipTarget {
+            relationship: input.parse::<Type>()?,
+        })
+    }
+}
+
+/// Returns the single field of a relationship struct, erroring if there is not exactly one.
+fn relationship_field<'a>(ast: &'a DeriveInput, attr: &str) -> Result<(Member, &'a Type)> {
+    let Data::Struct(DataStruct { fields, .. }) = &ast.data else {
+        return Err(syn::Error::new(
+            ast.span(),
+            format!("{attr} can only be derived for structs."),
+        ));
+    };
+    let field = match fields {
+        Fields::Named(fields) if fields.named.len() == 1 => fields.named.first(),
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => fields.unnamed.first(),
+        _ => None,
+    };
+    let Some(field) = field else {
+        return Err(syn::Error::new(
+            fields.span(),
+            format!("{attr} must be a struct with exactly one field."),
+        ));
+    };
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(0.into()),
+    };
+    Ok((member, &field.ty))
+}
+
+fn derive_relationship(
+    ast: &DeriveInput,
+    attrs: &Attrs,
+    bevy_ecs_path: &Path,
+) -> Result<Option<TokenStream2>> {
+    let Some(relationship) = &attrs.relationship else {
+        return Ok(None);
+    };
+    let (member, _) = relationship_field(ast, "Relationship")?;
+
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+    let relationship_target = &relationship.relationship_target;
+
+    Ok(Some(quote! {
+        impl #impl_generics #bevy_ecs_path::relationship::Relationship for #struct_name #type_generics #where_clause {
+            type RelationshipTarget = #relationship_target;
+
+            #[inline(always)]
+            fn get(&self) -> #bevy_ecs_path::entity::Entity {
+                self.#member
+            }
+
+            #[inline]
+            fn from(entity: #bevy_ecs_path::entity::Entity) -> Self {
+                Self {
+                    #member: entity
+                }
+            }
+        }
+    }))
+}
+
+fn derive_relationship_target(
+    ast: &DeriveInput,
+    attrs: &Attrs,
+    bevy_ecs_path: &Path,
+) -> Result<Option<TokenStream2>> {
+    let Some(relationship_target) = &attrs.relationship_target else {
+        return Ok(None);
+    };
+    if attrs.immutable {
+        return Err(syn::Error::new(
+            ast.span(),
+            "RelationshipTarget components must be mutable.",
+        ));
+    }
+    let (member, collection) = relationship_field(ast, "RelationshipTarget")?;
+
+    let struct_name = &ast.ident;
+    let (impl_generics, type_generics, where_clause) = &ast.generics.split_for_impl();
+    let relationship = &relationship_target.relationship;
+
+    Ok(Some(quote! {
+        impl #impl_generics #bevy_ecs_path::relationship::RelationshipTarget for #struct_name #type_generics #where_clause {
+            type Relationship = #relationship;
+            type Collection = #collection;
+
+            #[inline]
+            fn collection(&self) -> &Self::Collection {
+                &self.#member
+            }
+
+            #[inline]
+            fn collection_mut_risky(&mut self) -> &mut Self::Collection {
+                &mut self.#member
+            }
+
+            #[inline]
+            fn from_collection_risky(collection: Self::Collection) -> Self {
+                Self {
+                    #member: collection
+                }
+            }
+        }
+    }))
+}
@@ -5,6 +5,7 @@ use crate::{
     archetype::ArchetypeFlags,
     bundle::BundleInfo,
     change_detection::MAX_CHANGE_AGE,
+    dynamic_component::DynamicComponentLayout,
     entity::{Entity, EntityCloner},
     query::DebugCheckedUnwrap,
     storage::{SparseSetIndex, SparseSets, Storages, Table, TableRow},
@@ -320,6 +321,24 @@ pub use bevy_ecs_macros::require;
 /// }
 /// ```
 ///
+/// # Relationships
+///
+/// A component can link its entity to another entity with the `relationship` and `relationship_target`
+/// attributes, which set up the hooks that keep both sides in sync.
+/// See [`Relationship`](crate::relationship::Relationship) for details.
+///
+/// ```
+/// # use bevy_ecs::component::Component;
+/// # use bevy_ecs::entity::Entity;
+/// #[derive(Component)]
+/// #[relationship(relationship_target = EquippedItems)]
+/// struct EquippedBy(Entity);
+///
+/// #[derive(Component)]
+/// #[relationship_target(relationship = EquippedBy)]
+/// struct EquippedItems(Vec<Entity>);
+/// ```
+///
 /// # Implementing the trait for foreign types
 ///
 /// As a consequence of the [orphan rule], it is not possible to separate into two different crates the implementation of `Component` from the definition of a type.
@@ -1062,6 +1081,7 @@ pub struct Components {
     indices: TypeIdMap<ComponentId>,
     resource_indices: TypeIdMap<ComponentId>,
     component_clone_handlers: ComponentCloneHandlers,
+    dynamic_layouts: HashMap<ComponentId, Arc<DynamicComponentLayout>>,
 }
 
 impl Components {
@@ -1179,6 +1199,25 @@ impl Components {
         unsafe { self.components.get_unchecked(id.0) }
     }
 
+    /// Returns the layout of the [dynamic component](crate::dynamic_component) with the given `id`.
+    #[inline]
+    pub fn get_dynamic_layout(
+        &self,
+        id: ComponentId,
+    ) -> Option<&Arc<DynamicComponentLayout>> {
+        self.dynamic_layouts.get(&id)
+    }
+
+    /// Sets the layout of the [dynamic component](crate::dynamic_component) with the given `id`.
+    #[inline]
+    pub(crate) fn set_dynamic_layout(
+        &mut self,
+        id: ComponentId,
+        layout: Arc<DynamicComponentLayout>,
+    ) {
+        self.dynamic_layouts.insert(id, layout);
+    }
+
     #[inline]
     pub(crate) fn get_hooks_mut(&mut self, id: ComponentId) -> Option<&mut ComponentHooks> {
         self.components.get_mut(id.0).map(|info| &mut info.hooks)
@@ -0,0 +1,1100 @@
+//! Components whose layout is defined at runtime, for example by a scripting language.
+//!
+//! A [`DynamicComponentLayout`] describes a component as a list of named, primitive fields. Once
+//! registered with [`World::register_dynamic_component`], the component can be inserted with
+//! [`EntityWorldMut::insert_dynamic`], queried like any other component through its
+//! [`ComponentId`] (for example with a [`QueryBuilder`](crate::query::QueryBuilder) and
+//! [`FilteredEntityRef`] or [`FilteredEntityMut`]), and its fields read and written by name with
+//! [`FilteredEntityRef::get_dynamic`] and [`FilteredEntityMut::get_dynamic_mut`].
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::dynamic_component::*;
+//! # use bevy_ecs::query::QueryBuilder;
+//! # use bevy_ecs::world::FilteredEntityMut;
+//! let mut world = World::new();
+//! let health = world
+//!     .register_dynamic_component(
+//!         DynamicComponentLayout::new("Health")
+//!             .with_field("current", DynamicFieldKind::F32)
+//!             .with_field("max", DynamicFieldKind::F32),
+//!     )
+//!     .unwrap();
+//!
+//! let entity = world.spawn_empty().id();
+//! world
+//!     .entity_mut(entity)
+//!     .insert_dynamic(&DynamicComponentData::new("Health").with("max", 10.0f32))
+//!     .unwrap();
+//!
+//! let mut query = QueryBuilder::<FilteredEntityMut>::new(&mut world)
+//!     .mut_id(health)
+//!     .build();
+//! for mut entity in query.iter_mut(&mut world) {
+//!     let mut health = entity.get_dynamic_mut(health).unwrap();
+//!     let max = health.get("max").unwrap();
+//!     health.set("current", max).unwrap();
+//! }
+//!
+//! let health = world.get_dynamic(entity, health).unwrap();
+//! assert_eq!(health.get("current"), Some(DynamicValue::F32(10.0)));
+//! ```
+
+use alloc::{
+    string::{String, ToString},
+    sync::Arc,
+    vec,
+    vec::Vec,
+};
+use core::{alloc::Layout, ptr::NonNull};
+
+use bevy_ptr::{OwningPtr, Ptr, PtrMut};
+use bevy_utils::HashMap;
+use thiserror::Error;
+
+use crate::{
+    self as bevy_ecs,
+    change_detection::MutUntyped,
+    component::{ComponentDescriptor, ComponentId, StorageType},
+    entity::Entity,
+    system::Resource,
+    world::{EntityMut, EntityWorldMut, FilteredEntityMut, FilteredEntityRef, World},
+};
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+
+macro_rules! dynamic_fields {
+    ($($kind:ident($ty:ty)),* $(,)?) => {
+        /// The type of a field of a [`DynamicComponentLayout`].
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+        #[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq, Hash))]
+        pub enum DynamicFieldKind {
+            $(
+                #[doc = concat!("A `", stringify!($ty), "`.")]
+                $kind,
+            )*
+        }
+
+        impl DynamicFieldKind {
+            /// Returns the memory layout of a field of this kind.
+            pub fn layout(self) -> Layout {
+                match self {
+                    $(Self::$kind => Layout::new::<$ty>(),)*
+                }
+            }
+        }
+
+        /// The value of a field of a dynamic component.
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        #[cfg_attr(feature = "bevy_reflect", derive(Reflect), reflect(Debug, PartialEq))]
+        pub enum DynamicValue {
+            $(
+                #[doc = concat!("A `", stringify!($ty), "`.")]
+                $kind($ty),
+            )*
+        }
+
+        impl DynamicValue {
+            /// Returns the kind of field that can hold this value.
+            pub fn kind(&self) -> DynamicFieldKind {
+                match self {
+                    $(Self::$kind(_) => DynamicFieldKind::$kind,)*
+                }
+            }
+
+            /// # Safety
+            ///
+            /// `ptr` must point to a valid, aligned value of the type of `kind`.
+            unsafe fn read(kind: DynamicFieldKind, ptr: Ptr<'_>) -> Self {
+                match kind {
+                    // SAFETY: Guaranteed by the caller.
+                    $(DynamicFieldKind::$kind => Self::$kind(unsafe { *ptr.deref::<$ty>() }),)*
+                }
+            }
+
+            /// # Safety
+            ///
+            /// `ptr` must point to a valid, aligned value of the type of `self.kind()`.
+            unsafe fn write(self, ptr: PtrMut<'_>) {
+                match self {
+                    // SAFETY: Guaranteed by the caller.
+                    $(Self::$kind(value) => unsafe { *ptr.deref_mut::<$ty>() = value },)*
+                }
+            }
+        }
+
+        $(
+            impl From<$ty> for DynamicValue {
+                fn from(value: $ty) -> Self {
+                    Self::$kind(value)
+                }
+            }
+        )*
+    };
+}
+
+dynamic_fields!(
+    Bool(bool),
+    U8(u8),
+    U16(u16),
+    U32(u32),
+    U64(u64),
+    I8(i8),
+    I16(i16),
+    I32(i32),
+    I64(i64),
+    F32(f32),
+    F64(f64),
+);
+
+/// A field of a [`DynamicComponentLayout`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DynamicField {
+    name: String,
+    kind: DynamicFieldKind,
+    offset: usize,
+}
+
+impl DynamicField {
+    /// Returns the name of the field.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns the type of the field.
+    pub fn kind(&self) -> DynamicFieldKind {
+        self.kind
+    }
+
+    /// Returns the offset of the field from the start of the component, in bytes.
+    pub fn offset(&self) -> usize {
+        self.offset
+    }
+}
+
+/// The description of a component defined at runtime, made of named, primitive fields.
+///
+/// Fields are laid out in the order they are added, like a `#[repr(C)]` struct. A newly inserted
+/// component has every field that isn't explicitly set to zero, or `false`.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct DynamicComponentLayout {
+    name: String,
+    storage_type: StorageType,
+    fields: Vec<DynamicField>,
+    layout: Layout,
+}
+
+impl DynamicComponentLayout {
+    /// Creates a layout without fields for a component stored in tables.
+    ///
+    /// The name identifies the component, for example in the remote protocol and in scenes.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            storage_type: StorageType::Table,
+            fields: Vec::new(),
+            layout: Layout::new::<()>(),
+        }
+    }
+
+    /// Adds a field after the existing ones.
+    pub fn with_field(mut self, name: impl Into<String>, kind: DynamicFieldKind) -> Self {
+        let (layout, offset) = self
+            .layout
+            .extend(kind.layout())
+            .expect("dynamic component layout is too large");
+        self.layout = layout;
+        self.fields.push(DynamicField {
+            name: name.into(),
+            kind,
+            offset,
+        });
+        self
+    }
+
+    /// Sets how the component is stored.
+    pub fn with_storage_type(mut self, storage_type: StorageType) -> Self {
+        self.storage_type = storage_type;
+        self
+    }
+
+    /// Returns the name of the component.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns how the component is stored.
+    pub fn storage_type(&self) -> StorageType {
+        self.storage_type
+    }
+
+    /// Returns the fields of the component, in memory order.
+    pub fn fields(&self) -> &[DynamicField] {
+        &self.fields
+    }
+
+    /// Returns the field with the given name.
+    pub fn field(&self, name: &str) -> Option<&DynamicField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Returns the memory layout of the whole component.
+    pub fn layout(&self) -> Layout {
+        self.layout.pad_to_align()
+    }
+
+    fn typed_field(
+        &self,
+        name: &str,
+        kind: DynamicFieldKind,
+    ) -> Result<&DynamicField, DynamicComponentError> {
+        let field = self
+            .field(name)
+            .ok_or_else(|| DynamicComponentError::UnknownField {
+                component: self.name.clone(),
+                field: name.to_string(),
+            })?;
+        if field.kind != kind {
+            return Err(DynamicComponentError::KindMismatch {
+                component: self.name.clone(),
+                field: name.to_string(),
+                expected: field.kind,
+                found: kind,
+            });
+        }
+        Ok(field)
+    }
+}
+
+/// An error related to dynamic components.
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum DynamicComponentError {
+    /// A dynamic component with this name is already registered.
+    #[error("A dynamic component named `{0}` is already registered")]
+    DuplicateComponent(String),
+    /// The layout of a dynamic component has several fields with the same name.
+    #[error("Dynamic component `{component}` has several fields named `{field}`")]
+    DuplicateField {
+        /// The name of the component.
+        component: String,
+        /// The name of the field.
+        field: String,
+    },
+    /// No dynamic component with this name is registered.
+    #[error("No dynamic component named `{0}` is registered")]
+    UnknownComponent(String),
+    /// The dynamic component has no field with this name.
+    #[error("Dynamic component `{component}` has no field named `{field}`")]
+    UnknownField {
+        /// The name of the component.
+        component: String,
+        /// The name of the field.
+        field: String,
+    },
+    /// The value doesn't have the type of the field.
+    #[error(
+        "Field `{field}` of dynamic component `{component}` is a {expected:?}, not a {found:?}"
+    )]
+    KindMismatch {
+        /// The name of the component.
+        component: String,
+        /// The name of the field.
+        field: String,
+        /// The type of the field.
+        expected: DynamicFieldKind,
+        /// The type of the value.
+        found: DynamicFieldKind,
+    },
+}
+
+/// The names and layouts of the dynamic components registered with
+/// [`World::register_dynamic_component`].
+///
+/// The world keeps its own copy of the layouts to access the components, so this resource is only
+/// used to look them up.
+#[derive(Resource, Default, Debug)]
+pub struct DynamicComponentRegistry {
+    layouts: HashMap<ComponentId, Arc<DynamicComponentLayout>>,
+    ids: HashMap<String, ComponentId>,
+}
+
+impl DynamicComponentRegistry {
+    /// Returns the layout of the dynamic component with the given `id`.
+    pub fn get(&self, id: ComponentId) -> Option<&DynamicComponentLayout> {
+        self.layouts.get(&id).map(|layout| &**layout)
+    }
+
+    /// Returns the id of the dynamic component with the given name.
+    pub fn id(&self, name: &str) -> Option<ComponentId> {
+        self.ids.get(name).copied()
+    }
+
+    /// Returns an iterator over the registered dynamic components.
+    pub fn iter(&self) -> impl Iterator<Item = (ComponentId, &DynamicComponentLayout)> + '_ {
+        self.layouts.iter().map(|(id, layout)| (*id, &**layout))
+    }
+}
+
+/// A shared reference to a dynamic component, whose fields can be read by name.
+pub struct DynamicComponentRef<'a> {
+    layout: Arc<DynamicComponentLayout>,
+    ptr: Ptr<'a>,
+}
+
+impl DynamicComponentRef<'_> {
+    /// Returns the layout of the component.
+    pub fn layout(&self) -> &DynamicComponentLayout {
+        &self.layout
+    }
+
+    /// Returns the value of the field with the given name.
+    pub fn get(&self, field: &str) -> Option<DynamicValue> {
+        let field = self.layout.field(field)?;
+        // SAFETY: `ptr` points to a component with this layout.
+        Some(unsafe { read_field(self.ptr, field) })
+    }
+
+    /// Returns an iterator over the names and values of the fields.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, DynamicValue)> + '_ {
+        self.layout.fields.iter().map(|field| {
+            // SAFETY: `ptr` points to a component with this layout.
+            (field.name(), unsafe { read_field(self.ptr, field) })
+        })
+    }
+
+    /// Copies the values of the fields.
+    pub fn to_data(&self) -> DynamicComponentData {
+        DynamicComponentData::from_fields(&self.layout, self.iter())
+    }
+}
+
+/// A mutable reference to a dynamic component, whose fields can be read and written by name.
+///
+/// Writing a field triggers change detection.
+pub struct DynamicComponentMut<'a> {
+    layout: Arc<DynamicComponentLayout>,
+    value: MutUntyped<'a>,
+}
+
+impl DynamicComponentMut<'_> {
+    /// Returns the layout of the component.
+    pub fn layout(&self) -> &DynamicComponentLayout {
+        &self.layout
+    }
+
+    /// Returns the value of the field with the given name.
+    pub fn get(&self, field: &str) -> Option<DynamicValue> {
+        let field = self.layout.field(field)?;
+        // SAFETY: `value` points to a component with this layout.
+        Some(unsafe { read_field(self.value.as_ref(), field) })
+    }
+
+    /// Returns an iterator over the names and values of the fields.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, DynamicValue)> + '_ {
+        self.layout.fields.iter().map(|field| {
+            // SAFETY: `value` points to a component with this layout.
+            (field.name(), unsafe {
+                read_field(self.value.as_ref(), field)
+            })
+        })
+    }
+
+    /// Copies the values of the fields.
+    pub fn to_data(&self) -> DynamicComponentData {
+        DynamicComponentData::from_fields(&self.layout, self.iter())
+    }
+
+    /// Sets the value of the field with the given name.
+    pub fn set(
+        &mut self,
+        field: &str,
+        value: impl Into<DynamicValue>,
+    ) -> Result<(), DynamicComponentError> {
+        let value = value.into();
+        let field = self.layout.typed_field(field, value.kind())?;
+        // SAFETY: `value` points to a component with this layout, and the kind of the field was
+        // checked.
+        unsafe { value.write(self.value.as_mut().byte_add(field.offset)) };
+        Ok(())
+    }
+
+    /// Sets the values of the fields listed in `data`, leaving the others unchanged.
+    ///
+    /// Nothing is written if one of the fields doesn't exist or has a different type.
+    pub fn apply(&mut self, data: &DynamicComponentData) -> Result<(), DynamicComponentError> {
+        if data.name != self.layout.name {
+            return Err(DynamicComponentError::UnknownComponent(data.name.clone()));
+        }
+        for (field, value) in &data.fields {
+            self.layout.typed_field(field, value.kind())?;
+        }
+        for (field, value) in &data.fields {
+            self.set(field, *value)?;
+        }
+        Ok(())
+    }
+}
+
+/// # Safety
+///
+/// `ptr` must point to a component with a layout containing `field`.
+unsafe fn read_field(ptr: Ptr<'_>, field: &DynamicField) -> DynamicValue {
+    // SAFETY: Guaranteed by the caller.
+    unsafe { DynamicValue::read(field.kind, ptr.byte_add(field.offset)) }
+}
+
+/// An owned copy of the fields of a dynamic component, identified by name.
+///
+/// This is how dynamic components are inserted, serialized and sent over the remote protocol.
+/// Fields may be omitted.
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Debug, Default, PartialEq)
+)]
+pub struct DynamicComponentData {
+    /// The name of the dynamic component.
+    pub name: String,
+    /// The names and values of the fields.
+    pub fields: Vec<(String, DynamicValue)>,
+}
+
+impl DynamicComponentData {
+    /// Creates the data of the dynamic component with the given name, without fields.
+    pub fn new(name: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            fields: Vec::new(),
+        }
+    }
+
+    /// Adds the value of a field.
+    pub fn with(mut self, field: impl Into<String>, value: impl Into<DynamicValue>) -> Self {
+        self.fields.push((field.into(), value.into()));
+        self
+    }
+
+    /// Returns the value of the field with the given name, if it's set.
+    pub fn get(&self, field: &str) -> Option<DynamicValue> {
+        self.fields
+            .iter()
+            .find(|(name, _)| name == field)
+            .map(|(_, value)| *value)
+    }
+
+    fn from_fields<'a>(
+        layout: &DynamicComponentLayout,
+        fields: impl Iterator<Item = (&'a str, DynamicValue)>,
+    ) -> Self {
+        Self {
+            name: layout.name.clone(),
+            fields: fields
+                .map(|(name, value)| (name.to_string(), value))
+                .collect(),
+        }
+    }
+}
+
+impl<'w> FilteredEntityRef<'w> {
+    /// Returns the [dynamic component](crate::dynamic_component) with the given `id`, if the entity
+    /// has it and the access allows reading it.
+    pub fn get_dynamic(&self, id: ComponentId) -> Option<DynamicComponentRef<'w>> {
+        let layout = self.world_components().get_dynamic_layout(id)?.clone();
+        let ptr = self.get_by_id(id)?;
+        Some(DynamicComponentRef { layout, ptr })
+    }
+}
+
+impl FilteredEntityMut<'_> {
+    /// Returns the [dynamic component](crate::dynamic_component) with the given `id`, if the entity
+    /// has it and the access allows writing it.
+    pub fn get_dynamic_mut(&mut self, id: ComponentId) -> Option<DynamicComponentMut<'_>> {
+        let layout = self.world_components().get_dynamic_layout(id)?.clone();
+        let value = self.get_mut_by_id(id)?;
+        Some(DynamicComponentMut { layout, value })
+    }
+}
+
+impl EntityMut<'_> {
+    /// Returns the [dynamic component](crate::dynamic_component) with the given `id`, if the entity
+    /// has it.
+    pub fn get_dynamic_mut(&mut self, id: ComponentId) -> Option<DynamicComponentMut<'_>> {
+        let layout = self.world_components().get_dynamic_layout(id)?.clone();
+        let value = self.get_mut_by_id(id).ok()?;
+        Some(DynamicComponentMut { layout, value })
+    }
+}
+
+impl World {
+    /// Registers a component whose layout is defined at runtime, and returns its id.
+    ///
+    /// The layout is stored in the [`DynamicComponentRegistry`] resource. When the world has an
+    /// [`AppTypeRegistry`](crate::reflect::AppTypeRegistry), [`DynamicComponentSet`] is also
+    /// registered in it, so that dynamic components can be saved in scenes.
+    ///
+    /// See the [module docs](crate::dynamic_component) for an example.
+    pub fn register_dynamic_component(
+        &mut self,
+        layout: DynamicComponentLayout,
+    ) -> Result<ComponentId, DynamicComponentError> {
+        let registry = self.get_resource_or_init::<DynamicComponentRegistry>();
+        if registry.ids.contains_key(&layout.name) {
+            return Err(DynamicComponentError::DuplicateComponent(layout.name));
+        }
+        for (index, field) in layout.fields.iter().enumerate() {
+            if layout.fields[..index]
+                .iter()
+                .any(|other| other.name == field.name)
+            {
+                return Err(DynamicComponentError::DuplicateField {
+                    component: layout.name.clone(),
+                    field: field.name.clone(),
+                });
+            }
+        }
+
+        // SAFETY: The component only contains primitive values, which are `Send + Sync` and don't
+        // need to be dropped.
+        let descriptor = unsafe {
+            ComponentDescriptor::new_with_layout(
+                layout.name.clone(),
+                layout.storage_type,
+                layout.layout(),
+                None,
+                true,
+            )
+        };
+        let id = self.register_component_with_descriptor(descriptor);
+        let layout = Arc::new(layout);
+        self.components.set_dynamic_layout(id, layout.clone());
+
+        let mut registry = self.resource_mut::<DynamicComponentRegistry>();
+        registry.ids.insert(layout.name.clone(), id);
+        registry.layouts.insert(id, layout);
+
+        #[cfg(feature = "bevy_reflect")]
+        if let Some(type_registry) = self.get_resource::<crate::reflect::AppTypeRegistry>() {
+            let mut type_registry = type_registry.write();
+            if !type_registry.contains(core::any::TypeId::of::<DynamicComponentSet>()) {
+                DynamicComponentSet::register_type(&mut type_registry);
+            }
+        }
+
+        Ok(id)
+    }
+
+    /// Returns the dynamic component with the given `id` on `entity`.
+    ///
+    /// Returns `None` if the entity doesn't exist or doesn't have the component, or if `id`
+    /// isn't a [dynamic component](crate::dynamic_component).
+    pub fn get_dynamic(&self, entity: Entity, id: ComponentId) -> Option<DynamicComponentRef<'_>> {
+        let layout = self.components.get_dynamic_layout(id)?.clone();
+        let ptr = self.get_by_id(entity, id)?;
+        Some(DynamicComponentRef { layout, ptr })
+    }
+
+    /// Returns the dynamic component with the given `id` on `entity`, to read and write its fields.
+    ///
+    /// Returns `None` if the entity doesn't exist or doesn't have the component, or if `id`
+    /// isn't a [dynamic component](crate::dynamic_component).
+    pub fn get_dynamic_mut(
+        &mut self,
+        entity: Entity,
+        id: ComponentId,
+    ) -> Option<DynamicComponentMut<'_>> {
+        let layout = self.components.get_dynamic_layout(id)?.clone();
+        let value = self.get_mut_by_id(entity, id)?;
+        Some(DynamicComponentMut { layout, value })
+    }
+}
+
+impl EntityWorldMut<'_> {
+    /// Inserts the dynamic component described by `data`, replacing it if the entity already has
+    /// it.
+    ///
+    /// The fields that `data` doesn't set are zero, or `false`.
+    pub fn insert_dynamic(
+        &mut self,
+        data: &DynamicComponentData,
+    ) -> Result<&mut Self, DynamicComponentError> {
+        let world = self.world();
+        let Some((id, layout)) = world
+            .get_resource::<DynamicComponentRegistry>()
+            .and_then(|registry| registry.id(&data.name))
+            .and_then(|id| Some((id, world.components().get_dynamic_layout(id)?.clone())))
+            .filter(|(_, layout)| layout.name == data.name)
+        else {
+            return Err(DynamicComponentError::UnknownComponent(data.name.clone()));
+        };
+        // Fields are at most 8 bytes large, so their alignment is satisfied by `u64`s.
+        let mut buffer = vec![0u64; layout.layout().size().div_ceil(8)];
+        let ptr = NonNull::new(buffer.as_mut_ptr().cast::<u8>()).unwrap();
+        for (field, value) in &data.fields {
+            let offset = layout.typed_field(field, value.kind())?.offset;
+            // SAFETY: The buffer is large enough and aligned for the layout, and the kind of the
+            // field was checked.
+            unsafe { value.write(PtrMut::new(ptr).byte_add(offset)) };
+        }
+        // SAFETY: The buffer holds a valid value of the component, since all zeroes is a valid
+        // value for every field. The component doesn't need to be dropped, so the buffer can be
+        // freed as usual once the value is moved into the world.
+        unsafe { self.insert_by_id(id, OwningPtr::new(ptr)) };
+        Ok(self)
+    }
+
+    /// Returns the dynamic component with the given `id` on the entity, to read and write its
+    /// fields.
+    pub fn get_dynamic_mut(&mut self, id: ComponentId) -> Option<DynamicComponentMut<'_>> {
+        let layout = self.world().components().get_dynamic_layout(id)?.clone();
+        let value = self.get_mut_by_id(id).ok()?;
+        Some(DynamicComponentMut { layout, value })
+    }
+}
+
+/// All the dynamic components of an entity, as a reflected value.
+///
+/// Dynamic components don't have a Rust type, so they can't be registered in the
+/// [`TypeRegistry`](bevy_reflect::TypeRegistry) like other components. Instead, scenes store the
+/// dynamic components of each entity in this type, whose [`ReflectComponent`] inserts them by
+/// name. It is registered by [`World::register_dynamic_component`].
+///
+/// [`ReflectComponent::contains`] returns whether the entity has any dynamic component. Since the
+/// set isn't stored in the entity, [`ReflectComponent::reflect`] and
+/// [`ReflectComponent::reflect_mut`] always return `None`: use [`DynamicComponentSet::from_entity`]
+/// to read the dynamic components of an entity instead.
+///
+/// The other functions of the [`ReflectComponent`] panic if the set names a dynamic component that
+/// isn't registered in the world, or has invalid fields: use [`DynamicComponentSet::validate`] to
+/// check the set beforehand.
+///
+/// [`ReflectComponent`]: crate::reflect::ReflectComponent
+/// [`ReflectComponent::contains`]: crate::reflect::ReflectComponent::contains
+/// [`ReflectComponent::reflect`]: crate::reflect::ReflectComponent::reflect
+/// [`ReflectComponent::reflect_mut`]: crate::reflect::ReflectComponent::reflect_mut
+#[cfg(feature = "bevy_reflect")]
+#[derive(Clone, Debug, Default, PartialEq, Reflect)]
+#[reflect(Debug, Default, PartialEq)]
+pub struct DynamicComponentSet(pub Vec<DynamicComponentData>);
+
+#[cfg(feature = "bevy_reflect")]
+impl DynamicComponentSet {
+    /// Copies the dynamic components of `entity`.
+    pub fn from_entity(world: &World, entity: Entity) -> Self {
+        let Ok(entity) = world.get_entity(entity) else {
+            return Self::default();
+        };
+        let entity = FilteredEntityRef::from(entity);
+        Self(
+            entity
+                .archetype()
+                .components()
+                .filter_map(|id| entity.get_dynamic(id))
+                .map(|component| component.to_data())
+                .collect(),
+        )
+    }
+
+    /// Checks that all the components in the set are registered in `world`, and that their fields
+    /// exist and have the right kinds.
+    pub fn validate(&self, world: &World) -> Result<(), DynamicComponentError> {
+        let registry = world.get_resource::<DynamicComponentRegistry>();
+        for data in &self.0 {
+            let layout = registry
+                .and_then(|registry| registry.id(&data.name))
+                .and_then(|id| world.components().get_dynamic_layout(id))
+                .filter(|layout| layout.name == data.name)
+                .ok_or_else(|| DynamicComponentError::UnknownComponent(data.name.clone()))?;
+            for (field, value) in &data.fields {
+                layout.typed_field(field, value.kind())?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Inserts the components in `entity`, or sets the fields listed in the set if the entity
+    /// already has the component.
+    pub fn apply_or_insert(
+        &self,
+        entity: &mut EntityWorldMut,
+    ) -> Result<(), DynamicComponentError> {
+        for data in &self.0 {
+            let id = entity
+                .world()
+                .get_resource::<DynamicComponentRegistry>()
+                .and_then(|registry| registry.id(&data.name))
+                .ok_or_else(|| DynamicComponentError::UnknownComponent(data.name.clone()))?;
+            match entity.get_dynamic_mut(id) {
+                Some(mut component) => component.apply(data)?,
+                None => {
+                    entity.insert_dynamic(data)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Registers this type in `registry`, along with its [`ReflectComponent`].
+    ///
+    /// [`ReflectComponent`]: crate::reflect::ReflectComponent
+    pub fn register_type(registry: &mut bevy_reflect::TypeRegistry) {
+        use crate::reflect::{ReflectComponent, ReflectComponentFns};
+        use bevy_reflect::FromReflect;
+
+        fn from_reflect(value: &dyn bevy_reflect::PartialReflect) -> DynamicComponentSet {
+            DynamicComponentSet::from_reflect(value).unwrap_or_else(|| {
+                panic!(
+                    "Attempted to insert dynamic components from an invalid `{}`",
+                    value.reflect_type_path()
+                )
+            })
+        }
+
+        fn insert(
+            entity: &mut EntityWorldMut,
+            value: &dyn bevy_reflect::PartialReflect,
+            _: &bevy_reflect::TypeRegistry,
+        ) {
+            for data in &from_reflect(value).0 {
+                if let Err(err) = entity.insert_dynamic(data) {
+                    panic!("Failed to insert a dynamic component: {err}");
+                }
+            }
+        }
+
+        registry.register::<DynamicComponentSet>();
+        registry
+            .get_mut(core::any::TypeId::of::<DynamicComponentSet>())
+            .unwrap()
+            .insert(ReflectComponent::new(ReflectComponentFns {
+                insert,
+                apply: |mut entity, value| {
+                    for data in &from_reflect(value).0 {
+                        let components = entity.world_components();
+                        let id = entity.archetype().components().find(|&id| {
+                            components
+                                .get_dynamic_layout(id)
+                                .is_some_and(|layout| layout.name == data.name)
+                        });
+                        let Some(mut component) = id.and_then(|id| entity.get_dynamic_mut(id))
+                        else {
+                            panic!("The entity has no dynamic component named `{}`", data.name);
+                        };
+                        if let Err(err) = component.apply(data) {
+                            panic!("Failed to apply a dynamic component: {err}");
+                        }
+                    }
+                },
+                apply_or_insert: |entity, value, _| {
+                    if let Err(err) = from_reflect(value).apply_or_insert(entity) {
+                        panic!("Failed to apply a dynamic component: {err}");
+                    }
+                },
+                remove: |entity| {
+                    let ids = entity
+                        .world()
+                        .get_resource::<DynamicComponentRegistry>()
+                        .map(|registry| registry.layouts.keys().copied().collect::<Vec<_>>())
+                        .unwrap_or_default();
+                    for id in ids {
+                        entity.remove_by_id(id);
+                    }
+                },
+                contains: |entity| {
+                    let components = entity.world_components();
+                    entity
+                        .archetype()
+                        .components()
+                        .any(|id| components.get_dynamic_layout(id).is_some())
+                },
+                reflect: |_| None,
+                reflect_mut: |_| None,
+                reflect_unchecked_mut: |_| None,
+                copy: |source_world, destination_world, source_entity, destination_entity, _| {
+                    let set = DynamicComponentSet::from_entity(source_world, source_entity);
+                    if let Err(err) =
+                        set.apply_or_insert(&mut destination_world.entity_mut(destination_entity))
+                    {
+                        panic!("Failed to copy a dynamic component: {err}");
+                    }
+                },
+                register_component: |world| world.init_resource::<DynamicComponentRegistry>(),
+            }));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        component::Component,
+        query::{QueryBuilder, With},
+    };
+
+    #[derive(Component)]
+    struct Script;
+
+    fn position_layout() -> DynamicComponentLayout {
+        DynamicComponentLayout::new("Position")
+            .with_field("x", DynamicFieldKind::F32)
+            .with_field("visible", DynamicFieldKind::Bool)
+            .with_field("id", DynamicFieldKind::U64)
+    }
+
+    #[test]
+    fn layout_is_c_like() {
+        let layout = position_layout();
+        let offsets: Vec<_> = layout.fields().iter().map(DynamicField::offset).collect();
+        assert_eq!(offsets, [0, 4, 8]);
+        assert_eq!(layout.layout(), Layout::from_size_align(16, 8).unwrap());
+        assert_eq!(
+            DynamicComponentLayout::new("Empty").layout(),
+            Layout::new::<()>()
+        );
+    }
+
+    #[test]
+    fn register_errors() {
+        let mut world = World::new();
+        world.register_dynamic_component(position_layout()).unwrap();
+        assert_eq!(
+            world.register_dynamic_component(DynamicComponentLayout::new("Position")),
+            Err(DynamicComponentError::DuplicateComponent("Position".into()))
+        );
+        assert_eq!(
+            world.register_dynamic_component(
+                DynamicComponentLayout::new("Twice")
+                    .with_field("a", DynamicFieldKind::U8)
+                    .with_field("a", DynamicFieldKind::U8)
+            ),
+            Err(DynamicComponentError::DuplicateField {
+                component: "Twice".into(),
+                field: "a".into()
+            })
+        );
+        assert!(world
+            .resource::<DynamicComponentRegistry>()
+            .id("Twice")
+            .is_none());
+    }
+
+    #[test]
+    fn insert_and_access_fields() {
+        let mut world = World::new();
+        let position = world.register_dynamic_component(position_layout()).unwrap();
+        let entity = world.spawn(Script).id();
+
+        let data = DynamicComponentData::new("Position")
+            .with("x", 1.5f32)
+            .with("id", 7u64);
+        world.entity_mut(entity).insert_dynamic(&data).unwrap();
+
+        let component = world.get_dynamic(entity, position).unwrap();
+        assert_eq!(component.get("x"), Some(DynamicValue::F32(1.5)));
+        assert_eq!(component.get("visible"), Some(DynamicValue::Bool(false)));
+        assert_eq!(component.get("missing"), None);
+        assert_eq!(
+            component.to_data(),
+            DynamicComponentData::new("Position")
+                .with("x", 1.5f32)
+                .with("visible", false)
+                .with("id", 7u64)
+        );
+
+        let mut component = world.get_dynamic_mut(entity, position).unwrap();
+        component.set("visible", true).unwrap();
+        assert_eq!(
+            component.set("x", 1u8),
+            Err(DynamicComponentError::KindMismatch {
+                component: "Position".into(),
+                field: "x".into(),
+                expected: DynamicFieldKind::F32,
+                found: DynamicFieldKind::U8,
+            })
+        );
+        assert!(component.apply(&data.clone().with("y", 0.0f32)).is_err());
+        assert_eq!(
+            world.get_dynamic(entity, position).unwrap().get("visible"),
+            Some(DynamicValue::Bool(true))
+        );
+
+        assert_eq!(
+            world
+                .entity_mut(entity)
+                .insert_dynamic(&DynamicComponentData::new("Velocity"))
+                .err(),
+            Some(DynamicComponentError::UnknownComponent("Velocity".into()))
+        );
+    }
+
+    #[test]
+    fn query_dynamic_components() {
+        let mut world = World::new();
+        let position = world.register_dynamic_component(position_layout()).unwrap();
+        let sparse = world
+            .register_dynamic_component(
+                DynamicComponentLayout::new("Sparse")
+                    .with_field("value", DynamicFieldKind::I16)
+                    .with_storage_type(StorageType::SparseSet),
+            )
+            .unwrap();
+        for i in 0..4 {
+            let mut entity = world.spawn(Script);
+            entity
+                .insert_dynamic(&DynamicComponentData::new("Position").with("id", i as u64))
+                .unwrap();
+            if i % 2 == 0 {
+                entity
+                    .insert_dynamic(&DynamicComponentData::new("Sparse").with("value", -1i16))
+                    .unwrap();
+            }
+        }
+
+        let mut query = QueryBuilder::<FilteredEntityMut, With<Script>>::new(&mut world)
+            .mut_id(position)
+            .ref_id(sparse)
+            .build();
+        for mut entity in query.iter_mut(&mut world) {
+            let mut position = entity.get_dynamic_mut(position).unwrap();
+            let Some(DynamicValue::U64(id)) = position.get("id") else {
+                panic!("`id` should be a `u64`");
+            };
+            position.set("x", id as f32 * 2.0).unwrap();
+            // `sparse` is only readable.
+            assert!(entity.get_dynamic_mut(sparse).is_none());
+        }
+
+        let mut query = QueryBuilder::<FilteredEntityRef>::new(&mut world)
+            .ref_id(position)
+            .with_id(sparse)
+            .build();
+        let mut xs: Vec<_> = query
+            .iter(&world)
+            .map(
+                |entity| match entity.get_dynamic(position).unwrap().get("x") {
+                    Some(DynamicValue::F32(x)) => x,
+                    x => panic!("`x` should be a `f32`, got {x:?}"),
+                },
+            )
+            .collect();
+        xs.sort_by(f32::total_cmp);
+        assert_eq!(xs, [0.0, 4.0]);
+    }
+
+    #[test]
+    fn registry_of_another_world() {
+        let mut world = World::new();
+        let small = world
+            .register_dynamic_component(
+                DynamicComponentLayout::new("Small").with_field("value", DynamicFieldKind::Bool),
+            )
+            .unwrap();
+        let entity = world
+            .spawn_empty()
+            .insert_dynamic(&DynamicComponentData::new("Small"))
+            .unwrap()
+            .id();
+
+        // The layouts used to access the components don't come from the resource, which can be
+        // replaced by the registry of another world using the same ids.
+        let mut other_world = World::new();
+        let position = other_world
+            .register_dynamic_component(position_layout())
+            .unwrap();
+        assert_eq!(position, small);
+        let other_registry = other_world
+            .remove_resource::<DynamicComponentRegistry>()
+            .unwrap();
+        world.insert_resource(other_registry);
+
+        assert_eq!(
+            world.get_dynamic(entity, small).unwrap().to_data(),
+            DynamicComponentData::new("Small").with("value", false)
+        );
+        assert_eq!(
+            world
+                .entity_mut(entity)
+                .insert_dynamic(&DynamicComponentData::new("Position").with("id", u64::MAX))
+                .err(),
+            Some(DynamicComponentError::UnknownComponent("Position".into()))
+        );
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    #[test]
+    fn reflect_component_for_dynamic_set() {
+        use crate::reflect::{AppTypeRegistry, ReflectComponent};
+
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        let position = world.register_dynamic_component(position_layout()).unwrap();
+        let source = world.spawn(Script).id();
+        world
+            .entity_mut(source)
+            .insert_dynamic(&DynamicComponentData::new("Position").with("x", 3.0f32))
+            .unwrap();
+
+        let set = DynamicComponentSet::from_entity(&world, source);
+        assert_eq!(set.0.len(), 1);
+
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        let registry = registry.read();
+        let reflect_component = registry
+            .get(core::any::TypeId::of::<DynamicComponentSet>())
+            .unwrap()
+            .data::<ReflectComponent>()
+            .unwrap();
+
+        let target = world.spawn_empty().id();
+        assert!(!reflect_component.contains(world.entity(target)));
+        reflect_component.apply_or_insert(&mut world.entity_mut(target), &set, &registry);
+        assert!(reflect_component.contains(world.entity(target)));
+        assert_eq!(
+            world.get_dynamic(target, position).unwrap().get("x"),
+            Some(DynamicValue::F32(3.0))
+        );
+
+        let set = DynamicComponentSet(vec![
+            DynamicComponentData::new("Position").with("visible", true)
+        ]);
+        reflect_component.apply(world.entity_mut(target), &set);
+        assert_eq!(
+            world.get_dynamic(target, position).unwrap().to_data(),
+            DynamicComponentData::new("Position")
+                .with("x", 3.0f32)
+                .with("visible", true)
+                .with("id", 0u64)
+        );
+
+        reflect_component.remove(&mut world.entity_mut(target));
+        assert!(world.get_dynamic(target, position).is_none());
+    }
+
+    #[cfg(feature = "bevy_reflect")]
+    #[test]
+    fn validate_dynamic_set() {
+        let mut world = World::new();
+        let set =
+            DynamicComponentSet(vec![DynamicComponentData::new("Position").with("x", 1.0f32)]);
+        assert_eq!(
+            set.validate(&world).err(),
+            Some(DynamicComponentError::UnknownComponent("Position".into()))
+        );
+
+        world.register_dynamic_component(position_layout()).unwrap();
+        assert!(set.validate(&world).is_ok());
+
+        let set = DynamicComponentSet(vec![DynamicComponentData::new("Position").with("x", true)]);
+        assert!(matches!(
+            set.validate(&world),
+            Err(DynamicComponentError::KindMismatch { .. })
+        ));
+        let set =
+            DynamicComponentSet(vec![DynamicComponentData::new("Position").with("y", 1.0f32)]);
+        assert!(matches!(
+            set.validate(&world),
+            Err(DynamicComponentError::UnknownField { .. })
+        ));
+    }
+}
@@ -37,6 +37,8 @@
 //! [`EntityWorldMut::remove`]: crate::world::EntityWorldMut::remove
 mod clone_entities;
 mod map_entities;
+#[cfg(feature = "bevy_reflect")]
+mod transfer_entities;
 mod visit_entities;
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::Reflect;
@@ -44,6 +46,8 @@ use bevy_reflect::Reflect;
 use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
 pub use clone_entities::*;
 pub use map_entities::*;
+#[cfg(feature = "bevy_reflect")]
+pub use transfer_entities::*;
 pub use visit_entities::*;
 
 mod hash;
@@ -0,0 +1,395 @@
+use alloc::{borrow::ToOwned, string::String, vec::Vec};
+
+use bevy_utils::HashSet;
+use thiserror::Error;
+
+use crate::{
+    bundle::Bundle,
+    component::ComponentId,
+    entity::{Entity, EntityHashMap, SceneEntityMapper},
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities},
+    world::World,
+};
+
+/// Builder to clone or move entities from one [`World`] to another.
+///
+/// Unlike [`EntityCloneBuilder`](crate::entity::EntityCloneBuilder), which clones entities within
+/// a single world, the worlds don't need to share [`ComponentId`]s: components are matched through
+/// their [`TypeId`](core::any::TypeId) and copied with reflection, so every transferred component
+/// has to be registered in the [`AppTypeRegistry`] of the source world with [`ReflectComponent`]
+/// type data.
+///
+/// The builder can be used for several transfers between the same worlds.
+///
+/// All entities given to a transfer are transferred together. References between them, such as a hierarchy, are
+/// fixed up to point at the new entities through [`ReflectMapEntities`]. References to entities that
+/// are not transferred are mapped to dead entities of the destination world, the same way scenes do.
+///
+/// ```
+/// use bevy_ecs::{entity::EntityTransferBuilder, prelude::*};
+/// use bevy_reflect::Reflect;
+///
+/// #[derive(Component, Reflect, PartialEq, Debug)]
+/// #[reflect(Component)]
+/// struct Health(u32);
+///
+/// let mut staging = World::new();
+/// staging.init_resource::<AppTypeRegistry>();
+/// staging.resource::<AppTypeRegistry>().write().register::<Health>();
+/// let entity = staging.spawn(Health(100)).id();
+///
+/// let mut world = World::new();
+/// let entity_map = EntityTransferBuilder::new(&mut staging, &mut world)
+///     .move_entities([entity])
+///     .unwrap();
+///
+/// assert!(staging.get_entity(entity).is_err());
+/// assert_eq!(world.get::<Health>(entity_map[&entity]), Some(&Health(100)));
+/// ```
+pub struct EntityTransferBuilder<'w> {
+    source: &'w mut World,
+    destination: &'w mut World,
+    filter_allows_components: bool,
+    filter: HashSet<ComponentId>,
+}
+
+impl<'w> EntityTransferBuilder<'w> {
+    /// Creates a new [`EntityTransferBuilder`] transferring entities from `source` to `destination`.
+    pub fn new(source: &'w mut World, destination: &'w mut World) -> Self {
+        Self {
+            source,
+            destination,
+            filter_allows_components: false,
+            filter: Default::default(),
+        }
+    }
+
+    /// Returns the world entities are transferred from.
+    pub fn source(&self) -> &World {
+        self.source
+    }
+
+    /// Returns the world entities are transferred from.
+    pub fn source_mut(&mut self) -> &mut World {
+        self.source
+    }
+
+    /// Returns the world entities are transferred to.
+    pub fn destination(&self) -> &World {
+        self.destination
+    }
+
+    /// Returns the world entities are transferred to.
+    pub fn destination_mut(&mut self) -> &mut World {
+        self.destination
+    }
+
+    /// Adds all components of the bundle to the list of components to transfer.
+    ///
+    /// Note that all components are allowed by default, to transfer only explicitly allowed components make sure to call
+    /// [`deny_all`](`Self::deny_all`) before calling any of the `allow` methods.
+    pub fn allow<T: Bundle>(&mut self) -> &mut Self {
+        let mut ids = Vec::new();
+        T::get_component_ids(self.source.components(), &mut |id| ids.extend(id));
+        self.allow_by_ids(ids)
+    }
+
+    /// Extends the list of components to transfer. The ids are those of the source world.
+    ///
+    /// Note that all components are allowed by default, to transfer only explicitly allowed components make sure to call
+    /// [`deny_all`](`Self::deny_all`) before calling any of the `allow` methods.
+    pub fn allow_by_ids(&mut self, ids: impl IntoIterator<Item = ComponentId>) -> &mut Self {
+        if self.filter_allows_components {
+            self.filter.extend(ids);
+        } else {
+            ids.into_iter().for_each(|id| {
+                self.filter.remove(&id);
+            });
+        }
+        self
+    }
+
+    /// Resets the filter to allow all components to be transferred.
+    pub fn allow_all(&mut self) -> &mut Self {
+        self.filter_allows_components = false;
+        self.filter.clear();
+        self
+    }
+
+    /// Disallows all components of the bundle from being transferred.
+    pub fn deny<T: Bundle>(&mut self) -> &mut Self {
+        let mut ids = Vec::new();
+        T::get_component_ids(self.source.components(), &mut |id| ids.extend(id));
+        self.deny_by_ids(ids)
+    }
+
+    /// Extends the list of components that shouldn't be transferred. The ids are those of the
+    /// source world.
+    pub fn deny_by_ids(&mut self, ids: impl IntoIterator<Item = ComponentId>) -> &mut Self {
+        if self.filter_allows_components {
+            ids.into_iter().for_each(|id| {
+                self.filter.remove(&id);
+            });
+        } else {
+            self.filter.extend(ids);
+        }
+        self
+    }
+
+    /// Sets the filter to deny all components.
+    pub fn deny_all(&mut self) -> &mut Self {
+        self.filter_allows_components = true;
+        self.filter.clear();
+        self
+    }
+
+    /// Clones `entities` into the destination world.
+    ///
+    /// Returns the map from the source entities to their clones.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, without changing either world, if the source world has no
+    /// [`AppTypeRegistry`], if one of the entities doesn't exist or if one of the allowed
+    /// components isn't registered with [`ReflectComponent`].
+    pub fn clone_entities(
+        &mut self,
+        entities: impl IntoIterator<Item = Entity>,
+    ) -> Result<EntityHashMap<Entity>, EntityTransferError> {
+        let entities = entities.into_iter().collect::<Vec<_>>();
+        self.transfer(&entities)
+    }
+
+    /// Moves `entities` into the destination world.
+    ///
+    /// The entities are despawned from the source world, together with the components that
+    /// weren't transferred. Returns the map from the source entities to the new entities.
+    ///
+    /// # Note
+    ///
+    /// This won't clean up references to the moved entities in the source world (such as
+    /// parent-child relationships if you're using `bevy_hierarchy`), so only whole hierarchies
+    /// should be moved. `TransferEntityHierarchyExt::move_hierarchy` from `bevy_hierarchy` moves
+    /// an entity and its descendants, and detaches them from their parent.
+    ///
+    /// # Errors
+    ///
+    /// See [`clone_entities`](Self::clone_entities).
+    pub fn move_entities(
+        &mut self,
+        entities: impl IntoIterator<Item = Entity>,
+    ) -> Result<EntityHashMap<Entity>, EntityTransferError> {
+        let entities = entities.into_iter().collect::<Vec<_>>();
+        let entity_map = self.transfer(&entities)?;
+        for entity in entities {
+            self.source.despawn(entity);
+        }
+        Ok(entity_map)
+    }
+
+    fn transfer(
+        &mut self,
+        entities: &[Entity],
+    ) -> Result<EntityHashMap<Entity>, EntityTransferError> {
+        let EntityTransferBuilder {
+            source,
+            destination,
+            filter_allows_components,
+            filter,
+        } = self;
+        let filter_allows_components = *filter_allows_components;
+        let registry = source
+            .get_resource::<AppTypeRegistry>()
+            .ok_or(EntityTransferError::MissingAppTypeRegistry)?
+            .read();
+
+        let mut transferred = Vec::with_capacity(entities.len());
+        for &entity in entities {
+            let entity_ref = source
+                .get_entity(entity)
+                .map_err(|_| EntityTransferError::EntityNotFound(entity))?;
+            let mut components = Vec::new();
+            for id in entity_ref.archetype().components() {
+                if filter_allows_components != filter.contains(&id) {
+                    continue;
+                }
+                let info = source.components().get_info(id).unwrap();
+                let registration = info
+                    .type_id()
+                    .and_then(|type_id| registry.get(type_id))
+                    .filter(|registration| registration.contains::<ReflectComponent>())
+                    .ok_or_else(|| EntityTransferError::NotReflected(info.name().to_owned()))?;
+                components.push(registration);
+            }
+            transferred.push((entity_ref, components));
+        }
+
+        let mut entity_map = EntityHashMap::default();
+        for &entity in entities {
+            entity_map
+                .entry(entity)
+                .or_insert_with(|| destination.spawn_empty().id());
+        }
+
+        let transferred = transferred
+            .into_iter()
+            .map(|(entity_ref, registrations)| {
+                (entity_ref, entity_map[&entity_ref.id()], registrations)
+            })
+            .collect::<Vec<_>>();
+        SceneEntityMapper::world_scope(&mut entity_map, destination, |destination, mapper| {
+            for (entity_ref, target, registrations) in transferred {
+                for registration in registrations {
+                    let reflect_component = registration.data::<ReflectComponent>().unwrap();
+                    let mut component =
+                        reflect_component.reflect(entity_ref).unwrap().clone_value();
+                    if let Some(map_entities) = registration.data::<ReflectMapEntities>() {
+                        map_entities.map_entities(component.as_partial_reflect_mut(), mapper);
+                    }
+                    reflect_component.insert(
+                        &mut destination.entity_mut(target),
+                        component.as_partial_reflect(),
+                        &registry,
+                    );
+                }
+            }
+        });
+
+        // Only return the transferred entities, not the dead entities outside references were
+        // mapped to.
+        Ok(entities
+            .iter()
+            .map(|entity| (*entity, entity_map[entity]))
+            .collect())
+    }
+}
+
+/// An error that occurs when transferring entities with an [`EntityTransferBuilder`].
+#[derive(Error, Debug)]
+pub enum EntityTransferError {
+    /// The source [`World`] was missing the [`AppTypeRegistry`] resource.
+    #[error("The source `World` was missing the `AppTypeRegistry` resource")]
+    MissingAppTypeRegistry,
+    /// The entity doesn't exist in the source [`World`].
+    #[error("The entity {0:?} does not exist in the source `World`")]
+    EntityNotFound(Entity),
+    /// A component of a transferred entity isn't registered with [`ReflectComponent`].
+    #[error(
+        "The component `{0}` cannot be transferred: it is not registered with `ReflectComponent`"
+    )]
+    NotReflected(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{EntityTransferBuilder, EntityTransferError};
+    use crate::{
+        self as bevy_ecs,
+        component::Component,
+        entity::{Entity, EntityMapper, MapEntities},
+        reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities},
+        world::World,
+    };
+    use bevy_reflect::Reflect;
+
+    #[derive(Component, Reflect, PartialEq, Debug)]
+    #[reflect(Component)]
+    struct A(u32);
+
+    #[derive(Component, Reflect, PartialEq, Debug)]
+    #[reflect(Component)]
+    struct B;
+
+    #[derive(Component, Reflect, PartialEq, Debug)]
+    #[reflect(Component, MapEntities)]
+    struct Target(Entity);
+
+    impl MapEntities for Target {
+        fn map_entities<M: EntityMapper>(&mut self, entity_mapper: &mut M) {
+            self.0 = entity_mapper.map_entity(self.0);
+        }
+    }
+
+    #[derive(Component)]
+    struct NotReflected;
+
+    fn source_world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<A>();
+            registry.register::<B>();
+            registry.register::<Target>();
+        }
+        world
+    }
+
+    #[test]
+    fn clone_entities_between_worlds() {
+        let mut source = source_world();
+        let a = source.spawn(A(1)).id();
+        let b = source.spawn((A(2), B, Target(a))).id();
+
+        let mut destination = World::new();
+        // Offset the entity ids, so they don't match by accident.
+        destination.spawn(B);
+        let entity_map = EntityTransferBuilder::new(&mut source, &mut destination)
+            .deny::<B>()
+            .clone_entities([a, b])
+            .unwrap();
+
+        let (new_a, new_b) = (entity_map[&a], entity_map[&b]);
+        assert_ne!(new_a, a);
+        assert_eq!(destination.get::<A>(new_a), Some(&A(1)));
+        assert_eq!(destination.get::<A>(new_b), Some(&A(2)));
+        assert_eq!(destination.get::<Target>(new_b), Some(&Target(new_a)));
+        assert_eq!(destination.get::<B>(new_b), None);
+
+        assert_eq!(source.get::<A>(a), Some(&A(1)));
+        assert_eq!(source.get::<Target>(b), Some(&Target(a)));
+    }
+
+    #[test]
+    fn move_entities_between_worlds() {
+        let mut source = source_world();
+        let a = source.spawn((A(1), NotReflected)).id();
+        let b = source.spawn(Target(a)).id();
+
+        let mut destination = World::new();
+        let mut builder = EntityTransferBuilder::new(&mut source, &mut destination);
+        builder.deny::<NotReflected>();
+        let entity_map = builder.move_entities([b]).unwrap();
+
+        let new_b = entity_map[&b];
+        let mapped_a = destination.get::<Target>(new_b).unwrap().0;
+        assert_ne!(mapped_a, a);
+        assert!(destination.get_entity(mapped_a).is_err());
+        assert!(source.get_entity(b).is_err());
+        assert!(source.get_entity(a).is_ok());
+    }
+
+    #[test]
+    fn transfer_errors() {
+        let mut source = source_world();
+        let entity = source.spawn((A(1), NotReflected)).id();
+        let despawned = source.spawn_empty().id();
+        source.despawn(despawned);
+
+        let mut destination = World::new();
+        let mut builder = EntityTransferBuilder::new(&mut source, &mut destination);
+        assert!(matches!(
+            builder.move_entities([entity]),
+            Err(EntityTransferError::NotReflected(_))
+        ));
+        assert!(matches!(
+            builder.clone_entities([despawned]),
+            Err(EntityTransferError::EntityNotFound(e)) if e == despawned
+        ));
+        builder.deny_all().allow::<A>();
+        builder.clone_entities([entity]).unwrap();
+
+        assert!(source.get_entity(entity).is_ok());
+        assert_eq!(destination.iter_entities().count(), 1);
+    }
+}
@@ -0,0 +1,136 @@
+//! Disabled entities do not show up in queries unless the query explicitly mentions them.
+//!
+//! If for some reason you want to keep an entity around without it taking part in your game logic
+//! (an object pool, a prefab template kept in the world, a paused level chunk), you can add the
+//! [`Disabled`] component to it instead of despawning it, and remove the component again to bring
+//! the entity back.
+//!
+//! Every [`QueryState`](crate::query::QueryState) gets an implicit `Without<Disabled>` filter,
+//! unless its data or filters explicitly access [`Disabled`], for example through
+//! [`With<Disabled>`](crate::query::With), [`Has<Disabled>`](crate::query::Has) or
+//! `Option<&Disabled>`. This means that disabled entities are skipped by every system that
+//! doesn't opt in, including the ones provided by Bevy itself.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! #[derive(Component)]
+//! struct Projectile;
+//!
+//! let mut world = World::new();
+//! world.spawn(Projectile);
+//! world.spawn((Projectile, Disabled));
+//!
+//! // Disabled entities are hidden by default...
+//! assert_eq!(world.query::<&Projectile>().iter(&world).count(), 1);
+//! // ...unless the query asks for them.
+//! assert_eq!(world.query::<(&Projectile, Has<Disabled>)>().iter(&world).count(), 2);
+//! assert_eq!(world.query_filtered::<&Projectile, With<Disabled>>().iter(&world).count(), 1);
+//! ```
+//!
+//! Queries that access all components without naming them, such as `Query<EntityRef>`, do not
+//! count as mentioning [`Disabled`], and skip disabled entities as well.
+//!
+//! Note that disabling an entity does not disable its descendants or any other related entities.
+//! Commands, hooks and observers still apply to disabled entities.
+
+use crate as bevy_ecs;
+use crate::component::Component;
+
+#[cfg(feature = "bevy_reflect")]
+use crate::reflect::ReflectComponent;
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+
+/// A marker component for disabled entities. See [the module docs] for more info.
+///
+/// [the module docs]: crate::entity_disabling
+#[derive(Component, Clone, Debug, Default)]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Component, Debug, Default)
+)]
+pub struct Disabled;
+
+#[cfg(test)]
+mod tests {
+    use super::Disabled;
+    use crate as bevy_ecs;
+    use crate::{
+        prelude::{Component, EntityRef, Has, Or, QueryBuilder, With, World},
+        query::QueryState,
+    };
+
+    #[derive(Component)]
+    struct A;
+
+    #[derive(Component)]
+    struct B;
+
+    #[test]
+    fn disabled_entities_are_hidden() {
+        let mut world = World::new();
+        let enabled = world.spawn(A).id();
+        let disabled = world.spawn((A, Disabled)).id();
+
+        let mut query = world.query::<&A>();
+        assert_eq!(query.iter(&world).count(), 1);
+        assert!(query.get(&world, enabled).is_ok());
+        assert!(query.get(&world, disabled).is_err());
+
+        let mut query = world.query::<EntityRef>();
+        assert!(query.iter(&world).all(|entity| entity.id() != disabled));
+
+        world.entity_mut(disabled).remove::<Disabled>();
+        assert_eq!(query.iter(&world).count(), 2);
+    }
+
+    #[test]
+    fn disabled_entities_can_be_queried_explicitly() {
+        let mut world = World::new();
+        world.spawn(A);
+        world.spawn((A, Disabled));
+        world.spawn((B, Disabled));
+
+        assert_eq!(world.query::<(&A, Has<Disabled>)>().iter(&world).count(), 2);
+        assert_eq!(
+            world
+                .query::<(&A, Option<&Disabled>)>()
+                .iter(&world)
+                .count(),
+            2
+        );
+        assert_eq!(
+            world
+                .query_filtered::<&A, With<Disabled>>()
+                .iter(&world)
+                .count(),
+            1
+        );
+        assert_eq!(
+            world
+                .query_filtered::<(), Or<(With<A>, With<Disabled>)>>()
+                .iter(&world)
+                .count(),
+            3
+        );
+    }
+
+    #[test]
+    fn disabled_entities_in_dynamic_queries() {
+        let mut world = World::new();
+        world.spawn(A);
+        world.spawn((A, Disabled));
+
+        let mut query = QueryBuilder::<(), With<A>>::new(&mut world).build();
+        assert_eq!(query.iter(&world).count(), 1);
+
+        let mut query = QueryBuilder::<(), With<A>>::new(&mut world)
+            .with::<Disabled>()
+            .build();
+        assert_eq!(query.iter(&world).count(), 1);
+
+        let mut query = QueryState::<Has<Disabled>, With<A>>::new(&mut world);
+        assert_eq!(query.iter(&world).count(), 2);
+    }
+}
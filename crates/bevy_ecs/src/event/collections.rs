@@ -362,9 +362,9 @@ impl<E: Event> DerefMut for EventSequence<E> {
 
 /// [`Iterator`] over sent [`EventIds`](`EventId`) from a batch.
 pub struct SendBatchIds<E> {
-    last_count: usize,
-    event_count: usize,
-    _marker: PhantomData<E>,
+    pub(super) last_count: usize,
+    pub(super) event_count: usize,
+    pub(super) _marker: PhantomData<E>,
 }
 
 impl<E: Event> Iterator for SendBatchIds<E> {
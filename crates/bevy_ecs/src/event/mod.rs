@@ -5,6 +5,7 @@ mod event_cursor;
 mod iterators;
 mod mut_iterators;
 mod mutator;
+mod persistent;
 mod reader;
 mod registry;
 mod send_event;
@@ -23,6 +24,10 @@ pub use iterators::{EventIterator, EventIteratorWithId};
 pub use mut_iterators::EventMutParIter;
 pub use mut_iterators::{EventMutIterator, EventMutIteratorWithId};
 pub use mutator::EventMutator;
+pub use persistent::{
+    persistent_event_update_system, EventRecording, PersistentEventCursor, PersistentEventReader,
+    PersistentEvents,
+};
 pub use reader::EventReader;
 pub use registry::{EventRegistry, ShouldUpdateEvents};
 pub use send_event::SendEvent;
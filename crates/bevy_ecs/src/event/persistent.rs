@@ -0,0 +1,679 @@
+use crate as bevy_ecs;
+use alloc::{
+    collections::VecDeque,
+    sync::{Arc, Weak},
+    vec::Vec,
+};
+use bevy_ecs::{
+    change_detection::DetectChangesMut,
+    event::{Event, EventId, EventInstance, SendBatchIds},
+    system::{Local, Res, ResMut, Resource, SystemParam},
+    world::{FromWorld, World},
+};
+use bevy_utils::detailed_trace;
+use core::{
+    marker::PhantomData,
+    sync::atomic::{AtomicUsize, Ordering},
+};
+
+/// An event collection that keeps events until every registered [`PersistentEventCursor`] has
+/// read them.
+///
+/// Unlike [`Events`](super::Events), which drops events after two updates, this is suited for
+/// readers that don't run every frame, such as systems in `FixedUpdate` or behind a run
+/// condition. Events are only dropped by [`update`](Self::update), once all cursors have read
+/// them, or when more than [`max_len`](Self::max_len) events are stored, in which case the oldest
+/// ones are dropped and lagging cursors report them as [missed](PersistentEventCursor::missed_events).
+///
+/// Cursors unregister themselves when dropped. While no cursor is registered, events are kept
+/// (up to [`max_len`](Self::max_len)), so that cursors registered later can read them.
+///
+/// By default, at most [`DEFAULT_MAX_LEN`](Self::DEFAULT_MAX_LEN) events are kept, so that events
+/// nobody reads don't pile up forever. Use [`set_max_len`](Self::set_max_len) to change it.
+///
+/// Events can also be [recorded](Self::record) to an [`EventRecording`] ring buffer, to be
+/// inspected or [replayed](Self::replay) later.
+///
+/// # Example
+/// ```
+/// use bevy_ecs::event::{Event, PersistentEvents};
+///
+/// #[derive(Event)]
+/// struct MyEvent {
+///     value: usize
+/// }
+///
+/// let mut events = PersistentEvents::<MyEvent>::default();
+/// let mut fast = events.register_cursor();
+/// let mut slow = events.register_cursor();
+///
+/// events.send(MyEvent { value: 1 });
+/// assert_eq!(fast.read(&events).count(), 1);
+///
+/// // Events are kept across updates until every cursor read them.
+/// events.update();
+/// events.update();
+/// assert_eq!(events.len(), 1);
+/// assert_eq!(slow.read(&events).map(|event| event.value).sum::<usize>(), 1);
+///
+/// events.update();
+/// assert!(events.is_empty());
+/// ```
+#[derive(Resource)]
+pub struct PersistentEvents<E: Event> {
+    events: VecDeque<EventInstance<E>>,
+    start_event_count: usize,
+    event_count: usize,
+    max_len: Option<usize>,
+    cursors: Vec<Weak<AtomicUsize>>,
+    recording: Option<EventRecording<E>>,
+}
+
+// Derived Default impl would incorrectly require E: Default
+impl<E: Event> Default for PersistentEvents<E> {
+    fn default() -> Self {
+        Self {
+            events: VecDeque::new(),
+            start_event_count: 0,
+            event_count: 0,
+            max_len: Some(Self::DEFAULT_MAX_LEN),
+            cursors: Vec::new(),
+            recording: None,
+        }
+    }
+}
+
+impl<E: Event> core::fmt::Debug for PersistentEvents<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("PersistentEvents")
+            .field("len", &self.events.len())
+            .field("start_event_count", &self.start_event_count)
+            .field("event_count", &self.event_count)
+            .field("max_len", &self.max_len)
+            .field("cursors", &self.cursor_count())
+            .field(
+                "recording",
+                &self.recording.as_ref().map(EventRecording::len),
+            )
+            .finish()
+    }
+}
+
+impl<E: Event> PersistentEvents<E> {
+    /// The maximum number of events kept by [`Default`] collections.
+    pub const DEFAULT_MAX_LEN: usize = 1024;
+
+    /// Creates an empty collection that keeps at most `max_len` events.
+    pub fn with_max_len(max_len: usize) -> Self {
+        Self {
+            max_len: Some(max_len),
+            ..Default::default()
+        }
+    }
+
+    /// Returns the maximum number of events that are kept, if bounded.
+    pub fn max_len(&self) -> Option<usize> {
+        self.max_len
+    }
+
+    /// Sets the maximum number of events that are kept, dropping the oldest events if needed.
+    ///
+    /// With `None`, events are kept until every cursor has read them, and forever while no cursor
+    /// is registered.
+    pub fn set_max_len(&mut self, max_len: Option<usize>) {
+        self.max_len = max_len;
+        self.enforce_max_len();
+    }
+
+    /// Returns the index of the oldest event still stored.
+    pub fn oldest_event_count(&self) -> usize {
+        self.start_event_count
+    }
+
+    /// "Sends" an `event` by storing it until every cursor has read it.
+    /// This method returns the [ID](`EventId`) of the sent `event`.
+    pub fn send(&mut self, event: E) -> EventId<E> {
+        let event_id = EventId {
+            id: self.event_count,
+            _marker: PhantomData,
+        };
+        detailed_trace!("PersistentEvents::send() -> id: {}", event_id);
+
+        if let Some(recording) = &mut self.recording {
+            recording.push(EventInstance {
+                event_id,
+                event: (recording.clone_event)(&event),
+            });
+        }
+        self.events.push_back(EventInstance { event_id, event });
+        self.event_count += 1;
+        self.enforce_max_len();
+
+        event_id
+    }
+
+    /// Sends a list of `events` all at once.
+    /// This method returns the [IDs](`EventId`) of the sent `events`.
+    pub fn send_batch(&mut self, events: impl IntoIterator<Item = E>) -> SendBatchIds<E> {
+        let last_count = self.event_count;
+        for event in events {
+            self.send(event);
+        }
+        SendBatchIds {
+            last_count,
+            event_count: self.event_count,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Sends the default value of the event. Useful when the event is an empty struct.
+    /// This method returns the [ID](`EventId`) of the sent `event`.
+    pub fn send_default(&mut self) -> EventId<E>
+    where
+        E: Default,
+    {
+        self.send(Default::default())
+    }
+
+    /// Registers a new [`PersistentEventCursor`], which will read all events still stored.
+    pub fn register_cursor(&mut self) -> PersistentEventCursor<E> {
+        self.register_cursor_at(self.start_event_count)
+    }
+
+    /// Registers a new [`PersistentEventCursor`], which will ignore all events already stored
+    /// and read all future events.
+    pub fn register_cursor_current(&mut self) -> PersistentEventCursor<E> {
+        self.register_cursor_at(self.event_count)
+    }
+
+    fn register_cursor_at(&mut self, last_event_count: usize) -> PersistentEventCursor<E> {
+        let last_event_count = Arc::new(AtomicUsize::new(last_event_count));
+        self.cursors.push(Arc::downgrade(&last_event_count));
+        PersistentEventCursor {
+            last_event_count,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns the number of registered cursors that were not dropped yet.
+    pub fn cursor_count(&self) -> usize {
+        self.cursors
+            .iter()
+            .filter(|cursor| cursor.strong_count() > 0)
+            .count()
+    }
+
+    /// Drops the events that every registered cursor has read, and unregisters the dropped
+    /// cursors. In general, this should be called once per frame/update.
+    ///
+    /// [`persistent_event_update_system`] is a system that does this.
+    pub fn update(&mut self) {
+        self.cursors.retain(|cursor| cursor.strong_count() > 0);
+        let Some(read) = self
+            .cursors
+            .iter()
+            .filter_map(Weak::upgrade)
+            .map(|cursor| cursor.load(Ordering::Relaxed))
+            .min()
+        else {
+            return;
+        };
+        let read = read.clamp(self.start_event_count, self.event_count);
+        self.events.drain(..read - self.start_event_count);
+        self.start_event_count = read;
+    }
+
+    fn enforce_max_len(&mut self) {
+        let Some(max_len) = self.max_len else {
+            return;
+        };
+        let excess = self.events.len().saturating_sub(max_len);
+        self.events.drain(..excess);
+        self.start_event_count += excess;
+    }
+
+    /// Removes all events.
+    pub fn clear(&mut self) {
+        self.events.clear();
+        self.start_event_count = self.event_count;
+    }
+
+    /// Returns the number of events currently stored.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns true if there are no events currently stored.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Get a specific event by id if it is still stored.
+    pub fn get_event(&self, id: usize) -> Option<(&E, EventId<E>)> {
+        let index = id.checked_sub(self.start_event_count)?;
+        self.events
+            .get(index)
+            .map(|instance| (&instance.event, instance.event_id))
+    }
+
+    /// Starts recording the sent events to a ring buffer keeping the latest `capacity` events,
+    /// replacing any previous recording.
+    pub fn record(&mut self, capacity: usize)
+    where
+        E: Clone,
+    {
+        self.recording = Some(EventRecording {
+            events: VecDeque::with_capacity(capacity),
+            capacity,
+            clone_event: E::clone,
+        });
+    }
+
+    /// Returns the current recording, if any.
+    pub fn recording(&self) -> Option<&EventRecording<E>> {
+        self.recording.as_ref()
+    }
+
+    /// Stops recording, and returns the recording.
+    pub fn stop_recording(&mut self) -> Option<EventRecording<E>> {
+        self.recording.take()
+    }
+
+    /// Sends the recorded events again, in the order they were recorded.
+    ///
+    /// The replayed events get new [IDs](`EventId`), and are not recorded again.
+    pub fn replay(&mut self) -> SendBatchIds<E> {
+        let recording = self.recording.take();
+        let ids = self.send_batch(
+            recording
+                .iter()
+                .flat_map(|recording| recording.iter().map(recording.clone_event)),
+        );
+        self.recording = recording;
+        ids
+    }
+}
+
+/// A ring buffer of the latest events sent to [`PersistentEvents`], started with
+/// [`PersistentEvents::record`].
+pub struct EventRecording<E: Event> {
+    events: VecDeque<EventInstance<E>>,
+    capacity: usize,
+    clone_event: fn(&E) -> E,
+}
+
+impl<E: Event> core::fmt::Debug for EventRecording<E> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("EventRecording")
+            .field("len", &self.events.len())
+            .field("capacity", &self.capacity)
+            .finish()
+    }
+}
+
+impl<E: Event> EventRecording<E> {
+    fn push(&mut self, instance: EventInstance<E>) {
+        if self.capacity == 0 {
+            return;
+        }
+        if self.events.len() == self.capacity {
+            self.events.pop_front();
+        }
+        self.events.push_back(instance);
+    }
+
+    /// Returns the maximum number of recorded events.
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// Returns the number of recorded events.
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Returns true if no events were recorded.
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Iterates over the recorded events, from oldest to newest.
+    pub fn iter(&self) -> impl ExactSizeIterator<Item = &E> {
+        self.events.iter().map(|instance| &instance.event)
+    }
+
+    /// Like [`iter`](Self::iter), except also returning the [`EventId`] of the events.
+    pub fn iter_with_id(&self) -> impl ExactSizeIterator<Item = (&E, EventId<E>)> {
+        self.events
+            .iter()
+            .map(|instance| (&instance.event, instance.event_id))
+    }
+
+    /// Removes all recorded events.
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+}
+
+/// Stores the state of a [`PersistentEventReader`], and keeps the events it did not read yet in
+/// [`PersistentEvents`] until it reads them or is dropped.
+///
+/// Cursors are created with [`PersistentEvents::register_cursor`].
+#[derive(Debug)]
+pub struct PersistentEventCursor<E: Event> {
+    last_event_count: Arc<AtomicUsize>,
+    _marker: PhantomData<E>,
+}
+
+impl<E: Event> FromWorld for PersistentEventCursor<E> {
+    fn from_world(world: &mut World) -> Self {
+        world
+            .get_resource_or_init::<PersistentEvents<E>>()
+            .register_cursor()
+    }
+}
+
+impl<E: Event> PersistentEventCursor<E> {
+    /// Iterates over the events this cursor has not read yet, and marks all of them as read.
+    pub fn read<'a>(
+        &mut self,
+        events: &'a PersistentEvents<E>,
+    ) -> impl DoubleEndedIterator<Item = &'a E> + ExactSizeIterator {
+        self.read_with_id(events).map(|(event, _)| event)
+    }
+
+    /// Like [`read`](Self::read), except also returning the [`EventId`] of the events.
+    pub fn read_with_id<'a>(
+        &mut self,
+        events: &'a PersistentEvents<E>,
+    ) -> impl DoubleEndedIterator<Item = (&'a E, EventId<E>)> + ExactSizeIterator {
+        let skip = self.unread_index(events);
+        self.last_event_count
+            .store(events.event_count, Ordering::Relaxed);
+        events
+            .events
+            .range(skip..)
+            .map(|instance| (&instance.event, instance.event_id))
+    }
+
+    fn unread_index(&self, events: &PersistentEvents<E>) -> usize {
+        self.last_event_count
+            .load(Ordering::Relaxed)
+            .saturating_sub(events.start_event_count)
+            .min(events.events.len())
+    }
+
+    /// Returns the number of events this cursor has not read yet.
+    pub fn len(&self, events: &PersistentEvents<E>) -> usize {
+        events.events.len() - self.unread_index(events)
+    }
+
+    /// Returns true if this cursor has read all the stored events.
+    pub fn is_empty(&self, events: &PersistentEvents<E>) -> bool {
+        self.len(events) == 0
+    }
+
+    /// Returns the number of events that were dropped because of
+    /// [`PersistentEvents::max_len`] before this cursor read them.
+    pub fn missed_events(&self, events: &PersistentEvents<E>) -> usize {
+        events
+            .start_event_count
+            .saturating_sub(self.last_event_count.load(Ordering::Relaxed))
+    }
+
+    /// Marks all the stored events as read.
+    pub fn clear(&mut self, events: &PersistentEvents<E>) {
+        self.last_event_count
+            .store(events.event_count, Ordering::Relaxed);
+    }
+}
+
+/// Reads events of type `E` from [`PersistentEvents<E>`], which keeps them until this reader has
+/// read them, even if its system doesn't run every frame.
+///
+/// Its [`PersistentEventCursor`] is registered when the system is initialized, and reads all the
+/// events still stored at that time.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::event::{PersistentEventReader, PersistentEvents, persistent_event_update_system};
+/// #[derive(Event)]
+/// struct Hit(u32);
+///
+/// #[derive(Resource, Default)]
+/// struct Damage(u32);
+///
+/// let mut world = World::new();
+/// world.init_resource::<PersistentEvents<Hit>>();
+/// world.init_resource::<Damage>();
+///
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems((
+///     persistent_event_update_system::<Hit>,
+///     (|mut hits: PersistentEventReader<Hit>, mut damage: ResMut<Damage>| {
+///         damage.0 += hits.read().map(|hit| hit.0).sum::<u32>();
+///     })
+///     // Only runs every other frame.
+///     .run_if(|mut frame: Local<u32>| { *frame += 1; *frame % 2 == 0 }),
+/// ));
+///
+/// for hit in 1..=4 {
+///     world.resource_mut::<PersistentEvents<Hit>>().send(Hit(hit));
+///     schedule.run(&mut world);
+/// }
+/// assert_eq!(world.resource::<Damage>().0, 10);
+/// ```
+#[derive(SystemParam, Debug)]
+pub struct PersistentEventReader<'w, 's, E: Event> {
+    cursor: Local<'s, PersistentEventCursor<E>>,
+    events: Res<'w, PersistentEvents<E>>,
+}
+
+impl<'w, 's, E: Event> PersistentEventReader<'w, 's, E> {
+    /// Iterates over the events this reader has not read yet, and marks all of them as read.
+    pub fn read(&mut self) -> impl DoubleEndedIterator<Item = &E> + ExactSizeIterator {
+        self.cursor.read(&self.events)
+    }
+
+    /// Like [`read`](Self::read), except also returning the [`EventId`] of the events.
+    pub fn read_with_id(
+        &mut self,
+    ) -> impl DoubleEndedIterator<Item = (&E, EventId<E>)> + ExactSizeIterator {
+        self.cursor.read_with_id(&self.events)
+    }
+
+    /// Returns the number of events this reader has not read yet.
+    pub fn len(&self) -> usize {
+        self.cursor.len(&self.events)
+    }
+
+    /// Returns true if this reader has read all the stored events.
+    pub fn is_empty(&self) -> bool {
+        self.cursor.is_empty(&self.events)
+    }
+
+    /// Returns the number of events that were dropped before this reader read them.
+    pub fn missed_events(&self) -> usize {
+        self.cursor.missed_events(&self.events)
+    }
+
+    /// Marks all the stored events as read.
+    pub fn clear(&mut self) {
+        self.cursor.clear(&self.events);
+    }
+}
+
+/// A system that calls [`PersistentEvents::update`] on the [`PersistentEvents<E>`] resource.
+pub fn persistent_event_update_system<E: Event>(events: Option<ResMut<PersistentEvents<E>>>) {
+    if let Some(mut events) = events {
+        events.bypass_change_detection().update();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as bevy_ecs, event::*, prelude::*};
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Event, Clone, Copy, PartialEq, Eq, Debug)]
+    struct TestEvent(usize);
+
+    fn read(
+        cursor: &mut PersistentEventCursor<TestEvent>,
+        events: &PersistentEvents<TestEvent>,
+    ) -> Vec<usize> {
+        cursor.read(events).map(|event| event.0).collect()
+    }
+
+    #[test]
+    fn events_are_kept_until_all_cursors_read_them() {
+        let mut events = PersistentEvents::<TestEvent>::default();
+        let mut a = events.register_cursor();
+        let mut b = events.register_cursor();
+
+        events.send_batch([TestEvent(0), TestEvent(1)]);
+        assert_eq!(read(&mut a, &events), vec![0, 1]);
+        for _ in 0..3 {
+            events.update();
+        }
+        assert_eq!(events.len(), 2);
+
+        events.send(TestEvent(2));
+        assert_eq!(read(&mut b, &events), vec![0, 1, 2]);
+        events.update();
+        assert_eq!(events.len(), 1);
+        assert_eq!(read(&mut a, &events), vec![2]);
+        assert_eq!(read(&mut a, &events), vec![]);
+        events.update();
+        assert!(events.is_empty());
+        assert_eq!(events.oldest_event_count(), 3);
+    }
+
+    #[test]
+    fn dropped_cursors_are_unregistered() {
+        let mut events = PersistentEvents::<TestEvent>::default();
+        let mut a = events.register_cursor();
+        let b = events.register_cursor();
+        events.send(TestEvent(0));
+        read(&mut a, &events);
+        events.update();
+        assert_eq!(events.len(), 1);
+
+        drop(b);
+        events.update();
+        assert!(events.is_empty());
+        assert_eq!(events.cursor_count(), 1);
+
+        // Without cursors, events are kept for cursors registered later.
+        drop(a);
+        events.send(TestEvent(1));
+        events.update();
+        let mut c = events.register_cursor();
+        let mut d = events.register_cursor_current();
+        events.send(TestEvent(2));
+        assert_eq!(read(&mut c, &events), vec![1, 2]);
+        assert_eq!(read(&mut d, &events), vec![2]);
+    }
+
+    #[test]
+    fn max_len_drops_oldest_events() {
+        let mut events = PersistentEvents::<TestEvent>::with_max_len(2);
+        let mut cursor = events.register_cursor();
+        events.send_batch((0..5).map(TestEvent));
+        assert_eq!(cursor.len(&events), 2);
+        assert_eq!(cursor.missed_events(&events), 3);
+        assert_eq!(read(&mut cursor, &events), vec![3, 4]);
+        assert_eq!(
+            events.get_event(4).map(|(event, _)| *event),
+            Some(TestEvent(4))
+        );
+        assert!(events.get_event(2).is_none());
+
+        events.send_batch((5..7).map(TestEvent));
+        events.set_max_len(Some(1));
+        assert_eq!(read(&mut cursor, &events), vec![6]);
+    }
+
+    #[test]
+    fn unread_events_are_evicted_by_default() {
+        let max_len = PersistentEvents::<TestEvent>::DEFAULT_MAX_LEN;
+        let mut events = PersistentEvents::<TestEvent>::default();
+        events.send_batch((0..max_len + 10).map(TestEvent));
+        events.update();
+        assert_eq!(events.len(), max_len);
+        assert_eq!(events.oldest_event_count(), 10);
+
+        // A cursor that never reads doesn't keep more events either.
+        let _cursor = events.register_cursor();
+        events.send_batch((0..10).map(TestEvent));
+        events.update();
+        assert_eq!(events.len(), max_len);
+        assert_eq!(events.oldest_event_count(), 20);
+    }
+
+    #[test]
+    fn record_and_replay() {
+        let mut events = PersistentEvents::<TestEvent>::default();
+        let mut cursor = events.register_cursor();
+        events.send(TestEvent(0));
+        events.record(2);
+        events.send_batch((1..4).map(TestEvent));
+
+        let recording = events.recording().unwrap();
+        assert_eq!(
+            recording.iter().map(|event| event.0).collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+        assert_eq!(
+            recording
+                .iter_with_id()
+                .map(|(_, id)| id.id)
+                .collect::<Vec<_>>(),
+            vec![2, 3]
+        );
+        read(&mut cursor, &events);
+
+        let ids = events.replay();
+        assert_eq!(ids.map(|id| id.id).collect::<Vec<_>>(), vec![4, 5]);
+        assert_eq!(read(&mut cursor, &events), vec![2, 3]);
+        assert_eq!(events.recording().unwrap().len(), 2);
+
+        assert_eq!(events.stop_recording().unwrap().len(), 2);
+        events.send(TestEvent(6));
+        assert!(events.recording().is_none());
+    }
+
+    #[test]
+    fn persistent_event_reader_in_schedule() {
+        #[derive(Resource, Default)]
+        struct Read(Vec<usize>);
+
+        let mut world = World::new();
+        world.init_resource::<PersistentEvents<TestEvent>>();
+        world.init_resource::<Read>();
+        world
+            .resource_mut::<PersistentEvents<TestEvent>>()
+            .send(TestEvent(0));
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems((
+            persistent_event_update_system::<TestEvent>,
+            (|mut reader: PersistentEventReader<TestEvent>, mut read: ResMut<Read>| {
+                read.0.extend(reader.read().map(|event| event.0));
+            })
+            .run_if(|mut runs: Local<usize>| {
+                *runs += 1;
+                *runs % 3 == 0
+            }),
+        ));
+
+        for i in 1..=6 {
+            world
+                .resource_mut::<PersistentEvents<TestEvent>>()
+                .send(TestEvent(i));
+            schedule.run(&mut world);
+        }
+        assert_eq!(world.resource::<Read>().0, vec![0, 1, 2, 3, 4, 5, 6]);
+        assert!(world.resource::<PersistentEvents<TestEvent>>().len() <= 1);
+    }
+}
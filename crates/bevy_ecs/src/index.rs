@@ -0,0 +1,280 @@
+//! Indexes that map the values of a component to the entities holding them.
+//!
+//! Answering "which entity has `PlayerId(7)`" with a [`Query`](crate::system::Query) means scanning
+//! every entity with a `PlayerId`. For components that are looked up by value often, an index can be
+//! registered with [`World::register_index`], which keeps a [`ComponentIndex`] resource up to date
+//! using [component hooks](crate::component::ComponentHooks). Systems can then look entities up
+//! through the [`Index`] system param.
+//!
+//! Only [`Immutable`] components can be indexed: their value can only change by inserting a new one,
+//! which runs the hooks that maintain the index.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::{index::Index, system::RunSystemOnce};
+//! #[derive(Component, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+//! #[component(immutable)]
+//! struct ChunkCoord(i32, i32);
+//!
+//! let mut world = World::new();
+//! world.register_index::<ChunkCoord>();
+//!
+//! world.spawn(ChunkCoord(3, 4));
+//! world.spawn(ChunkCoord(3, 5));
+//! world.spawn(ChunkCoord(4, 0));
+//!
+//! fn chunk_system(index: Index<ChunkCoord>) {
+//!     // Equality lookups...
+//!     assert_eq!(index.get(&ChunkCoord(3, 4)).count(), 1);
+//!     // ...and range lookups.
+//!     assert_eq!(index.range(ChunkCoord(3, 0)..ChunkCoord(4, 0)).count(), 2);
+//! }
+//!
+//! world.run_system_once(chunk_system).unwrap();
+//! ```
+
+use crate as bevy_ecs;
+use crate::{
+    component::{Component, ComponentId, Immutable},
+    entity::{Entity, EntityHashSet},
+    system::{Res, Resource, SystemParam},
+    world::{DeferredWorld, World},
+};
+use alloc::collections::BTreeMap;
+use core::{
+    fmt::Debug,
+    ops::{Deref, RangeBounds},
+};
+
+/// A [`Resource`] mapping each value of the component `C` to the entities that have it.
+///
+/// The index is created and kept up to date by [`World::register_index`]. Systems usually read it
+/// through the [`Index`] system param.
+#[derive(Resource, Debug)]
+pub struct ComponentIndex<C: Component<Mutability = Immutable> + Ord> {
+    entities: BTreeMap<C, EntityHashSet>,
+}
+
+impl<C: Component<Mutability = Immutable> + Ord> Default for ComponentIndex<C> {
+    fn default() -> Self {
+        Self {
+            entities: BTreeMap::new(),
+        }
+    }
+}
+
+impl<C: Component<Mutability = Immutable> + Ord> ComponentIndex<C> {
+    /// Returns an iterator over the entities whose `C` component is equal to `value`.
+    pub fn get(&self, value: &C) -> impl Iterator<Item = Entity> + '_ {
+        self.entities.get(value).into_iter().flatten().copied()
+    }
+
+    /// Returns `true` if any entity has a `C` component equal to `value`.
+    pub fn contains(&self, value: &C) -> bool {
+        self.entities.contains_key(value)
+    }
+
+    /// Returns an iterator over the entities whose `C` component lies in `range`, along with that
+    /// value, in ascending order of the value.
+    pub fn range<R: RangeBounds<C>>(&self, range: R) -> impl Iterator<Item = (&C, Entity)> + '_ {
+        self.entities
+            .range(range)
+            .flat_map(|(value, entities)| entities.iter().map(move |entity| (value, *entity)))
+    }
+
+    /// Returns an iterator over every indexed value and entity, in ascending order of the value.
+    pub fn iter(&self) -> impl Iterator<Item = (&C, Entity)> + '_ {
+        self.range(..)
+    }
+
+    /// Returns the number of distinct values in the index.
+    pub fn len(&self) -> usize {
+        self.entities.len()
+    }
+
+    /// Returns `true` if no entity has a `C` component.
+    pub fn is_empty(&self) -> bool {
+        self.entities.is_empty()
+    }
+
+    fn insert(&mut self, value: C, entity: Entity) {
+        self.entities.entry(value).or_default().insert(entity);
+    }
+
+    fn remove(&mut self, value: &C, entity: Entity) {
+        if let Some(entities) = self.entities.get_mut(value) {
+            entities.remove(&entity);
+            if entities.is_empty() {
+                self.entities.remove(value);
+            }
+        }
+    }
+}
+
+/// A [`SystemParam`] to look entities up by the value of their `C` component.
+///
+/// The index for `C` must have been registered with [`World::register_index`]. See the
+/// [module docs](crate::index) for an example.
+#[derive(SystemParam)]
+pub struct Index<'w, C: Component<Mutability = Immutable> + Ord> {
+    index: Res<'w, ComponentIndex<C>>,
+}
+
+impl<'w, C: Component<Mutability = Immutable> + Ord> Deref for Index<'w, C> {
+    type Target = ComponentIndex<C>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.index
+    }
+}
+
+impl<'w, C: Component<Mutability = Immutable> + Ord + Debug> Debug for Index<'w, C> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_tuple("Index").field(&*self.index).finish()
+    }
+}
+
+/// Sets up the [`ComponentIndex`] of `C` in the world. See [`World::register_index`].
+pub(crate) fn register_index<C: Component<Mutability = Immutable> + Ord + Clone>(
+    world: &mut World,
+) {
+    if world.contains_resource::<ComponentIndex<C>>() {
+        return;
+    }
+    let hooks = world.register_component_hooks::<C>();
+    if hooks
+        .try_on_insert(on_insert::<C>)
+        .and_then(|hooks| hooks.try_on_replace(on_replace::<C>))
+        .is_none()
+    {
+        panic!(
+            "Cannot index {}: it already has an on_insert or on_replace hook",
+            core::any::type_name::<C>()
+        );
+    }
+    world.init_resource::<ComponentIndex<C>>();
+}
+
+fn on_insert<C: Component<Mutability = Immutable> + Ord + Clone>(
+    mut world: DeferredWorld,
+    entity: Entity,
+    _: ComponentId,
+) {
+    let value = world.entity(entity).get::<C>().unwrap().clone();
+    if let Some(mut index) = world.get_resource_mut::<ComponentIndex<C>>() {
+        index.insert(value, entity);
+    }
+}
+
+fn on_replace<C: Component<Mutability = Immutable> + Ord + Clone>(
+    mut world: DeferredWorld,
+    entity: Entity,
+    _: ComponentId,
+) {
+    let value = world.entity(entity).get::<C>().unwrap().clone();
+    if let Some(mut index) = world.get_resource_mut::<ComponentIndex<C>>() {
+        index.remove(&value, entity);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ComponentIndex, Index};
+    use crate as bevy_ecs;
+    use crate::{
+        component::{Component, ComponentId},
+        entity::Entity,
+        system::RunSystemOnce,
+        world::{DeferredWorld, World},
+    };
+    use alloc::vec::Vec;
+
+    #[derive(Component, Clone, PartialEq, Eq, PartialOrd, Ord, Debug)]
+    #[component(immutable)]
+    struct PlayerId(u32);
+
+    fn entities(index: &ComponentIndex<PlayerId>, id: u32) -> Vec<Entity> {
+        let mut entities: Vec<_> = index.get(&PlayerId(id)).collect();
+        entities.sort();
+        entities
+    }
+
+    #[test]
+    fn index_tracks_inserts_replacements_and_removals() {
+        let mut world = World::new();
+        world.register_index::<PlayerId>();
+
+        let a = world.spawn(PlayerId(1)).id();
+        let b = world.spawn(PlayerId(1)).id();
+        let c = world.spawn(PlayerId(2)).id();
+
+        let index = world.resource::<ComponentIndex<PlayerId>>();
+        assert_eq!(entities(index, 1), [a, b]);
+        assert_eq!(entities(index, 2), [c]);
+        assert_eq!(index.len(), 2);
+
+        world.entity_mut(b).insert(PlayerId(2));
+        let index = world.resource::<ComponentIndex<PlayerId>>();
+        assert_eq!(entities(index, 1), [a]);
+        assert_eq!(entities(index, 2), [b, c]);
+
+        world.entity_mut(a).remove::<PlayerId>();
+        world.despawn(c);
+        let index = world.resource::<ComponentIndex<PlayerId>>();
+        assert!(!index.contains(&PlayerId(1)));
+        assert_eq!(entities(index, 2), [b]);
+        assert_eq!(index.len(), 1);
+    }
+
+    #[test]
+    fn index_range_lookups() {
+        let mut world = World::new();
+        world.register_index::<PlayerId>();
+
+        let ids: Vec<_> = (0..10).map(|id| world.spawn(PlayerId(id)).id()).collect();
+
+        let index = world.resource::<ComponentIndex<PlayerId>>();
+        let found: Vec<_> = index
+            .range(PlayerId(3)..PlayerId(6))
+            .map(|(id, entity)| (id.0, entity))
+            .collect();
+        assert_eq!(found, [(3, ids[3]), (4, ids[4]), (5, ids[5])]);
+        assert_eq!(index.range(PlayerId(8)..).count(), 2);
+        assert_eq!(index.iter().count(), 10);
+    }
+
+    #[test]
+    fn index_system_param() {
+        let mut world = World::new();
+        world.register_index::<PlayerId>();
+        let player = world.spawn(PlayerId(7)).id();
+
+        let found = world
+            .run_system_once(|index: Index<PlayerId>| index.get(&PlayerId(7)).next())
+            .unwrap();
+        assert_eq!(found, Some(player));
+    }
+
+    #[test]
+    fn index_registration_is_idempotent() {
+        let mut world = World::new();
+        world.register_index::<PlayerId>();
+        world.register_index::<PlayerId>();
+        let player = world.spawn(PlayerId(7)).id();
+
+        let index = world.resource::<ComponentIndex<PlayerId>>();
+        assert_eq!(entities(index, 7), [player]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_component_with_hooks() {
+        #[derive(Component, Clone, PartialEq, Eq, PartialOrd, Ord)]
+        #[component(immutable, on_insert = on_insert)]
+        struct Hooked;
+
+        fn on_insert(_: DeferredWorld, _: Entity, _: ComponentId) {}
+
+        World::new().register_index::<Hooked>();
+    }
+}
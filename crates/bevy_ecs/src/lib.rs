@@ -22,15 +22,19 @@ pub mod batching;
 pub mod bundle;
 pub mod change_detection;
 pub mod component;
+pub mod dynamic_component;
 pub mod entity;
+pub mod entity_disabling;
 pub mod event;
 pub mod identifier;
+pub mod index;
 pub mod intern;
 pub mod label;
 pub mod observer;
 pub mod query;
 #[cfg(feature = "bevy_reflect")]
 pub mod reflect;
+pub mod relationship;
 pub mod removal_detection;
 pub mod result;
 pub mod schedule;
@@ -52,9 +56,11 @@ pub mod prelude {
         change_detection::{DetectChanges, DetectChangesMut, Mut, Ref},
         component::{require, Component},
         entity::{Entity, EntityMapper},
+        entity_disabling::Disabled,
         event::{Event, EventMutator, EventReader, EventWriter, Events},
         observer::{CloneEntityWithObserversExt, Observer, Trigger},
         query::{Added, AnyOf, Changed, Has, Or, QueryBuilder, QueryState, With, Without},
+        relationship::{Relationship, RelationshipTarget},
         removal_detection::RemovedComponents,
         result::{Error, Result},
         schedule::{
@@ -69,7 +75,7 @@ pub mod prelude {
         },
         world::{
             Command, EntityMut, EntityRef, EntityWorldMut, FilteredResources, FilteredResourcesMut,
-            FromWorld, OnAdd, OnInsert, OnRemove, OnReplace, World,
+            FromWorld, OnAdd, OnInsert, OnMutate, OnRemove, OnReplace, World,
         },
     };
 
@@ -1489,6 +1495,8 @@ mod tests {
         let b_id = world.components.get_id(TypeId::of::<B>()).unwrap();
         expected.add_component_write(a_id);
         expected.add_component_read(b_id);
+        // Every query hides disabled entities by default.
+        expected.and_without(crate::world::DISABLED);
         assert!(
             query.component_access.eq(&expected),
             "ComponentId access from query fetch and query filter should be combined"
@@ -1,10 +1,12 @@
 //! Types for creating and storing [`Observer`]s
 
 mod entity_observer;
+mod mutation;
 mod runner;
 mod trigger_event;
 
 pub use entity_observer::CloneEntityWithObserversExt;
+pub use mutation::TrackedMutations;
 pub use runner::*;
 pub use trigger_event::*;
 
@@ -1368,4 +1370,36 @@ mod tests {
         assert_eq!(4, *counter.0.get(&a_id).unwrap());
         assert_eq!(3, *counter.0.get(&b_id).unwrap());
     }
+
+    #[test]
+    fn fallible_observer() {
+        use crate::result::{DefaultErrorHandler, Error, ErrorContext};
+
+        fn record_error(_: Error, ctx: ErrorContext) {
+            assert!(matches!(ctx, ErrorContext::Observer { .. }));
+        }
+
+        let mut world = World::new();
+        world.insert_resource(DefaultErrorHandler(record_error));
+        world.init_resource::<Order>();
+        world.add_observer(|_: Trigger<EventA>, mut res: ResMut<Order>| -> Result {
+            res.observed("fallible");
+            Err("error")?;
+            res.observed("unreachable");
+            Ok(())
+        });
+        world.flush();
+        world.trigger(EventA);
+
+        assert_eq!(vec!["fallible"], world.resource::<Order>().0);
+    }
+
+    #[test]
+    #[should_panic]
+    fn fallible_observer_panics_by_default() {
+        let mut world = World::new();
+        world.add_observer(|_: Trigger<EventA>| -> Result { Err("error")? });
+        world.flush();
+        world.trigger(EventA);
+    }
 }
@@ -0,0 +1,326 @@
+use alloc::vec::Vec;
+use bevy_ptr::UnsafeCellDeref;
+
+use crate::{
+    self as bevy_ecs,
+    component::{Component, ComponentId, ComponentTicks, Mutable, StorageType, Tick},
+    entity::Entity,
+    system::Resource,
+    world::{DeferredWorld, World, ON_MUTATE},
+};
+
+/// The components whose mutations trigger [`OnMutate`](crate::world::OnMutate), registered with
+/// [`World::track_mutations`].
+#[derive(Resource, Debug)]
+pub struct TrackedMutations {
+    components: Vec<ComponentId>,
+    last_check: Tick,
+}
+
+impl TrackedMutations {
+    /// Returns the components whose mutations are tracked.
+    pub fn components(&self) -> &[ComponentId] {
+        &self.components
+    }
+
+    /// Returns the change tick at which mutations were last checked by
+    /// [`World::trigger_mutations`].
+    pub fn last_check(&self) -> Tick {
+        self.last_check
+    }
+
+    pub(crate) fn check_change_ticks(&mut self, change_tick: Tick) {
+        self.last_check.check_tick(change_tick);
+    }
+}
+
+impl World {
+    /// Makes [`World::trigger_mutations`] trigger [`OnMutate`](crate::world::OnMutate) for every
+    /// `T` component that was mutated since it last ran.
+    ///
+    /// Observers can then react to mutations through `Trigger<OnMutate, T>` instead of polling
+    /// with [`Changed<T>`](crate::query::Changed) in a system.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use core::sync::atomic::{AtomicUsize, Ordering};
+    /// #[derive(Component)]
+    /// struct Health(u32);
+    ///
+    /// static MUTATIONS: AtomicUsize = AtomicUsize::new(0);
+    ///
+    /// let mut world = World::new();
+    /// world.track_mutations::<Health>();
+    /// world.add_observer(|_: Trigger<OnMutate, Health>| {
+    ///     MUTATIONS.fetch_add(1, Ordering::Relaxed);
+    /// });
+    ///
+    /// let entity = world.spawn(Health(10)).id();
+    /// world.trigger_mutations();
+    /// assert_eq!(MUTATIONS.load(Ordering::Relaxed), 0);
+    ///
+    /// world.get_mut::<Health>(entity).unwrap().0 -= 1;
+    /// world.trigger_mutations();
+    /// assert_eq!(MUTATIONS.load(Ordering::Relaxed), 1);
+    /// ```
+    pub fn track_mutations<T: Component<Mutability = Mutable>>(&mut self) {
+        let id = self.register_component::<T>();
+        self.track_mutations_by_id(id);
+    }
+
+    /// Makes [`World::trigger_mutations`] trigger [`OnMutate`](crate::world::OnMutate) for every
+    /// component with the given `id` that was mutated since it last ran.
+    ///
+    /// See [`World::track_mutations`] for the typed version.
+    pub fn track_mutations_by_id(&mut self, id: ComponentId) {
+        let last_check = self.change_tick();
+        let tracked = self
+            .get_resource_or_insert_with(|| TrackedMutations {
+                components: Vec::new(),
+                last_check,
+            })
+            .into_inner();
+        if !tracked.components.contains(&id) {
+            tracked.components.push(id);
+        }
+    }
+
+    /// Triggers [`OnMutate`](crate::world::OnMutate) for every component tracked with
+    /// [`World::track_mutations`] whose change tick advanced since this method last ran, then
+    /// applies the commands queued by the observers.
+    ///
+    /// Components added since the last check only trigger [`OnAdd`](crate::world::OnAdd) and
+    /// [`OnInsert`](crate::world::OnInsert), but components that were replaced by inserting a new
+    /// value count as mutated. Mutations made by the triggered observers are reported the next
+    /// time this method runs.
+    ///
+    /// This is the sync point at which mutations are delivered: when using `bevy_app`, it runs at
+    /// the end of every frame, in the `Last` schedule.
+    pub fn trigger_mutations(&mut self) {
+        let Some(tracked) = self.get_resource::<TrackedMutations>() else {
+            return;
+        };
+        let components = tracked.components.clone();
+        let last_check = tracked.last_check;
+        // Like a system run, so that later changes get a newer tick.
+        let this_check = self.increment_change_tick();
+
+        let mut mutated = Vec::<(Entity, ComponentId)>::new();
+        let is_mutated = |ticks: ComponentTicks| {
+            ticks.is_changed(last_check, this_check) && !ticks.is_added(last_check, this_check)
+        };
+        for &component in &components {
+            let (Some(info), Some(archetypes)) = (
+                self.components().get_info(component),
+                self.archetypes().component_index().get(&component),
+            ) else {
+                continue;
+            };
+            for archetype_id in archetypes.keys() {
+                let archetype = &self.archetypes()[*archetype_id];
+                match info.storage_type() {
+                    StorageType::Table => {
+                        let table = &self.storages().tables[archetype.table_id()];
+                        let (Some(added), Some(changed)) = (
+                            table.get_added_ticks_slice_for(component),
+                            table.get_changed_ticks_slice_for(component),
+                        ) else {
+                            continue;
+                        };
+                        for entity in archetype.entities() {
+                            let row = entity.table_row().as_usize();
+                            // SAFETY: no mutable access to the ticks exists while `self` is
+                            // borrowed.
+                            let ticks = unsafe {
+                                ComponentTicks {
+                                    added: added[row].read(),
+                                    changed: changed[row].read(),
+                                }
+                            };
+                            if is_mutated(ticks) {
+                                mutated.push((entity.id(), component));
+                            }
+                        }
+                    }
+                    StorageType::SparseSet => {
+                        let Some(sparse_set) = self.storages().sparse_sets.get(component) else {
+                            continue;
+                        };
+                        for entity in archetype.entities() {
+                            if sparse_set.get_ticks(entity.id()).is_some_and(is_mutated) {
+                                mutated.push((entity.id(), component));
+                            }
+                        }
+                    }
+                }
+            }
+        }
+
+        self.resource_mut::<TrackedMutations>().last_check = this_check;
+
+        let mut world = DeferredWorld::from(&mut *self);
+        for (entity, component) in mutated {
+            // SAFETY: `OnMutate` is a unit struct, like the triggers of the component hooks.
+            unsafe {
+                world.trigger_observers(ON_MUTATE, entity, core::iter::once(component));
+            }
+        }
+        self.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::TrackedMutations;
+    use crate::{
+        self as bevy_ecs,
+        change_detection::{CHECK_TICK_THRESHOLD, MAX_CHANGE_AGE},
+        prelude::*,
+    };
+    use alloc::{vec, vec::Vec};
+
+    #[derive(Component)]
+    struct A(u32);
+
+    #[derive(Component)]
+    struct B;
+
+    #[derive(Component)]
+    #[component(storage = "SparseSet")]
+    struct C(u32);
+
+    #[derive(Resource, Default)]
+    struct Mutated(Vec<Entity>);
+
+    fn world() -> World {
+        let mut world = World::new();
+        world.init_resource::<Mutated>();
+        world.track_mutations::<A>();
+        world.add_observer(
+            |trigger: Trigger<OnMutate, A>, mut mutated: ResMut<Mutated>| {
+                mutated.0.push(trigger.target());
+            },
+        );
+        world
+    }
+
+    fn take_mutated(world: &mut World) -> Vec<Entity> {
+        world.trigger_mutations();
+        core::mem::take(&mut world.resource_mut::<Mutated>().0)
+    }
+
+    #[test]
+    fn on_mutate_tracks_mutations() {
+        let mut world = world();
+        let a = world.spawn(A(0)).id();
+        let b = world.spawn(A(0)).id();
+        assert_eq!(take_mutated(&mut world), []);
+
+        world.get_mut::<A>(b).unwrap().0 += 1;
+        assert_eq!(take_mutated(&mut world), [b]);
+        assert_eq!(take_mutated(&mut world), []);
+
+        // Replacing counts as a mutation.
+        world.entity_mut(a).insert(A(2));
+        assert_eq!(take_mutated(&mut world), [a]);
+
+        // Reading does not.
+        world.get::<A>(a).unwrap();
+        world.entity_mut(a).insert(B);
+        assert_eq!(take_mutated(&mut world), []);
+    }
+
+    #[test]
+    fn on_mutate_from_systems() {
+        let mut world = world();
+        let entity = world.spawn(A(0)).id();
+        assert_eq!(take_mutated(&mut world), []);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(|mut query: Query<&mut A>| {
+            for mut a in &mut query {
+                a.0 += 1;
+            }
+        });
+        schedule.run(&mut world);
+        assert_eq!(take_mutated(&mut world), [entity]);
+
+        // Untracked components never trigger.
+        world.spawn(B);
+        schedule.run(&mut world);
+        assert_eq!(take_mutated(&mut world), [entity]);
+    }
+
+    #[test]
+    fn on_mutate_in_observer_is_reported_next_time() {
+        let mut world = world();
+        world.add_observer(|trigger: Trigger<OnMutate, A>, mut query: Query<&mut A>| {
+            let mut a = query.get_mut(trigger.target()).unwrap();
+            if a.0 < 3 {
+                a.0 += 1;
+            }
+        });
+        let entity = world.spawn(A(0)).id();
+        assert_eq!(take_mutated(&mut world), []);
+
+        world.get_mut::<A>(entity).unwrap().0 = 1;
+        for _ in 1..=3 {
+            assert_eq!(take_mutated(&mut world), [entity]);
+        }
+        assert_eq!(take_mutated(&mut world), []);
+        assert_eq!(world.get::<A>(entity).unwrap().0, 3);
+    }
+
+    #[test]
+    fn track_mutations_is_idempotent() {
+        let mut world = world();
+        world.track_mutations::<A>();
+        let entity = world.spawn(A(0)).id();
+        // Mutating a component added since the last check only counts as adding it.
+        world.get_mut::<A>(entity).unwrap().0 = 1;
+        assert_eq!(take_mutated(&mut world), []);
+
+        world.get_mut::<A>(entity).unwrap().0 = 2;
+        assert_eq!(world.resource::<TrackedMutations>().components().len(), 1);
+        assert_eq!(take_mutated(&mut world), vec![entity]);
+    }
+
+    #[test]
+    fn on_mutate_sparse_set() {
+        let mut world = world();
+        world.track_mutations::<C>();
+        world.add_observer(
+            |trigger: Trigger<OnMutate, C>, mut mutated: ResMut<Mutated>| {
+                mutated.0.push(trigger.target());
+            },
+        );
+        let a = world.spawn(C(0)).id();
+        let b = world.spawn((A(0), C(0))).id();
+        assert_eq!(take_mutated(&mut world), []);
+
+        world.get_mut::<C>(a).unwrap().0 += 1;
+        world.get_mut::<C>(b).unwrap().0 += 1;
+        // Sparse set components are not visited in spawn order.
+        let mut mutated = take_mutated(&mut world);
+        mutated.sort();
+        let mut expected = [a, b];
+        expected.sort();
+        assert_eq!(mutated, expected);
+        assert_eq!(take_mutated(&mut world), []);
+    }
+
+    #[test]
+    fn last_check_is_clamped() {
+        let mut world = world();
+        world.trigger_mutations();
+
+        // A lot of ticks pass without mutations being triggered.
+        *world.change_tick.get_mut() += MAX_CHANGE_AGE + CHECK_TICK_THRESHOLD;
+        world.check_change_ticks();
+        let last_check = world.resource::<TrackedMutations>().last_check();
+        assert_eq!(
+            world.change_tick().relative_to(last_check).get(),
+            MAX_CHANGE_AGE
+        );
+    }
+}
@@ -5,6 +5,7 @@ use crate::{
     observer::{ObserverDescriptor, ObserverTrigger},
     prelude::*,
     query::DebugCheckedUnwrap,
+    result::{DefaultErrorHandler, ErrorContext},
     system::{IntoObserverSystem, ObserverSystem},
     world::DeferredWorld,
 };
@@ -374,10 +375,20 @@ fn observer_system_runner<E: Event, B: Bundle, S: ObserverSystem<E, B>>(
     // - there are no outstanding references to world except a private component
     // - system is an `ObserverSystem` so won't mutate world beyond the access of a `DeferredWorld`
     // - system is the same type erased system from above
+    // - the error handler resource is only read after the system has finished running
     unsafe {
         (*system).update_archetype_component_access(world);
         if (*system).validate_param_unsafe(world) {
-            (*system).run_unsafe(trigger, world);
+            if let Err(err) = (*system).run_unsafe(trigger, world) {
+                let handler = DefaultErrorHandler::handler_or_default(world.get_resource());
+                handler(
+                    err,
+                    ErrorContext::Observer {
+                        name: (*system).name(),
+                        last_run: (*system).get_last_run(),
+                    },
+                );
+            }
             (*system).queue_deferred(world.into_deferred());
         }
     }
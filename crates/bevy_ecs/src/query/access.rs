@@ -1116,6 +1116,22 @@ impl<T: SparseSetIndex> FilteredAccess<T> {
             .iter()
             .flat_map(|f| f.without.ones().map(T::get_sparse_set_index))
     }
+
+    /// Returns `true` if the index is explicitly used by this access: as a component read or write,
+    /// as an archetypal access, or as a `With` or `Without` filter.
+    ///
+    /// Accesses that read or write all components, like [`EntityRef`](crate::world::EntityRef),
+    /// do not count as explicitly using any index.
+    pub fn contains(&self, index: T) -> bool {
+        let index = index.sparse_set_index();
+        (!self.access.component_read_and_writes_inverted
+            && self.access.component_read_and_writes.contains(index))
+            || self.access.archetypal.contains(index)
+            || self
+                .filter_sets
+                .iter()
+                .any(|f| f.with.contains(index) || f.without.contains(index))
+    }
 }
 
 #[derive(Eq, PartialEq)]
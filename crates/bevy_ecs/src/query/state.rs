@@ -9,7 +9,7 @@ use crate::{
         WorldQuery,
     },
     storage::{SparseSetIndex, TableId},
-    world::{unsafe_world_cell::UnsafeWorldCell, World, WorldId},
+    world::{unsafe_world_cell::UnsafeWorldCell, World, WorldId, DISABLED},
 };
 use bevy_utils::tracing::warn;
 #[cfg(feature = "trace")]
@@ -197,7 +197,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
     fn new_uninitialized(world: &mut World) -> Self {
         let fetch_state = D::init_state(world);
         let filter_state = F::init_state(world);
-        Self::from_states_uninitialized(world.id(), fetch_state, filter_state)
+        Self::from_states_uninitialized(world, fetch_state, filter_state)
     }
 
     /// Creates a new [`QueryState`] but does not populate it with the matched results from the World yet
@@ -208,7 +208,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         let fetch_state = D::get_state(world.components())?;
         let filter_state = F::get_state(world.components())?;
         Some(Self::from_states_uninitialized(
-            world.id(),
+            world,
             fetch_state,
             filter_state,
         ))
@@ -219,7 +219,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
     /// `new_archetype` and its variants must be called on all of the World's archetypes before the
     /// state can return valid query results.
     fn from_states_uninitialized(
-        world_id: WorldId,
+        world: &World,
         fetch_state: <D as WorldQuery>::State,
         filter_state: <F as WorldQuery>::State,
     ) -> Self {
@@ -236,12 +236,15 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         // properly considered in a global "cross-query" context (both within systems and across systems).
         component_access.extend(&filter_component_access);
 
+        // Hide disabled entities unless the query explicitly asks for them.
+        Self::apply_default_filters(&mut component_access);
+
         // For queries without dynamic filters the dense-ness of the query is equal to the dense-ness
         // of its static type parameters.
         let is_dense = D::IS_DENSE && F::IS_DENSE;
 
         Self {
-            world_id,
+            world_id: world.id(),
             archetype_generation: ArchetypeGeneration::initial(),
             matched_storage_ids: Vec::new(),
             is_dense,
@@ -265,6 +268,9 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         let filter_state = F::init_state(builder.world_mut());
         D::set_access(&mut fetch_state, builder.access());
 
+        let mut component_access = builder.access().clone();
+        Self::apply_default_filters(&mut component_access);
+
         let mut state = Self {
             world_id: builder.world().id(),
             archetype_generation: ArchetypeGeneration::initial(),
@@ -273,7 +279,7 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
             is_dense: builder.is_dense(),
             fetch_state,
             filter_state,
-            component_access: builder.access().clone(),
+            component_access,
             matched_tables: Default::default(),
             matched_archetypes: Default::default(),
             #[cfg(feature = "trace")]
@@ -287,6 +293,17 @@ impl<D: QueryData, F: QueryFilter> QueryState<D, F> {
         state
     }
 
+    /// Adds the filters that every query gets by default to `component_access`.
+    ///
+    /// Currently this hides [`Disabled`] entities, unless the access explicitly mentions [`Disabled`].
+    ///
+    /// [`Disabled`]: crate::entity_disabling::Disabled
+    fn apply_default_filters(component_access: &mut FilteredAccess<ComponentId>) {
+        if !component_access.contains(DISABLED) {
+            component_access.and_without(DISABLED);
+        }
+    }
+
     /// Checks if the query is empty for the given [`World`], where the last change and current tick are given.
     ///
     /// This is equivalent to `self.iter().next().is_none()`, and thus the worst case runtime will be `O(n)`
@@ -0,0 +1,414 @@
+//! This module provides functionality to link entities to each other using specialized components called "relationships". See the [`Relationship`] trait for more info.
+
+mod relationship_source_collection;
+
+pub use relationship_source_collection::*;
+
+use crate::{
+    component::{Component, ComponentId, Immutable, Mutable},
+    entity::Entity,
+    world::{DeferredWorld, World},
+};
+use alloc::vec::Vec;
+use bevy_utils::tracing::warn;
+use core::marker::PhantomData;
+
+/// A [`Component`] on a "source" [`Entity`] that references another target [`Entity`], creating a "relationship" between them. Every [`Relationship`]
+/// has a corresponding [`RelationshipTarget`] type (and vice-versa), which exists on the "target" entity of a relationship and contains the list of all
+/// "source" entities that relate to the given "target"
+///
+/// The [`Relationship`] component is the "source of truth" and the [`RelationshipTarget`] component reflects that source of truth. When a [`Relationship`]
+/// component is inserted on an [`Entity`], the corresponding [`RelationshipTarget`] component is inserted on the target entity if it does
+/// not already exist, and the "source" entity is automatically added to the [`RelationshipTarget`] collection (this is done via "component hooks",
+/// and takes effect the next time commands are applied to the [`World`]).
+///
+/// When a [`Relationship`] is replaced or removed (including when its entity is despawned), the "source" entity is removed from the [`RelationshipTarget`]
+/// collection. When the last "source" entity is removed, the [`RelationshipTarget`] component is removed from the "target" entity. When a
+/// [`RelationshipTarget`] is removed (including when its entity is despawned), the [`Relationship`] component is removed from every "source" entity
+/// that still points to it.
+///
+/// Relationship components are [`Immutable`]: to point a source entity at a new target, insert a new [`Relationship`] value, which keeps
+/// both sides of the relationship in sync.
+///
+/// [`Relationship`] and [`RelationshipTarget`] should always be derived via the [`Component`] trait to ensure the hooks are set up properly.
+///
+/// ```
+/// # use bevy_ecs::component::Component;
+/// # use bevy_ecs::entity::Entity;
+/// #[derive(Component)]
+/// #[relationship(relationship_target = Children)]
+/// pub struct ChildOf(pub Entity);
+///
+/// #[derive(Component)]
+/// #[relationship_target(relationship = ChildOf)]
+/// pub struct Children(Vec<Entity>);
+/// ```
+///
+/// Every [`Relationship`] can be used as a [`Traversal`](crate::traversal::Traversal), so observers can propagate events from
+/// a source entity to its target with `#[event(traversal = &'static ChildOf)]`-style configuration.
+pub trait Relationship: Component<Mutability = Immutable> + Sized {
+    /// The [`Component`] added to the "target" entities of this [`Relationship`], which contains the list of all "source"
+    /// entities that relate to the "target".
+    type RelationshipTarget: RelationshipTarget<Relationship = Self>;
+
+    /// Gets the [`Entity`] ID of the related entity.
+    fn get(&self) -> Entity;
+
+    /// Creates this [`Relationship`] from the given `entity`.
+    fn from(entity: Entity) -> Self;
+
+    /// The `on_insert` component hook that maintains the [`Relationship`] / [`RelationshipTarget`] connection.
+    fn on_insert(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let target_entity = world.entity(entity).get::<Self>().unwrap().get();
+        if target_entity == entity {
+            warn!(
+                "The {}({target_entity:?}) relationship on entity {entity:?} points to itself. The invalid {} relationship has been removed.",
+                core::any::type_name::<Self>(),
+                core::any::type_name::<Self>()
+            );
+            world.commands().entity(entity).remove::<Self>();
+            return;
+        }
+        if world.get_entity(target_entity).is_err() {
+            warn!(
+                "The {}({target_entity:?}) relationship on entity {entity:?} relates to an entity that does not exist. The invalid {} relationship has been removed.",
+                core::any::type_name::<Self>(),
+                core::any::type_name::<Self>()
+            );
+            world.commands().entity(entity).remove::<Self>();
+            return;
+        }
+        if let Some(mut relationship_target) =
+            world.get_mut::<Self::RelationshipTarget>(target_entity)
+        {
+            relationship_target.collection_mut_risky().add(entity);
+        } else {
+            // Inserting the target collection changes the archetype of the target, which has to be deferred.
+            world
+                .commands()
+                .queue(AddToRelationshipTarget::<Self>::new(entity, target_entity));
+        }
+    }
+
+    /// The `on_replace` component hook that maintains the [`Relationship`] / [`RelationshipTarget`] connection.
+    // note: think of this as "on_drop"
+    fn on_replace(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let target_entity = world.entity(entity).get::<Self>().unwrap().get();
+        let Ok(mut target_entity_mut) = world.get_entity_mut(target_entity) else {
+            return;
+        };
+        let Some(mut relationship_target) = target_entity_mut.get_mut::<Self::RelationshipTarget>()
+        else {
+            return;
+        };
+        relationship_target.collection_mut_risky().remove(entity);
+        if relationship_target.len() == 0 {
+            world
+                .commands()
+                .queue(RemoveEmptyRelationshipTarget::<Self>::new(target_entity));
+        }
+    }
+}
+
+/// A [`Component`] containing the collection of entities that relate to this [`Entity`] via the associated `Relationship` type.
+/// See the [`Relationship`] documentation for more information.
+pub trait RelationshipTarget: Component<Mutability = Mutable> + Sized {
+    /// The [`Relationship`] that populates this [`RelationshipTarget`] collection.
+    type Relationship: Relationship<RelationshipTarget = Self>;
+    /// The collection type that stores the "source" entities for this [`RelationshipTarget`] component.
+    type Collection: RelationshipSourceCollection;
+
+    /// Returns a reference to the stored [`RelationshipTarget::Collection`].
+    fn collection(&self) -> &Self::Collection;
+    /// Returns a mutable reference to the stored [`RelationshipTarget::Collection`].
+    ///
+    /// # Warning
+    /// This should generally not be called by user code, as modifying the internal collection could invalidate the relationship.
+    fn collection_mut_risky(&mut self) -> &mut Self::Collection;
+
+    /// Creates a new [`RelationshipTarget`] from the given [`RelationshipTarget::Collection`].
+    ///
+    /// # Warning
+    /// This should generally not be called by user code, as constructing the internal collection could invalidate the relationship.
+    fn from_collection_risky(collection: Self::Collection) -> Self;
+
+    /// The `on_replace` component hook that maintains the [`Relationship`] / [`RelationshipTarget`] connection.
+    // note: think of this as "on_drop"
+    fn on_replace(mut world: DeferredWorld, entity: Entity, _: ComponentId) {
+        let sources = world
+            .entity(entity)
+            .get::<Self>()
+            .unwrap()
+            .iter()
+            .collect::<Vec<_>>();
+        let mut commands = world.commands();
+        for source_entity in sources {
+            commands.queue(move |world: &mut World| {
+                let Ok(mut source) = world.get_entity_mut(source_entity) else {
+                    return;
+                };
+                // Only remove the relationship if it still points to this target. It may have
+                // been re-targeted since this command was queued.
+                if source
+                    .get::<Self::Relationship>()
+                    .is_some_and(|relationship| relationship.get() == entity)
+                {
+                    source.remove::<Self::Relationship>();
+                }
+            });
+        }
+    }
+
+    /// Creates this [`RelationshipTarget`] with the given pre-allocated entity capacity.
+    fn with_capacity(capacity: usize) -> Self {
+        let collection =
+            <Self::Collection as RelationshipSourceCollection>::with_capacity(capacity);
+        Self::from_collection_risky(collection)
+    }
+
+    /// Iterates the entities stored in this collection.
+    #[inline]
+    fn iter(&self) -> <Self::Collection as RelationshipSourceCollection>::SourceIter<'_> {
+        self.collection().iter()
+    }
+
+    /// Returns the number of entities in this collection.
+    #[inline]
+    fn len(&self) -> usize {
+        self.collection().len()
+    }
+
+    /// Returns true if this entity collection is empty.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.collection().is_empty()
+    }
+}
+
+/// Command queued by [`Relationship::on_insert`] when `target` has no [`RelationshipTarget`] yet.
+/// It adds `source` to the [`RelationshipTarget`] of `target`, inserting the [`RelationshipTarget`] if it still does not exist.
+struct AddToRelationshipTarget<R: Relationship> {
+    source: Entity,
+    target: Entity,
+    _marker: PhantomData<fn() -> R>,
+}
+
+impl<R: Relationship> AddToRelationshipTarget<R> {
+    fn new(source: Entity, target: Entity) -> Self {
+        Self {
+            source,
+            target,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<R: Relationship> crate::world::Command for AddToRelationshipTarget<R> {
+    fn apply(self, world: &mut World) {
+        // The relationship may have been removed or re-targeted before this command was applied.
+        let Some(relationship) = world.get::<R>(self.source) else {
+            return;
+        };
+        if relationship.get() != self.target {
+            return;
+        }
+        let Ok(mut target) = world.get_entity_mut(self.target) else {
+            return;
+        };
+        if let Some(mut relationship_target) = target.get_mut::<R::RelationshipTarget>() {
+            // Another queued command may have already added this source.
+            if !relationship_target
+                .iter()
+                .any(|source| source == self.source)
+            {
+                relationship_target.collection_mut_risky().add(self.source);
+            }
+        } else {
+            let mut relationship_target =
+                <R::RelationshipTarget as RelationshipTarget>::with_capacity(1);
+            relationship_target.collection_mut_risky().add(self.source);
+            target.insert(relationship_target);
+        }
+    }
+}
+
+/// Command queued by [`Relationship::on_replace`] that removes the [`RelationshipTarget`] of `target`
+/// if it is still empty when the command is applied.
+struct RemoveEmptyRelationshipTarget<R: Relationship> {
+    target: Entity,
+    _marker: PhantomData<fn() -> R>,
+}
+
+impl<R: Relationship> RemoveEmptyRelationshipTarget<R> {
+    fn new(target: Entity) -> Self {
+        Self {
+            target,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<R: Relationship> crate::world::Command for RemoveEmptyRelationshipTarget<R> {
+    fn apply(self, world: &mut World) {
+        let Ok(mut target) = world.get_entity_mut(self.target) else {
+            return;
+        };
+        if target
+            .get::<R::RelationshipTarget>()
+            .is_some_and(RelationshipTarget::is_empty)
+        {
+            target.remove::<R::RelationshipTarget>();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate as bevy_ecs;
+    use crate::{
+        component::Component, entity::Entity, event::Event, observer::Trigger,
+        relationship::RelationshipTarget, system::ResMut, world::World,
+    };
+    use alloc::vec;
+
+    #[derive(Component, Debug, PartialEq, Eq)]
+    #[relationship(relationship_target = Likes)]
+    struct LikedBy(Entity);
+
+    #[derive(Component, Debug)]
+    #[relationship_target(relationship = LikedBy)]
+    struct Likes(Vec<Entity>);
+
+    fn sources(world: &World, target: Entity) -> Option<Vec<Entity>> {
+        world
+            .get::<Likes>(target)
+            .map(|likes| likes.iter().collect())
+    }
+
+    #[test]
+    fn insert_adds_to_target() {
+        let mut world = World::new();
+        let target = world.spawn_empty().id();
+        let a = world.spawn(LikedBy(target)).id();
+        let b = world.spawn(LikedBy(target)).id();
+        world.flush();
+
+        assert_eq!(sources(&world, target), Some(vec![a, b]));
+    }
+
+    #[test]
+    fn replace_moves_between_targets() {
+        let mut world = World::new();
+        let first = world.spawn_empty().id();
+        let second = world.spawn_empty().id();
+        let source = world.spawn(LikedBy(first)).id();
+        world.flush();
+
+        world.entity_mut(source).insert(LikedBy(second));
+        world.flush();
+
+        assert_eq!(sources(&world, first), None);
+        assert_eq!(sources(&world, second), Some(vec![source]));
+    }
+
+    #[test]
+    fn remove_and_despawn_source() {
+        let mut world = World::new();
+        let target = world.spawn_empty().id();
+        let a = world.spawn(LikedBy(target)).id();
+        let b = world.spawn(LikedBy(target)).id();
+        world.flush();
+
+        world.entity_mut(a).remove::<LikedBy>();
+        assert_eq!(sources(&world, target), Some(vec![b]));
+
+        world.despawn(b);
+        world.flush();
+        assert_eq!(sources(&world, target), None);
+    }
+
+    #[test]
+    fn despawn_target_removes_relationships() {
+        let mut world = World::new();
+        let target = world.spawn_empty().id();
+        let a = world.spawn(LikedBy(target)).id();
+        let b = world.spawn(LikedBy(target)).id();
+        world.flush();
+
+        world.despawn(target);
+        world.flush();
+
+        assert!(world.get::<LikedBy>(a).is_none());
+        assert!(world.get::<LikedBy>(b).is_none());
+    }
+
+    #[test]
+    fn invalid_relationships_are_removed() {
+        let mut world = World::new();
+        let missing = world.spawn_empty().id();
+        world.despawn(missing);
+
+        let a = world.spawn_empty().id();
+        world.entity_mut(a).insert(LikedBy(a));
+        world.flush();
+        assert!(world.get::<LikedBy>(a).is_none());
+
+        let b = world.spawn(LikedBy(missing)).id();
+        world.flush();
+        assert!(world.get::<LikedBy>(b).is_none());
+    }
+
+    #[test]
+    fn relationships_from_commands() {
+        let mut world = World::new();
+        let target = world.spawn_empty().id();
+
+        let mut commands = world.commands();
+        let a = commands.spawn(LikedBy(target)).id();
+        let b = commands.spawn(LikedBy(target)).id();
+        let c = commands.spawn(LikedBy(target)).id();
+        commands.entity(b).despawn();
+        world.flush();
+
+        assert_eq!(sources(&world, target), Some(vec![a, c]));
+    }
+
+    #[test]
+    fn relationship_traversal() {
+        #[derive(Component)]
+        #[relationship(relationship_target = Owns)]
+        struct OwnedBy(Entity);
+
+        #[derive(Component)]
+        #[relationship_target(relationship = OwnedBy)]
+        struct Owns(Vec<Entity>);
+
+        #[derive(Component)]
+        struct EventPropagating;
+
+        impl Event for EventPropagating {
+            type Traversal = &'static OwnedBy;
+            const AUTO_PROPAGATE: bool = true;
+        }
+
+        #[derive(crate::system::Resource, Default)]
+        struct Order(Vec<Entity>);
+
+        let mut world = World::new();
+        world.init_resource::<Order>();
+        let root = world.spawn_empty().id();
+        let middle = world.spawn(OwnedBy(root)).id();
+        let leaf = world.spawn(OwnedBy(middle)).id();
+        world.add_observer(
+            |trigger: Trigger<EventPropagating>, mut order: ResMut<Order>| {
+                order.0.push(trigger.target());
+            },
+        );
+        world.flush();
+
+        world.trigger_targets(EventPropagating, leaf);
+        world.flush();
+
+        assert_eq!(world.resource::<Order>().0, vec![leaf, middle, root]);
+    }
+}
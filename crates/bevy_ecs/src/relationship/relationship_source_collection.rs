@@ -0,0 +1,154 @@
+use crate::entity::{Entity, EntityHash, EntityHashSet};
+use alloc::vec::Vec;
+use bevy_utils::hashbrown;
+use smallvec::SmallVec;
+
+/// The internal [`Entity`] collection used by a [`RelationshipTarget`](crate::relationship::RelationshipTarget) component.
+/// This is not intended to be modified directly by users, as it could invalidate the correctness of relationships.
+pub trait RelationshipSourceCollection {
+    /// The type of iterator returned by the `iter` method.
+    type SourceIter<'a>: Iterator<Item = Entity>
+    where
+        Self: 'a;
+
+    /// Returns an instance with the given pre-allocated entity `capacity`.
+    fn with_capacity(capacity: usize) -> Self;
+
+    /// Adds the given `entity` to the collection.
+    fn add(&mut self, entity: Entity);
+
+    /// Removes the given `entity` from the collection.
+    fn remove(&mut self, entity: Entity);
+
+    /// Iterates all entities in the collection.
+    fn iter(&self) -> Self::SourceIter<'_>;
+
+    /// Returns the current length of the collection.
+    fn len(&self) -> usize;
+
+    /// Returns true if the collection contains no entities.
+    #[inline]
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl RelationshipSourceCollection for Vec<Entity> {
+    type SourceIter<'a> = core::iter::Copied<core::slice::Iter<'a, Entity>>;
+
+    fn with_capacity(capacity: usize) -> Self {
+        Vec::with_capacity(capacity)
+    }
+
+    fn add(&mut self, entity: Entity) {
+        Vec::push(self, entity);
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        if let Some(index) = <[Entity]>::iter(self).position(|e| *e == entity) {
+            Vec::remove(self, index);
+        }
+    }
+
+    fn iter(&self) -> Self::SourceIter<'_> {
+        <[Entity]>::iter(self).copied()
+    }
+
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+}
+
+impl<A: smallvec::Array<Item = Entity>> RelationshipSourceCollection for SmallVec<A> {
+    type SourceIter<'a>
+        = core::iter::Copied<core::slice::Iter<'a, Entity>>
+    where
+        A: 'a;
+
+    fn with_capacity(capacity: usize) -> Self {
+        SmallVec::with_capacity(capacity)
+    }
+
+    fn add(&mut self, entity: Entity) {
+        SmallVec::push(self, entity);
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        if let Some(index) = <[Entity]>::iter(self).position(|e| *e == entity) {
+            SmallVec::remove(self, index);
+        }
+    }
+
+    fn iter(&self) -> Self::SourceIter<'_> {
+        <[Entity]>::iter(self).copied()
+    }
+
+    fn len(&self) -> usize {
+        SmallVec::len(self)
+    }
+}
+
+impl RelationshipSourceCollection for EntityHashSet {
+    type SourceIter<'a> = core::iter::Copied<hashbrown::hash_set::Iter<'a, Entity>>;
+
+    fn with_capacity(capacity: usize) -> Self {
+        EntityHashSet::with_capacity_and_hasher(capacity, EntityHash)
+    }
+
+    fn add(&mut self, entity: Entity) {
+        hashbrown::HashSet::insert(self, entity);
+    }
+
+    fn remove(&mut self, entity: Entity) {
+        hashbrown::HashSet::remove(self, &entity);
+    }
+
+    fn iter(&self) -> Self::SourceIter<'_> {
+        hashbrown::HashSet::iter(self).copied()
+    }
+
+    fn len(&self) -> usize {
+        hashbrown::HashSet::len(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn vec_collection_preserves_order() {
+        let a = Entity::from_raw(1);
+        let b = Entity::from_raw(2);
+        let c = Entity::from_raw(3);
+
+        let mut collection = <Vec<Entity> as RelationshipSourceCollection>::with_capacity(3);
+        collection.add(a);
+        collection.add(b);
+        collection.add(c);
+        RelationshipSourceCollection::remove(&mut collection, b);
+
+        assert_eq!(
+            RelationshipSourceCollection::iter(&collection).collect::<Vec<_>>(),
+            vec![a, c]
+        );
+    }
+
+    #[test]
+    fn hash_set_collection() {
+        let a = Entity::from_raw(1);
+        let b = Entity::from_raw(2);
+
+        let mut collection = <EntityHashSet as RelationshipSourceCollection>::with_capacity(2);
+        collection.add(a);
+        collection.add(b);
+        collection.add(b);
+        assert_eq!(RelationshipSourceCollection::len(&collection), 2);
+
+        RelationshipSourceCollection::remove(&mut collection, a);
+        assert_eq!(
+            RelationshipSourceCollection::iter(&collection).collect::<Vec<_>>(),
+            vec![b]
+        );
+    }
+}
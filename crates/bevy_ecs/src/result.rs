@@ -1,7 +1,186 @@
 //! Contains error and result helpers for use in fallible systems.
+//!
+//! Systems, observers and commands may return [`Result`] instead of `()`. When they return an
+//! [`Error`], it is passed to the [`ErrorHandler`] stored in the [`DefaultErrorHandler`] resource of
+//! the [`World`] they run in, along with an [`ErrorContext`] describing what failed.
+//! If that resource does not exist, errors [`panic`].
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! use bevy_ecs::result::{warn, DefaultErrorHandler};
+//!
+//! #[derive(Component)]
+//! struct Player;
+//!
+//! #[derive(Component)]
+//! struct Health(u32);
+//!
+//! fn heal_player(mut query: Query<&mut Health, With<Player>>) -> Result {
+//!     let mut health = query.get_single_mut()?;
+//!     health.0 += 1;
+//!     Ok(())
+//! }
+//!
+//! let mut world = World::new();
+//! // Log a warning instead of panicking when a system fails.
+//! world.insert_resource(DefaultErrorHandler(warn));
+//!
+//! let mut schedule = Schedule::default();
+//! schedule.add_systems(heal_player);
+//! // There is no player yet, so `heal_player` returns an error which gets logged.
+//! schedule.run(&mut world);
+//! ```
+
+use alloc::borrow::Cow;
+use core::fmt;
+
+use crate::{self as bevy_ecs, component::Tick, system::Resource, world::World};
 
 /// A dynamic error type for use in fallible systems.
 pub type Error = Box<dyn core::error::Error + Send + Sync + 'static>;
 
 /// A result type for use in fallible systems.
 pub type Result<T = (), E = Error> = core::result::Result<T, E>;
+
+/// Additional information about the system, observer or command that returned an [`Error`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ErrorContext {
+    /// The error occurred in a system run by a [`Schedule`](crate::schedule::Schedule).
+    System {
+        /// The name of the system that failed.
+        name: Cow<'static, str>,
+        /// The last tick that the system was run.
+        last_run: Tick,
+    },
+    /// The error occurred in an [`Observer`](crate::observer::Observer).
+    Observer {
+        /// The name of the observer system that failed.
+        name: Cow<'static, str>,
+        /// The last tick that the observer system was run.
+        last_run: Tick,
+    },
+    /// The error occurred in a [`Command`](crate::world::Command).
+    Command {
+        /// The type name of the command that failed.
+        name: Cow<'static, str>,
+    },
+}
+
+impl ErrorContext {
+    /// The name of the system, observer or command that failed.
+    pub fn name(&self) -> &str {
+        match self {
+            Self::System { name, .. } | Self::Observer { name, .. } | Self::Command { name } => {
+                name
+            }
+        }
+    }
+
+    /// A string representation of the kind of this context, such as `"system"`.
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::System { .. } => "system",
+            Self::Observer { .. } => "observer",
+            Self::Command { .. } => "command",
+        }
+    }
+}
+
+impl fmt::Display for ErrorContext {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} `{}`", self.kind(), self.name())
+    }
+}
+
+/// A function that handles an [`Error`] returned by a system, observer or command.
+pub type ErrorHandler = fn(Error, ErrorContext);
+
+/// The [`ErrorHandler`] used for the systems, observers and commands that run in a [`World`].
+///
+/// When this resource is missing, errors [`panic`].
+#[derive(Resource, Clone, Copy)]
+pub struct DefaultErrorHandler(pub ErrorHandler);
+
+impl Default for DefaultErrorHandler {
+    fn default() -> Self {
+        Self(panic)
+    }
+}
+
+impl fmt::Debug for DefaultErrorHandler {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("DefaultErrorHandler(..)")
+    }
+}
+
+impl DefaultErrorHandler {
+    /// Returns the [`ErrorHandler`] stored in the given resource, or [`panic`] if there is none.
+    ///
+    /// Shared by every place that looks the resource up, whichever kind of world it has access to.
+    #[inline]
+    pub(crate) fn handler_or_default(resource: Option<&Self>) -> ErrorHandler {
+        resource.copied().unwrap_or_default().0
+    }
+}
+
+impl World {
+    /// Returns the [`ErrorHandler`] stored in the [`DefaultErrorHandler`] resource,
+    /// or [`panic`] if the resource does not exist.
+    #[inline]
+    pub fn default_error_handler(&self) -> ErrorHandler {
+        DefaultErrorHandler::handler_or_default(self.get_resource())
+    }
+}
+
+macro_rules! inner {
+    ($call:path, $e:ident, $c:ident) => {
+        $call!("Encountered an error in {}: {}", $c, $e);
+    };
+}
+
+/// Error handler that panics with the system name and the error.
+#[track_caller]
+#[inline]
+pub fn panic(error: Error, ctx: ErrorContext) {
+    inner!(panic, error, ctx);
+}
+
+/// Error handler that logs the system name and the error at the `error` level.
+#[track_caller]
+#[inline]
+pub fn error(error: Error, ctx: ErrorContext) {
+    inner!(bevy_utils::tracing::error, error, ctx);
+}
+
+/// Error handler that logs the system name and the error at the `warn` level.
+#[track_caller]
+#[inline]
+pub fn warn(error: Error, ctx: ErrorContext) {
+    inner!(bevy_utils::tracing::warn, error, ctx);
+}
+
+/// Error handler that logs the system name and the error at the `info` level.
+#[track_caller]
+#[inline]
+pub fn info(error: Error, ctx: ErrorContext) {
+    inner!(bevy_utils::tracing::info, error, ctx);
+}
+
+/// Error handler that logs the system name and the error at the `debug` level.
+#[track_caller]
+#[inline]
+pub fn debug(error: Error, ctx: ErrorContext) {
+    inner!(bevy_utils::tracing::debug, error, ctx);
+}
+
+/// Error handler that logs the system name and the error at the `trace` level.
+#[track_caller]
+#[inline]
+pub fn trace(error: Error, ctx: ErrorContext) {
+    inner!(bevy_utils::tracing::trace, error, ctx);
+}
+
+/// Error handler that ignores the error.
+#[track_caller]
+#[inline]
+pub fn ignore(_: Error, _: ErrorContext) {}
@@ -18,7 +18,8 @@ use crate::{
     component::{ComponentId, Tick},
     prelude::{IntoSystemSet, SystemSet},
     query::Access,
-    schedule::{BoxedCondition, InternedSystemSet, NodeId, SystemTypeSet},
+    result::ErrorHandler,
+    schedule::{BoxedCondition, InternedSystemSet, NodeId, SystemSpan, SystemTypeSet},
     system::{ScheduleSystem, System, SystemIn},
     world::{unsafe_world_cell::UnsafeWorldCell, DeferredWorld, World},
 };
@@ -32,8 +33,19 @@ pub(super) trait SystemExecutor: Send + Sync {
         schedule: &mut SystemSchedule,
         world: &mut World,
         skip_systems: Option<&FixedBitSet>,
+        error_handler: ErrorHandler,
     );
     fn set_apply_final_deferred(&mut self, value: bool);
+    /// Sets whether the executor records when each system ran.
+    ///
+    /// Executors that don't support profiling ignore this.
+    fn set_profiling(&mut self, _value: bool) {}
+    /// Returns when each system ran during the last run, indexed like [`SystemSchedule::systems`].
+    ///
+    /// Empty if profiling is disabled or unsupported.
+    fn take_system_spans(&mut self) -> Vec<Option<SystemSpan>> {
+        Vec::new()
+    }
 }
 
 /// Specifies how a [`Schedule`](super::Schedule) will be run.
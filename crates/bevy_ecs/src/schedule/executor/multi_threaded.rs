@@ -7,7 +7,7 @@ use bevy_tasks::{ComputeTaskPool, Scope, TaskPool, ThreadExecutor};
 use bevy_utils::tracing::info_span;
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::Span;
-use bevy_utils::{default, syncunsafecell::SyncUnsafeCell};
+use bevy_utils::{default, syncunsafecell::SyncUnsafeCell, Instant};
 use core::panic::AssertUnwindSafe;
 
 use concurrent_queue::ConcurrentQueue;
@@ -17,7 +17,10 @@ use crate::{
     archetype::ArchetypeComponentId,
     prelude::Resource,
     query::Access,
-    schedule::{is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule},
+    result::{ErrorContext, ErrorHandler},
+    schedule::{
+        is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule, SystemSpan,
+    },
     system::{ScheduleSystem, System},
     world::{unsafe_world_cell::UnsafeWorldCell, World},
 };
@@ -32,6 +35,7 @@ struct Environment<'env, 'sys> {
     systems: &'sys [SyncUnsafeCell<ScheduleSystem>],
     conditions: SyncUnsafeCell<Conditions<'sys>>,
     world_cell: UnsafeWorldCell<'env>,
+    error_handler: ErrorHandler,
 }
 
 struct Conditions<'a> {
@@ -46,6 +50,7 @@ impl<'env, 'sys> Environment<'env, 'sys> {
         executor: &'env MultiThreadedExecutor,
         schedule: &'sys mut SystemSchedule,
         world: &'env mut World,
+        error_handler: ErrorHandler,
     ) -> Self {
         Environment {
             executor,
@@ -57,6 +62,7 @@ impl<'env, 'sys> Environment<'env, 'sys> {
                 systems_in_sets_with_conditions: &schedule.systems_in_sets_with_conditions,
             }),
             world_cell: world.as_unsafe_world_cell(),
+            error_handler,
         }
     }
 }
@@ -77,6 +83,8 @@ struct SystemTaskMetadata {
 /// The result of running a system that is sent across a channel.
 struct SystemResult {
     system_index: usize,
+    /// When the system ran, if profiling.
+    span: Option<SystemSpan>,
 }
 
 /// Runs the schedule using a thread pool. Non-conflicting systems can run in parallel.
@@ -87,6 +95,8 @@ pub struct MultiThreadedExecutor {
     system_completion: ConcurrentQueue<SystemResult>,
     /// Setting when true applies deferred system buffers after all systems have run
     apply_final_deferred: bool,
+    /// Setting when true records when each system ran in [`ExecutorState::system_spans`].
+    profiling: bool,
     /// When set, tells the executor that a thread has panicked.
     panic_payload: Mutex<Option<Box<dyn Any + Send>>>,
     starting_systems: FixedBitSet,
@@ -123,6 +133,8 @@ pub struct ExecutorState {
     completed_systems: FixedBitSet,
     /// Systems that have run but have not had their buffers applied.
     unapplied_systems: FixedBitSet,
+    /// When each system ran, if profiling.
+    system_spans: Vec<Option<SystemSpan>>,
 }
 
 /// References to data required by the executor.
@@ -183,6 +195,7 @@ impl SystemExecutor for MultiThreadedExecutor {
         schedule: &mut SystemSchedule,
         world: &mut World,
         _skip_systems: Option<&FixedBitSet>,
+        error_handler: ErrorHandler,
     ) {
         let state = self.state.get_mut().unwrap();
         // reset counts
@@ -194,6 +207,10 @@ impl SystemExecutor for MultiThreadedExecutor {
             .num_dependencies_remaining
             .clone_from(&schedule.system_dependencies);
         state.ready_systems.clone_from(&self.starting_systems);
+        if self.profiling {
+            state.system_spans.clear();
+            state.system_spans.resize(schedule.systems.len(), None);
+        }
 
         // If stepping is enabled, make sure we skip those systems that should
         // not be run.
@@ -216,7 +233,7 @@ impl SystemExecutor for MultiThreadedExecutor {
             .map(|e| e.0.clone());
         let thread_executor = thread_executor.as_deref();
 
-        let environment = &Environment::new(self, schedule, world);
+        let environment = &Environment::new(self, schedule, world, error_handler);
 
         ComputeTaskPool::get_or_init(TaskPool::default).scope_with_executor(
             false,
@@ -262,20 +279,30 @@ impl SystemExecutor for MultiThreadedExecutor {
     fn set_apply_final_deferred(&mut self, value: bool) {
         self.apply_final_deferred = value;
     }
+
+    fn set_profiling(&mut self, value: bool) {
+        self.profiling = value;
+    }
+
+    fn take_system_spans(&mut self) -> Vec<Option<SystemSpan>> {
+        core::mem::take(&mut self.state.get_mut().unwrap().system_spans)
+    }
 }
 
 impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
     fn system_completed(
         &self,
         system_index: usize,
+        start: Option<Instant>,
         res: Result<(), Box<dyn Any + Send>>,
         system: &ScheduleSystem,
     ) {
+        let span = start.map(SystemSpan::until_now);
         // tell the executor that the system finished
         self.environment
             .executor
             .system_completion
-            .push(SystemResult { system_index })
+            .push(SystemResult { system_index, span })
             .unwrap_or_else(|error| unreachable!("{}", error));
         if let Err(payload) = res {
             eprintln!("Encountered a panic in system `{}`!", &*system.name());
@@ -288,6 +315,11 @@ impl<'scope, 'env: 'scope, 'sys> Context<'scope, 'env, 'sys> {
         self.tick_executor();
     }
 
+    /// Returns the current time if the executor is profiling.
+    fn start_profiling(&self) -> Option<Instant> {
+        self.environment.executor.profiling.then(Instant::now)
+    }
+
     fn try_lock<'a>(&'a self) -> Option<(&'a mut Conditions<'sys>, MutexGuard<'a, ExecutorState>)> {
         let guard = self.environment.executor.state.try_lock().ok()?;
         // SAFETY: This is an exclusive access as no other location fetches conditions mutably, and
@@ -326,6 +358,7 @@ impl MultiThreadedExecutor {
             system_completion: ConcurrentQueue::unbounded(),
             starting_systems: FixedBitSet::new(),
             apply_final_deferred: true,
+            profiling: false,
             panic_payload: Mutex::new(None),
             #[cfg(feature = "trace")]
             executor_span: info_span!("multithreaded executor"),
@@ -349,6 +382,7 @@ impl ExecutorState {
             skipped_systems: FixedBitSet::new(),
             completed_systems: FixedBitSet::new(),
             unapplied_systems: FixedBitSet::new(),
+            system_spans: Vec::new(),
         }
     }
 
@@ -597,20 +631,26 @@ impl ExecutorState {
         let system_meta = &self.system_task_metadata[system_index];
 
         let task = async move {
+            let start = context.start_profiling();
             let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
                 // SAFETY:
                 // - The caller ensures that we have permission to
                 // access the world data used by the system.
                 // - `update_archetype_component_access` has been called.
-                unsafe {
-                    // TODO: implement an error-handling API instead of suppressing a possible failure.
-                    let _ = __rust_begin_short_backtrace::run_unsafe(
-                        system,
-                        context.environment.world_cell,
-                    );
+                let result = unsafe {
+                    __rust_begin_short_backtrace::run_unsafe(system, context.environment.world_cell)
                 };
+                if let Err(err) = result {
+                    (context.environment.error_handler)(
+                        err,
+                        ErrorContext::System {
+                            name: system.name(),
+                            last_run: system.get_last_run(),
+                        },
+                    );
+                }
             }));
-            context.system_completed(system_index, res, system);
+            context.system_completed(system_index, start, res, system);
         };
 
         self.active_access
@@ -637,24 +677,33 @@ impl ExecutorState {
             let unapplied_systems = self.unapplied_systems.clone();
             self.unapplied_systems.clear();
             let task = async move {
+                let start = context.start_profiling();
                 // SAFETY: `can_run` returned true for this system, which means
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
                 let res = apply_deferred(&unapplied_systems, context.environment.systems, world);
-                context.system_completed(system_index, res, system);
+                context.system_completed(system_index, start, res, system);
             };
 
             context.scope.spawn_on_scope(task);
         } else {
             let task = async move {
+                let start = context.start_profiling();
                 // SAFETY: `can_run` returned true for this system, which means
                 // that no other systems currently have access to the world.
                 let world = unsafe { context.environment.world_cell.world_mut() };
                 let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
-                    // TODO: implement an error-handling API instead of suppressing a possible failure.
-                    let _ = __rust_begin_short_backtrace::run(system, world);
+                    if let Err(err) = __rust_begin_short_backtrace::run(system, world) {
+                        (context.environment.error_handler)(
+                            err,
+                            ErrorContext::System {
+                                name: system.name(),
+                                last_run: system.get_last_run(),
+                            },
+                        );
+                    }
                 }));
-                context.system_completed(system_index, res, system);
+                context.system_completed(system_index, start, res, system);
             };
 
             context.scope.spawn_on_scope(task);
@@ -665,7 +714,7 @@ impl ExecutorState {
     }
 
     fn finish_system_and_handle_dependents(&mut self, result: SystemResult) {
-        let SystemResult { system_index, .. } = result;
+        let SystemResult { system_index, span } = result;
 
         if self.system_task_metadata[system_index].is_exclusive {
             self.exclusive_running = false;
@@ -680,6 +729,9 @@ impl ExecutorState {
         self.running_systems.remove(system_index);
         self.completed_systems.insert(system_index);
         self.unapplied_systems.insert(system_index);
+        if span.is_some() {
+            self.system_spans[system_index] = span;
+        }
 
         self.signal_dependents(system_index);
     }
@@ -1,10 +1,14 @@
 #[cfg(feature = "trace")]
 use bevy_utils::tracing::info_span;
+use bevy_utils::Instant;
 use core::panic::AssertUnwindSafe;
 use fixedbitset::FixedBitSet;
 
 use crate::{
-    schedule::{is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule},
+    result::{ErrorContext, ErrorHandler},
+    schedule::{
+        is_apply_deferred, BoxedCondition, ExecutorKind, SystemExecutor, SystemSchedule, SystemSpan,
+    },
     system::System,
     world::World,
 };
@@ -25,6 +29,10 @@ pub struct SingleThreadedExecutor {
     unapplied_systems: FixedBitSet,
     /// Setting when true applies deferred system buffers after all systems have run
     apply_final_deferred: bool,
+    /// Setting when true records when each system ran in `system_spans`.
+    profiling: bool,
+    /// When each system ran during the last run, if profiling.
+    system_spans: Vec<Option<SystemSpan>>,
 }
 
 impl SystemExecutor for SingleThreadedExecutor {
@@ -46,6 +54,7 @@ impl SystemExecutor for SingleThreadedExecutor {
         schedule: &mut SystemSchedule,
         world: &mut World,
         _skip_systems: Option<&FixedBitSet>,
+        error_handler: ErrorHandler,
     ) {
         // If stepping is enabled, make sure we skip those systems that should
         // not be run.
@@ -55,6 +64,11 @@ impl SystemExecutor for SingleThreadedExecutor {
             self.completed_systems |= skipped_systems;
         }
 
+        if self.profiling {
+            self.system_spans.clear();
+            self.system_spans.resize(schedule.systems.len(), None);
+        }
+
         for system_index in 0..schedule.systems.len() {
             #[cfg(feature = "trace")]
             let name = schedule.systems[system_index].name();
@@ -102,25 +116,33 @@ impl SystemExecutor for SingleThreadedExecutor {
                 continue;
             }
 
+            let start = self.profiling.then(Instant::now);
+
             if is_apply_deferred(system) {
                 self.apply_deferred(schedule, world);
+                self.record_span(system_index, start);
                 continue;
             }
 
             let res = std::panic::catch_unwind(AssertUnwindSafe(|| {
-                if system.is_exclusive() {
-                    // TODO: implement an error-handling API instead of suppressing a possible failure.
-                    let _ = __rust_begin_short_backtrace::run(system, world);
+                let result = if system.is_exclusive() {
+                    __rust_begin_short_backtrace::run(system, world)
                 } else {
                     // Use run_unsafe to avoid immediately applying deferred buffers
                     let world = world.as_unsafe_world_cell();
                     system.update_archetype_component_access(world);
                     // SAFETY: We have exclusive, single-threaded access to the world and
                     // update_archetype_component_access is being called immediately before this.
-                    unsafe {
-                        // TODO: implement an error-handling API instead of suppressing a possible failure.
-                        let _ = __rust_begin_short_backtrace::run_unsafe(system, world);
-                    };
+                    unsafe { __rust_begin_short_backtrace::run_unsafe(system, world) }
+                };
+                if let Err(err) = result {
+                    error_handler(
+                        err,
+                        ErrorContext::System {
+                            name: system.name(),
+                            last_run: system.get_last_run(),
+                        },
+                    );
                 }
             }));
             if let Err(payload) = res {
@@ -128,6 +150,7 @@ impl SystemExecutor for SingleThreadedExecutor {
                 std::panic::resume_unwind(payload);
             }
             self.unapplied_systems.insert(system_index);
+            self.record_span(system_index, start);
         }
 
         if self.apply_final_deferred {
@@ -140,6 +163,14 @@ impl SystemExecutor for SingleThreadedExecutor {
     fn set_apply_final_deferred(&mut self, apply_final_deferred: bool) {
         self.apply_final_deferred = apply_final_deferred;
     }
+
+    fn set_profiling(&mut self, profiling: bool) {
+        self.profiling = profiling;
+    }
+
+    fn take_system_spans(&mut self) -> Vec<Option<SystemSpan>> {
+        core::mem::take(&mut self.system_spans)
+    }
 }
 
 impl SingleThreadedExecutor {
@@ -152,6 +183,14 @@ impl SingleThreadedExecutor {
             completed_systems: FixedBitSet::new(),
             unapplied_systems: FixedBitSet::new(),
             apply_final_deferred: true,
+            profiling: false,
+            system_spans: Vec::new(),
+        }
+    }
+
+    fn record_span(&mut self, system_index: usize, start: Option<Instant>) {
+        if let Some(start) = start {
+            self.system_spans[system_index] = Some(SystemSpan::until_now(start));
         }
     }
 
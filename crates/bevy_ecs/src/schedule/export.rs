@@ -0,0 +1,471 @@
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::Write;
+
+use bevy_utils::{HashMap, HashSet};
+use disqualified::ShortName;
+
+use crate::{
+    schedule::{NodeId, Schedule, ScheduleNotInitialized},
+    system::System,
+};
+
+use super::graph::Direction::Incoming;
+
+/// The text format to export a [`Schedule`] graph to, with [`Schedule::export_graph`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ScheduleGraphFormat {
+    /// The [Graphviz](https://graphviz.org) DOT language.
+    #[default]
+    Dot,
+    /// A [Mermaid](https://mermaid.js.org) flowchart.
+    Mermaid,
+}
+
+/// Options for [`Schedule::export_graph`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScheduleExportSettings {
+    /// If set to true, the outermost named system sets are drawn as single nodes, instead of
+    /// clusters containing their systems and nested sets.
+    ///
+    /// Defaults to `false`.
+    pub collapse_sets: bool,
+    /// If set to true, pairs of systems with conflicting access and no order between them are
+    /// connected by a dashed edge.
+    ///
+    /// Defaults to `true`.
+    pub show_ambiguities: bool,
+    /// If set to true, systems and system sets with run conditions are highlighted.
+    ///
+    /// Defaults to `true`.
+    pub color_conditions: bool,
+    /// If set to true, node names will be shortened instead of the fully qualified type path.
+    ///
+    /// Defaults to `true`.
+    pub use_shortnames: bool,
+}
+
+impl Default for ScheduleExportSettings {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ScheduleExportSettings {
+    /// Default export settings.
+    /// See the field-level documentation for the default value of each field.
+    pub const fn new() -> Self {
+        Self {
+            collapse_sets: false,
+            show_ambiguities: true,
+            color_conditions: true,
+            use_shortnames: true,
+        }
+    }
+}
+
+impl Schedule {
+    /// Returns the graph of the systems of this schedule in the given text `format`, so that it
+    /// can be rendered by external tools.
+    ///
+    /// Systems are connected in the order they run in, and are grouped in clusters by their
+    /// system sets.
+    ///
+    /// Note: this method will return [`ScheduleNotInitialized`] if the
+    /// schedule has never been initialized or run.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_ecs::schedule::{ScheduleExportSettings, ScheduleGraphFormat};
+    /// fn a() {}
+    /// fn b() {}
+    ///
+    /// let mut world = World::new();
+    /// let mut schedule = Schedule::default();
+    /// schedule.add_systems((a, b).chain());
+    /// schedule.initialize(&mut world).unwrap();
+    ///
+    /// let dot = schedule
+    ///     .export_graph(ScheduleGraphFormat::Dot, &ScheduleExportSettings::default())
+    ///     .unwrap();
+    /// assert!(dot.starts_with("digraph"));
+    /// ```
+    pub fn export_graph(
+        &self,
+        format: ScheduleGraphFormat,
+        settings: &ScheduleExportSettings,
+    ) -> Result<String, ScheduleNotInitialized> {
+        let graph = ExportGraph::new(self, settings)?;
+        let title = format!("{:?}", self.label());
+        Ok(match format {
+            ScheduleGraphFormat::Dot => graph.to_dot(&title),
+            ScheduleGraphFormat::Mermaid => graph.to_mermaid(),
+        })
+    }
+}
+
+/// A node of an exported graph: either a system, or a system set drawn as a cluster or, if
+/// collapsed, as a node.
+struct ExportNode {
+    id: String,
+    label: String,
+    parent: Option<String>,
+    conditional: bool,
+}
+
+/// The parts of a [`Schedule`] that are exported, independent of the format.
+struct ExportGraph {
+    clusters: Vec<ExportNode>,
+    nodes: Vec<ExportNode>,
+    edges: Vec<(String, String)>,
+    ambiguities: Vec<(String, String)>,
+}
+
+impl ExportGraph {
+    fn new(
+        schedule: &Schedule,
+        settings: &ScheduleExportSettings,
+    ) -> Result<Self, ScheduleNotInitialized> {
+        let graph = schedule.graph();
+        let executable = schedule.executable();
+        let hierarchy = graph.hierarchy().graph();
+        let name = |name: String| {
+            if settings.use_shortnames {
+                ShortName(&name).to_string()
+            } else {
+                name
+            }
+        };
+        let id = |node: NodeId| match node {
+            NodeId::System(index) => format!("system_{index}"),
+            NodeId::Set(index) => format!("set_{index}"),
+        };
+        let is_named_set = |node: NodeId| {
+            graph
+                .get_set_at(node)
+                .is_some_and(|set| set.system_type().is_none() && !set.is_anonymous())
+        };
+        // Anonymous and system type sets are not drawn, so their members belong to their parents.
+        let parent = |node: NodeId| {
+            let mut stack = Vec::from([node]);
+            let mut visited = HashSet::<NodeId>::default();
+            while let Some(node) = stack.pop() {
+                for parent in hierarchy.neighbors_directed(node, Incoming) {
+                    if is_named_set(parent) {
+                        return Some(parent);
+                    }
+                    if visited.insert(parent) {
+                        stack.push(parent);
+                    }
+                }
+            }
+            None
+        };
+        // The outermost named set containing the node, or the node itself.
+        let root = |mut node: NodeId| {
+            while let Some(parent) = parent(node) {
+                node = parent;
+            }
+            node
+        };
+
+        let conditional_sets = executable
+            .set_ids
+            .iter()
+            .zip(&executable.set_conditions)
+            .filter(|(_, conditions)| settings.color_conditions && !conditions.is_empty())
+            .map(|(&set, _)| set)
+            .collect::<Vec<_>>();
+
+        let mut export = ExportGraph {
+            clusters: Vec::new(),
+            nodes: Vec::new(),
+            edges: Vec::new(),
+            ambiguities: Vec::new(),
+        };
+
+        let mut exported = HashMap::<NodeId, NodeId>::default();
+        for (index, (node, system)) in schedule.systems()?.enumerate() {
+            let exported_node = if settings.collapse_sets {
+                root(node)
+            } else {
+                node
+            };
+            exported.insert(node, exported_node);
+            if exported_node != node {
+                continue;
+            }
+            // Conditions of anonymous sets are drawn on their systems, since the sets aren't drawn.
+            let conditional = !executable.system_conditions[index].is_empty()
+                || executable.sets_with_conditions_of_systems[index]
+                    .ones()
+                    .any(|set| !is_named_set(executable.set_ids[set]));
+            export.nodes.push(ExportNode {
+                id: id(node),
+                label: name(system.name().into()),
+                parent: parent(node).map(id),
+                conditional: settings.color_conditions && conditional,
+            });
+        }
+
+        // `system_sets` iterates in an unspecified order.
+        let mut sets = graph
+            .system_sets()
+            .filter(|&(set_node, ..)| is_named_set(set_node))
+            .collect::<Vec<_>>();
+        sets.sort_by_key(|(set_node, ..)| set_node.index());
+        for (set_node, set, _) in sets {
+            let set_node_export = ExportNode {
+                id: id(set_node),
+                label: name(format!("{set:?}")),
+                parent: parent(set_node).map(id),
+                conditional: conditional_sets.contains(&set_node),
+            };
+            if !settings.collapse_sets {
+                export.clusters.push(set_node_export);
+            } else if parent(set_node).is_none() {
+                export.nodes.push(set_node_export);
+            }
+        }
+
+        let mut edges = HashSet::<(NodeId, NodeId)>::default();
+        for (index, dependents) in executable.system_dependents.iter().enumerate() {
+            let from = exported[&executable.system_ids[index]];
+            for &dependent in dependents {
+                let to = exported[&executable.system_ids[dependent]];
+                if from != to && edges.insert((from, to)) {
+                    export.edges.push((id(from), id(to)));
+                }
+            }
+        }
+
+        if settings.show_ambiguities {
+            let mut ambiguities = HashSet::<(NodeId, NodeId)>::default();
+            for &(a, b, _) in graph.conflicting_systems() {
+                let (a, b) = (exported[&a], exported[&b]);
+                if a != b && ambiguities.insert((a.min(b), a.max(b))) {
+                    export.ambiguities.push((id(a), id(b)));
+                }
+            }
+        }
+
+        Ok(export)
+    }
+
+    fn to_dot(&self, title: &str) -> String {
+        let mut dot = String::new();
+        let _ = writeln!(dot, "digraph {} {{", dot_string(title));
+        dot.push_str("\trankdir=LR;\n");
+        dot.push_str("\tnode [shape=box, style=\"rounded,filled\", fillcolor=white];\n");
+        self.write_dot_children(&mut dot, None, 1);
+        for (from, to) in &self.edges {
+            let _ = writeln!(dot, "\t{from} -> {to};");
+        }
+        for (a, b) in &self.ambiguities {
+            let _ = writeln!(
+                dot,
+                "\t{a} -> {b} [dir=none, style=dashed, color=red, constraint=false];"
+            );
+        }
+        dot.push_str("}\n");
+        dot
+    }
+
+    fn write_dot_children(&self, dot: &mut String, parent: Option<&str>, depth: usize) {
+        let indent = "\t".repeat(depth);
+        for cluster in self.children(&self.clusters, parent) {
+            let _ = writeln!(dot, "{indent}subgraph cluster_{} {{", cluster.id);
+            let _ = writeln!(dot, "{indent}\tlabel={};", dot_string(&cluster.label));
+            if cluster.conditional {
+                let _ = writeln!(dot, "{indent}\tstyle=filled;");
+                let _ = writeln!(dot, "{indent}\tfillcolor={};", dot_string(CONDITIONAL_SET));
+            }
+            self.write_dot_children(dot, Some(&cluster.id), depth + 1);
+            let _ = writeln!(dot, "{indent}}}");
+        }
+        for node in self.children(&self.nodes, parent) {
+            let _ = write!(
+                dot,
+                "{indent}{} [label={}",
+                node.id,
+                dot_string(&node.label)
+            );
+            if node.conditional {
+                let _ = write!(dot, ", fillcolor={}", dot_string(CONDITIONAL_SYSTEM));
+            }
+            dot.push_str("];\n");
+        }
+    }
+
+    fn to_mermaid(&self) -> String {
+        let mut mermaid = String::from("flowchart LR\n");
+        self.write_mermaid_children(&mut mermaid, None, 1);
+        for (from, to) in &self.edges {
+            let _ = writeln!(mermaid, "\t{from} --> {to}");
+        }
+        for (a, b) in &self.ambiguities {
+            let _ = writeln!(mermaid, "\t{a} -.-|ambiguous| {b}");
+        }
+        let _ = writeln!(
+            mermaid,
+            "\tclassDef conditionalSystem fill:{CONDITIONAL_SYSTEM}"
+        );
+        let _ = writeln!(mermaid, "\tclassDef conditionalSet fill:{CONDITIONAL_SET}");
+        for node in &self.nodes {
+            if node.conditional {
+                let class = if node.id.starts_with("set_") {
+                    "conditionalSet"
+                } else {
+                    "conditionalSystem"
+                };
+                let _ = writeln!(mermaid, "\tclass {} {class}", node.id);
+            }
+        }
+        for cluster in &self.clusters {
+            if cluster.conditional {
+                let _ = writeln!(mermaid, "\tclass {} conditionalSet", cluster.id);
+            }
+        }
+        mermaid
+    }
+
+    fn write_mermaid_children(&self, mermaid: &mut String, parent: Option<&str>, depth: usize) {
+        let indent = "\t".repeat(depth);
+        for cluster in self.children(&self.clusters, parent) {
+            let _ = writeln!(
+                mermaid,
+                "{indent}subgraph {} [\"{}\"]",
+                cluster.id,
+                mermaid_string(&cluster.label)
+            );
+            self.write_mermaid_children(mermaid, Some(&cluster.id), depth + 1);
+            let _ = writeln!(mermaid, "{indent}end");
+        }
+        for node in self.children(&self.nodes, parent) {
+            let _ = writeln!(
+                mermaid,
+                "{indent}{}[\"{}\"]",
+                node.id,
+                mermaid_string(&node.label)
+            );
+        }
+    }
+
+    fn children<'a>(
+        &self,
+        nodes: &'a [ExportNode],
+        parent: Option<&'a str>,
+    ) -> impl Iterator<Item = &'a ExportNode> {
+        nodes
+            .iter()
+            .filter(move |node| node.parent.as_deref() == parent)
+    }
+}
+
+const CONDITIONAL_SYSTEM: &str = "#ffd8a8";
+const CONDITIONAL_SET: &str = "#fff3bf";
+
+fn dot_string(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
+
+fn mermaid_string(value: &str) -> String {
+    value
+        .replace('"', "#quot;")
+        .replace('<', "#lt;")
+        .replace('>', "#gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        self as bevy_ecs,
+        prelude::*,
+        schedule::{ScheduleExportSettings, ScheduleGraphFormat},
+    };
+
+    #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    struct Physics;
+
+    #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    struct Collisions;
+
+    #[derive(Resource)]
+    struct Counter;
+
+    fn input() {}
+    fn integrate() {}
+    fn detect(_: ResMut<Counter>) {}
+    fn respond(_: ResMut<Counter>) {}
+
+    fn schedule() -> Schedule {
+        let mut world = World::new();
+        let mut schedule = Schedule::default();
+        schedule
+            .configure_sets(Collisions.in_set(Physics).run_if(|| true))
+            .add_systems((
+                input.before(Physics),
+                integrate.in_set(Physics),
+                (detect, respond).in_set(Collisions),
+            ));
+        schedule.initialize(&mut world).unwrap();
+        schedule
+    }
+
+    #[test]
+    fn export_dot() {
+        let dot = schedule()
+            .export_graph(ScheduleGraphFormat::Dot, &ScheduleExportSettings::default())
+            .unwrap();
+        assert!(dot.starts_with("digraph \"DefaultSchedule\" {"));
+        assert!(dot.contains("subgraph cluster_set_"));
+        assert!(dot.contains("label=\"Physics\";"));
+        assert!(dot.contains("fillcolor=\"#fff3bf\";"));
+        assert!(dot.contains("[label=\"input\"]"));
+        assert!(dot.contains("dir=none, style=dashed"));
+        assert!(dot.ends_with("}\n"));
+    }
+
+    #[test]
+    fn export_mermaid() {
+        let mermaid = schedule()
+            .export_graph(
+                ScheduleGraphFormat::Mermaid,
+                &ScheduleExportSettings {
+                    show_ambiguities: false,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert!(mermaid.starts_with("flowchart LR\n"));
+        assert!(mermaid.contains("[\"Collisions\"]"));
+        assert!(mermaid.contains("[\"integrate\"]"));
+        assert!(mermaid.contains(" --> "));
+        assert!(!mermaid.contains("ambiguous"));
+    }
+
+    #[test]
+    fn export_collapsed_sets() {
+        let dot = schedule()
+            .export_graph(
+                ScheduleGraphFormat::Dot,
+                &ScheduleExportSettings {
+                    collapse_sets: true,
+                    ..Default::default()
+                },
+            )
+            .unwrap();
+        assert!(!dot.contains("subgraph"));
+        assert!(!dot.contains("integrate"));
+        assert!(!dot.contains("Collisions"));
+        assert!(dot.contains("[label=\"Physics\"]"));
+        assert!(!dot.contains("dir=none"));
+        assert_eq!(dot.matches(" -> ").count(), 1);
+    }
+
+    #[test]
+    fn export_uninitialized() {
+        assert!(Schedule::default()
+            .export_graph(ScheduleGraphFormat::Dot, &ScheduleExportSettings::default())
+            .is_err());
+    }
+}
@@ -3,14 +3,16 @@
 mod condition;
 mod config;
 mod executor;
+mod export;
 mod graph;
+mod profiler;
 #[allow(clippy::module_inception)]
 mod schedule;
 mod set;
 mod stepping;
 
 use self::graph::*;
-pub use self::{condition::*, config::*, executor::*, schedule::*, set::*};
+pub use self::{condition::*, config::*, executor::*, export::*, profiler::*, schedule::*, set::*};
 
 pub use self::graph::NodeId;
 
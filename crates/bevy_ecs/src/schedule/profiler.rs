@@ -0,0 +1,417 @@
+use alloc::{borrow::Cow, collections::VecDeque, string::String, vec::Vec};
+use std::thread::ThreadId;
+
+use bevy_utils::{Duration, Instant};
+use fixedbitset::FixedBitSet;
+
+use crate::{
+    self as bevy_ecs,
+    schedule::{InternedScheduleLabel, SystemSchedule},
+    system::{Resource, System},
+};
+
+/// Records how long every system takes to run, for every [`Schedule`](super::Schedule) run while
+/// this resource exists in the [`World`](crate::world::World).
+///
+/// After each run, a [`ScheduleProfile`] is added to the resource. The profiles are kept until
+/// they're [drained](Self::drain), so whoever inserts this resource should drain it regularly.
+/// At most [`max_profiles`](Self::max_profiles) profiles are kept: once it is reached, the oldest
+/// profile is dropped for every new one.
+///
+/// Systems are only timed by the [`SingleThreaded`](super::ExecutorKind::SingleThreaded) and
+/// [`MultiThreaded`](super::ExecutorKind::MultiThreaded) executors.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::schedule::ScheduleProfiler;
+/// fn my_system() {}
+///
+/// let mut world = World::new();
+/// let mut schedule = Schedule::default();
+/// schedule.add_systems(my_system);
+///
+/// world.init_resource::<ScheduleProfiler>();
+/// schedule.run(&mut world);
+///
+/// let profile = world.resource_mut::<ScheduleProfiler>().drain().next().unwrap();
+/// assert!(profile.systems()[0].name().ends_with("my_system"));
+/// assert!(profile.systems()[0].span().is_some());
+/// ```
+#[derive(Resource, Debug)]
+pub struct ScheduleProfiler {
+    profiles: VecDeque<ScheduleProfile>,
+    max_profiles: usize,
+}
+
+impl Default for ScheduleProfiler {
+    fn default() -> Self {
+        Self::with_max_profiles(Self::DEFAULT_MAX_PROFILES)
+    }
+}
+
+impl ScheduleProfiler {
+    /// The number of profiles kept by a [default](Self::default) profiler.
+    pub const DEFAULT_MAX_PROFILES: usize = 1024;
+
+    /// Creates a profiler that keeps at most `max_profiles` profiles between two
+    /// [drains](Self::drain).
+    pub fn with_max_profiles(max_profiles: usize) -> Self {
+        Self {
+            profiles: VecDeque::new(),
+            max_profiles,
+        }
+    }
+
+    /// Returns the maximum number of profiles kept between two [drains](Self::drain).
+    pub fn max_profiles(&self) -> usize {
+        self.max_profiles
+    }
+
+    /// Returns the profiles that have been recorded since the last [`drain`](Self::drain), in
+    /// the order the schedule runs finished.
+    pub fn profiles(&self) -> impl ExactSizeIterator<Item = &ScheduleProfile> {
+        self.profiles.iter()
+    }
+
+    /// Removes and returns the recorded profiles, in the order the schedule runs finished.
+    pub fn drain(&mut self) -> impl Iterator<Item = ScheduleProfile> + '_ {
+        self.profiles.drain(..)
+    }
+
+    pub(super) fn push(&mut self, profile: ScheduleProfile) {
+        if self.max_profiles == 0 {
+            return;
+        }
+        if self.profiles.len() == self.max_profiles {
+            self.profiles.pop_front();
+        }
+        self.profiles.push_back(profile);
+    }
+}
+
+/// The wall time during which a system ran.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SystemSpan {
+    /// When the system started running.
+    pub start: Instant,
+    /// When the system finished running.
+    pub end: Instant,
+    /// The thread the system ran on.
+    pub thread: ThreadId,
+}
+
+impl SystemSpan {
+    /// Creates a span that started at `start` and ends now, on the current thread.
+    pub(super) fn until_now(start: Instant) -> Self {
+        Self {
+            start,
+            end: Instant::now(),
+            thread: std::thread::current().id(),
+        }
+    }
+
+    /// Returns how long the system ran.
+    pub fn duration(&self) -> Duration {
+        self.end.saturating_duration_since(self.start)
+    }
+}
+
+/// The timings of a single run of a [`Schedule`](super::Schedule), recorded by the
+/// [`ScheduleProfiler`].
+#[derive(Debug, Clone)]
+pub struct ScheduleProfile {
+    label: InternedScheduleLabel,
+    span: SystemSpan,
+    systems: Vec<SystemProfile>,
+    sets: Vec<SetProfile>,
+    critical_path: Vec<usize>,
+}
+
+impl ScheduleProfile {
+    pub(super) fn new(
+        label: InternedScheduleLabel,
+        span: SystemSpan,
+        schedule: &SystemSchedule,
+        sets: &[(String, FixedBitSet)],
+        mut spans: Vec<Option<SystemSpan>>,
+    ) -> Self {
+        spans.resize(schedule.systems.len(), None);
+        let systems = schedule
+            .systems
+            .iter()
+            .zip(spans)
+            .map(|(system, span)| SystemProfile {
+                name: system.name(),
+                span,
+            })
+            .collect::<Vec<_>>();
+
+        let sets = sets
+            .iter()
+            .filter_map(|(name, set_systems)| {
+                let mut spans = set_systems
+                    .ones()
+                    .filter_map(|index| systems.get(index)?.span)
+                    .peekable();
+                let first = *spans.peek()?;
+                let mut set = SetProfile {
+                    name: name.clone(),
+                    start: first.start,
+                    end: first.end,
+                    busy: Duration::ZERO,
+                };
+                for span in spans {
+                    set.start = set.start.min(span.start);
+                    set.end = set.end.max(span.end);
+                    set.busy += span.duration();
+                }
+                Some(set)
+            })
+            .collect();
+
+        let critical_path = critical_path(&schedule.system_dependents, &systems);
+
+        Self {
+            label,
+            span,
+            systems,
+            sets,
+            critical_path,
+        }
+    }
+
+    /// Returns the label of the profiled schedule.
+    pub fn label(&self) -> InternedScheduleLabel {
+        self.label
+    }
+
+    /// Returns the wall time of the whole schedule run.
+    pub fn span(&self) -> SystemSpan {
+        self.span
+    }
+
+    /// Returns the profiles of the systems of the schedule, in topological order.
+    pub fn systems(&self) -> &[SystemProfile] {
+        &self.systems
+    }
+
+    /// Returns the profiles of the named system sets of the schedule in which at least one system
+    /// ran.
+    pub fn sets(&self) -> &[SetProfile] {
+        &self.sets
+    }
+
+    /// Returns the indices in [`systems`](Self::systems) of the chain of dependent systems that
+    /// took the longest to run, in the order they ran.
+    ///
+    /// No matter how many threads are available, the schedule can't run faster than this chain.
+    pub fn critical_path(&self) -> &[usize] {
+        &self.critical_path
+    }
+
+    /// Returns the sum of the run times of the systems on the [critical path](Self::critical_path).
+    pub fn critical_path_duration(&self) -> Duration {
+        self.critical_path
+            .iter()
+            .filter_map(|&index| self.systems[index].span)
+            .map(|span| span.duration())
+            .sum()
+    }
+}
+
+/// The timings of a system in a [`ScheduleProfile`].
+#[derive(Debug, Clone)]
+pub struct SystemProfile {
+    name: Cow<'static, str>,
+    span: Option<SystemSpan>,
+}
+
+impl SystemProfile {
+    /// Returns the name of the system.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns when the system ran, or `None` if it was skipped.
+    pub fn span(&self) -> Option<SystemSpan> {
+        self.span
+    }
+}
+
+/// The timings of a system set in a [`ScheduleProfile`].
+#[derive(Debug, Clone)]
+pub struct SetProfile {
+    name: String,
+    start: Instant,
+    end: Instant,
+    busy: Duration,
+}
+
+impl SetProfile {
+    /// Returns the name of the system set.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Returns when the first system of the set started running.
+    pub fn start(&self) -> Instant {
+        self.start
+    }
+
+    /// Returns when the last system of the set finished running.
+    pub fn end(&self) -> Instant {
+        self.end
+    }
+
+    /// Returns the wall time between the start of the first system and the end of the last one.
+    pub fn duration(&self) -> Duration {
+        self.end.saturating_duration_since(self.start)
+    }
+
+    /// Returns the sum of the run times of the systems of the set.
+    ///
+    /// This is larger than the [`duration`](Self::duration) if systems of the set ran in parallel.
+    pub fn busy(&self) -> Duration {
+        self.busy
+    }
+}
+
+/// Finds the longest path through the dependency graph, weighted by the system run times.
+///
+/// Systems are sorted topologically, so dependents always come after their dependencies.
+fn critical_path(dependents: &[Vec<usize>], systems: &[SystemProfile]) -> Vec<usize> {
+    let mut cost = alloc::vec![Duration::ZERO; systems.len()];
+    let mut previous = alloc::vec![None; systems.len()];
+    for (index, system) in systems.iter().enumerate() {
+        cost[index] += system.span.map(|span| span.duration()).unwrap_or_default();
+        for &dependent in &dependents[index] {
+            if cost[index] > cost[dependent] {
+                cost[dependent] = cost[index];
+                previous[dependent] = Some(index);
+            }
+        }
+    }
+
+    let mut path = Vec::new();
+    let mut current = cost
+        .iter()
+        .enumerate()
+        .max_by_key(|(_, cost)| **cost)
+        .map(|(index, _)| index);
+    while let Some(index) = current {
+        path.push(index);
+        current = previous[index];
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::ScheduleProfiler;
+    use crate::{
+        self as bevy_ecs,
+        prelude::*,
+        schedule::{ExecutorKind, ScheduleLabel},
+    };
+    use bevy_utils::Duration;
+    use std::thread::sleep;
+
+    #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+    struct Profiled;
+
+    #[derive(SystemSet, Debug, Clone, PartialEq, Eq, Hash)]
+    struct Slow;
+
+    fn fast() {}
+
+    fn slow_a() {
+        sleep(Duration::from_millis(5));
+    }
+
+    fn slow_b() {
+        sleep(Duration::from_millis(5));
+    }
+
+    fn never() {}
+
+    fn run_profiled(executor: ExecutorKind) {
+        let mut world = World::new();
+        world.init_resource::<ScheduleProfiler>();
+
+        let mut schedule = Schedule::new(Profiled);
+        schedule.set_executor_kind(executor);
+        schedule.add_systems((
+            (slow_a, slow_b).chain().in_set(Slow),
+            fast,
+            never.run_if(|| false),
+        ));
+        schedule.run(&mut world);
+
+        let mut profiler = world.resource_mut::<ScheduleProfiler>();
+        let profiles: Vec<_> = profiler.drain().collect();
+        assert_eq!(profiles.len(), 1);
+        let profile = &profiles[0];
+        assert_eq!(profile.label(), Profiled.intern());
+
+        let system = |name: &str| {
+            profile
+                .systems()
+                .iter()
+                .position(|system| system.name().ends_with(name))
+                .unwrap()
+        };
+        let systems = profile.systems();
+        let (a, b) = (system("slow_a"), system("slow_b"));
+        assert!(systems[a].span().unwrap().duration() >= Duration::from_millis(5));
+        assert!(systems[system("fast")].span().is_some());
+        assert!(systems[system("never")].span().is_none());
+        assert!(systems[b].span().unwrap().start >= systems[a].span().unwrap().end);
+
+        let sets = profile.sets();
+        assert_eq!(sets.len(), 1);
+        assert_eq!(sets[0].name(), "Slow");
+        assert!(sets[0].busy() >= Duration::from_millis(10));
+        assert!(sets[0].duration() >= sets[0].busy());
+
+        assert_eq!(profile.critical_path(), [a, b]);
+        assert!(profile.critical_path_duration() >= Duration::from_millis(10));
+        assert!(profile.span().duration() >= profile.critical_path_duration());
+    }
+
+    #[test]
+    fn profile_single_threaded() {
+        run_profiled(ExecutorKind::SingleThreaded);
+    }
+
+    #[test]
+    fn profile_multi_threaded() {
+        run_profiled(ExecutorKind::MultiThreaded);
+    }
+
+    #[test]
+    fn no_profile_without_resource() {
+        let mut world = World::new();
+        let mut schedule = Schedule::new(Profiled);
+        schedule.add_systems(fast);
+        schedule.run(&mut world);
+
+        world.init_resource::<ScheduleProfiler>();
+        schedule.run(&mut world);
+        assert_eq!(world.resource::<ScheduleProfiler>().profiles().len(), 1);
+    }
+
+    #[test]
+    fn profiles_are_capped() {
+        let mut world = World::new();
+        world.insert_resource(ScheduleProfiler::with_max_profiles(2));
+        let mut schedule = Schedule::new(Profiled);
+        schedule.add_systems(fast);
+        for _ in 0..3 {
+            schedule.run(&mut world);
+        }
+
+        let profiler = world.resource::<ScheduleProfiler>();
+        assert_eq!(profiler.profiles().len(), 2);
+    }
+}
@@ -6,7 +6,7 @@ use bevy_utils::tracing::info_span;
 use bevy_utils::{
     default,
     tracing::{error, info, warn},
-    HashMap, HashSet,
+    HashMap, HashSet, Instant,
 };
 use disqualified::ShortName;
 use fixedbitset::FixedBitSet;
@@ -264,6 +264,8 @@ pub struct Schedule {
     executable: SystemSchedule,
     executor: Box<dyn SystemExecutor>,
     executor_initialized: bool,
+    /// Named system sets and the systems in them, cached for the [`ScheduleProfiler`].
+    profiled_sets: Option<Vec<(String, FixedBitSet)>>,
 }
 
 #[derive(ScheduleLabel, Hash, PartialEq, Eq, Debug, Clone)]
@@ -288,6 +290,7 @@ impl Schedule {
             executable: SystemSchedule::new(),
             executor: make_executor(ExecutorKind::default()),
             executor_initialized: false,
+            profiled_sets: None,
         }
     }
 
@@ -383,8 +386,15 @@ impl Schedule {
         self.initialize(world)
             .unwrap_or_else(|e| panic!("Error when initializing schedule {:?}: {e}", self.label));
 
+        let error_handler = world.default_error_handler();
+
+        let profiling = world.contains_resource::<ScheduleProfiler>();
+        self.executor.set_profiling(profiling);
+        let start = profiling.then(Instant::now);
+
         #[cfg(not(feature = "bevy_debug_stepping"))]
-        self.executor.run(&mut self.executable, world, None);
+        self.executor
+            .run(&mut self.executable, world, None, error_handler);
 
         #[cfg(feature = "bevy_debug_stepping")]
         {
@@ -393,8 +403,34 @@ impl Schedule {
                 Some(mut stepping) => stepping.skipped_systems(self),
             };
 
-            self.executor
-                .run(&mut self.executable, world, skip_systems.as_ref());
+            self.executor.run(
+                &mut self.executable,
+                world,
+                skip_systems.as_ref(),
+                error_handler,
+            );
+        }
+
+        if let Some(start) = start {
+            self.record_profile(world, start);
+        }
+    }
+
+    /// Adds the [`ScheduleProfile`] of the last run to the [`ScheduleProfiler`].
+    fn record_profile(&mut self, world: &mut World, start: Instant) {
+        let span = SystemSpan::until_now(start);
+        let sets = self
+            .profiled_sets
+            .get_or_insert_with(|| self.graph.profiled_sets(&self.executable));
+        let profile = ScheduleProfile::new(
+            self.label,
+            span,
+            &self.executable,
+            sets,
+            self.executor.take_system_spans(),
+        );
+        if let Some(mut profiler) = world.get_resource_mut::<ScheduleProfiler>() {
+            profiler.push(profile);
         }
     }
 
@@ -417,6 +453,7 @@ impl Schedule {
             )?;
             self.graph.changed = false;
             self.executor_initialized = false;
+            self.profiled_sets = None;
         }
 
         if !self.executor_initialized {
@@ -1263,6 +1300,38 @@ impl ScheduleGraph {
         (set_systems, set_system_bitsets)
     }
 
+    /// Returns the name of every named system set, along with the indices in `schedule` of the
+    /// systems in it.
+    fn profiled_sets(&self, schedule: &SystemSchedule) -> Vec<(String, FixedBitSet)> {
+        let system_indices = schedule
+            .system_ids
+            .iter()
+            .enumerate()
+            .map(|(index, &id)| (id, index))
+            .collect::<HashMap<_, _>>();
+        let (set_systems, _) =
+            self.map_sets_to_systems(&self.hierarchy.topsort, &self.hierarchy.graph);
+
+        self.hierarchy
+            .topsort
+            .iter()
+            .filter(|id| id.is_set())
+            .filter_map(|id| {
+                let set = &self.system_sets[id.index()];
+                if set.is_system_type() || set.is_anonymous() {
+                    return None;
+                }
+                let mut systems = FixedBitSet::with_capacity(schedule.systems.len());
+                for system in set_systems.get(id)? {
+                    if let Some(&index) = system_indices.get(system) {
+                        systems.insert(index);
+                    }
+                }
+                Some((set.name(), systems))
+            })
+            .collect()
+    }
+
     fn get_dependency_flattened(&mut self, set_systems: &HashMap<NodeId, Vec<NodeId>>) -> DiGraph {
         // flatten: combine `in_set` with `before` and `after` information
         // have to do it like this to preserve transitivity
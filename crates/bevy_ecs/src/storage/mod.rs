@@ -24,11 +24,13 @@ mod blob_array;
 mod blob_vec;
 mod resource;
 mod sparse_set;
+mod stats;
 mod table;
 mod thin_array_ptr;
 
 pub use resource::*;
 pub use sparse_set::*;
+pub use stats::*;
 pub use table::*;
 
 /// The raw data stores of a [`World`](crate::world::World)
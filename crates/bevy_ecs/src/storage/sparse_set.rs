@@ -161,6 +161,17 @@ impl ComponentSparseSet {
         self.dense.len() == 0
     }
 
+    /// Returns the number of bytes allocated by the sparse set.
+    pub fn allocated_bytes(&self) -> usize {
+        #[cfg(not(debug_assertions))]
+        let entity_size = size_of::<EntityIndex>();
+        #[cfg(debug_assertions)]
+        let entity_size = size_of::<Entity>();
+        self.dense.allocated_bytes()
+            + self.entities.capacity() * entity_size
+            + self.sparse.values.capacity() * size_of::<Option<TableRow>>()
+    }
+
     /// Inserts the `entity` key and component `value` pair into this sparse
     /// set.
     ///
@@ -0,0 +1,325 @@
+use alloc::vec::Vec;
+use bevy_utils::HashMap;
+
+use crate::{
+    archetype::{ArchetypeId, Archetypes},
+    component::ComponentId,
+    entity::Entity,
+    storage::{SparseSets, TableId, Tables},
+    world::World,
+};
+
+/// A snapshot of how the entities and components of a [`World`] are laid out in memory, returned
+/// by [`World::storage_stats`].
+///
+/// This is useful to spot archetype fragmentation, for example when many marker components are
+/// combined in different ways: it shows up as many archetypes and tables with few entities each.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// #[derive(Component)]
+/// struct Position(f32, f32);
+///
+/// #[derive(Component)]
+/// #[component(storage = "SparseSet")]
+/// struct Selected;
+///
+/// let mut world = World::new();
+/// world.spawn_batch((0..10).map(|i| Position(i as f32, 0.)));
+/// world.spawn((Position(0., 0.), Selected));
+///
+/// let stats = world.storage_stats();
+/// let position = world.component_id::<Position>().unwrap();
+/// let archetype = stats
+///     .archetypes
+///     .iter()
+///     .find(|archetype| archetype.components == [position])
+///     .unwrap();
+/// assert_eq!(archetype.entity_count, 10);
+/// let selected = world.component_id::<Selected>().unwrap();
+/// let sparse_set = stats
+///     .sparse_sets
+///     .iter()
+///     .find(|sparse_set| sparse_set.component_id == selected)
+///     .unwrap();
+/// assert_eq!(sparse_set.len, 1);
+/// assert!(stats.component_bytes()[&position] >= 11 * size_of::<Position>());
+/// ```
+#[derive(Debug, Clone, PartialEq)]
+pub struct StorageStats {
+    /// The statistics of every archetype, including empty ones.
+    pub archetypes: Vec<ArchetypeStats>,
+    /// The statistics of every table, including empty ones.
+    pub tables: Vec<TableStats>,
+    /// The statistics of the storage of every sparse set component.
+    pub sparse_sets: Vec<SparseSetStats>,
+}
+
+impl StorageStats {
+    /// Returns the number of archetypes.
+    ///
+    /// Archetypes are never removed, so the difference between two snapshots is the number of
+    /// archetypes created in between.
+    pub fn archetype_count(&self) -> usize {
+        self.archetypes.len()
+    }
+
+    /// Returns the number of archetypes without entities.
+    pub fn empty_archetype_count(&self) -> usize {
+        self.archetypes
+            .iter()
+            .filter(|archetype| archetype.entity_count == 0)
+            .count()
+    }
+
+    /// Returns the number of entities stored in tables, divided by the number of entities they
+    /// can store without reallocating, or 1 if the tables don't have any capacity.
+    pub fn table_occupancy(&self) -> f64 {
+        let (entities, capacity) =
+            self.tables
+                .iter()
+                .fold((0, 0), |(entities, capacity), table| {
+                    (entities + table.entity_count, capacity + table.capacity)
+                });
+        if capacity == 0 {
+            1.0
+        } else {
+            entities as f64 / capacity as f64
+        }
+    }
+
+    /// Returns the number of bytes allocated by tables and sparse sets.
+    pub fn allocated_bytes(&self) -> usize {
+        self.tables
+            .iter()
+            .map(TableStats::allocated_bytes)
+            .sum::<usize>()
+            + self
+                .sparse_sets
+                .iter()
+                .map(|sparse_set| sparse_set.allocated_bytes)
+                .sum::<usize>()
+    }
+
+    /// Returns the number of bytes allocated to store each component, across all tables and
+    /// sparse sets.
+    pub fn component_bytes(&self) -> HashMap<ComponentId, usize> {
+        let mut bytes = HashMap::<ComponentId, usize>::default();
+        let columns = self.tables.iter().flat_map(|table| {
+            table
+                .columns
+                .iter()
+                .map(|column| (column.component_id, column.allocated_bytes))
+        });
+        let sparse_sets = self
+            .sparse_sets
+            .iter()
+            .map(|sparse_set| (sparse_set.component_id, sparse_set.allocated_bytes));
+        for (component_id, allocated_bytes) in columns.chain(sparse_sets) {
+            *bytes.entry(component_id).or_default() += allocated_bytes;
+        }
+        bytes
+    }
+}
+
+/// The statistics of an [`Archetype`](crate::archetype::Archetype).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ArchetypeStats {
+    /// The id of the archetype.
+    pub id: ArchetypeId,
+    /// The id of the table storing the table components of the archetype.
+    pub table_id: TableId,
+    /// The number of entities in the archetype.
+    pub entity_count: usize,
+    /// The components of the archetype.
+    pub components: Vec<ComponentId>,
+}
+
+/// The statistics of a [`Table`](crate::storage::Table).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableStats {
+    /// The id of the table.
+    pub id: TableId,
+    /// The number of entities stored in the table.
+    pub entity_count: usize,
+    /// The number of entities the table can store without reallocating.
+    pub capacity: usize,
+    /// The statistics of the columns of the table.
+    pub columns: Vec<ColumnStats>,
+}
+
+impl TableStats {
+    /// Returns the number of entities stored in the table, divided by its capacity, or 1 if it
+    /// doesn't have any capacity.
+    pub fn occupancy(&self) -> f64 {
+        if self.capacity == 0 {
+            1.0
+        } else {
+            self.entity_count as f64 / self.capacity as f64
+        }
+    }
+
+    /// Returns the number of bytes allocated by the table, including its list of entities.
+    pub fn allocated_bytes(&self) -> usize {
+        self.capacity * size_of::<Entity>()
+            + self
+                .columns
+                .iter()
+                .map(|column| column.allocated_bytes)
+                .sum::<usize>()
+    }
+}
+
+/// The statistics of a [`ThinColumn`](crate::storage::ThinColumn) of a table.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnStats {
+    /// The component stored in the column.
+    pub component_id: ComponentId,
+    /// The number of bytes allocated by the column, including change detection ticks.
+    pub allocated_bytes: usize,
+}
+
+/// The statistics of a [`ComponentSparseSet`](crate::storage::ComponentSparseSet).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SparseSetStats {
+    /// The component stored in the sparse set.
+    pub component_id: ComponentId,
+    /// The number of components stored in the sparse set.
+    pub len: usize,
+    /// The number of bytes allocated by the sparse set, including change detection ticks.
+    pub allocated_bytes: usize,
+}
+
+impl Archetypes {
+    /// Returns the statistics of every archetype.
+    pub fn stats(&self) -> Vec<ArchetypeStats> {
+        self.iter()
+            .map(|archetype| ArchetypeStats {
+                id: archetype.id(),
+                table_id: archetype.table_id(),
+                entity_count: archetype.len(),
+                components: archetype.components().collect(),
+            })
+            .collect()
+    }
+}
+
+impl Tables {
+    /// Returns the statistics of every table.
+    pub fn stats(&self) -> Vec<TableStats> {
+        self.iter()
+            .enumerate()
+            .map(|(index, table)| TableStats {
+                id: TableId::from_usize(index),
+                entity_count: table.entity_count(),
+                capacity: table.capacity(),
+                columns: table
+                    .column_allocated_bytes()
+                    .map(|(component_id, allocated_bytes)| ColumnStats {
+                        component_id,
+                        allocated_bytes,
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+}
+
+impl SparseSets {
+    /// Returns the statistics of every sparse set.
+    pub fn stats(&self) -> Vec<SparseSetStats> {
+        self.iter()
+            .map(|(component_id, sparse_set)| SparseSetStats {
+                component_id,
+                len: sparse_set.len(),
+                allocated_bytes: sparse_set.allocated_bytes(),
+            })
+            .collect()
+    }
+}
+
+impl World {
+    /// Returns a snapshot of how the entities and components of this world are laid out in
+    /// memory.
+    ///
+    /// This walks every archetype, table and sparse set, so it is meant for debugging and
+    /// monitoring rather than to be called in hot code.
+    pub fn storage_stats(&self) -> StorageStats {
+        StorageStats {
+            archetypes: self.archetypes().stats(),
+            tables: self.storages().tables.stats(),
+            sparse_sets: self.storages().sparse_sets.stats(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as bevy_ecs, prelude::*};
+
+    #[derive(Component)]
+    #[expect(dead_code, reason = "The field gives the component its size.")]
+    struct A(u64);
+
+    #[derive(Component)]
+    struct B;
+
+    #[derive(Component)]
+    #[component(storage = "SparseSet")]
+    #[expect(dead_code, reason = "The field gives the component its size.")]
+    struct S(u32);
+
+    #[test]
+    fn archetype_fragmentation() {
+        let mut world = World::new();
+        let before = world.storage_stats();
+
+        world.spawn_batch((0..4).map(A));
+        let entity = world.spawn((A(4), B)).id();
+        world.entity_mut(entity).remove::<B>();
+
+        let stats = world.storage_stats();
+        // `(A)` and `(A, B)`.
+        assert_eq!(stats.archetype_count() - before.archetype_count(), 2);
+        assert_eq!(
+            stats.empty_archetype_count() - before.empty_archetype_count(),
+            1
+        );
+        let a = world.component_id::<A>().unwrap();
+        let archetype = stats
+            .archetypes
+            .iter()
+            .find(|archetype| archetype.components == [a])
+            .unwrap();
+        assert_eq!(archetype.entity_count, 5);
+
+        let table = &stats.tables[archetype.table_id.as_usize()];
+        assert_eq!(table.entity_count, 5);
+        assert!(table.capacity >= 5);
+        assert!(table.occupancy() <= 1.0);
+        assert_eq!(table.columns.len(), 1);
+        assert_eq!(table.columns[0].component_id, a);
+        assert!(table.columns[0].allocated_bytes >= table.capacity * size_of::<u64>());
+    }
+
+    #[test]
+    fn sparse_set_and_component_bytes() {
+        let mut world = World::new();
+        world.spawn_batch((0..3).map(|i| (A(i), S(i as u32))));
+
+        let stats = world.storage_stats();
+        let a = world.component_id::<A>().unwrap();
+        let s = world.component_id::<S>().unwrap();
+        let sparse_set = stats
+            .sparse_sets
+            .iter()
+            .find(|sparse_set| sparse_set.component_id == s)
+            .unwrap();
+        assert_eq!(sparse_set.len, 3);
+
+        let bytes = stats.component_bytes();
+        assert_eq!(bytes[&s], sparse_set.allocated_bytes);
+        assert!(bytes[&a] >= 3 * size_of::<u64>());
+        assert!(stats.allocated_bytes() > bytes[&a] + bytes[&s]);
+    }
+}
@@ -35,6 +35,15 @@ impl ThinColumn {
         }
     }
 
+    /// Returns the number of bytes allocated by this column, if it has the given `capacity`.
+    pub(crate) fn allocated_bytes(&self, capacity: usize) -> usize {
+        #[cfg(feature = "track_change_detection")]
+        let changed_by_size = size_of::<&'static Location<'static>>();
+        #[cfg(not(feature = "track_change_detection"))]
+        let changed_by_size = 0;
+        capacity * (self.data.layout().size() + 2 * size_of::<Tick>() + changed_by_size)
+    }
+
     /// Swap-remove and drop the removed element, but the component at `row` must not be the last element.
     ///
     /// # Safety
@@ -366,6 +375,17 @@ impl Column {
         self.data.layout()
     }
 
+    /// Returns the number of bytes allocated by this column.
+    pub(crate) fn allocated_bytes(&self) -> usize {
+        #[cfg(feature = "track_change_detection")]
+        let changed_by_bytes = self.changed_by.capacity() * size_of::<&'static Location<'static>>();
+        #[cfg(not(feature = "track_change_detection"))]
+        let changed_by_bytes = 0;
+        self.data.capacity() * self.data.layout().size()
+            + (self.added_ticks.capacity() + self.changed_ticks.capacity()) * size_of::<Tick>()
+            + changed_by_bytes
+    }
+
     /// Writes component data to the column at given row.
     /// Assumes the slot is initialized, calls drop.
     ///
@@ -624,6 +624,15 @@ impl Table {
         self.columns.values()
     }
 
+    /// Iterates over the components of the [`Table`], with the number of bytes allocated by their
+    /// [`ThinColumn`].
+    pub fn column_allocated_bytes(&self) -> impl Iterator<Item = (ComponentId, usize)> + '_ {
+        let capacity = self.capacity();
+        self.columns
+            .iter()
+            .map(move |(id, column)| (*id, column.allocated_bytes(capacity)))
+    }
+
     /// Clears all of the stored components in the [`Table`].
     pub(crate) fn clear(&mut self) {
         let len = self.entity_count();
@@ -14,6 +14,7 @@ use crate::{
     entity::{Entities, Entity, EntityCloneBuilder},
     event::{Event, SendEvent},
     observer::{Observer, TriggerEvent, TriggerTargets},
+    result::{ErrorContext, ErrorHandler},
     schedule::ScheduleLabel,
     system::{input::SystemInput, RunSystemWith, SystemId},
     world::{
@@ -71,7 +72,6 @@ pub use parallel_scope::*;
 /// // NOTE: type inference fails here, so annotations are required on the closure.
 /// commands.queue(|w: &mut World| {
 ///     // Mutate the world however you want...
-///     # todo!();
 /// });
 /// # }
 /// ```
@@ -570,7 +570,44 @@ impl<'w, 's> Commands<'w, 's> {
     /// # bevy_ecs::system::assert_is_system(add_three_to_counter_system);
     /// # bevy_ecs::system::assert_is_system(add_twenty_five_to_counter_system);
     /// ```
-    pub fn queue<C: Command>(&mut self, command: C) {
+    ///
+    /// Commands that return a [`Result`](crate::result::Result) pass their errors to the
+    /// [default error handler](crate::result::DefaultErrorHandler) of the [`World`].
+    /// Use [`queue_handled`](Self::queue_handled) to use a different [`ErrorHandler`].
+    pub fn queue<C: Command<T> + HandleError<T>, T>(&mut self, command: C) {
+        self.queue_internal(command.handle_error());
+    }
+
+    /// Pushes a generic [`Command`] to the command queue, passing any error it returns to `error_handler`.
+    ///
+    /// See [`queue`](Self::queue) for more information.
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// use bevy_ecs::result::ignore;
+    ///
+    /// fn system(mut commands: Commands) {
+    ///     commands.queue_handled(
+    ///         |world: &mut World| -> Result {
+    ///             world.get_resource_mut::<Time>().ok_or("no time")?;
+    ///             Ok(())
+    ///         },
+    ///         ignore,
+    ///     );
+    /// }
+    /// # #[derive(Resource)]
+    /// # struct Time;
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn queue_handled<C: Command<T> + HandleError<T>, T>(
+        &mut self,
+        command: C,
+        error_handler: ErrorHandler,
+    ) {
+        self.queue_internal(command.handle_error_with(error_handler));
+    }
+
+    fn queue_internal(&mut self, command: impl Command) {
         match &mut self.queue {
             InternalQueue::CommandQueue(queue) => {
                 queue.push(command);
@@ -1919,12 +1956,65 @@ impl<'a, T: Component> EntityEntryCommands<'a, T> {
     }
 }
 
-impl<F> Command for F
+impl<F, Out> Command<Out> for F
 where
-    F: FnOnce(&mut World) + Send + 'static,
+    F: FnOnce(&mut World) -> Out + Send + 'static,
 {
-    fn apply(self, world: &mut World) {
-        self(world);
+    fn apply(self, world: &mut World) -> Out {
+        self(world)
+    }
+}
+
+/// Converts a [`Command`] into a [`Command`] that handles its own output.
+///
+/// Infallible commands are returned as-is. The errors returned by commands that output a
+/// [`Result`](crate::result::Result) are passed to an [`ErrorHandler`].
+pub trait HandleError<Out = ()> {
+    /// Returns a [`Command`] that passes any error returned by this command to the
+    /// [default error handler](crate::result::DefaultErrorHandler) of the [`World`] it is applied to.
+    fn handle_error(self) -> impl Command;
+
+    /// Returns a [`Command`] that passes any error returned by this command to `error_handler`.
+    fn handle_error_with(self, error_handler: ErrorHandler) -> impl Command;
+}
+
+impl<C, T, E> HandleError<Result<T, E>> for C
+where
+    C: Command<Result<T, E>>,
+    E: Into<crate::result::Error>,
+{
+    fn handle_error(self) -> impl Command {
+        move |world: &mut World| {
+            if let Err(err) = self.apply(world) {
+                (world.default_error_handler())(err.into(), command_error_context::<C>());
+            }
+        }
+    }
+
+    fn handle_error_with(self, error_handler: ErrorHandler) -> impl Command {
+        move |world: &mut World| {
+            if let Err(err) = self.apply(world) {
+                error_handler(err.into(), command_error_context::<C>());
+            }
+        }
+    }
+}
+
+impl<C: Command> HandleError for C {
+    #[inline]
+    fn handle_error(self) -> impl Command {
+        self
+    }
+
+    #[inline]
+    fn handle_error_with(self, _error_handler: ErrorHandler) -> impl Command {
+        self
+    }
+}
+
+fn command_error_context<C>() -> ErrorContext {
+    ErrorContext::Command {
+        name: core::any::type_name::<C>().into(),
     }
 }
 
@@ -1946,6 +2036,34 @@ where
     }
 }
 
+#[doc(hidden)]
+pub struct FallibleEntityWorldMut;
+
+impl<F> EntityCommand<FallibleEntityWorldMut> for F
+where
+    F: FnOnce(EntityWorldMut) -> crate::result::Result + Send + 'static,
+{
+    fn apply(self, id: Entity, world: &mut World) {
+        if let Err(err) = self(world.entity_mut(id)) {
+            (world.default_error_handler())(err, command_error_context::<F>());
+        }
+    }
+}
+
+#[doc(hidden)]
+pub struct FallibleEntityCommand;
+
+impl<F> EntityCommand<FallibleEntityCommand> for F
+where
+    F: FnOnce(Entity, &mut World) -> crate::result::Result + Send + 'static,
+{
+    fn apply(self, id: Entity, world: &mut World) {
+        if let Err(err) = self(id, world) {
+            (world.default_error_handler())(err, command_error_context::<F>());
+        }
+    }
+}
+
 /// A [`Command`] that consumes an iterator of [`Bundle`]s to spawn a series of entities.
 ///
 /// This is more efficient than spawning the entities individually.
@@ -2678,4 +2796,42 @@ mod tests {
         assert!(world.contains_resource::<W<i32>>());
         assert!(world.contains_resource::<W<f64>>());
     }
+
+    #[test]
+    fn fallible_commands() {
+        use crate::result::{DefaultErrorHandler, Error, ErrorContext};
+
+        static ERRORS: AtomicUsize = AtomicUsize::new(0);
+
+        fn count_errors(_: Error, ctx: ErrorContext) {
+            assert!(matches!(ctx, ErrorContext::Command { .. }));
+            ERRORS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        let mut world = World::default();
+        world.insert_resource(DefaultErrorHandler(count_errors));
+        let mut queue = CommandQueue::default();
+        {
+            let mut commands = Commands::new(&mut queue, &world);
+            commands.queue(|world: &mut World| -> crate::result::Result {
+                world.get_resource::<W<u8>>().ok_or("missing resource")?;
+                Ok(())
+            });
+            commands.queue_handled(
+                |_: &mut World| -> crate::result::Result { Err("ignored")? },
+                crate::result::ignore,
+            );
+            commands.spawn_empty().queue(
+                |entity: crate::world::EntityWorldMut| -> crate::result::Result {
+                    entity.get::<W<u8>>().ok_or("missing component")?;
+                    Ok(())
+                },
+            );
+            commands.insert_resource(W(1u8));
+        }
+        queue.apply(&mut world);
+
+        assert_eq!(ERRORS.load(Ordering::Relaxed), 2);
+        assert!(world.contains_resource::<W<u8>>());
+    }
 }
@@ -1760,6 +1760,7 @@ mod tests {
     }
 
     #[test]
+    #[should_panic]
     fn simple_fallible_system() {
         fn sys() -> Result {
             Err("error")?;
@@ -1769,4 +1770,32 @@ mod tests {
         let mut world = World::new();
         run_system(&mut world, sys);
     }
+
+    #[test]
+    fn fallible_system_custom_error_handler() {
+        use crate::result::{DefaultErrorHandler, Error, ErrorContext};
+        use core::sync::atomic::{AtomicUsize, Ordering};
+
+        static ERRORS: AtomicUsize = AtomicUsize::new(0);
+
+        fn count_errors(_: Error, ctx: ErrorContext) {
+            assert!(matches!(ctx, ErrorContext::System { .. }));
+            assert!(ctx.name().ends_with("sys"));
+            ERRORS.fetch_add(1, Ordering::Relaxed);
+        }
+
+        fn sys(query: Query<&W<u32>>) -> Result {
+            query.get_single()?;
+            Ok(())
+        }
+
+        let mut world = World::new();
+        world.insert_resource(DefaultErrorHandler(count_errors));
+        run_system(&mut world, sys);
+        assert_eq!(ERRORS.load(Ordering::Relaxed), 1);
+
+        world.spawn(W(0u32));
+        run_system(&mut world, sys);
+        assert_eq!(ERRORS.load(Ordering::Relaxed), 1);
+    }
 }
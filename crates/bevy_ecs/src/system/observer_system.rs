@@ -1,12 +1,14 @@
 use crate::{
     prelude::{Bundle, Trigger},
-    system::System,
+    result::Result,
+    schedule::{Fallible, Infallible},
+    system::{input::SystemIn, Adapt, AdapterSystem, System},
 };
 
 use super::IntoSystem;
 
 /// Implemented for [`System`]s that have a [`Trigger`] as the first argument.
-pub trait ObserverSystem<E: 'static, B: Bundle, Out = ()>:
+pub trait ObserverSystem<E: 'static, B: Bundle, Out = Result>:
     System<In = Trigger<'static, E, B>, Out = Out> + Send + 'static
 {
 }
@@ -21,12 +23,15 @@ impl<
 }
 
 /// Implemented for systems that convert into [`ObserverSystem`].
+///
+/// Observer systems may either return `()` or a [`Result`]. Errors are passed to the
+/// [default error handler](crate::result::DefaultErrorHandler) of the [`World`](crate::world::World).
 #[diagnostic::on_unimplemented(
     message = "`{Self}` cannot become an `ObserverSystem`",
     label = "the trait `IntoObserverSystem` is not implemented",
     note = "for function `ObserverSystem`s, ensure the first argument is a `Trigger<T>` and any subsequent ones are `SystemParam`"
 )]
-pub trait IntoObserverSystem<E: 'static, B: Bundle, M, Out = ()>: Send + 'static {
+pub trait IntoObserverSystem<E: 'static, B: Bundle, M, Out = Result>: Send + 'static {
     /// The type of [`System`] that this instance converts into.
     type System: ObserverSystem<E, B, Out>;
 
@@ -40,7 +45,7 @@ impl<
         Out,
         E: 'static,
         B: Bundle,
-    > IntoObserverSystem<E, B, M, Out> for S
+    > IntoObserverSystem<E, B, (Fallible, M), Out> for S
 where
     S::System: ObserverSystem<E, B, Out>,
 {
@@ -51,6 +56,40 @@ where
     }
 }
 
+impl<S, M, E, B> IntoObserverSystem<E, B, (Infallible, M), Result> for S
+where
+    S: IntoSystem<Trigger<'static, E, B>, (), M> + Send + 'static,
+    S::System: ObserverSystem<E, B, ()>,
+    E: 'static,
+    B: Bundle,
+{
+    type System = AdapterSystem<InfallibleObserverAdapter, S::System>;
+
+    fn into_system(this: Self) -> Self::System {
+        let system = IntoSystem::into_system(this);
+        let name = system.name();
+        AdapterSystem::new(InfallibleObserverAdapter, system, name)
+    }
+}
+
+/// An [`Adapt`] implementation that turns an infallible observer system into one that returns [`Result`].
+#[doc(hidden)]
+pub struct InfallibleObserverAdapter;
+
+impl<S: System<Out = ()>> Adapt<S> for InfallibleObserverAdapter {
+    type In = S::In;
+    type Out = Result;
+
+    fn adapt(
+        &mut self,
+        input: SystemIn<'_, S>,
+        run_system: impl FnOnce(SystemIn<'_, S>),
+    ) -> Result {
+        run_system(input);
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::{
@@ -1,6 +1,6 @@
 //! A trait for components that let you traverse the ECS.
 
-use crate::{entity::Entity, query::ReadOnlyQueryData};
+use crate::{entity::Entity, query::ReadOnlyQueryData, relationship::Relationship};
 
 /// A component that can point to another entity, and which can be used to define a path through the ECS.
 ///
@@ -30,3 +30,14 @@ impl<D> Traversal<D> for () {
         None
     }
 }
+
+/// This provides generalized hierarchy traversal for use in [event propagation].
+///
+/// `Traversal::traverse` follows the [`Relationship`] from the source entity to its target.
+///
+/// [event propagation]: crate::observer::Trigger::propagate
+impl<R: Relationship, D> Traversal<D> for &R {
+    fn traverse(item: Self::Item<'_>, _data: &D) -> Option<Entity> {
+        Some(item.get())
+    }
+}
@@ -0,0 +1,263 @@
+use alloc::{boxed::Box, sync::Arc};
+use core::{
+    future::{poll_fn, Future},
+    task::{Poll, Waker},
+};
+use std::sync::Mutex;
+
+use concurrent_queue::ConcurrentQueue;
+
+use crate::{
+    self as bevy_ecs,
+    system::{IntoSystem, Resource, RunSystemError, RunSystemOnce},
+    world::World,
+};
+
+type AsyncRequest = Box<dyn FnOnce(&mut World) + Send>;
+
+/// A handle that lets futures access the [`World`] it was created from, for example futures
+/// spawned on the [`AsyncComputeTaskPool`](bevy_tasks::AsyncComputeTaskPool).
+///
+/// Each access is queued, and runs with exclusive access to the world the next time
+/// [`World::apply_async_requests`] runs. When using the `AsyncWorldPlugin` of `bevy_app`, this
+/// happens at the end of every frame, in the `Last` schedule. The future then resumes with the
+/// result, so logic spanning several frames can be written as straight-line async code.
+///
+/// The handle is a [`Resource`] that can be cloned into the futures. If the world is dropped
+/// while an access is pending, its future never completes.
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::AsyncWorld;
+/// # use bevy_tasks::{block_on, poll_once};
+/// #[derive(Resource, Default)]
+/// struct Score(u32);
+///
+/// let mut world = World::new();
+/// world.init_resource::<Score>();
+///
+/// let async_world = world.async_world();
+/// let mut script = Box::pin(async move {
+///     for _ in 0..3 {
+///         async_world.run(|world| world.resource_mut::<Score>().0 += 1).await;
+///     }
+///     async_world.run(|world| world.resource::<Score>().0).await
+/// });
+///
+/// let mut frames = 0;
+/// let score = loop {
+///     if let Some(score) = block_on(poll_once(&mut script)) {
+///         break score;
+///     }
+///     world.apply_async_requests();
+///     frames += 1;
+/// };
+/// assert_eq!((score, frames), (3, 4));
+/// ```
+#[derive(Resource, Clone)]
+pub struct AsyncWorld {
+    requests: Arc<ConcurrentQueue<AsyncRequest>>,
+}
+
+impl Default for AsyncWorld {
+    fn default() -> Self {
+        Self {
+            requests: Arc::new(ConcurrentQueue::unbounded()),
+        }
+    }
+}
+
+impl core::fmt::Debug for AsyncWorld {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("AsyncWorld")
+            .field("pending_requests", &self.requests.len())
+            .finish()
+    }
+}
+
+struct Reply<R> {
+    value: Option<R>,
+    waker: Option<Waker>,
+}
+
+impl AsyncWorld {
+    /// Runs `f` with exclusive access to the world at its next sync point, and returns what it
+    /// returned.
+    ///
+    /// The access is queued the first time the returned future is polled.
+    pub fn run<R: Send + 'static>(
+        &self,
+        f: impl FnOnce(&mut World) -> R + Send + 'static,
+    ) -> impl Future<Output = R> + Send + 'static {
+        let reply = Arc::new(Mutex::new(Reply {
+            value: None,
+            waker: None,
+        }));
+        let sender = reply.clone();
+        let mut request = Some(Box::new(move |world: &mut World| {
+            let value = f(world);
+            let waker = {
+                let mut reply = sender.lock().unwrap();
+                reply.value = Some(value);
+                reply.waker.take()
+            };
+            if let Some(waker) = waker {
+                waker.wake();
+            }
+        }) as AsyncRequest);
+        let requests = self.requests.clone();
+
+        poll_fn(move |cx| {
+            let mut reply = reply.lock().unwrap();
+            if let Some(value) = reply.value.take() {
+                return Poll::Ready(value);
+            }
+            reply.waker = Some(cx.waker().clone());
+            drop(reply);
+            if let Some(request) = request.take() {
+                // The queue is unbounded and never closed.
+                let _ = requests.push(request);
+            }
+            Poll::Pending
+        })
+    }
+
+    /// Runs `system` once on the world at its next sync point, and returns its output.
+    ///
+    /// The system is initialized for every run, like with [`RunSystemOnce::run_system_once`], so its
+    /// [`Local`](crate::system::Local)s and change detection don't persist between runs.
+    pub fn run_system<T, Out, Marker>(
+        &self,
+        system: T,
+    ) -> impl Future<Output = Result<Out, RunSystemError>> + Send + 'static
+    where
+        T: IntoSystem<(), Out, Marker> + Send + 'static,
+        Out: Send + 'static,
+    {
+        self.run(move |world| world.run_system_once(system))
+    }
+
+    /// Waits until the next sync point of the world.
+    ///
+    /// When using the `AsyncWorldPlugin` of `bevy_app`, this waits for the end of the current frame.
+    pub fn next_sync_point(&self) -> impl Future<Output = ()> + Send + 'static {
+        self.run(|_| ())
+    }
+
+    /// Returns the number of accesses waiting for the next sync point.
+    pub fn pending_requests(&self) -> usize {
+        self.requests.len()
+    }
+}
+
+impl World {
+    /// Returns the [`AsyncWorld`] handle of this world, inserting it if needed.
+    pub fn async_world(&mut self) -> AsyncWorld {
+        self.get_resource_or_init::<AsyncWorld>().clone()
+    }
+
+    /// Runs the accesses queued by the futures using the [`AsyncWorld`] handle of this world,
+    /// then wakes those futures.
+    ///
+    /// Only the accesses queued when this method is called run, so futures that queue a new
+    /// access as soon as they are woken up resume at the next call.
+    pub fn apply_async_requests(&mut self) {
+        let Some(async_world) = self.get_resource::<AsyncWorld>() else {
+            return;
+        };
+        let requests = async_world.requests.clone();
+        for _ in 0..requests.len() {
+            let Ok(request) = requests.pop() else {
+                break;
+            };
+            request(self);
+        }
+        self.flush();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{self as bevy_ecs, prelude::*};
+    use alloc::boxed::Box;
+    use bevy_tasks::{block_on, poll_once};
+
+    #[derive(Component)]
+    struct Door;
+
+    #[derive(Resource, Default)]
+    struct Opened(bool);
+
+    #[test]
+    fn run_at_sync_point() {
+        let mut world = World::new();
+        let async_world = world.async_world();
+
+        let mut spawn = Box::pin(async_world.run(|world| world.spawn(Door).id()));
+        assert!(block_on(poll_once(&mut spawn)).is_none());
+        assert_eq!(async_world.pending_requests(), 1);
+        assert_eq!(world.query::<&Door>().iter(&world).count(), 0);
+
+        world.apply_async_requests();
+        assert_eq!(async_world.pending_requests(), 0);
+        let entity = block_on(poll_once(&mut spawn)).unwrap();
+        assert!(world.entity(entity).contains::<Door>());
+    }
+
+    #[test]
+    fn not_queued_until_polled() {
+        let mut world = World::new();
+        let async_world = world.async_world();
+
+        let spawn = async_world.run(|world| world.spawn(Door).id());
+        assert_eq!(async_world.pending_requests(), 0);
+        drop(spawn);
+        world.apply_async_requests();
+        assert_eq!(world.query::<&Door>().iter(&world).count(), 0);
+    }
+
+    #[test]
+    fn script_spans_sync_points() {
+        let mut world = World::new();
+        world.init_resource::<Opened>();
+        let async_world = world.async_world();
+
+        let mut script = Box::pin(async move {
+            let door = async_world.run(|world| world.spawn(Door).id()).await;
+            async_world.next_sync_point().await;
+            async_world
+                .run_system(|mut opened: ResMut<Opened>| opened.0 = true)
+                .await
+                .unwrap();
+            async_world.run(move |world| world.despawn(door)).await
+        });
+
+        let mut sync_points = 0;
+        let despawned = loop {
+            if let Some(despawned) = block_on(poll_once(&mut script)) {
+                break despawned;
+            }
+            world.apply_async_requests();
+            sync_points += 1;
+        };
+        assert!(despawned);
+        assert_eq!(sync_points, 4);
+        assert!(world.resource::<Opened>().0);
+        assert_eq!(world.query::<&Door>().iter(&world).count(), 0);
+    }
+
+    #[test]
+    fn run_from_other_thread() {
+        let mut world = World::new();
+        let async_world = world.async_world();
+
+        let thread =
+            std::thread::spawn(move || block_on(async_world.run(|world| world.spawn(Door).id())));
+        while !thread.is_finished() {
+            world.apply_async_requests();
+            std::thread::yield_now();
+        }
+        let entity = thread.join().unwrap();
+        assert!(world.entity(entity).contains::<Door>());
+    }
+}
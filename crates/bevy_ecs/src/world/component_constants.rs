@@ -13,6 +13,10 @@ pub const ON_INSERT: ComponentId = ComponentId::new(1);
 pub const ON_REPLACE: ComponentId = ComponentId::new(2);
 /// [`ComponentId`] for [`OnRemove`]
 pub const ON_REMOVE: ComponentId = ComponentId::new(3);
+/// [`ComponentId`] for [`Disabled`](crate::entity_disabling::Disabled)
+pub const DISABLED: ComponentId = ComponentId::new(4);
+/// [`ComponentId`] for [`OnMutate`]
+pub const ON_MUTATE: ComponentId = ComponentId::new(5);
 
 /// Trigger emitted when a component is added to an entity. See [`crate::component::ComponentHooks::on_add`]
 /// for more information.
@@ -35,6 +39,13 @@ pub struct OnInsert;
 #[cfg_attr(feature = "bevy_reflect", reflect(Debug))]
 pub struct OnReplace;
 
+/// Trigger emitted when a component was mutated, for components whose mutations are tracked
+/// with [`World::track_mutations`]. See [`World::trigger_mutations`] for more information.
+#[derive(Event, Debug)]
+#[cfg_attr(feature = "bevy_reflect", derive(Reflect))]
+#[cfg_attr(feature = "bevy_reflect", reflect(Debug))]
+pub struct OnMutate;
+
 /// Trigger emitted when a component is removed from an entity. See [`crate::component::ComponentHooks::on_remove`]
 /// for more information.
 #[derive(Event, Debug)]
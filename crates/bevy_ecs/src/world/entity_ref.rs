@@ -423,6 +423,12 @@ impl<'w> EntityMut<'w> {
         self.0.archetype()
     }
 
+    /// Returns the components registered in the world of the entity.
+    #[inline]
+    pub(crate) fn world_components(&self) -> &'w Components {
+        self.0.world().components()
+    }
+
     /// Returns `true` if the current entity has a component of type `T`.
     /// Otherwise, this returns `false`.
     ///
@@ -2529,6 +2535,12 @@ impl<'w> FilteredEntityRef<'w> {
         self.entity.archetype()
     }
 
+    /// Returns the components registered in the world of the entity.
+    #[inline]
+    pub(crate) fn world_components(&self) -> &'w Components {
+        self.entity.world().components()
+    }
+
     /// Returns a reference to the underlying [`Access`].
     #[inline]
     pub fn access(&self) -> &Access<ComponentId> {
@@ -2814,6 +2826,12 @@ impl<'w> FilteredEntityMut<'w> {
         self.entity.archetype()
     }
 
+    /// Returns the components registered in the world of the entity.
+    #[inline]
+    pub(crate) fn world_components(&self) -> &'w Components {
+        self.entity.world().components()
+    }
+
     /// Returns a reference to the underlying [`Access`].
     #[inline]
     pub fn access(&self) -> &Access<ComponentId> {
@@ -1,5 +1,6 @@
 //! Defines the [`World`] and APIs for accessing it directly.
 
+mod async_world;
 pub(crate) mod command_queue;
 mod component_constants;
 mod deferred_world;
@@ -8,6 +9,8 @@ mod entity_ref;
 pub mod error;
 mod filtered_resource;
 mod identifier;
+#[cfg(feature = "bevy_reflect")]
+pub mod snapshot;
 mod spawn_batch;
 pub mod unsafe_world_cell;
 
@@ -18,6 +21,7 @@ pub use crate::{
     change_detection::{Mut, Ref, CHECK_TICK_THRESHOLD},
     world::command_queue::CommandQueue,
 };
+pub use async_world::AsyncWorld;
 pub use component_constants::*;
 pub use deferred_world::DeferredWorld;
 pub use entity_fetch::WorldEntityFetch;
@@ -27,6 +31,8 @@ pub use entity_ref::{
 };
 pub use filtered_resource::*;
 pub use identifier::WorldId;
+#[cfg(feature = "bevy_reflect")]
+pub use snapshot::{SnapshotBuilder, SnapshotError, WorldSnapshot};
 pub use spawn_batch::*;
 
 use crate::{
@@ -35,12 +41,14 @@ use crate::{
     change_detection::{MutUntyped, TicksMut},
     component::{
         Component, ComponentCloneHandlers, ComponentDescriptor, ComponentHooks, ComponentId,
-        ComponentInfo, ComponentTicks, Components, Mutable, RequiredComponents,
+        ComponentInfo, ComponentTicks, Components, Immutable, Mutable, RequiredComponents,
         RequiredComponentsError, Tick,
     },
     entity::{AllocAtWithoutReplacement, Entities, Entity, EntityHashSet, EntityLocation},
+    entity_disabling::Disabled,
     event::{Event, EventId, Events, SendBatchIds},
-    observer::Observers,
+    index,
+    observer::{Observers, TrackedMutations},
     query::{DebugCheckedUnwrap, QueryData, QueryEntityError, QueryFilter, QueryState},
     removal_detection::RemovedComponentEvents,
     schedule::{Schedule, ScheduleLabel, Schedules},
@@ -93,13 +101,43 @@ use unsafe_world_cell::{UnsafeEntityCell, UnsafeWorldCell};
 ///     commands.queue(AddToCounter(42));
 /// }
 /// ```
-pub trait Command: Send + 'static {
+///
+/// # Fallible commands
+///
+/// Commands can return a [`Result`](crate::result::Result) by implementing `Command<Result>`.
+/// When queued through [`Commands`], any error they return is passed to the
+/// [default error handler](crate::result::DefaultErrorHandler) of the [`World`].
+///
+/// ```
+/// # use bevy_ecs::prelude::*;
+/// # use bevy_ecs::world::Command;
+/// # #[derive(Resource)]
+/// # struct Counter(u64);
+/// struct IncrementCounter;
+///
+/// impl Command<Result> for IncrementCounter {
+///     fn apply(self, world: &mut World) -> Result {
+///         world.get_resource_mut::<Counter>().ok_or("missing counter")?.0 += 1;
+///         Ok(())
+///     }
+/// }
+///
+/// fn some_system(mut commands: Commands) {
+///     commands.queue(IncrementCounter);
+///     // Closures work too.
+///     commands.queue(|world: &mut World| -> Result {
+///         world.get_resource_mut::<Counter>().ok_or("missing counter")?.0 += 1;
+///         Ok(())
+///     });
+/// }
+/// ```
+pub trait Command<Out = ()>: Send + 'static {
     /// Applies this command, causing it to mutate the provided `world`.
     ///
     /// This method is used to define what a command "does" when it is ultimately applied.
     /// Because this method takes `self`, you can store data or settings on the type that implements this trait.
     /// This data is set by the system or other source of the command, and then ultimately read in this method.
-    fn apply(self, world: &mut World);
+    fn apply(self, world: &mut World) -> Out;
 }
 
 /// Stores and exposes operations on [entities](Entity), [components](Component), resources,
@@ -183,6 +221,8 @@ impl World {
         assert_eq!(ON_INSERT, self.register_component::<OnInsert>());
         assert_eq!(ON_REPLACE, self.register_component::<OnReplace>());
         assert_eq!(ON_REMOVE, self.register_component::<OnRemove>());
+        assert_eq!(DISABLED, self.register_component::<Disabled>());
+        assert_eq!(ON_MUTATE, self.register_component::<OnMutate>());
     }
     /// Creates a new empty [`World`].
     ///
@@ -294,6 +334,21 @@ impl World {
         self.components.get_hooks_mut(id)
     }
 
+    /// Registers a [`ComponentIndex`](index::ComponentIndex) for `T`, which maps each value of `T`
+    /// to the entities that have it. Look entities up through the [`Index`](index::Index) system
+    /// param, or the [`ComponentIndex`](index::ComponentIndex) resource.
+    ///
+    /// The index is kept up to date with the `on_insert` and `on_replace` [hooks](ComponentHooks)
+    /// of `T`. Registering the same index twice does nothing.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `T` already has an `on_insert` or `on_replace` hook, or if `T` exists in any
+    /// archetypes.
+    pub fn register_index<T: Component<Mutability = Immutable> + Ord + Clone>(&mut self) {
+        index::register_index::<T>(self);
+    }
+
     /// Registers the given component `R` as a [required component] for `T`.
     ///
     /// When `T` is added to an entity, `R` and its own required components will also be added
@@ -3302,6 +3357,10 @@ impl World {
             schedules.check_change_ticks(change_tick);
         }
 
+        if let Some(mut tracked) = self.get_resource_mut::<TrackedMutations>() {
+            tracked.check_change_ticks(change_tick);
+        }
+
         self.last_check_tick = change_tick;
     }
 
@@ -0,0 +1,616 @@
+//! Snapshots of parts of a [`World`], which the world can later be rolled back to.
+//!
+//! A [`WorldSnapshot`] captures the values of a chosen set of components and resources, selected
+//! with a [`SnapshotBuilder`]. [Restoring](WorldSnapshot::restore) it puts those components and
+//! resources back the way they were, re-spawns entities that were despawned in the meantime with
+//! the same [`Entity`] ids, and despawns entities that were spawned since with one of the
+//! components. Entity generations never go back to ones that were used since the snapshot was
+//! taken: if the index of a despawned entity was reused in the meantime, the entity is re-spawned
+//! with a newer generation instead, and references to it in the restored values are mapped with
+//! [`ReflectMapEntities`].
+//!
+//! Values are copied with reflection, the same way [`component_clone_via_reflect`] clones
+//! components, so every captured type needs to be registered in the [`AppTypeRegistry`] with
+//! [`ReflectComponent`] or [`ReflectResource`] type data. The [component clone
+//! handlers](crate::component::ComponentCloneHandlers) aren't used, since they copy values
+//! between entities of a world, while a snapshot owns its values. Components that aren't
+//! captured are not part of the snapshot, so entities re-spawned by a restore only get the
+//! captured components back.
+//!
+//! ```
+//! # use bevy_ecs::prelude::*;
+//! # use bevy_ecs::world::SnapshotBuilder;
+//! # use bevy_reflect::Reflect;
+//! #[derive(Component, Reflect, PartialEq, Debug)]
+//! #[reflect(Component)]
+//! struct Position(i32);
+//!
+//! let mut world = World::new();
+//! world.init_resource::<AppTypeRegistry>();
+//! world.resource_mut::<AppTypeRegistry>().write().register::<Position>();
+//!
+//! let player = world.spawn(Position(0)).id();
+//! let snapshot = SnapshotBuilder::new(&world)
+//!     .allow::<Position>()
+//!     .build()
+//!     .unwrap();
+//!
+//! world.entity_mut(player).insert(Position(10));
+//! let projectile = world.spawn(Position(5)).id();
+//!
+//! snapshot.restore(&mut world).unwrap();
+//! assert_eq!(world.get::<Position>(player), Some(&Position(0)));
+//! assert!(world.get_entity(projectile).is_err());
+//! ```
+//!
+//! [`component_clone_via_reflect`]: crate::component::component_clone_via_reflect
+
+use crate::{
+    archetype::ArchetypeEntity,
+    bundle::Bundle,
+    component::{ComponentId, ComponentInfo},
+    entity::{AllocAtWithoutReplacement, Entity, EntityHashMap, EntityHashSet, EntityMapper},
+    identifier::masks::IdentifierMask,
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities, ReflectResource},
+    system::Resource,
+    world::World,
+};
+use alloc::{borrow::ToOwned, boxed::Box, string::String, vec::Vec};
+use bevy_reflect::{PartialReflect, TypeRegistry};
+use core::num::NonZero;
+use thiserror::Error;
+
+/// Builder for a [`WorldSnapshot`]. Selects the components and resources that get captured.
+///
+/// See the [module docs](crate::world::snapshot) for an example.
+pub struct SnapshotBuilder<'w> {
+    world: &'w World,
+    components: Vec<ComponentId>,
+    resources: Vec<ComponentId>,
+}
+
+impl<'w> SnapshotBuilder<'w> {
+    /// Creates a new [`SnapshotBuilder`] for the given [`World`], capturing nothing.
+    pub fn new(world: &'w World) -> Self {
+        Self {
+            world,
+            components: Vec::new(),
+            resources: Vec::new(),
+        }
+    }
+
+    /// Adds all components of the bundle to the components to capture.
+    ///
+    /// Components that were never registered in the world are ignored.
+    pub fn allow<T: Bundle>(&mut self) -> &mut Self {
+        let mut ids = Vec::new();
+        T::get_component_ids(self.world.components(), &mut |id| ids.extend(id));
+        self.allow_by_ids(ids)
+    }
+
+    /// Extends the components to capture.
+    pub fn allow_by_ids(&mut self, ids: impl IntoIterator<Item = ComponentId>) -> &mut Self {
+        for id in ids {
+            if !self.components.contains(&id) {
+                self.components.push(id);
+            }
+        }
+        self
+    }
+
+    /// Adds the resource `R` to the resources to capture.
+    ///
+    /// Resources that were never registered in the world are ignored.
+    pub fn allow_resource<R: Resource>(&mut self) -> &mut Self {
+        self.allow_resources_by_ids(self.world.components().resource_id::<R>())
+    }
+
+    /// Extends the resources to capture.
+    pub fn allow_resources_by_ids(
+        &mut self,
+        ids: impl IntoIterator<Item = ComponentId>,
+    ) -> &mut Self {
+        for id in ids {
+            if !self.resources.contains(&id) {
+                self.resources.push(id);
+            }
+        }
+        self
+    }
+
+    /// Captures the selected components and resources of the world.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the world has no [`AppTypeRegistry`], or if one of the selected types
+    /// is not registered with [`ReflectComponent`] or [`ReflectResource`].
+    pub fn build(&self) -> Result<WorldSnapshot, SnapshotError> {
+        let world = self.world;
+        let registry = world
+            .get_resource::<AppTypeRegistry>()
+            .ok_or(SnapshotError::MissingAppTypeRegistry)?
+            .read();
+        let components = reflect_components(world, &registry, &self.components)?;
+
+        let mut entities = Vec::new();
+        for archetype in world.archetypes().iter() {
+            if !components.iter().any(|(id, _)| archetype.contains(*id)) {
+                continue;
+            }
+            for archetype_entity in archetype.entities() {
+                let entity = world.entity(archetype_entity.id());
+                let values = components
+                    .iter()
+                    .filter_map(|(id, reflect_component)| {
+                        let value = reflect_component.reflect(entity)?;
+                        Some((*id, value.clone_value()))
+                    })
+                    .collect();
+                entities.push((entity.id(), values));
+            }
+        }
+
+        let mut resources = Vec::new();
+        for (id, reflect_resource) in reflect_resources(world, &registry, &self.resources)? {
+            if let Some(value) = reflect_resource.reflect(world) {
+                resources.push((id, value.clone_value()));
+            }
+        }
+
+        Ok(WorldSnapshot {
+            alive: world.iter_entities().map(|entity| entity.id()).collect(),
+            components: self.components.clone(),
+            resources: self.resources.clone(),
+            entities,
+            resource_values: resources,
+        })
+    }
+}
+
+/// The values of a set of components and resources of a [`World`] at some point in time.
+///
+/// Created with a [`SnapshotBuilder`]. See the [module docs](crate::world::snapshot) for more
+/// information.
+#[derive(Debug)]
+pub struct WorldSnapshot {
+    alive: EntityHashSet,
+    components: Vec<ComponentId>,
+    resources: Vec<ComponentId>,
+    entities: Vec<(Entity, Vec<(ComponentId, Box<dyn PartialReflect>)>)>,
+    resource_values: Vec<(ComponentId, Box<dyn PartialReflect>)>,
+}
+
+impl WorldSnapshot {
+    /// Returns the components captured by this snapshot.
+    pub fn components(&self) -> &[ComponentId] {
+        &self.components
+    }
+
+    /// Returns the resources captured by this snapshot.
+    pub fn resources(&self) -> &[ComponentId] {
+        &self.resources
+    }
+
+    /// Returns the entities that had at least one of the captured components.
+    pub fn entities(&self) -> impl Iterator<Item = Entity> + '_ {
+        self.entities.iter().map(|(entity, _)| *entity)
+    }
+
+    /// Rolls the world back to this snapshot.
+    ///
+    /// - Entities that were spawned after the snapshot was taken and have one of the captured
+    ///   components are despawned.
+    /// - Entities that existed when the snapshot was taken but have gained one of the captured
+    ///   components since have those components removed.
+    /// - Entities of the snapshot that no longer exist are re-spawned with the same [`Entity`] id,
+    ///   or with a newer generation if their index was used by another entity since.
+    /// - The captured components of the snapshot entities are inserted again, and the captured
+    ///   components they didn't have are removed. Other components are left untouched.
+    /// - The captured resources are inserted again, or removed if they didn't exist.
+    ///
+    /// Components and resources are inserted even if their value didn't change, so their hooks
+    /// and observers run and change detection picks them up.
+    ///
+    /// Returns the entities that were re-spawned with a newer generation, mapped from their id in
+    /// the snapshot to their new id.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error, without changing the world, if the world has no [`AppTypeRegistry`], if
+    /// one of the captured types is not registered with [`ReflectComponent`] or
+    /// [`ReflectResource`], or if the id of a despawned entity is now used by an entity that is
+    /// not part of the snapshot.
+    pub fn restore(&self, world: &mut World) -> Result<EntityHashMap<Entity>, SnapshotError> {
+        let registry = world
+            .get_resource::<AppTypeRegistry>()
+            .ok_or(SnapshotError::MissingAppTypeRegistry)?
+            .clone();
+        let registry = registry.read();
+        let components = reflect_components(world, &registry, &self.components)?;
+        let resources = reflect_resources(world, &registry, &self.resources)?;
+
+        world.flush();
+        let snapshot_entities = self.entities().collect::<EntityHashSet>();
+        let mut added_since = Vec::new();
+        for archetype in world.archetypes().iter() {
+            if components.iter().any(|(id, _)| archetype.contains(*id)) {
+                added_since.extend(
+                    archetype
+                        .entities()
+                        .iter()
+                        .map(ArchetypeEntity::id)
+                        .filter(|entity| !snapshot_entities.contains(entity)),
+                );
+            }
+        }
+        let despawned = added_since
+            .iter()
+            .copied()
+            .filter(|entity| !self.alive.contains(entity))
+            .collect::<EntityHashSet>();
+        for entity in self.entities() {
+            if let Some(current) = world.entities().resolve_from_id(entity.index()) {
+                if current != entity
+                    && world.get_entity(current).is_ok()
+                    && !despawned.contains(&current)
+                {
+                    return Err(SnapshotError::EntityIdInUse { entity, current });
+                }
+            }
+        }
+
+        for entity in added_since {
+            if !despawned.contains(&entity) {
+                let mut entity_mut = world.entity_mut(entity);
+                for (_, reflect_component) in &components {
+                    reflect_component.remove(&mut entity_mut);
+                }
+            } else {
+                world.despawn(entity);
+            }
+        }
+
+        let mut respawned = EntityHashMap::default();
+        for entity in self.entities() {
+            world.flush();
+            if world.get_entity(entity).is_ok() {
+                continue;
+            }
+            // The generation of the entity can only be reused if its index was not used since it
+            // was despawned, otherwise handles to the entities that used it would become valid.
+            let unused = Entity::from_raw_and_generation(
+                entity.index(),
+                IdentifierMask::inc_masked_high_by(NonZero::new(entity.generation()).unwrap(), 1),
+            );
+            let new = match world.entities().resolve_from_id(entity.index()) {
+                Some(current) if current != unused => current,
+                _ => entity,
+            };
+            match world.entities.alloc_at_without_replacement(new) {
+                // SAFETY: entity was just allocated
+                AllocAtWithoutReplacement::DidNotExist => unsafe {
+                    world.spawn_at_empty_internal(new);
+                },
+                AllocAtWithoutReplacement::Exists(_)
+                | AllocAtWithoutReplacement::ExistsWithWrongGeneration => {
+                    unreachable!("entities using the ids of snapshot entities were despawned")
+                }
+            }
+            if new != entity {
+                respawned.insert(entity, new);
+            }
+        }
+
+        for (entity, values) in &self.entities {
+            let entity = respawned.get(entity).copied().unwrap_or(*entity);
+            for (id, reflect_component) in &components {
+                match values.iter().find(|(value_id, _)| value_id == id) {
+                    Some((_, value)) => {
+                        let mapped = map_respawned(world, &registry, *id, &**value, &respawned);
+                        reflect_component.insert(
+                            &mut world.entity_mut(entity),
+                            mapped.as_deref().unwrap_or(&**value),
+                            &registry,
+                        );
+                    }
+                    None => reflect_component.remove(&mut world.entity_mut(entity)),
+                }
+            }
+        }
+
+        for (id, reflect_resource) in &resources {
+            match self
+                .resource_values
+                .iter()
+                .find(|(value_id, _)| value_id == id)
+            {
+                Some((_, value)) => {
+                    let mapped = map_respawned(world, &registry, *id, &**value, &respawned);
+                    reflect_resource.insert(
+                        world,
+                        mapped.as_deref().unwrap_or(&**value),
+                        &registry,
+                    );
+                }
+                None => reflect_resource.remove(world),
+            }
+        }
+        world.flush();
+
+        Ok(respawned)
+    }
+}
+
+/// An error that occurs when taking or restoring a [`WorldSnapshot`].
+#[derive(Error, Debug)]
+pub enum SnapshotError {
+    /// The [`World`] was missing the [`AppTypeRegistry`] resource.
+    #[error("The `World` was missing the `AppTypeRegistry` resource")]
+    MissingAppTypeRegistry,
+    /// A captured component or resource is not registered with [`ReflectComponent`] or
+    /// [`ReflectResource`].
+    #[error("`{name}` ({id:?}) cannot be captured: it is not registered with `ReflectComponent` or `ReflectResource`")]
+    NotReflected {
+        /// The id of the component or resource.
+        id: ComponentId,
+        /// The name of the component or resource.
+        name: String,
+    },
+    /// The id of a snapshot entity that was despawned is now used by another entity.
+    #[error("Cannot re-spawn {entity:?}: its id is used by {current:?}, which is not part of the snapshot")]
+    EntityIdInUse {
+        /// The snapshot entity.
+        entity: Entity,
+        /// The entity currently using its id.
+        current: Entity,
+    },
+}
+
+/// Maps the entities of a snapshot that were re-spawned with a newer generation.
+struct RespawnedMapper<'a>(&'a EntityHashMap<Entity>);
+
+impl EntityMapper for RespawnedMapper<'_> {
+    fn map_entity(&mut self, entity: Entity) -> Entity {
+        self.0.get(&entity).copied().unwrap_or(entity)
+    }
+}
+
+/// Returns a copy of `value` with the references to `respawned` entities mapped, if there are any
+/// and the type of `id` is registered with [`ReflectMapEntities`].
+fn map_respawned(
+    world: &World,
+    registry: &TypeRegistry,
+    id: ComponentId,
+    value: &dyn PartialReflect,
+    respawned: &EntityHashMap<Entity>,
+) -> Option<Box<dyn PartialReflect>> {
+    if respawned.is_empty() {
+        return None;
+    }
+    let map_entities = world
+        .components()
+        .get_info(id)
+        .and_then(ComponentInfo::type_id)
+        .and_then(|type_id| registry.get_type_data::<ReflectMapEntities>(type_id))?;
+    let mut value = value.clone_value();
+    map_entities.map_entities(&mut *value, &mut RespawnedMapper(respawned));
+    Some(value)
+}
+
+fn not_reflected(world: &World, id: ComponentId) -> SnapshotError {
+    SnapshotError::NotReflected {
+        id,
+        name: world
+            .components()
+            .get_name(id)
+            .unwrap_or("<unknown>")
+            .to_owned(),
+    }
+}
+
+fn reflect_components<'r>(
+    world: &World,
+    registry: &'r TypeRegistry,
+    ids: &[ComponentId],
+) -> Result<Vec<(ComponentId, &'r ReflectComponent)>, SnapshotError> {
+    ids.iter()
+        .map(|&id| {
+            world
+                .components()
+                .get_info(id)
+                .and_then(ComponentInfo::type_id)
+                .and_then(|type_id| registry.get_type_data::<ReflectComponent>(type_id))
+                .map(|reflect_component| (id, reflect_component))
+                .ok_or_else(|| not_reflected(world, id))
+        })
+        .collect()
+}
+
+fn reflect_resources<'r>(
+    world: &World,
+    registry: &'r TypeRegistry,
+    ids: &[ComponentId],
+) -> Result<Vec<(ComponentId, &'r ReflectResource)>, SnapshotError> {
+    ids.iter()
+        .map(|&id| {
+            world
+                .components()
+                .get_info(id)
+                .and_then(ComponentInfo::type_id)
+                .and_then(|type_id| registry.get_type_data::<ReflectResource>(type_id))
+                .map(|reflect_resource| (id, reflect_resource))
+                .ok_or_else(|| not_reflected(world, id))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SnapshotBuilder, SnapshotError};
+    use crate as bevy_ecs;
+    use crate::{
+        component::Component,
+        entity::{Entity, VisitEntities, VisitEntitiesMut},
+        reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities, ReflectResource},
+        system::Resource,
+        world::World,
+    };
+    use bevy_reflect::Reflect;
+
+    #[derive(Component, Reflect, PartialEq, Debug)]
+    #[reflect(Component)]
+    struct Position(i32);
+
+    #[derive(Component, Reflect, PartialEq, Debug)]
+    #[reflect(Component)]
+    #[component(immutable)]
+    struct Team(u8);
+
+    #[derive(Component, PartialEq, Debug)]
+    struct Untracked;
+
+    #[derive(Component, Reflect, PartialEq, Debug, VisitEntities, VisitEntitiesMut)]
+    #[reflect(Component, MapEntities)]
+    struct Target(Entity);
+
+    #[derive(Resource, Reflect, PartialEq, Debug)]
+    #[reflect(Resource)]
+    struct Frame(u32);
+
+    fn world() -> World {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = world.resource::<AppTypeRegistry>().write();
+            registry.register::<Position>();
+            registry.register::<Team>();
+            registry.register::<Target>();
+            registry.register::<Frame>();
+        }
+        world
+    }
+
+    #[test]
+    fn restore_components_and_resources() {
+        let mut world = world();
+        world.insert_resource(Frame(1));
+        let a = world.spawn((Position(1), Team(1), Untracked)).id();
+        let b = world.spawn(Position(2)).id();
+
+        let snapshot = SnapshotBuilder::new(&world)
+            .allow::<(Position, Team)>()
+            .allow_resource::<Frame>()
+            .build()
+            .unwrap();
+
+        world.resource_mut::<Frame>().0 = 2;
+        world.entity_mut(a).insert(Position(10)).remove::<Team>();
+        world.entity_mut(b).insert(Team(2));
+
+        snapshot.restore(&mut world).unwrap();
+        assert_eq!(world.resource::<Frame>(), &Frame(1));
+        assert_eq!(world.get::<Position>(a), Some(&Position(1)));
+        assert_eq!(world.get::<Team>(a), Some(&Team(1)));
+        assert_eq!(world.get::<Untracked>(a), Some(&Untracked));
+        assert_eq!(world.get::<Position>(b), Some(&Position(2)));
+        assert_eq!(world.get::<Team>(b), None);
+
+        // The same snapshot can be restored again.
+        world.remove_resource::<Frame>();
+        world.entity_mut(b).insert(Position(20));
+        snapshot.restore(&mut world).unwrap();
+        assert_eq!(world.resource::<Frame>(), &Frame(1));
+        assert_eq!(world.get::<Position>(b), Some(&Position(2)));
+    }
+
+    #[test]
+    fn restore_entities() {
+        let mut world = world();
+        let existing = world.spawn(Untracked).id();
+        let despawned = world.spawn((Position(1), Untracked)).id();
+
+        let snapshot = SnapshotBuilder::new(&world)
+            .allow::<Position>()
+            .build()
+            .unwrap();
+
+        let spawned_untracked = world.spawn(Untracked).id();
+        world.despawn(despawned);
+        world.entity_mut(existing).insert(Position(3));
+
+        // The index of `despawned` is free again, so it is re-spawned with the same id.
+        let respawned = snapshot.restore(&mut world).unwrap();
+        assert!(respawned.is_empty());
+        assert_eq!(world.get::<Position>(despawned), Some(&Position(1)));
+        // Components that weren't captured are not re-spawned.
+        assert_eq!(world.get::<Untracked>(despawned), None);
+        assert!(world.get_entity(spawned_untracked).is_ok());
+        assert_eq!(world.get::<Position>(existing), None);
+        assert_eq!(world.get::<Untracked>(existing), Some(&Untracked));
+    }
+
+    #[test]
+    fn restore_entities_with_reused_index() {
+        let mut world = world();
+        let despawned = world.spawn(Position(1)).id();
+        let target = world.spawn(Target(despawned)).id();
+
+        let snapshot = SnapshotBuilder::new(&world)
+            .allow::<(Position, Target)>()
+            .build()
+            .unwrap();
+
+        world.despawn(despawned);
+        let spawned = world.spawn(Position(2)).id();
+        assert_eq!(spawned.index(), despawned.index());
+
+        // Generations keep moving forward, so handles to `spawned` stay invalid.
+        let respawned = snapshot.restore(&mut world).unwrap();
+        assert!(world.get_entity(spawned).is_err());
+        assert!(world.get_entity(despawned).is_err());
+        let new = respawned[&despawned];
+        assert_eq!(new.index(), despawned.index());
+        assert!(new.generation() > spawned.generation());
+        assert_eq!(world.get::<Position>(new), Some(&Position(1)));
+        assert_eq!(world.get::<Target>(target), Some(&Target(new)));
+    }
+
+    #[test]
+    fn restore_entity_id_in_use() {
+        let mut world = world();
+        let despawned = world.spawn(Position(1)).id();
+
+        let snapshot = SnapshotBuilder::new(&world)
+            .allow::<Position>()
+            .build()
+            .unwrap();
+
+        world.despawn(despawned);
+        let reused = world.spawn(Untracked).id();
+        assert_eq!(reused.index(), despawned.index());
+
+        assert!(matches!(
+            snapshot.restore(&mut world),
+            Err(SnapshotError::EntityIdInUse { entity, current })
+                if entity == despawned && current == reused
+        ));
+        assert!(world.get_entity(reused).is_ok());
+    }
+
+    #[test]
+    fn snapshot_requires_reflection() {
+        let mut world = world();
+        world.spawn(Untracked);
+
+        assert!(matches!(
+            SnapshotBuilder::new(&world).allow::<Untracked>().build(),
+            Err(SnapshotError::NotReflected { .. })
+        ));
+
+        world.remove_resource::<AppTypeRegistry>();
+        assert!(matches!(
+            SnapshotBuilder::new(&world).allow::<Position>().build(),
+            Err(SnapshotError::MissingAppTypeRegistry)
+        ));
+    }
+}
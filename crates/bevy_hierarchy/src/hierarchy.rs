@@ -9,6 +9,11 @@ use bevy_ecs::{
     world::{Command, DeferredWorld, EntityWorldMut, World},
 };
 use bevy_utils::tracing::debug;
+#[cfg(feature = "reflect")]
+use {
+    alloc::vec,
+    bevy_ecs::entity::{EntityHashMap, EntityTransferBuilder, EntityTransferError},
+};
 
 /// Despawns the given entity and all its children recursively
 #[derive(Debug)]
@@ -263,6 +268,58 @@ fn component_clone_parent(world: &mut DeferredWorld, entity_cloner: &EntityClone
         .set_parent(parent);
 }
 
+/// Trait that holds functions for transferring entities recursively down the hierarchy between worlds.
+#[cfg(feature = "reflect")]
+pub trait TransferEntityHierarchyExt {
+    /// Clones `root` and all its descendants into the destination world, keeping their hierarchy.
+    ///
+    /// The clone of `root` has no parent. Returns the map from the source entities to their clones.
+    fn clone_hierarchy(
+        &mut self,
+        root: Entity,
+    ) -> Result<EntityHashMap<Entity>, EntityTransferError>;
+    /// Moves `root` and all its descendants into the destination world, keeping their hierarchy.
+    ///
+    /// `root` is removed from the children of its parent in the source world, and the moved `root`
+    /// has no parent. Returns the map from the source entities to the new entities.
+    fn move_hierarchy(
+        &mut self,
+        root: Entity,
+    ) -> Result<EntityHashMap<Entity>, EntityTransferError>;
+}
+
+#[cfg(feature = "reflect")]
+impl TransferEntityHierarchyExt for EntityTransferBuilder<'_> {
+    fn clone_hierarchy(
+        &mut self,
+        root: Entity,
+    ) -> Result<EntityHashMap<Entity>, EntityTransferError> {
+        let mut entities = vec![root];
+        let mut i = 0;
+        while let Some(&entity) = entities.get(i) {
+            if let Some(children) = self.source().get::<Children>(entity) {
+                entities.extend(children.iter().copied());
+            }
+            i += 1;
+        }
+
+        let entity_map = self.clone_entities(entities)?;
+        self.destination_mut()
+            .entity_mut(entity_map[&root])
+            .remove::<Parent>();
+        Ok(entity_map)
+    }
+
+    fn move_hierarchy(
+        &mut self,
+        root: Entity,
+    ) -> Result<EntityHashMap<Entity>, EntityTransferError> {
+        let entity_map = self.clone_hierarchy(root)?;
+        self.source_mut().entity_mut(root).despawn_recursive();
+        Ok(entity_map)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use bevy_ecs::{
@@ -495,4 +552,53 @@ mod tests {
             .get::<Children>()
             .is_some_and(|c| c.contains(&child_clone)));
     }
+
+    #[cfg(feature = "reflect")]
+    #[test]
+    fn move_hierarchy_between_worlds() {
+        use crate::{BuildChildren, Parent, TransferEntityHierarchyExt};
+        use bevy_ecs::{entity::EntityTransferBuilder, reflect::AppTypeRegistry};
+
+        let mut source = World::default();
+        source.init_resource::<AppTypeRegistry>();
+        {
+            let mut registry = source.resource::<AppTypeRegistry>().write();
+            registry.register::<Parent>();
+            registry.register::<Children>();
+        }
+        let grandchild = source.spawn_empty().id();
+        let child = source.spawn_empty().add_child(grandchild).id();
+        let root = source.spawn_empty().add_child(child).id();
+        let parent = source.spawn_empty().add_child(root).id();
+
+        let mut destination = World::default();
+        let entity_map = EntityTransferBuilder::new(&mut source, &mut destination)
+            .move_hierarchy(root)
+            .unwrap();
+
+        assert_eq!(entity_map.len(), 3);
+        let (new_root, new_child, new_grandchild) = (
+            entity_map[&root],
+            entity_map[&child],
+            entity_map[&grandchild],
+        );
+        assert!(destination.get::<Parent>(new_root).is_none());
+        assert_eq!(
+            destination.get::<Children>(new_root).unwrap().as_ref(),
+            &[new_child]
+        );
+        assert_eq!(
+            destination.get::<Parent>(new_child).unwrap().get(),
+            new_root
+        );
+        assert_eq!(
+            destination.get::<Parent>(new_grandchild).unwrap().get(),
+            new_child
+        );
+
+        for entity in [root, child, grandchild] {
+            assert!(source.get_entity(entity).is_err());
+        }
+        assert!(source.get::<Children>(parent).is_none_or(|c| c.is_empty()));
+    }
 }
@@ -3,26 +3,41 @@
 use core::any::TypeId;
 
 use anyhow::{anyhow, Result as AnyhowResult};
+#[cfg(feature = "bevy_asset")]
+use bevy_asset::{AssetPath, AssetServer};
 use bevy_ecs::{
     component::ComponentId,
+    dynamic_component::{
+        DynamicComponentData, DynamicComponentLayout, DynamicComponentRef,
+        DynamicComponentRegistry, DynamicComponentSet, DynamicField, DynamicFieldKind,
+        DynamicValue,
+    },
     entity::Entity,
     event::EventCursor,
     query::QueryBuilder,
-    reflect::{AppTypeRegistry, ReflectComponent},
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
     removal_detection::RemovedComponentEntity,
+    schedule::{ScheduleExportSettings, ScheduleGraphFormat, Schedules},
     system::{In, Local},
     world::{EntityRef, EntityWorldMut, FilteredEntityRef, World},
 };
 use bevy_hierarchy::BuildChildren as _;
 use bevy_reflect::{
-    serde::{ReflectSerializer, TypedReflectDeserializer},
-    PartialReflect, TypeRegistration, TypeRegistry,
+    serde::{ReflectSerializer, TypedReflectDeserializer, TypedReflectSerializer},
+    FromReflect as _, GetPath as _, PartialReflect, TypeRegistration, TypeRegistry,
 };
 use bevy_utils::HashMap;
 use serde::{de::DeserializeSeed as _, Deserialize, Serialize};
 use serde_json::{Map, Value};
 
-use crate::{error_codes, BrpError, BrpResult};
+use crate::{
+    error_codes,
+    schemas::{
+        json_schema::{export_type, JsonSchemaBevyType},
+        open_rpc::{InfoObject, MethodObject, OpenRpcDocument},
+    },
+    BrpError, BrpResult, RemoteMethods,
+};
 
 /// The method path for a `bevy/get` request.
 pub const BRP_GET_METHOD: &str = "bevy/get";
@@ -36,6 +51,9 @@ pub const BRP_SPAWN_METHOD: &str = "bevy/spawn";
 /// The method path for a `bevy/insert` request.
 pub const BRP_INSERT_METHOD: &str = "bevy/insert";
 
+/// The method path for a `bevy/mutate_component` request.
+pub const BRP_MUTATE_COMPONENT_METHOD: &str = "bevy/mutate_component";
+
 /// The method path for a `bevy/remove` request.
 pub const BRP_REMOVE_METHOD: &str = "bevy/remove";
 
@@ -54,6 +72,36 @@ pub const BRP_GET_AND_WATCH_METHOD: &str = "bevy/get+watch";
 /// The method path for a `bevy/list+watch` request.
 pub const BRP_LIST_AND_WATCH_METHOD: &str = "bevy/list+watch";
 
+/// The method path for a `bevy/get_resource` request.
+pub const BRP_GET_RESOURCE_METHOD: &str = "bevy/get_resource";
+
+/// The method path for a `bevy/insert_resource` request.
+pub const BRP_INSERT_RESOURCE_METHOD: &str = "bevy/insert_resource";
+
+/// The method path for a `bevy/mutate_resource` request.
+pub const BRP_MUTATE_RESOURCE_METHOD: &str = "bevy/mutate_resource";
+
+/// The method path for a `bevy/remove_resource` request.
+pub const BRP_REMOVE_RESOURCE_METHOD: &str = "bevy/remove_resource";
+
+/// The method path for a `bevy/list_resources` request.
+pub const BRP_LIST_RESOURCES_METHOD: &str = "bevy/list_resources";
+
+/// The method path for a `bevy/registry/schema` request.
+pub const BRP_REGISTRY_SCHEMA_METHOD: &str = "bevy/registry/schema";
+
+/// The method path for a `bevy/schedule/graph` request.
+pub const BRP_SCHEDULE_GRAPH_METHOD: &str = "bevy/schedule/graph";
+
+/// The method path for a `bevy/storage/stats` request.
+pub const BRP_STORAGE_STATS_METHOD: &str = "bevy/storage/stats";
+
+/// The method path for a `bevy/asset/dependency_graph` request.
+pub const BRP_ASSET_DEPENDENCY_GRAPH_METHOD: &str = "bevy/asset/dependency_graph";
+
+/// The method path for a `rpc.discover` request.
+pub const RPC_DISCOVER_METHOD: &str = "rpc.discover";
+
 /// `bevy/get`: Retrieves one or more components from the entity with the given
 /// ID.
 ///
@@ -160,6 +208,30 @@ pub struct BrpInsertParams {
     pub components: HashMap<String, Value>,
 }
 
+/// `bevy/mutate_component`: Sets the value of a field of a component on an entity.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpMutateComponentParams {
+    /// The ID of the entity with the component to mutate.
+    pub entity: Entity,
+
+    /// The [full path] of the component type being mutated.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub component: String,
+
+    /// The [path] of the field within the component, e.g. `.translation.x`.
+    ///
+    /// An empty path refers to the entire component.
+    ///
+    /// [path]: bevy_reflect::GetPath
+    pub path: String,
+
+    /// The serialized value that the field is set to.
+    pub value: Value,
+}
+
 /// `bevy/reparent`: Assign a new parent to one or more entities.
 ///
 /// The server responds with a null.
@@ -187,6 +259,166 @@ pub struct BrpListParams {
     pub entity: Entity,
 }
 
+/// `bevy/get_resource`: Retrieves the value of a given resource.
+///
+/// The server responds with a [`BrpGetResourceResponse`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpGetResourceParams {
+    /// The [full path] of the resource type being requested.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub resource: String,
+}
+
+/// `bevy/insert_resource`: Inserts a resource into the world with a given
+/// value, replacing any existing value.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpInsertResourceParams {
+    /// The [full path] of the resource type being inserted.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub resource: String,
+
+    /// The serialized value of the resource to be inserted.
+    pub value: Value,
+}
+
+/// `bevy/mutate_resource`: Sets the value of a field of a resource.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpMutateResourceParams {
+    /// The [full path] of the resource type being mutated.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub resource: String,
+
+    /// The [path] of the field within the resource, e.g. `.volume` or `.color.0`.
+    ///
+    /// An empty path refers to the entire resource.
+    ///
+    /// [path]: bevy_reflect::GetPath
+    pub path: String,
+
+    /// The serialized value that the field is set to.
+    pub value: Value,
+}
+
+/// `bevy/remove_resource`: Removes the given resource from the world.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpRemoveResourceParams {
+    /// The [full path] of the resource type being removed.
+    ///
+    /// [full path]: bevy_reflect::TypePath::type_path
+    pub resource: String,
+}
+
+/// `bevy/registry/schema`: Returns the JSON Schema of every reflected type in the
+/// [`AppTypeRegistry`], optionally filtered by crate and by registered type data.
+///
+/// The server responds with a [`BrpJsonSchemaResponse`].
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct BrpJsonSchemaQueryFilter {
+    /// The crates from which types should be included.
+    ///
+    /// If empty, types from every crate are included.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub with_crates: Vec<String>,
+
+    /// The crates from which types should be excluded.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub without_crates: Vec<String>,
+
+    /// Constraints on the type data registered for the included types.
+    #[serde(default)]
+    pub type_limit: JsonSchemaTypeLimit,
+}
+
+/// `bevy/schedule/graph`: Exports the graph of the systems of a schedule, to render it with
+/// external tools.
+///
+/// The server responds with a [`BrpScheduleGraphResponse`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpScheduleGraphParams {
+    /// The label of the schedule, as printed by its `Debug` implementation: e.g. `Update`.
+    pub schedule: String,
+
+    /// The format of the graph. Defaults to `dot`.
+    #[serde(default)]
+    pub format: BrpScheduleGraphFormat,
+
+    /// Whether the outermost system sets are drawn as single nodes. Defaults to false.
+    #[serde(default)]
+    pub collapse_sets: bool,
+
+    /// Whether systems with ambiguous order are connected. Defaults to true.
+    #[serde(default = "default_true")]
+    pub show_ambiguities: bool,
+
+    /// Whether systems and system sets with run conditions are highlighted. Defaults to true.
+    #[serde(default = "default_true")]
+    pub color_conditions: bool,
+}
+
+/// The format of the graph returned by a `bevy/schedule/graph` request.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BrpScheduleGraphFormat {
+    /// The Graphviz DOT language.
+    #[default]
+    Dot,
+    /// A Mermaid flowchart.
+    Mermaid,
+}
+
+/// `bevy/asset/dependency_graph`: Exports the graph of the assets an asset depends on and the
+/// assets that depend on it, with their loaders and load states.
+///
+/// The server responds with a [`BrpAssetDependencyGraphResponse`].
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpAssetDependencyGraphParams {
+    /// The path of the asset: e.g. `models/ship.gltf#Scene0`.
+    pub path: String,
+
+    /// The format of the graph. Defaults to `json`.
+    #[serde(default)]
+    pub format: BrpAssetDependencyGraphFormat,
+}
+
+/// The format of the graph returned by a `bevy/asset/dependency_graph` request.
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, Default, PartialEq, Eq)]
+#[serde(rename_all = "lowercase")]
+pub enum BrpAssetDependencyGraphFormat {
+    /// A JSON object with the `roots`, `nodes` and `edges` of the graph.
+    #[default]
+    Json,
+    /// The Graphviz DOT language.
+    Dot,
+}
+
+fn default_true() -> bool {
+    true
+}
+
+/// Constraints on the type data registered for types, such as `Component` for
+/// [`ReflectComponent`] or `Resource` for [`ReflectResource`].
+#[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
+pub struct JsonSchemaTypeLimit {
+    /// Types must have at least one of these registered to be included.
+    ///
+    /// If empty, this constraint is ignored.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub with: Vec<String>,
+
+    /// Types must have none of these registered to be included.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub without: Vec<String>,
+}
+
 /// Describes the data that is to be fetched in a query.
 #[derive(Debug, Serialize, Deserialize, Clone, Default, PartialEq)]
 pub struct BrpQuery {
@@ -284,6 +516,16 @@ pub enum BrpGetWatchingResponse {
 /// The response to a `bevy/list` request.
 pub type BrpListResponse = Vec<String>;
 
+/// The response to a `bevy/get_resource` request.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpGetResourceResponse {
+    /// The value of the requested resource.
+    pub value: Value,
+}
+
+/// The response to a `bevy/list_resources` request.
+pub type BrpListResourcesResponse = Vec<String>;
+
 /// A single response from a `bevy/list+watch` request.
 #[derive(Debug, Default, Serialize, Deserialize, Clone, PartialEq)]
 pub struct BrpListWatchingResponse {
@@ -294,6 +536,72 @@ pub struct BrpListWatchingResponse {
 /// The response to a `bevy/query` request.
 pub type BrpQueryResponse = Vec<BrpQueryRow>;
 
+/// The response to a `bevy/schedule/graph` request.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpScheduleGraphResponse {
+    /// The graph of the schedule, in the requested format.
+    pub graph: String,
+}
+
+/// The response to a `bevy/asset/dependency_graph` request.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpAssetDependencyGraphResponse {
+    /// The graph of the asset, as a JSON object or a string depending on the requested format.
+    pub graph: Value,
+}
+
+/// The response to a `bevy/storage/stats` request.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpStorageStatsResponse {
+    /// The number of archetypes. Archetypes are never removed, so the difference between two
+    /// responses is the number of archetypes created in between.
+    pub archetype_count: usize,
+    /// The number of archetypes without entities.
+    pub empty_archetype_count: usize,
+    /// The number of entities stored in tables, divided by the number of entities they can store
+    /// without reallocating.
+    pub table_occupancy: f64,
+    /// The number of bytes allocated by tables and sparse sets.
+    pub allocated_bytes: usize,
+    /// The number of bytes allocated to store each component, by component name.
+    pub component_bytes: HashMap<String, usize>,
+    /// The statistics of every archetype.
+    pub archetypes: Vec<BrpArchetypeStats>,
+    /// The statistics of every table.
+    pub tables: Vec<BrpTableStats>,
+}
+
+/// The statistics of an archetype, in a `bevy/storage/stats` response.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct BrpArchetypeStats {
+    /// The index of the archetype.
+    pub id: usize,
+    /// The index of the table storing the table components of the archetype.
+    pub table: usize,
+    /// The number of entities in the archetype.
+    pub entity_count: usize,
+    /// The names of the components of the archetype.
+    pub components: Vec<String>,
+}
+
+/// The statistics of a table, in a `bevy/storage/stats` response.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Eq)]
+pub struct BrpTableStats {
+    /// The index of the table.
+    pub id: usize,
+    /// The number of entities stored in the table.
+    pub entity_count: usize,
+    /// The number of entities the table can store without reallocating.
+    pub capacity: usize,
+    /// The number of bytes allocated by the table.
+    pub allocated_bytes: usize,
+}
+
+/// The response to a `bevy/registry/schema` request, mapping each [type path] to its JSON Schema.
+///
+/// [type path]: bevy_reflect::TypePath::type_path
+pub type BrpJsonSchemaResponse = HashMap<String, JsonSchemaBevyType>;
+
 /// One query match result: a single entity paired with the requested components.
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct BrpQueryRow {
@@ -341,8 +649,14 @@ pub fn process_remote_get_request(In(params): In<Option<Value>>, world: &World)
     let type_registry = app_type_registry.read();
     let entity_ref = get_entity(world, entity)?;
 
-    let response =
-        reflect_components_to_response(components, strict, entity, entity_ref, &type_registry)?;
+    let response = reflect_components_to_response(
+        components,
+        strict,
+        entity,
+        entity_ref,
+        world,
+        &type_registry,
+    )?;
     serde_json::to_value(response).map_err(BrpError::internal)
 }
 
@@ -367,30 +681,19 @@ pub fn process_remote_get_watching_request(
     let mut errors = <HashMap<_, _>>::default();
 
     'component_loop: for component_path in components {
-        let Ok(type_registration) =
-            get_component_type_registration(&type_registry, &component_path)
-        else {
-            let err =
-                BrpError::component_error(format!("Unknown component type: `{component_path}`"));
-            if strict {
-                return Err(err);
-            }
-            errors.insert(
-                component_path,
-                serde_json::to_value(err).map_err(BrpError::internal)?,
-            );
-            continue;
-        };
-        let Some(component_id) = world.components().get_id(type_registration.type_id()) else {
-            let err = BrpError::component_error(format!("Unknown component: `{component_path}`"));
-            if strict {
-                return Err(err);
+        let component_id = match get_component_id(&type_registry, world, &component_path) {
+            Ok(component_id) => component_id,
+            Err(err) => {
+                let err = BrpError::component_error(err);
+                if strict {
+                    return Err(err);
+                }
+                errors.insert(
+                    component_path,
+                    serde_json::to_value(err).map_err(BrpError::internal)?,
+                );
+                continue;
             }
-            errors.insert(
-                component_path,
-                serde_json::to_value(err).map_err(BrpError::internal)?,
-            );
-            continue;
         };
 
         if let Some(ticks) = entity_ref.get_change_ticks_by_id(component_id) {
@@ -419,7 +722,7 @@ pub fn process_remote_get_watching_request(
     }
 
     let response =
-        reflect_components_to_response(changed, strict, entity, entity_ref, &type_registry)?;
+        reflect_components_to_response(changed, strict, entity, entity_ref, world, &type_registry)?;
 
     let response = match response {
         BrpGetResponse::Lenient {
@@ -450,6 +753,7 @@ fn reflect_components_to_response(
     strict: bool,
     entity: Entity,
     entity_ref: EntityRef,
+    world: &World,
     type_registry: &TypeRegistry,
 ) -> BrpResult<BrpGetResponse> {
     let mut response = if strict {
@@ -462,7 +766,7 @@ fn reflect_components_to_response(
     };
 
     for component_path in components {
-        match reflect_component(&component_path, entity, entity_ref, type_registry) {
+        match reflect_component(&component_path, entity, entity_ref, world, type_registry) {
             Ok(serialized_object) => match response {
                 BrpGetResponse::Strict(ref mut components)
                 | BrpGetResponse::Lenient {
@@ -489,8 +793,21 @@ fn reflect_component(
     component_path: &str,
     entity: Entity,
     entity_ref: EntityRef,
+    world: &World,
     type_registry: &TypeRegistry,
 ) -> BrpResult<Map<String, Value>> {
+    if let Some(component_id) = dynamic_component_id(type_registry, world, component_path) {
+        let Some(component) = world.get_dynamic(entity, component_id) else {
+            return Err(BrpError::component_not_present(component_path, entity));
+        };
+        let mut serialized_object = Map::new();
+        serialized_object.insert(
+            component_path.to_owned(),
+            dynamic_component_to_json(&component),
+        );
+        return Ok(serialized_object);
+    }
+
     let reflect_component =
         get_reflect_component(type_registry, component_path).map_err(BrpError::component_error)?;
 
@@ -532,71 +849,57 @@ pub fn process_remote_query_request(In(params): In<Option<Value>>, world: &mut W
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
 
-    let components =
-        get_component_ids(&type_registry, world, components).map_err(BrpError::component_error)?;
+    let components = get_queried_components(&type_registry, world, components)
+        .map_err(BrpError::component_error)?;
     let option =
-        get_component_ids(&type_registry, world, option).map_err(BrpError::component_error)?;
-    let has = get_component_ids(&type_registry, world, has).map_err(BrpError::component_error)?;
-    let without =
-        get_component_ids(&type_registry, world, without).map_err(BrpError::component_error)?;
-    let with = get_component_ids(&type_registry, world, with).map_err(BrpError::component_error)?;
+        get_queried_components(&type_registry, world, option).map_err(BrpError::component_error)?;
+    let has =
+        get_queried_components(&type_registry, world, has).map_err(BrpError::component_error)?;
+    let without = without
+        .iter()
+        .map(|component_path| get_component_id(&type_registry, world, component_path))
+        .collect::<AnyhowResult<Vec<_>>>()
+        .map_err(BrpError::component_error)?;
+    let with = with
+        .iter()
+        .map(|component_path| get_component_id(&type_registry, world, component_path))
+        .collect::<AnyhowResult<Vec<_>>>()
+        .map_err(BrpError::component_error)?;
 
     let mut query = QueryBuilder::<FilteredEntityRef>::new(world);
-    for (_, component) in &components {
-        query.ref_id(*component);
+    for component in &components {
+        query.ref_id(component.id);
     }
-    for (_, option) in &option {
+    for option in &option {
         query.optional(|query| {
-            query.ref_id(*option);
+            query.ref_id(option.id);
         });
     }
-    for (_, has) in &has {
+    for has in &has {
         query.optional(|query| {
-            query.ref_id(*has);
+            query.ref_id(has.id);
         });
     }
-    for (_, without) in without {
+    for without in without {
         query.without_id(without);
     }
-    for (_, with) in with {
+    for with in with {
         query.with_id(with);
     }
 
     // At this point, we can safely unify `components` and `option`, since we only retrieved
     // entities that actually have all the `components` already.
-    //
-    // We also will just collect the `ReflectComponent` values from the type registry all
-    // at once so that we can reuse them between components.
-    let paths_and_reflect_components: Vec<(&str, &ReflectComponent)> = components
-        .into_iter()
-        .chain(option)
-        .map(|(type_id, _)| reflect_component_from_id(type_id, &type_registry))
-        .collect::<AnyhowResult<Vec<(&str, &ReflectComponent)>>>()
-        .map_err(BrpError::component_error)?;
-
-    // ... and the analogous construction for `has`:
-    let has_paths_and_reflect_components: Vec<(&str, &ReflectComponent)> = has
-        .into_iter()
-        .map(|(type_id, _)| reflect_component_from_id(type_id, &type_registry))
-        .collect::<AnyhowResult<Vec<(&str, &ReflectComponent)>>>()
-        .map_err(BrpError::component_error)?;
+    let components: Vec<QueriedComponent> = components.into_iter().chain(option).collect();
 
     let mut response = BrpQueryResponse::default();
     let mut query = query.build();
     for row in query.iter(world) {
         // The map of component values:
-        let components_map = build_components_map(
-            row.clone(),
-            paths_and_reflect_components.iter().copied(),
-            &type_registry,
-        )
-        .map_err(BrpError::component_error)?;
+        let components_map = build_components_map(row.clone(), &components, &type_registry)
+            .map_err(BrpError::component_error)?;
 
         // The map of boolean-valued component presences:
-        let has_map = build_has_map(
-            row.clone(),
-            has_paths_and_reflect_components.iter().copied(),
-        );
+        let has_map = build_has_map(row.clone(), &has);
         response.push(BrpQueryRow {
             entity: row.id(),
             components: components_map,
@@ -614,8 +917,8 @@ pub fn process_remote_spawn_request(In(params): In<Option<Value>>, world: &mut W
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
 
-    let reflect_components =
-        deserialize_components(&type_registry, components).map_err(BrpError::component_error)?;
+    let reflect_components = deserialize_components(&type_registry, world, components)
+        .map_err(BrpError::component_error)?;
 
     let entity = world.spawn_empty();
     let entity_id = entity.id();
@@ -636,8 +939,8 @@ pub fn process_remote_insert_request(
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
 
-    let reflect_components =
-        deserialize_components(&type_registry, components).map_err(BrpError::component_error)?;
+    let reflect_components = deserialize_components(&type_registry, world, components)
+        .map_err(BrpError::component_error)?;
 
     insert_reflected_components(
         &type_registry,
@@ -649,6 +952,75 @@ pub fn process_remote_insert_request(
     Ok(Value::Null)
 }
 
+/// Handles a `bevy/mutate_component` request coming from a client.
+///
+/// This method allows you to mutate a single field inside a component, or the entire component
+/// when `path` is empty, without replacing the rest of it.
+pub fn process_remote_mutate_component_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpMutateComponentParams {
+        entity,
+        component,
+        path,
+        value,
+    } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    if let Some(component_id) = dynamic_component_id(&type_registry, world, &component) {
+        let mut entity_world_mut = get_entity_mut(world, entity)?;
+        let Some(mut dynamic) = entity_world_mut.get_dynamic_mut(component_id) else {
+            return Err(BrpError::component_not_present(&component, entity));
+        };
+        let data = match path.strip_prefix('.').unwrap_or(&path) {
+            "" => dynamic_component_from_json(dynamic.layout(), value),
+            field => dynamic
+                .layout()
+                .field(field)
+                .ok_or_else(|| anyhow!("Component `{component}` has no field `{field}`"))
+                .and_then(|field| Ok(dynamic_value_from_json(field.kind(), value)?))
+                .map(|value| DynamicComponentData::new(&component).with(field, value)),
+        }
+        .map_err(BrpError::component_error)?;
+        dynamic.apply(&data).map_err(BrpError::component_error)?;
+        return Ok(Value::Null);
+    }
+
+    let reflect_component =
+        get_reflect_component(&type_registry, &component).map_err(BrpError::component_error)?;
+
+    // Immutable components can only be replaced as a whole, with `bevy/insert`.
+    let immutable = get_component_id(&type_registry, world, &component)
+        .ok()
+        .and_then(|component_id| world.components().get_info(component_id))
+        .is_some_and(|info| !info.mutable());
+    if immutable {
+        return Err(BrpError::component_error(format!(
+            "Component `{component}` is immutable and cannot be mutated"
+        )));
+    }
+
+    let mut entity_world_mut = get_entity_mut(world, entity)?;
+    let Some(mut reflected) = reflect_component.reflect_mut(&mut entity_world_mut) else {
+        return Err(BrpError::component_not_present(&component, entity));
+    };
+
+    // Get the field within the component, then deserialize the new value based on its type.
+    let field = reflected
+        .reflect_path_mut(path.as_str())
+        .map_err(|err| BrpError::invalid_path(&path, &err))?;
+    let value = deserialize_reflected_field(&type_registry, field, value)
+        .map_err(BrpError::component_error)?;
+    field
+        .try_apply(&*value)
+        .map_err(BrpError::component_error)?;
+
+    Ok(Value::Null)
+}
+
 /// Handles a `bevy/remove` request (remove components) coming from a client.
 pub fn process_remote_remove_request(
     In(params): In<Option<Value>>,
@@ -659,12 +1031,15 @@ pub fn process_remote_remove_request(
     let app_type_registry = world.resource::<AppTypeRegistry>().clone();
     let type_registry = app_type_registry.read();
 
-    let component_ids =
-        get_component_ids(&type_registry, world, components).map_err(BrpError::component_error)?;
+    let component_ids = components
+        .iter()
+        .map(|component_path| get_component_id(&type_registry, world, component_path))
+        .collect::<AnyhowResult<Vec<_>>>()
+        .map_err(BrpError::component_error)?;
 
     // Remove the components.
     let mut entity_world_mut = get_entity_mut(world, entity)?;
-    for (_, component_id) in component_ids {
+    for component_id in component_ids {
         entity_world_mut.remove_by_id(component_id);
     }
 
@@ -734,10 +1109,17 @@ pub fn process_remote_list_request(In(params): In<Option<Value>>, world: &World)
     // If `None`, list all registered components.
     else {
         for registered_type in type_registry.iter() {
-            if registered_type.data::<ReflectComponent>().is_some() {
+            if registered_type.data::<ReflectComponent>().is_some()
+                && registered_type.type_id() != TypeId::of::<DynamicComponentSet>()
+            {
                 response.push(registered_type.type_info().type_path().to_owned());
             }
         }
+        if let Some(dynamic_components) = world.get_resource::<DynamicComponentRegistry>() {
+            for (_, layout) in dynamic_components.iter() {
+                response.push(layout.name().to_owned());
+            }
+        }
     }
 
     // Sort both for cleanliness and to reduce the risk that clients start
@@ -793,6 +1175,310 @@ pub fn process_remote_list_watching_request(
     }
 }
 
+/// Handles a `bevy/get_resource` request coming from a client.
+pub fn process_remote_get_resource_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let BrpGetResourceParams {
+        resource: resource_path,
+    } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+    let reflect_resource =
+        get_reflect_resource(&type_registry, &resource_path).map_err(BrpError::resource_error)?;
+
+    let Some(reflected) = reflect_resource.reflect(world) else {
+        return Err(BrpError::resource_not_present(&resource_path));
+    };
+
+    // Use the `TypedReflectSerializer` so that only the value is returned, without being
+    // wrapped in a map keyed by its type path.
+    let reflect_serializer =
+        TypedReflectSerializer::new(reflected.as_partial_reflect(), &type_registry);
+    let value = serde_json::to_value(reflect_serializer).map_err(BrpError::resource_error)?;
+
+    serde_json::to_value(BrpGetResourceResponse { value }).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/insert_resource` request coming from a client.
+pub fn process_remote_insert_resource_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpInsertResourceParams {
+        resource: resource_path,
+        value,
+    } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let reflected_resource = deserialize_resource(&type_registry, &resource_path, value)
+        .map_err(BrpError::resource_error)?;
+
+    let reflect_resource =
+        get_reflect_resource(&type_registry, &resource_path).map_err(BrpError::resource_error)?;
+    reflect_resource.insert(world, &*reflected_resource, &type_registry);
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/mutate_resource` request coming from a client.
+///
+/// This method allows you to mutate a single field inside a resource, or the entire resource
+/// when `path` is empty.
+pub fn process_remote_mutate_resource_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpMutateResourceParams {
+        resource: resource_path,
+        path: field_path,
+        value,
+    } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let reflect_resource =
+        get_reflect_resource(&type_registry, &resource_path).map_err(BrpError::resource_error)?;
+    let Some(mut reflected_resource) = reflect_resource.reflect_mut(world) else {
+        return Err(BrpError::resource_not_present(&resource_path));
+    };
+
+    // Get the field within the resource, then deserialize the new value based on its type.
+    let field = reflected_resource
+        .reflect_path_mut(field_path.as_str())
+        .map_err(|err| BrpError::invalid_path(&field_path, &err))?;
+    let value = deserialize_reflected_field(&type_registry, field, value)
+        .map_err(BrpError::resource_error)?;
+    field.try_apply(&*value).map_err(BrpError::resource_error)?;
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/remove_resource` request coming from a client.
+pub fn process_remote_remove_resource_request(
+    In(params): In<Option<Value>>,
+    world: &mut World,
+) -> BrpResult {
+    let BrpRemoveResourceParams {
+        resource: resource_path,
+    } = parse_some(params)?;
+
+    let app_type_registry = world.resource::<AppTypeRegistry>().clone();
+    let type_registry = app_type_registry.read();
+
+    let reflect_resource =
+        get_reflect_resource(&type_registry, &resource_path).map_err(BrpError::resource_error)?;
+    reflect_resource.remove(world);
+
+    Ok(Value::Null)
+}
+
+/// Handles a `bevy/list_resources` request coming from a client.
+pub fn process_remote_list_resources_request(
+    In(_params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+
+    let mut response = BrpListResourcesResponse::default();
+    for registered_type in type_registry.iter() {
+        if registered_type.data::<ReflectResource>().is_some() {
+            response.push(registered_type.type_info().type_path().to_owned());
+        }
+    }
+
+    // Sort both for cleanliness and to reduce the risk that clients start
+    // accidentally depending on the order.
+    response.sort();
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/registry/schema` request (list all registered types as JSON Schemas)
+/// coming from a client.
+pub fn export_registry_types(In(params): In<Option<Value>>, world: &World) -> BrpResult {
+    let filter: BrpJsonSchemaQueryFilter = match params {
+        None => Default::default(),
+        Some(params) => parse(params)?,
+    };
+
+    let app_type_registry = world.resource::<AppTypeRegistry>();
+    let type_registry = app_type_registry.read();
+
+    let response: BrpJsonSchemaResponse = type_registry
+        .iter()
+        .map(export_type)
+        .filter(|(_, schema)| {
+            // Types without a crate name are never part of the crates listed in `with_crates`.
+            if !filter.with_crates.is_empty()
+                && !schema
+                    .crate_name
+                    .as_ref()
+                    .is_some_and(|crate_name| filter.with_crates.contains(crate_name))
+            {
+                return false;
+            }
+            if let Some(crate_name) = &schema.crate_name {
+                if filter.without_crates.contains(crate_name) {
+                    return false;
+                }
+            }
+
+            let has_reflect_type =
+                |names: &Vec<String>| names.iter().any(|name| schema.reflect_types.contains(name));
+            if !filter.type_limit.with.is_empty() && !has_reflect_type(&filter.type_limit.with) {
+                return false;
+            }
+            !has_reflect_type(&filter.type_limit.without)
+        })
+        .collect();
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/schedule/graph` request coming from a client.
+pub fn process_remote_schedule_graph_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let BrpScheduleGraphParams {
+        schedule: label,
+        format,
+        collapse_sets,
+        show_ambiguities,
+        color_conditions,
+    } = parse_some(params)?;
+
+    // Schedules that are currently running, such as the one running this method, are not in
+    // `Schedules`.
+    let schedule = world
+        .get_resource::<Schedules>()
+        .and_then(|schedules| {
+            schedules
+                .iter()
+                .find(|(schedule_label, _)| format!("{schedule_label:?}") == label)
+        })
+        .map(|(_, schedule)| schedule)
+        .ok_or_else(|| BrpError::schedule_error(format!("Unknown schedule: `{label}`")))?;
+
+    let format = match format {
+        BrpScheduleGraphFormat::Dot => ScheduleGraphFormat::Dot,
+        BrpScheduleGraphFormat::Mermaid => ScheduleGraphFormat::Mermaid,
+    };
+    let settings = ScheduleExportSettings {
+        collapse_sets,
+        show_ambiguities,
+        color_conditions,
+        ..Default::default()
+    };
+    let graph = schedule
+        .export_graph(format, &settings)
+        .map_err(|err| BrpError::schedule_error(format!("Schedule `{label}`: {err}")))?;
+
+    serde_json::to_value(BrpScheduleGraphResponse { graph }).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/storage/stats` request coming from a client.
+pub fn process_remote_storage_stats_request(
+    In(_params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let stats = world.storage_stats();
+    let components = world.components();
+    let name = |component_id: ComponentId| {
+        components
+            .get_name(component_id)
+            .map_or_else(|| format!("{component_id:?}"), ToString::to_string)
+    };
+
+    let response = BrpStorageStatsResponse {
+        archetype_count: stats.archetype_count(),
+        empty_archetype_count: stats.empty_archetype_count(),
+        table_occupancy: stats.table_occupancy(),
+        allocated_bytes: stats.allocated_bytes(),
+        component_bytes: stats
+            .component_bytes()
+            .into_iter()
+            .map(|(component_id, bytes)| (name(component_id), bytes))
+            .collect(),
+        archetypes: stats
+            .archetypes
+            .iter()
+            .map(|archetype| BrpArchetypeStats {
+                id: archetype.id.index(),
+                table: archetype.table_id.as_usize(),
+                entity_count: archetype.entity_count,
+                components: archetype.components.iter().copied().map(name).collect(),
+            })
+            .collect(),
+        tables: stats
+            .tables
+            .iter()
+            .map(|table| BrpTableStats {
+                id: table.id.as_usize(),
+                entity_count: table.entity_count,
+                capacity: table.capacity,
+                allocated_bytes: table.allocated_bytes(),
+            })
+            .collect(),
+    };
+
+    serde_json::to_value(response).map_err(BrpError::internal)
+}
+
+/// Handles a `bevy/asset/dependency_graph` request coming from a client.
+#[cfg(feature = "bevy_asset")]
+pub fn process_remote_asset_dependency_graph_request(
+    In(params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let BrpAssetDependencyGraphParams { path, format } = parse_some(params)?;
+
+    let asset_server = world
+        .get_resource::<AssetServer>()
+        .ok_or_else(|| BrpError::asset_error("The `AssetServer` is not in the world"))?;
+    let asset_path = AssetPath::try_parse(&path)
+        .map_err(|err| BrpError::asset_error(format!("Invalid asset path `{path}`: {err}")))?;
+    let graph = asset_server
+        .get_path_dependency_graph(asset_path)
+        .ok_or_else(|| BrpError::asset_error(format!("No asset is loaded from `{path}`")))?;
+
+    let graph = match format {
+        BrpAssetDependencyGraphFormat::Json => {
+            serde_json::to_value(graph).map_err(BrpError::internal)?
+        }
+        BrpAssetDependencyGraphFormat::Dot => Value::String(graph.to_dot()),
+    };
+
+    serde_json::to_value(BrpAssetDependencyGraphResponse { graph }).map_err(BrpError::internal)
+}
+
+/// Handles a `rpc.discover` request (list all methods and their parameters) coming from a
+/// client.
+///
+/// The server responds with an [`OpenRpcDocument`].
+pub fn process_remote_list_methods_request(
+    In(_params): In<Option<Value>>,
+    world: &World,
+) -> BrpResult {
+    let mut methods = world.resource::<RemoteMethods>().methods();
+    methods.sort();
+
+    let document = OpenRpcDocument {
+        openrpc: "1.3.2".to_owned(),
+        info: InfoObject::default(),
+        methods: methods.into_iter().map(MethodObject::new).collect(),
+    };
+
+    serde_json::to_value(document).map_err(BrpError::internal)
+}
+
 /// Immutably retrieves an entity from the [`World`], returning an error if the
 /// entity isn't present.
 fn get_entity(world: &World, entity: Entity) -> Result<EntityRef<'_>, BrpError> {
@@ -809,47 +1495,126 @@ fn get_entity_mut(world: &mut World, entity: Entity) -> Result<EntityWorldMut<'_
         .map_err(|_| BrpError::entity_not_found(entity))
 }
 
-/// Returns the [`TypeId`] and [`ComponentId`] of the components with the given
-/// full path names.
-///
-/// Note that the supplied path names must be *full* path names: e.g.
-/// `bevy_transform::components::transform::Transform` instead of `Transform`.
-fn get_component_ids(
+/// Returns the [`ComponentId`] of the component with the given full path name, or of the
+/// [dynamic component](bevy_ecs::dynamic_component) with the given name.
+fn get_component_id(
     type_registry: &TypeRegistry,
     world: &World,
-    component_paths: Vec<String>,
-) -> AnyhowResult<Vec<(TypeId, ComponentId)>> {
-    let mut component_ids = vec![];
+    component_path: &str,
+) -> AnyhowResult<ComponentId> {
+    if let Some(component_id) = dynamic_component_id(type_registry, world, component_path) {
+        return Ok(component_id);
+    }
+    let type_id = get_component_type_registration(type_registry, component_path)?.type_id();
+    world
+        .components()
+        .get_id(type_id)
+        .ok_or_else(|| anyhow!("Component `{}` isn't used in the world", component_path))
+}
 
-    for component_path in component_paths {
-        let type_id = get_component_type_registration(type_registry, &component_path)?.type_id();
-        let Some(component_id) = world.components().get_id(type_id) else {
+/// Returns the id of the [dynamic component](bevy_ecs::dynamic_component) with the given name,
+/// unless a type with this path is registered.
+fn dynamic_component_id(
+    type_registry: &TypeRegistry,
+    world: &World,
+    component_path: &str,
+) -> Option<ComponentId> {
+    if type_registry.get_with_type_path(component_path).is_some() {
+        return None;
+    }
+    world
+        .get_resource::<DynamicComponentRegistry>()?
+        .id(component_path)
+}
+
+/// Serializes a dynamic component as an object of its fields.
+fn dynamic_component_to_json(component: &DynamicComponentRef) -> Value {
+    Value::Object(
+        component
+            .iter()
+            .map(|(field, value)| {
+                let value = match value {
+                    DynamicValue::Bool(value) => value.into(),
+                    DynamicValue::U8(value) => value.into(),
+                    DynamicValue::U16(value) => value.into(),
+                    DynamicValue::U32(value) => value.into(),
+                    DynamicValue::U64(value) => value.into(),
+                    DynamicValue::I8(value) => value.into(),
+                    DynamicValue::I16(value) => value.into(),
+                    DynamicValue::I32(value) => value.into(),
+                    DynamicValue::I64(value) => value.into(),
+                    DynamicValue::F32(value) => value.into(),
+                    DynamicValue::F64(value) => value.into(),
+                };
+                (field.to_owned(), value)
+            })
+            .collect(),
+    )
+}
+
+/// Deserializes the fields of a dynamic component from an object, which may omit fields.
+fn dynamic_component_from_json(
+    layout: &DynamicComponentLayout,
+    value: Value,
+) -> AnyhowResult<DynamicComponentData> {
+    let Value::Object(fields) = value else {
+        return Err(anyhow!("Component `{}` must be an object", layout.name()));
+    };
+    let mut data = DynamicComponentData::new(layout.name());
+    for (field, value) in fields {
+        let Some(kind) = layout.field(&field).map(DynamicField::kind) else {
             return Err(anyhow!(
-                "Component `{}` isn't used in the world",
-                component_path
+                "Component `{}` has no field `{field}`",
+                layout.name()
             ));
         };
-
-        component_ids.push((type_id, component_id));
+        let value = dynamic_value_from_json(kind, value)
+            .map_err(|err| anyhow!("{}.{field} is invalid: {err}", layout.name()))?;
+        data.fields.push((field, value));
     }
+    Ok(data)
+}
 
-    Ok(component_ids)
+/// Deserializes the value of a field of a dynamic component.
+fn dynamic_value_from_json(
+    kind: DynamicFieldKind,
+    value: Value,
+) -> serde_json::Result<DynamicValue> {
+    Ok(match kind {
+        DynamicFieldKind::Bool => DynamicValue::Bool(serde_json::from_value(value)?),
+        DynamicFieldKind::U8 => DynamicValue::U8(serde_json::from_value(value)?),
+        DynamicFieldKind::U16 => DynamicValue::U16(serde_json::from_value(value)?),
+        DynamicFieldKind::U32 => DynamicValue::U32(serde_json::from_value(value)?),
+        DynamicFieldKind::U64 => DynamicValue::U64(serde_json::from_value(value)?),
+        DynamicFieldKind::I8 => DynamicValue::I8(serde_json::from_value(value)?),
+        DynamicFieldKind::I16 => DynamicValue::I16(serde_json::from_value(value)?),
+        DynamicFieldKind::I32 => DynamicValue::I32(serde_json::from_value(value)?),
+        DynamicFieldKind::I64 => DynamicValue::I64(serde_json::from_value(value)?),
+        DynamicFieldKind::F32 => DynamicValue::F32(serde_json::from_value(value)?),
+        DynamicFieldKind::F64 => DynamicValue::F64(serde_json::from_value(value)?),
+    })
 }
 
-/// Given an entity (`entity_ref`) and a list of reflected component information
-/// (`paths_and_reflect_components`), return a map which associates each component to
-/// its serialized value from the entity.
+/// Given an entity (`entity_ref`) and a list of queried components (`components`), return a map
+/// which associates each component to its serialized value from the entity.
 ///
 /// This is intended to be used on an entity which has already been filtered; components
 /// where the value is not present on an entity are simply skipped.
-fn build_components_map<'a>(
+fn build_components_map(
     entity_ref: FilteredEntityRef,
-    paths_and_reflect_components: impl Iterator<Item = (&'a str, &'a ReflectComponent)>,
+    components: &[QueriedComponent],
     type_registry: &TypeRegistry,
 ) -> AnyhowResult<HashMap<String, Value>> {
     let mut serialized_components_map = <HashMap<_, _>>::default();
 
-    for (type_path, reflect_component) in paths_and_reflect_components {
+    for component in components {
+        let Some(reflect_component) = component.reflect_component else {
+            if let Some(dynamic) = entity_ref.get_dynamic(component.id) {
+                serialized_components_map
+                    .insert(component.path.clone(), dynamic_component_to_json(&dynamic));
+            }
+            continue;
+        };
         let Some(reflected) = reflect_component.reflect(entity_ref.clone()) else {
             continue;
         };
@@ -857,7 +1622,10 @@ fn build_components_map<'a>(
         let reflect_serializer =
             ReflectSerializer::new(reflected.as_partial_reflect(), type_registry);
         let Value::Object(serialized_object) = serde_json::to_value(&reflect_serializer)? else {
-            return Err(anyhow!("Component `{}` could not be serialized", type_path));
+            return Err(anyhow!(
+                "Component `{}` could not be serialized",
+                component.path
+            ));
         };
 
         serialized_components_map.extend(serialized_object.into_iter());
@@ -866,57 +1634,101 @@ fn build_components_map<'a>(
     Ok(serialized_components_map)
 }
 
-/// Given an entity (`entity_ref`) and list of reflected component information
-/// (`paths_and_reflect_components`), return a map which associates each component to
-/// a boolean value indicating whether or not that component is present on the entity.
-fn build_has_map<'a>(
+/// Given an entity (`entity_ref`) and list of queried components (`components`), return a map
+/// which associates each component to a boolean value indicating whether or not that component is
+/// present on the entity.
+fn build_has_map(
     entity_ref: FilteredEntityRef,
-    paths_and_reflect_components: impl Iterator<Item = (&'a str, &'a ReflectComponent)>,
+    components: &[QueriedComponent],
 ) -> HashMap<String, Value> {
     let mut has_map = <HashMap<_, _>>::default();
 
-    for (type_path, reflect_component) in paths_and_reflect_components {
-        let has = reflect_component.contains(entity_ref.clone());
-        has_map.insert(type_path.to_owned(), Value::Bool(has));
+    for component in components {
+        let has = match component.reflect_component {
+            Some(reflect_component) => reflect_component.contains(entity_ref.clone()),
+            None => entity_ref.contains_id(component.id),
+        };
+        has_map.insert(component.path.clone(), Value::Bool(has));
     }
 
     has_map
 }
 
-/// Given a component ID, return the associated [type path] and `ReflectComponent` if possible.
-///
-/// The `ReflectComponent` part is the meat of this; the type path is only used for error messages.
-///
-/// [type path]: bevy_reflect::TypePath::type_path
-fn reflect_component_from_id(
-    component_type_id: TypeId,
-    type_registry: &TypeRegistry,
-) -> AnyhowResult<(&str, &ReflectComponent)> {
-    let Some(type_registration) = type_registry.get(component_type_id) else {
-        return Err(anyhow!(
-            "Component `{:?}` isn't registered",
-            component_type_id
-        ));
-    };
+/// A component named in a `bevy/query` request.
+struct QueriedComponent<'r> {
+    /// The [type path] of the component, or the name of the dynamic component.
+    ///
+    /// [type path]: bevy_reflect::TypePath::type_path
+    path: String,
+    id: ComponentId,
+    /// The `ReflectComponent` of the component, or `None` for a
+    /// [dynamic component](bevy_ecs::dynamic_component).
+    reflect_component: Option<&'r ReflectComponent>,
+}
 
-    let type_path = type_registration.type_info().type_path();
+/// Returns the [`QueriedComponent`]s with the given full path names or
+/// [dynamic component](bevy_ecs::dynamic_component) names.
+fn get_queried_components<'r>(
+    type_registry: &'r TypeRegistry,
+    world: &World,
+    component_paths: Vec<String>,
+) -> AnyhowResult<Vec<QueriedComponent<'r>>> {
+    let mut components = vec![];
 
-    let Some(reflect_component) = type_registration.data::<ReflectComponent>() else {
-        return Err(anyhow!("Component `{}` isn't reflectable", type_path));
-    };
+    for component_path in component_paths {
+        if let Some(id) = dynamic_component_id(type_registry, world, &component_path) {
+            components.push(QueriedComponent {
+                path: component_path,
+                id,
+                reflect_component: None,
+            });
+            continue;
+        }
+
+        let registration = get_component_type_registration(type_registry, &component_path)?;
+        let Some(id) = world.components().get_id(registration.type_id()) else {
+            return Err(anyhow!(
+                "Component `{}` isn't used in the world",
+                component_path
+            ));
+        };
+        let Some(reflect_component) = registration.data::<ReflectComponent>() else {
+            return Err(anyhow!("Component `{}` isn't reflectable", component_path));
+        };
+        components.push(QueriedComponent {
+            path: registration.type_info().type_path().to_owned(),
+            id,
+            reflect_component: Some(reflect_component),
+        });
+    }
 
-    Ok((type_path, reflect_component))
+    Ok(components)
 }
 
 /// Given a collection of component paths and their associated serialized values (`components`),
 /// return the associated collection of deserialized reflected values.
+///
+/// [Dynamic components](bevy_ecs::dynamic_component) are gathered in a single
+/// [`DynamicComponentSet`].
 fn deserialize_components(
     type_registry: &TypeRegistry,
+    world: &World,
     components: HashMap<String, Value>,
 ) -> AnyhowResult<Vec<Box<dyn PartialReflect>>> {
     let mut reflect_components = vec![];
+    let mut dynamic_components = DynamicComponentSet::default();
 
     for (component_path, component) in components {
+        if let Some(component_id) = dynamic_component_id(type_registry, world, &component_path) {
+            let layout = world
+                .resource::<DynamicComponentRegistry>()
+                .get(component_id)
+                .unwrap();
+            dynamic_components
+                .0
+                .push(dynamic_component_from_json(layout, component)?);
+            continue;
+        }
         let Some(component_type) = type_registry.get_with_type_path(&component_path) else {
             return Err(anyhow!("Unknown component type: `{}`", component_path));
         };
@@ -924,9 +1736,21 @@ fn deserialize_components(
             TypedReflectDeserializer::new(component_type, type_registry)
                 .deserialize(&component)
                 .map_err(|err| anyhow!("{component_path} is invalid: {err}"))?;
+        // A set of dynamic components can also be sent as is, and is checked with the others.
+        if component_type.type_id() == TypeId::of::<DynamicComponentSet>() {
+            let set = DynamicComponentSet::from_reflect(&*reflected)
+                .ok_or_else(|| anyhow!("{component_path} is invalid"))?;
+            dynamic_components.0.extend(set.0);
+            continue;
+        }
         reflect_components.push(reflected);
     }
 
+    if !dynamic_components.0.is_empty() {
+        dynamic_components.validate(world)?;
+        reflect_components.push(Box::new(dynamic_components));
+    }
+
     Ok(reflect_components)
 }
 
@@ -946,6 +1770,60 @@ fn insert_reflected_components(
     Ok(())
 }
 
+/// Given a resource's type path and its serialized `value`, return the deserialized reflected
+/// value.
+fn deserialize_resource(
+    type_registry: &TypeRegistry,
+    resource_path: &str,
+    value: Value,
+) -> AnyhowResult<Box<dyn PartialReflect>> {
+    let Some(resource_type) = type_registry.get_with_type_path(resource_path) else {
+        return Err(anyhow!("Unknown resource type: `{}`", resource_path));
+    };
+    let reflected: Box<dyn PartialReflect> =
+        TypedReflectDeserializer::new(resource_type, type_registry)
+            .deserialize(&value)
+            .map_err(|err| anyhow!("{resource_path} is invalid: {err}"))?;
+    Ok(reflected)
+}
+
+/// Deserialize `value` into the type of the reflected `field`, which must be registered in the
+/// given `type_registry`.
+fn deserialize_reflected_field(
+    type_registry: &TypeRegistry,
+    field: &dyn PartialReflect,
+    value: Value,
+) -> AnyhowResult<Box<dyn PartialReflect>> {
+    let Some(field_type_info) = field.get_represented_type_info() else {
+        return Err(anyhow!(
+            "Field `{}` has no type information",
+            field.reflect_type_path()
+        ));
+    };
+    let field_path = field_type_info.type_path();
+    let Some(field_type) = type_registry.get(field_type_info.type_id()) else {
+        return Err(anyhow!("Unknown field type: `{}`", field_path));
+    };
+    TypedReflectDeserializer::new(field_type, type_registry)
+        .deserialize(&value)
+        .map_err(|err| anyhow!("Failed to deserialize `{field_path}`: {err}"))
+}
+
+/// Given a resource's type path, return the associated [`ReflectResource`] from the given
+/// `type_registry` if possible.
+fn get_reflect_resource<'r>(
+    type_registry: &'r TypeRegistry,
+    resource_path: &str,
+) -> AnyhowResult<&'r ReflectResource> {
+    let resource_registration = type_registry
+        .get_with_type_path(resource_path)
+        .ok_or_else(|| anyhow!("Unknown resource type: `{}`", resource_path))?;
+
+    resource_registration
+        .data::<ReflectResource>()
+        .ok_or_else(|| anyhow!("Resource `{}` isn't reflectable", resource_path))
+}
+
 /// Given a component's type path, return the associated [`ReflectComponent`] from the given
 /// `type_registry` if possible.
 fn get_reflect_component<'r>(
@@ -1004,5 +1882,466 @@ mod tests {
         test_serialize_deserialize(BrpListParams {
             entity: Entity::from_raw(0),
         });
+        test_serialize_deserialize(BrpJsonSchemaQueryFilter::default());
+    }
+
+    #[test]
+    fn export_registry_types_filters() {
+        use bevy_ecs::{component::Component, reflect::ReflectResource, system::Resource};
+        use bevy_reflect::{Reflect, TypePath};
+
+        #[derive(Component, Reflect)]
+        #[reflect(Component)]
+        struct Foo;
+
+        #[derive(Resource, Reflect)]
+        #[reflect(Resource)]
+        struct Bar;
+
+        let mut world = World::new();
+        {
+            let registry = world.get_resource_or_init::<AppTypeRegistry>();
+            let mut registry = registry.write();
+            registry.register::<Foo>();
+            registry.register::<Bar>();
+            registry.register::<u32>();
+        }
+
+        let export = |world: &World, filter: Value| -> BrpJsonSchemaResponse {
+            let value = export_registry_types(In(Some(filter)), world).unwrap();
+            serde_json::from_value(value).unwrap()
+        };
+
+        let components = export(
+            &world,
+            serde_json::json!({ "type_limit": { "with": ["Component"] } }),
+        );
+        assert!(components.contains_key(Foo::type_path()));
+        assert!(!components.contains_key(Bar::type_path()));
+
+        let not_resources = export(
+            &world,
+            serde_json::json!({ "type_limit": { "without": ["Resource"] } }),
+        );
+        assert!(not_resources.contains_key(Foo::type_path()));
+        assert!(!not_resources.contains_key(Bar::type_path()));
+
+        let other_crates = export(
+            &world,
+            serde_json::json!({ "without_crates": ["bevy_remote"] }),
+        );
+        assert!(!other_crates.contains_key(Foo::type_path()));
+        assert!(!other_crates.contains_key(Bar::type_path()));
+        assert!(other_crates.contains_key("u32"));
+
+        // Primitives have no crate name, so they are not part of any crate.
+        let this_crate = export(
+            &world,
+            serde_json::json!({ "with_crates": ["bevy_remote"] }),
+        );
+        assert!(this_crate.contains_key(Foo::type_path()));
+        assert!(!this_crate.contains_key("u32"));
+    }
+
+    #[test]
+    fn resource_methods() {
+        use bevy_ecs::{reflect::ReflectResource, system::Resource};
+        use bevy_reflect::{Reflect, TypePath};
+        use serde_json::json;
+
+        #[derive(Resource, Reflect, Debug, PartialEq)]
+        #[reflect(Resource)]
+        struct Settings {
+            volume: f32,
+            muted: bool,
+        }
+
+        let mut world = World::new();
+        world
+            .get_resource_or_init::<AppTypeRegistry>()
+            .write()
+            .register::<Settings>();
+        let path = Settings::type_path();
+
+        process_remote_insert_resource_request(
+            In(Some(json!({
+                "resource": path,
+                "value": { "volume": 0.5, "muted": false },
+            }))),
+            &mut world,
+        )
+        .unwrap();
+        assert_eq!(
+            world.resource::<Settings>(),
+            &Settings {
+                volume: 0.5,
+                muted: false
+            }
+        );
+
+        process_remote_mutate_resource_request(
+            In(Some(
+                json!({ "resource": path, "path": ".muted", "value": true }),
+            )),
+            &mut world,
+        )
+        .unwrap();
+        assert!(world.resource::<Settings>().muted);
+
+        let response: BrpGetResourceResponse = serde_json::from_value(
+            process_remote_get_resource_request(In(Some(json!({ "resource": path }))), &world)
+                .unwrap(),
+        )
+        .unwrap();
+        assert_eq!(response.value, json!({ "volume": 0.5, "muted": true }));
+
+        let list = process_remote_list_resources_request(In(None), &world).unwrap();
+        assert_eq!(list, json!([path]));
+
+        process_remote_remove_resource_request(In(Some(json!({ "resource": path }))), &mut world)
+            .unwrap();
+        assert!(!world.contains_resource::<Settings>());
+
+        let error =
+            process_remote_get_resource_request(In(Some(json!({ "resource": path }))), &world)
+                .unwrap_err();
+        assert_eq!(error.code, error_codes::RESOURCE_NOT_PRESENT);
+    }
+
+    #[test]
+    fn mutate_component() {
+        use bevy_ecs::component::Component;
+        use bevy_reflect::{Reflect, TypePath};
+        use serde_json::json;
+
+        #[derive(Component, Reflect, Debug, PartialEq)]
+        #[reflect(Component)]
+        struct Position {
+            x: f32,
+            y: f32,
+        }
+
+        let mut world = World::new();
+        world
+            .get_resource_or_init::<AppTypeRegistry>()
+            .write()
+            .register::<Position>();
+        let entity = world.spawn(Position { x: 1.0, y: 2.0 }).id();
+        let component = Position::type_path();
+
+        process_remote_mutate_component_request(
+            In(Some(json!({
+                "entity": entity,
+                "component": component,
+                "path": ".y",
+                "value": 5.0,
+            }))),
+            &mut world,
+        )
+        .unwrap();
+        assert_eq!(
+            world.get::<Position>(entity),
+            Some(&Position { x: 1.0, y: 5.0 })
+        );
+
+        let error = process_remote_mutate_component_request(
+            In(Some(json!({
+                "entity": entity,
+                "component": component,
+                "path": ".z",
+                "value": 5.0,
+            }))),
+            &mut world,
+        )
+        .unwrap_err();
+        assert_eq!(error.code, error_codes::INVALID_REFLECT_PATH);
+        assert_eq!(error.data.as_ref().unwrap()["kind"], json!("access"));
+
+        let error = process_remote_mutate_component_request(
+            In(Some(json!({
+                "entity": entity,
+                "component": component,
+                "path": ".x[",
+                "value": 5.0,
+            }))),
+            &mut world,
+        )
+        .unwrap_err();
+        assert_eq!(error.code, error_codes::INVALID_REFLECT_PATH);
+        assert_eq!(error.data.as_ref().unwrap()["kind"], json!("parse"));
+
+        let error = process_remote_mutate_component_request(
+            In(Some(json!({
+                "entity": entity,
+                "component": component,
+                "path": ".x",
+                "value": "not a number",
+            }))),
+            &mut world,
+        )
+        .unwrap_err();
+        assert_eq!(error.code, error_codes::COMPONENT_ERROR);
+    }
+
+    #[test]
+    fn mutate_immutable_component() {
+        use bevy_ecs::component::Component;
+        use bevy_reflect::{Reflect, TypePath};
+        use serde_json::json;
+
+        #[derive(Component, Reflect, Debug, PartialEq)]
+        #[component(immutable)]
+        #[reflect(Component)]
+        struct Name {
+            value: u32,
+        }
+
+        let mut world = World::new();
+        world
+            .get_resource_or_init::<AppTypeRegistry>()
+            .write()
+            .register::<Name>();
+        let entity = world.spawn(Name { value: 1 }).id();
+
+        let error = process_remote_mutate_component_request(
+            In(Some(json!({
+                "entity": entity,
+                "component": Name::type_path(),
+                "path": ".value",
+                "value": 2,
+            }))),
+            &mut world,
+        )
+        .unwrap_err();
+        assert_eq!(error.code, error_codes::COMPONENT_ERROR);
+        assert_eq!(world.get::<Name>(entity), Some(&Name { value: 1 }));
+    }
+
+    #[test]
+    fn dynamic_components() {
+        use bevy_ecs::dynamic_component::{DynamicComponentLayout, DynamicFieldKind};
+        use serde_json::json;
+
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        let health = world
+            .register_dynamic_component(
+                DynamicComponentLayout::new("Health")
+                    .with_field("current", DynamicFieldKind::F32)
+                    .with_field("regenerates", DynamicFieldKind::Bool),
+            )
+            .unwrap();
+
+        let response = process_remote_spawn_request(
+            In(Some(
+                json!({ "components": { "Health": { "current": 3.0 } } }),
+            )),
+            &mut world,
+        )
+        .unwrap();
+        let entity: Entity = serde_json::from_value(response["entity"].clone()).unwrap();
+
+        process_remote_mutate_component_request(
+            In(Some(json!({
+                "entity": entity,
+                "component": "Health",
+                "path": ".regenerates",
+                "value": true,
+            }))),
+            &mut world,
+        )
+        .unwrap();
+        let error = process_remote_mutate_component_request(
+            In(Some(json!({
+                "entity": entity,
+                "component": "Health",
+                "path": ".current",
+                "value": "full",
+            }))),
+            &mut world,
+        )
+        .unwrap_err();
+        assert_eq!(error.code, error_codes::COMPONENT_ERROR);
+
+        let response = process_remote_get_request(
+            In(Some(json!({
+                "entity": entity,
+                "components": ["Health"],
+                "strict": true,
+            }))),
+            &world,
+        )
+        .unwrap();
+        assert_eq!(
+            response,
+            json!({ "Health": { "current": 3.0, "regenerates": true } })
+        );
+
+        let response = process_remote_list_request(In(None), &world).unwrap();
+        assert_eq!(response, json!(["Health"]));
+
+        world.spawn_empty();
+        let response = process_remote_query_request(
+            In(Some(json!({
+                "data": { "components": ["Health"], "has": ["Health"] },
+                "filter": { "with": ["Health"] },
+            }))),
+            &mut world,
+        )
+        .unwrap();
+        assert_eq!(
+            response,
+            json!([{
+                "entity": entity,
+                "components": { "Health": { "current": 3.0, "regenerates": true } },
+                "has": { "Health": true },
+            }])
+        );
+
+        process_remote_remove_request(
+            In(Some(json!({ "entity": entity, "components": ["Health"] }))),
+            &mut world,
+        )
+        .unwrap();
+        assert!(world.get_dynamic(entity, health).is_none());
+
+        let insert_set = |world: &mut World, name: &str| {
+            process_remote_insert_request(
+                In(Some(json!({
+                    "entity": entity,
+                    "components": {
+                        "bevy_ecs::dynamic_component::DynamicComponentSet": [
+                            { "name": name, "fields": [] },
+                        ],
+                    },
+                }))),
+                world,
+            )
+        };
+        let error = insert_set(&mut world, "Mana").unwrap_err();
+        assert_eq!(error.code, error_codes::COMPONENT_ERROR);
+        insert_set(&mut world, "Health").unwrap();
+        assert!(world.get_dynamic(entity, health).is_some());
+    }
+
+    #[test]
+    fn schedule_graph() {
+        use bevy_ecs::schedule::{IntoSystemConfigs, Schedule, ScheduleLabel};
+        use serde_json::json;
+
+        #[derive(ScheduleLabel, Debug, Clone, PartialEq, Eq, Hash)]
+        struct Tick;
+
+        fn first() {}
+        fn second() {}
+
+        let mut world = World::new();
+        let mut schedule = Schedule::new(Tick);
+        schedule.add_systems((first, second).chain());
+        world.add_schedule(schedule);
+        world.run_schedule(Tick);
+
+        let graph = |params: Value| {
+            process_remote_schedule_graph_request(In(Some(params)), &world)
+                .map(|value| serde_json::from_value::<BrpScheduleGraphResponse>(value).unwrap())
+        };
+
+        let dot = graph(json!({ "schedule": "Tick" })).unwrap().graph;
+        assert!(dot.starts_with("digraph \"Tick\""));
+        let mermaid = graph(json!({ "schedule": "Tick", "format": "mermaid" }))
+            .unwrap()
+            .graph;
+        assert!(mermaid.contains("[\"second\"]"));
+
+        let error = graph(json!({ "schedule": "Missing" })).unwrap_err();
+        assert_eq!(error.code, error_codes::SCHEDULE_ERROR);
+    }
+
+    #[test]
+    fn storage_stats() {
+        use bevy_ecs::component::Component;
+
+        #[derive(Component)]
+        struct Health;
+
+        #[derive(Component)]
+        struct Marker;
+
+        let mut world = World::new();
+        world.spawn_batch((0..3).map(|_| Health));
+        world.spawn((Health, Marker));
+
+        let response: BrpStorageStatsResponse =
+            serde_json::from_value(process_remote_storage_stats_request(In(None), &world).unwrap())
+                .unwrap();
+        assert_eq!(response.archetype_count, world.archetypes().len());
+        let health = world
+            .components()
+            .get_name(world.component_id::<Health>().unwrap())
+            .unwrap();
+        let archetype = response
+            .archetypes
+            .iter()
+            .find(|archetype| archetype.components == [health])
+            .unwrap();
+        assert_eq!(archetype.entity_count, 3);
+        assert_eq!(response.tables[archetype.table].entity_count, 3);
+        assert!(response.component_bytes[health] >= 4 * size_of::<u32>());
+    }
+
+    #[cfg(feature = "bevy_asset")]
+    #[test]
+    fn asset_dependency_graph() {
+        use bevy_asset::{
+            io::{
+                memory::{Dir, MemoryAssetReader},
+                AssetSource, AssetSourceBuilders, AssetSourceId,
+            },
+            AssetServer, AssetServerMode, Assets, LoadedUntypedAsset,
+        };
+        use bevy_tasks::{IoTaskPool, TaskPool};
+        use serde_json::json;
+
+        IoTaskPool::get_or_init(TaskPool::default);
+        let mut world = World::new();
+        let graph = |world: &World, params: Value| {
+            process_remote_asset_dependency_graph_request(In(Some(params)), world).map(|value| {
+                serde_json::from_value::<BrpAssetDependencyGraphResponse>(value)
+                    .unwrap()
+                    .graph
+            })
+        };
+
+        let error = graph(&world, json!({ "path": "a.png" })).unwrap_err();
+        assert_eq!(error.code, error_codes::ASSET_ERROR);
+
+        let mut sources = AssetSourceBuilders::default();
+        sources.insert(
+            AssetSourceId::Default,
+            AssetSource::build().with_reader(|| {
+                Box::new(MemoryAssetReader {
+                    root: Dir::default(),
+                })
+            }),
+        );
+        let asset_server = AssetServer::new(
+            sources.build_sources(false, false),
+            AssetServerMode::Unprocessed,
+            false,
+        );
+        asset_server.register_asset(&Assets::<LoadedUntypedAsset>::default());
+        let _handle = asset_server.load::<LoadedUntypedAsset>("a.png");
+        world.insert_resource(asset_server);
+
+        let json = graph(&world, json!({ "path": "a.png" })).unwrap();
+        assert_eq!(json["roots"], json!([0]));
+        assert_eq!(json["nodes"][0]["path"], json!("a.png"));
+        assert!(json["nodes"][0]["id"]["index"].is_u64());
+        assert_eq!(json["edges"], json!([]));
+        let dot = graph(&world, json!({ "path": "a.png", "format": "dot" })).unwrap();
+        assert!(dot.as_str().unwrap().starts_with("digraph assets"));
+
+        let error = graph(&world, json!({ "path": "b.png" })).unwrap_err();
+        assert_eq!(error.code, error_codes::ASSET_ERROR);
     }
 }
@@ -3,8 +3,9 @@
 //! Adding the [`RemotePlugin`] to your [`App`] will setup everything needed without
 //! starting any transports. To start accepting remote connections you will need to
 //! add a second plugin like the [`RemoteHttpPlugin`](http::RemoteHttpPlugin) to enable communication
-//! over HTTP. These *remote clients* can inspect and alter the state of the
-//! entity-component system.
+//! over HTTP, or the `RemoteWebSocketPlugin` from the `websocket` module (behind the `websocket`
+//! feature) to enable communication over a WebSocket. These *remote clients* can inspect and
+//! alter the state of the entity-component system.
 //!
 //! The Bevy Remote Protocol is based on the JSON-RPC 2.0 protocol.
 //!
@@ -102,6 +103,12 @@
 //! in the ECS. Each of these methods uses the `bevy/` prefix, which is a namespace reserved for
 //! BRP built-in methods.
 //!
+//! [Dynamic components] are named by the name of their layout instead of a type name, and their
+//! values are objects mapping field names to values. Every method taking components supports
+//! them.
+//!
+//! [Dynamic components]: bevy_ecs::dynamic_component
+//!
 //! ### bevy/get
 //!
 //! Retrieve the values of one or more components from an entity.
@@ -189,6 +196,23 @@
 //!
 //! `result`: null.
 //!
+//! ### `bevy/mutate_component`
+//!
+//! Mutate a field in a component, without replacing the rest of the component.
+//!
+//! `params`:
+//! - `entity`: The ID of the entity with the component to mutate.
+//! - `component`: The component's [fully-qualified type name].
+//! - `path`: The path of the field within the component, such as `.translation.x`. An empty
+//!   path refers to the entire component. See [`GetPath`](bevy_reflect::GetPath) for the syntax.
+//! - `value`: The serialized value to insert at `path`.
+//!
+//! `result`: null.
+//!
+//! If `path` can't be parsed or doesn't lead to a field, the error has the
+//! `INVALID_REFLECT_PATH` code, and its `data` contains the `path`, the `kind` of error
+//! (`"parse"`, `"access"` or `"downcast"`) and, if known, the `offset` into the path.
+//!
 //! ### bevy/reparent
 //!
 //! Assign a new parent to one or more entities.
@@ -256,6 +280,132 @@
 //! - `removed`: An array of fully-qualified type names of components removed from the entity
 //!   in the last tick.
 //!
+//! ### `bevy/get_resource`
+//!
+//! Extract the value of a given resource from the world.
+//!
+//! `params`:
+//! - `resource`: The [fully-qualified type name] of the resource to get.
+//!
+//! `result`:
+//! - `value`: The serialized value of the resource.
+//!
+//! ### `bevy/insert_resource`
+//!
+//! Insert the given resource into the world with the given value, replacing any existing value.
+//!
+//! `params`:
+//! - `resource`: The [fully-qualified type name] of the resource to insert.
+//! - `value`: The serialized value of the resource to be inserted.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/mutate_resource`
+//!
+//! Mutate a field in a resource.
+//!
+//! `params`:
+//! - `resource`: The [fully-qualified type name] of the resource to mutate.
+//! - `path`: The path of the field within the resource, such as `.volume`. An empty path
+//!   refers to the entire resource.
+//! - `value`: The serialized value to insert at `path`.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/remove_resource`
+//!
+//! Remove the given resource from the world.
+//!
+//! `params`:
+//! - `resource`: The [fully-qualified type name] of the resource to remove.
+//!
+//! `result`: null.
+//!
+//! ### `bevy/list_resources`
+//!
+//! List all reflectable registered resource types. This method has no parameters.
+//!
+//! `result`: An array of [fully-qualified type names] of registered resource types.
+//!
+//! ### bevy/registry/schema
+//!
+//! Retrieve the JSON Schema of every type registered in the `AppTypeRegistry`.
+//!
+//! `params` (optional):
+//! - `with_crates`: An array of crate names. Only types from these crates are included.
+//! - `without_crates`: An array of crate names. Types from these crates are excluded.
+//! - `type_limit`: An object with the optional fields `with` and `without`, which are arrays of
+//!   type data names such as `Component`, `Resource`, `Default`, `Serialize` and `Deserialize`.
+//!   Only types with at least one of the `with` type data registered, and none of the `without`
+//!   type data, are included.
+//!
+//! `result`: A map associating each [fully-qualified type name] with its JSON Schema. Fields are
+//! referenced as `#/$defs/<type name>`.
+//!
+//! ### bevy/schedule/graph
+//!
+//! Export the graph of the systems of a schedule, to render it with external tools. The schedule
+//! running the remote methods can't be exported.
+//!
+//! `params`:
+//! - `schedule`: The label of the schedule, as printed by its `Debug` implementation: e.g. `Update`.
+//! - `format` (optional): Either `dot` for the Graphviz DOT language, or `mermaid` for a Mermaid
+//!   flowchart. Defaults to `dot`.
+//! - `collapse_sets` (optional): Whether the outermost system sets are drawn as single nodes.
+//!   Defaults to false.
+//! - `show_ambiguities` (optional): Whether systems with conflicting access and no order between
+//!   them are connected. Defaults to true.
+//! - `color_conditions` (optional): Whether systems and system sets with run conditions are
+//!   highlighted. Defaults to true.
+//!
+//! `result`:
+//! - `graph`: The graph of the schedule, in the requested format.
+//!
+//! ### bevy/storage/stats
+//!
+//! Report how entities and components are laid out in memory, to spot archetype fragmentation.
+//!
+//! `params`: None.
+//!
+//! `result`:
+//! - `archetype_count`: The number of archetypes. Archetypes are never removed, so comparing two
+//!   results gives the number of archetypes created in between.
+//! - `empty_archetype_count`: The number of archetypes without entities.
+//! - `table_occupancy`: The number of entities stored in tables, divided by their capacity.
+//! - `allocated_bytes`: The number of bytes allocated by tables and sparse sets.
+//! - `component_bytes`: A map associating each [fully-qualified type name] of component with the
+//!   number of bytes allocated to store it.
+//! - `archetypes`: An array with the `id`, `table`, `entity_count` and component names
+//!   (`components`) of every archetype.
+//! - `tables`: An array with the `id`, `entity_count`, `capacity` and `allocated_bytes` of every
+//!   table.
+//!
+//! ### `bevy/asset/dependency_graph`
+//!
+//! Export the graph of the assets an asset depends on and the assets that depend on it. Only
+//! available with the `bevy_asset` feature.
+//!
+//! `params`:
+//! - `path`: The path of the asset, which must have been loaded by the `AssetServer`.
+//! - `format` (optional): Either `json` for a JSON object, or `dot` for the Graphviz DOT language.
+//!   Defaults to `json`.
+//!
+//! `result`:
+//! - `graph`: The graph of the asset, in the requested format. As a JSON object, it contains the
+//!   `nodes` of the graph with their `path`, `loader`, `load_state`, `dependency_load_state`,
+//!   `recursive_dependency_load_state` and `error`, the indices of the requested assets in
+//!   `nodes` (`roots`), and the `edges` from a `dependent` to a `dependency`, whose `kind` is
+//!   either `asset` or `loader`.
+//!
+//! ### rpc.discover
+//!
+//! Discover the methods supported by the server.
+//!
+//! `params`: None.
+//!
+//! `result`: An [OpenRPC](https://spec.open-rpc.org) document listing every registered method,
+//! along with the parameters of the built-in methods.
+//!
 //!
 //! ## Custom methods
 //!
@@ -309,6 +459,7 @@ use bevy_ecs::{
     system::{Commands, In, IntoSystem, ResMut, Resource, System, SystemId},
     world::World,
 };
+use bevy_reflect::ReflectPathError;
 use bevy_utils::{prelude::default, HashMap};
 use serde::{Deserialize, Serialize};
 use serde_json::Value;
@@ -317,6 +468,9 @@ use std::sync::RwLock;
 pub mod builtin_methods;
 #[cfg(feature = "http")]
 pub mod http;
+pub mod schemas;
+#[cfg(feature = "websocket")]
+pub mod websocket;
 
 const CHANNEL_SIZE: usize = 16;
 
@@ -371,7 +525,7 @@ impl RemotePlugin {
 
 impl Default for RemotePlugin {
     fn default() -> Self {
-        Self::empty()
+        let plugin = Self::empty()
             .with_method(
                 builtin_methods::BRP_GET_METHOD,
                 builtin_methods::process_remote_get_request,
@@ -388,6 +542,10 @@ impl Default for RemotePlugin {
                 builtin_methods::BRP_INSERT_METHOD,
                 builtin_methods::process_remote_insert_request,
             )
+            .with_method(
+                builtin_methods::BRP_MUTATE_COMPONENT_METHOD,
+                builtin_methods::process_remote_mutate_component_request,
+            )
             .with_method(
                 builtin_methods::BRP_REMOVE_METHOD,
                 builtin_methods::process_remote_remove_request,
@@ -412,6 +570,48 @@ impl Default for RemotePlugin {
                 builtin_methods::BRP_LIST_AND_WATCH_METHOD,
                 builtin_methods::process_remote_list_watching_request,
             )
+            .with_method(
+                builtin_methods::BRP_GET_RESOURCE_METHOD,
+                builtin_methods::process_remote_get_resource_request,
+            )
+            .with_method(
+                builtin_methods::BRP_INSERT_RESOURCE_METHOD,
+                builtin_methods::process_remote_insert_resource_request,
+            )
+            .with_method(
+                builtin_methods::BRP_MUTATE_RESOURCE_METHOD,
+                builtin_methods::process_remote_mutate_resource_request,
+            )
+            .with_method(
+                builtin_methods::BRP_REMOVE_RESOURCE_METHOD,
+                builtin_methods::process_remote_remove_resource_request,
+            )
+            .with_method(
+                builtin_methods::BRP_LIST_RESOURCES_METHOD,
+                builtin_methods::process_remote_list_resources_request,
+            )
+            .with_method(
+                builtin_methods::BRP_REGISTRY_SCHEMA_METHOD,
+                builtin_methods::export_registry_types,
+            )
+            .with_method(
+                builtin_methods::BRP_SCHEDULE_GRAPH_METHOD,
+                builtin_methods::process_remote_schedule_graph_request,
+            )
+            .with_method(
+                builtin_methods::BRP_STORAGE_STATS_METHOD,
+                builtin_methods::process_remote_storage_stats_request,
+            )
+            .with_method(
+                builtin_methods::RPC_DISCOVER_METHOD,
+                builtin_methods::process_remote_list_methods_request,
+            );
+        #[cfg(feature = "bevy_asset")]
+        let plugin = plugin.with_method(
+            builtin_methods::BRP_ASSET_DEPENDENCY_GRAPH_METHOD,
+            builtin_methods::process_remote_asset_dependency_graph_request,
+        );
+        plugin
     }
 }
 
@@ -537,6 +737,11 @@ impl RemoteMethods {
     pub fn get(&self, method: &str) -> Option<&RemoteMethodSystemId> {
         self.0.get(method)
     }
+
+    /// Get the names of all registered methods.
+    pub fn methods(&self) -> Vec<String> {
+        self.0.keys().cloned().collect()
+    }
 }
 
 /// Holds the [`BrpMessage`]'s of all ongoing watching requests along with their handlers.
@@ -670,6 +875,70 @@ impl BrpError {
         }
     }
 
+    /// A [reflect path] couldn't be parsed or didn't lead to a valid field.
+    ///
+    /// The `data` of the error contains the `path`, the `kind` of error (`"parse"`, `"access"`
+    /// or `"downcast"`) and, if known, the `offset` in the path at which the error occurred.
+    ///
+    /// [reflect path]: bevy_reflect::GetPath
+    #[must_use]
+    pub fn invalid_path(path: &str, error: &ReflectPathError) -> Self {
+        let (kind, offset) = match error {
+            ReflectPathError::InvalidAccess(error) => ("access", error.offset().copied()),
+            ReflectPathError::InvalidDowncast => ("downcast", None),
+            ReflectPathError::ParseError { offset, .. } => ("parse", Some(*offset)),
+        };
+        Self {
+            code: error_codes::INVALID_REFLECT_PATH,
+            message: error.to_string(),
+            data: Some(serde_json::json!({
+                "path": path,
+                "kind": kind,
+                "offset": offset,
+            })),
+        }
+    }
+
+    /// Resource wasn't found in the world.
+    #[must_use]
+    pub fn resource_not_present(resource: &str) -> Self {
+        Self {
+            code: error_codes::RESOURCE_NOT_PRESENT,
+            message: format!("Resource `{resource}` not present in the world"),
+            data: None,
+        }
+    }
+
+    /// An arbitrary resource error. Possibly related to reflection.
+    #[must_use]
+    pub fn resource_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::RESOURCE_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
+
+    /// A schedule couldn't be found or exported.
+    #[must_use]
+    pub fn schedule_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::SCHEDULE_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
+
+    /// An asset couldn't be found or inspected.
+    #[must_use]
+    pub fn asset_error<E: ToString>(error: E) -> Self {
+        Self {
+            code: error_codes::ASSET_ERROR,
+            message: error.to_string(),
+            data: None,
+        }
+    }
+
     /// An arbitrary internal error.
     #[must_use]
     pub fn internal<E: ToString>(error: E) -> Self {
@@ -724,6 +993,21 @@ pub mod error_codes {
 
     /// Cannot reparent an entity to itself.
     pub const SELF_REPARENT: i16 = -23404;
+
+    /// Could not parse or follow a reflect path.
+    pub const INVALID_REFLECT_PATH: i16 = -23405;
+
+    /// Could not reflect or find resource.
+    pub const RESOURCE_ERROR: i16 = -23501;
+
+    /// Could not find resource in the world.
+    pub const RESOURCE_NOT_PRESENT: i16 = -23502;
+
+    /// Could not find or export schedule.
+    pub const SCHEDULE_ERROR: i16 = -23601;
+
+    /// Could not find or inspect asset.
+    pub const ASSET_ERROR: i16 = -23701;
 }
 
 /// The result of a request.
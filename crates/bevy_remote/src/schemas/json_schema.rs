@@ -0,0 +1,510 @@
+//! Module with JSON Schema type for Bevy Registry Types.
+//! It tries to follow this standard: <https://json-schema.org/specification>
+use core::any::TypeId;
+
+use bevy_ecs::reflect::{ReflectComponent, ReflectResource};
+use bevy_reflect::{
+    prelude::ReflectDefault, serde::SerializationData, NamedField, ReflectDeserialize,
+    ReflectSerialize, TypeInfo, TypeRegistration, UnnamedField, VariantInfo,
+};
+use bevy_utils::HashMap;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Map, Value};
+
+/// Exports schema info for a given type.
+///
+/// Returns the [type path] of the type along with its JSON Schema.
+///
+/// [type path]: bevy_reflect::TypePath::type_path
+pub fn export_type(reg: &TypeRegistration) -> (String, JsonSchemaBevyType) {
+    let t = reg.type_info();
+    let binding = t.type_path_table();
+
+    let short_path = binding.short_path();
+    let type_path = binding.path();
+    let mut typed_schema = JsonSchemaBevyType {
+        reflect_types: get_registered_reflect_types(reg),
+        short_path: short_path.to_owned(),
+        type_path: type_path.to_owned(),
+        crate_name: binding.crate_name().map(str::to_owned),
+        module_path: binding.module_path().map(str::to_owned),
+        ..Default::default()
+    };
+    match t {
+        TypeInfo::Struct(info) => {
+            typed_schema.properties = info
+                .iter()
+                .map(|field| (field.name().to_owned(), named_field_schema(field)))
+                .collect::<HashMap<_, _>>();
+            typed_schema.required = info.iter().map(|field| field.name().to_owned()).collect();
+            typed_schema.additional_properties = Some(false);
+            typed_schema.schema_type = Some(SchemaType::Object);
+            typed_schema.kind = SchemaKind::Struct;
+        }
+        TypeInfo::Enum(info) => {
+            typed_schema.kind = SchemaKind::Enum;
+
+            if is_option(t) {
+                // `Option` is serialized as either `null` or its inner value.
+                typed_schema.one_of = vec![json!({ "type": "null" })];
+                if let Some(VariantInfo::Tuple(some)) = info.variant("Some") {
+                    typed_schema
+                        .one_of
+                        .extend(some.iter().map(unnamed_field_schema));
+                }
+            } else {
+                let simple = info
+                    .iter()
+                    .all(|variant| matches!(variant, VariantInfo::Unit(_)));
+                if simple {
+                    typed_schema.schema_type = Some(SchemaType::String);
+                    typed_schema.one_of = info
+                        .iter()
+                        .map(|variant| Value::String(variant.name().to_owned()))
+                        .collect();
+                } else {
+                    // Unit variants are strings and the others are objects, so the type is left
+                    // to the variants.
+                    typed_schema.one_of = info.iter().map(variant_schema).collect();
+                }
+            }
+        }
+        TypeInfo::TupleStruct(info) => {
+            // Tuple structs with a single field are serialized as that field.
+            if info.field_len() == 1 && reg.data::<SerializationData>().is_none() {
+                typed_schema.one_of = info.iter().map(unnamed_field_schema).collect();
+            } else {
+                typed_schema.schema_type = Some(SchemaType::Array);
+                typed_schema.prefix_items = info.iter().map(unnamed_field_schema).collect();
+                typed_schema.items = Some(false.into());
+            }
+            typed_schema.kind = SchemaKind::TupleStruct;
+        }
+        TypeInfo::Tuple(info) => {
+            typed_schema.schema_type = Some(SchemaType::Array);
+            typed_schema.kind = SchemaKind::Tuple;
+            typed_schema.prefix_items = info.iter().map(unnamed_field_schema).collect();
+            typed_schema.items = Some(false.into());
+        }
+        TypeInfo::List(info) => {
+            typed_schema.schema_type = Some(SchemaType::Array);
+            typed_schema.kind = SchemaKind::List;
+            typed_schema.items = Some(type_ref(info.item_ty().path()));
+        }
+        TypeInfo::Array(info) => {
+            typed_schema.schema_type = Some(SchemaType::Array);
+            typed_schema.kind = SchemaKind::Array;
+            typed_schema.items = Some(type_ref(info.item_ty().path()));
+            typed_schema.min_items = Some(info.capacity());
+            typed_schema.max_items = Some(info.capacity());
+        }
+        TypeInfo::Map(info) => {
+            typed_schema.schema_type = Some(SchemaType::Object);
+            typed_schema.kind = SchemaKind::Map;
+            typed_schema.key_type = Some(type_ref(info.key_ty().path()));
+            typed_schema.value_type = Some(type_ref(info.value_ty().path()));
+        }
+        TypeInfo::Set(info) => {
+            typed_schema.schema_type = Some(SchemaType::Array);
+            typed_schema.kind = SchemaKind::Set;
+            typed_schema.items = Some(type_ref(info.value_ty().path()));
+            typed_schema.unique_items = Some(true);
+        }
+        TypeInfo::Opaque(info) => {
+            typed_schema.schema_type = Some(SchemaType::from_type_path(info.type_path()));
+            if typed_schema.schema_type == Some(SchemaType::Integer)
+                && info.type_path().starts_with('u')
+            {
+                typed_schema.minimum = Some(0);
+            }
+            typed_schema.kind = SchemaKind::Value;
+        }
+    };
+
+    (type_path.to_owned(), typed_schema)
+}
+
+/// Returns the names of the commonly used type data registered for the given type.
+fn get_registered_reflect_types(reg: &TypeRegistration) -> Vec<String> {
+    // Vec could be moved to allow registering more types by game maker.
+    let registered_reflect_types = [
+        (TypeId::of::<ReflectComponent>(), "Component"),
+        (TypeId::of::<ReflectResource>(), "Resource"),
+        (TypeId::of::<ReflectDefault>(), "Default"),
+        (TypeId::of::<ReflectSerialize>(), "Serialize"),
+        (TypeId::of::<ReflectDeserialize>(), "Deserialize"),
+    ];
+
+    registered_reflect_types
+        .into_iter()
+        .filter(|(id, _)| reg.data_by_id(*id).is_some())
+        .map(|(_, name)| name.to_owned())
+        .collect()
+}
+
+/// Returns true if the given type is [`Option`], which is serialized as its inner value or `null`.
+fn is_option(info: &TypeInfo) -> bool {
+    let table = info.type_path_table();
+    table.module_path() == Some("core::option") && table.ident() == Some("Option")
+}
+
+/// Returns a JSON Schema reference to the type with the given [type path].
+///
+/// [type path]: bevy_reflect::TypePath::type_path
+fn type_ref(type_path: &str) -> Value {
+    json!({ "$ref": format!("#/$defs/{type_path}") })
+}
+
+fn named_field_schema(field: &NamedField) -> Value {
+    type_ref(field.type_path())
+}
+
+fn unnamed_field_schema(field: &UnnamedField) -> Value {
+    type_ref(field.type_path())
+}
+
+/// Returns the schema of a single enum variant, which is externally tagged with its name.
+fn variant_schema(variant: &VariantInfo) -> Value {
+    match variant {
+        VariantInfo::Struct(info) => {
+            let properties = info
+                .iter()
+                .map(|field| (field.name().to_owned(), named_field_schema(field)))
+                .collect::<Map<_, _>>();
+            let required = info.iter().map(NamedField::name).collect::<Vec<_>>();
+            json!({
+                "type": "object",
+                "properties": {
+                    info.name(): {
+                        "type": "object",
+                        "properties": properties,
+                        "required": required,
+                        "additionalProperties": false,
+                    }
+                },
+                "required": [info.name()],
+                "additionalProperties": false,
+            })
+        }
+        VariantInfo::Tuple(info) => {
+            let value = if info.field_len() == 1 {
+                info.iter().map(unnamed_field_schema).next().unwrap()
+            } else {
+                json!({
+                    "type": "array",
+                    "prefixItems": info.iter().map(unnamed_field_schema).collect::<Vec<_>>(),
+                    "items": false,
+                })
+            };
+            json!({
+                "type": "object",
+                "properties": { info.name(): value },
+                "required": [info.name()],
+                "additionalProperties": false,
+            })
+        }
+        VariantInfo::Unit(info) => json!({ "type": "string", "const": info.name() }),
+    }
+}
+
+/// JSON Schema type for Bevy Registry Types.
+///
+/// Alongside the standard JSON Schema keywords, this contains information about the Rust type
+/// and about the type data registered for it.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonSchemaBevyType {
+    /// Bevy specific field, short path of the type.
+    pub short_path: String,
+    /// Bevy specific field, full path of the type.
+    pub type_path: String,
+    /// Bevy specific field, path of the module that type is part of.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub module_path: Option<String>,
+    /// Bevy specific field, name of the crate that type is part of.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub crate_name: Option<String>,
+    /// Bevy specific field, names of the commonly used type data registered for the type,
+    /// e.g. `Component` for [`ReflectComponent`].
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub reflect_types: Vec<String>,
+    /// Bevy specific field, [`TypeInfo`] type mapping.
+    pub kind: SchemaKind,
+    /// Bevy specific field, provided when [`SchemaKind`] `kind` field is equal to [`SchemaKind::Map`].
+    ///
+    /// It contains type info of key of the Map.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub key_type: Option<Value>,
+    /// Bevy specific field, provided when [`SchemaKind`] `kind` field is equal to [`SchemaKind::Map`].
+    ///
+    /// It contains type info of value of the Map.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub value_type: Option<Value>,
+    /// The type keyword is fundamental to JSON Schema. It specifies the data type for a schema.
+    ///
+    /// This is omitted for types that may be serialized as more than one type, such as [`Option`].
+    #[serde(rename = "type", skip_serializing_if = "Option::is_none", default)]
+    pub schema_type: Option<SchemaType>,
+    /// The behavior of this keyword depends on the presence and annotation results of "properties"
+    /// and "patternProperties" within the same schema object.
+    /// Validation with "additionalProperties" applies only to the child
+    /// values of instance names that do not appear in the annotation results of either "properties" or "patternProperties".
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub additional_properties: Option<bool>,
+    /// Validation succeeds if, for each name that appears in both the instance and as a name
+    /// within this keyword's value, the child instance for that name successfully validates
+    /// against the corresponding schema.
+    #[serde(skip_serializing_if = "HashMap::is_empty", default)]
+    pub properties: HashMap<String, Value>,
+    /// An object instance is valid against this keyword if every item in the array is the name of a property in the instance.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub required: Vec<String>,
+    /// An instance validates successfully against this keyword if it validates successfully against exactly one schema defined by this keyword's value.
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub one_of: Vec<Value>,
+    /// Validation succeeds if each element of the instance validates against the schema at the same position, if any. This keyword does not constrain the length of the array. If the array is longer than this keyword's value, this keyword validates only the prefix of matching length.
+    ///
+    /// This keyword produces an annotation value which is the largest index to which this keyword
+    /// applied a subschema. The value MAY be a boolean true if a subschema was applied to every
+    /// index of the instance, such as is produced by the "items" keyword.
+    /// This annotation affects the behavior of "items" and "unevaluatedItems".
+    #[serde(skip_serializing_if = "Vec::is_empty", default)]
+    pub prefix_items: Vec<Value>,
+    /// This keyword applies its subschema to all instance elements at indexes greater
+    /// than the length of the "prefixItems" array in the same schema object,
+    /// as reported by the annotation result of that "prefixItems" keyword.
+    /// If no such annotation result exists, "items" applies its subschema to all
+    /// instance array elements.
+    ///
+    /// If the "items" subschema is applied to any positions within the instance array,
+    /// it produces an annotation result of boolean true, indicating that all remaining
+    /// array elements have been evaluated against this keyword's subschema.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub items: Option<Value>,
+    /// An array instance is valid against this keyword if its size is greater than, or equal to, the value of this keyword.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub min_items: Option<usize>,
+    /// An array instance is valid against this keyword if its size is less than, or equal to, the value of this keyword.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub max_items: Option<usize>,
+    /// If this keyword has boolean value false, the instance validates successfully.
+    /// If it has boolean value true, the instance validates successfully if all of its elements are unique.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub unique_items: Option<bool>,
+    /// Validation succeeds if the numeric instance is greater than or equal to the given number.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub minimum: Option<i64>,
+    /// Type description
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description: Option<String>,
+}
+
+/// Kind of json schema, maps [`TypeInfo`] type
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+pub enum SchemaKind {
+    /// Struct
+    #[default]
+    Struct,
+    /// Enum type
+    Enum,
+    /// A key-value map
+    Map,
+    /// Array
+    Array,
+    /// List
+    List,
+    /// Fixed size collection of items
+    Tuple,
+    /// Fixed size collection of items with named fields
+    TupleStruct,
+    /// Set of unique values
+    Set,
+    /// Single value, eg. primitive types
+    Value,
+}
+
+/// Type of json schema
+#[derive(Debug, Serialize, Deserialize, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum SchemaType {
+    /// Represents a string value.
+    String,
+    /// Represents a number, which may be a floating-point number.
+    Number,
+    /// Represents an integer.
+    Integer,
+    /// Represents an object with key-value pairs.
+    #[default]
+    Object,
+    /// Represents an array of values.
+    Array,
+    /// Represents a boolean value (true or false).
+    Boolean,
+    /// Represents a null value.
+    Null,
+}
+
+impl SchemaType {
+    /// Returns the [`SchemaType`] that an opaque type with the given [type path] serializes as.
+    ///
+    /// [type path]: bevy_reflect::TypePath::type_path
+    pub fn from_type_path(type_path: &str) -> Self {
+        match type_path {
+            "bool" => Self::Boolean,
+            "u8" | "u16" | "u32" | "u64" | "u128" | "usize" | "i8" | "i16" | "i32" | "i64"
+            | "i128" | "isize" => Self::Integer,
+            "f32" | "f64" => Self::Number,
+            "char"
+            | "str"
+            | "alloc::string::String"
+            | "alloc::borrow::Cow<str>"
+            | "std::path::PathBuf"
+            | "bevy_utils::Cow<str>" => Self::String,
+            _ => Self::Object,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_ecs::{component::Component, reflect::AppTypeRegistry, system::Resource};
+    use bevy_reflect::{prelude::ReflectDefault, Reflect};
+
+    #[test]
+    fn reflect_export_struct() {
+        #[derive(Reflect, Resource, Default, Deserialize, Serialize)]
+        #[reflect(Resource, Default, Serialize, Deserialize)]
+        struct Foo {
+            a: f32,
+            b: Option<f32>,
+        }
+
+        let atr = AppTypeRegistry::default();
+        {
+            let mut register = atr.write();
+            register.register::<Foo>();
+        }
+        let type_registry = atr.read();
+        let foo_registration = type_registry
+            .get(TypeId::of::<Foo>())
+            .expect("SHOULD BE REGISTERED")
+            .clone();
+        let (_, schema) = export_type(&foo_registration);
+
+        assert!(
+            !schema.reflect_types.contains(&"Component".to_owned()),
+            "Should not be a component"
+        );
+        assert!(
+            schema.reflect_types.contains(&"Resource".to_owned()),
+            "Should be a resource"
+        );
+        assert_eq!(schema.kind, SchemaKind::Struct);
+        assert_eq!(schema.schema_type, Some(SchemaType::Object));
+        assert_eq!(schema.additional_properties, Some(false));
+        assert!(schema.properties.contains_key("a"));
+        assert!(schema.properties.contains_key("b"));
+        assert_eq!(schema.required, vec!["a".to_owned(), "b".to_owned()]);
+        assert_eq!(schema.properties["a"], json!({ "$ref": "#/$defs/f32" }));
+    }
+
+    #[test]
+    fn reflect_export_enum() {
+        #[derive(Reflect, Component, Default)]
+        #[reflect(Component, Default)]
+        enum EnumComponent {
+            ValueOne(i32),
+            ValueTwo {
+                test: i32,
+            },
+            #[default]
+            NoValue,
+        }
+
+        #[derive(Reflect)]
+        enum UnitEnum {
+            A,
+            B,
+        }
+
+        let atr = AppTypeRegistry::default();
+        {
+            let mut register = atr.write();
+            register.register::<EnumComponent>();
+            register.register::<UnitEnum>();
+        }
+        let type_registry = atr.read();
+        let registration = type_registry
+            .get(TypeId::of::<EnumComponent>())
+            .expect("SHOULD BE REGISTERED");
+        let (_, schema) = export_type(registration);
+
+        assert!(schema.reflect_types.contains(&"Component".to_owned()));
+        assert!(schema.reflect_types.contains(&"Default".to_owned()));
+        assert_eq!(schema.kind, SchemaKind::Enum);
+        assert_eq!(schema.one_of.len(), 3);
+        assert_eq!(schema.schema_type, None);
+        assert_eq!(
+            schema.one_of[2],
+            json!({ "type": "string", "const": "NoValue" })
+        );
+        assert_eq!(
+            schema.one_of[0],
+            json!({
+                "type": "object",
+                "properties": { "ValueOne": { "$ref": "#/$defs/i32" } },
+                "required": ["ValueOne"],
+                "additionalProperties": false,
+            })
+        );
+
+        let registration = type_registry
+            .get(TypeId::of::<UnitEnum>())
+            .expect("SHOULD BE REGISTERED");
+        let (_, schema) = export_type(registration);
+        assert_eq!(schema.schema_type, Some(SchemaType::String));
+        assert_eq!(schema.one_of, vec![json!("A"), json!("B")]);
+    }
+
+    #[test]
+    fn reflect_export_collections() {
+        let atr = AppTypeRegistry::default();
+        {
+            let mut register = atr.write();
+            register.register::<Vec<u32>>();
+            register.register::<[u8; 4]>();
+            register.register::<(u8, bool)>();
+            register.register::<HashMap<String, f32>>();
+        }
+        let type_registry = atr.read();
+        let export = |id| export_type(type_registry.get(id).unwrap()).1;
+
+        let list = export(TypeId::of::<Vec<u32>>());
+        assert_eq!(list.kind, SchemaKind::List);
+        assert_eq!(list.items, Some(json!({ "$ref": "#/$defs/u32" })));
+
+        let array = export(TypeId::of::<[u8; 4]>());
+        assert_eq!(array.kind, SchemaKind::Array);
+        assert_eq!(array.min_items, Some(4));
+        assert_eq!(array.max_items, Some(4));
+
+        let tuple = export(TypeId::of::<(u8, bool)>());
+        assert_eq!(tuple.kind, SchemaKind::Tuple);
+        assert_eq!(tuple.prefix_items.len(), 2);
+        assert_eq!(tuple.items, Some(json!(false)));
+
+        let map = export(TypeId::of::<HashMap<String, f32>>());
+        assert_eq!(map.kind, SchemaKind::Map);
+        assert_eq!(
+            map.key_type,
+            Some(json!({ "$ref": "#/$defs/alloc::string::String" }))
+        );
+        assert_eq!(map.value_type, Some(json!({ "$ref": "#/$defs/f32" })));
+
+        let uint = export(TypeId::of::<u32>());
+        assert_eq!(uint.kind, SchemaKind::Value);
+        assert_eq!(uint.schema_type, Some(SchemaType::Integer));
+        assert_eq!(uint.minimum, Some(0));
+    }
+}
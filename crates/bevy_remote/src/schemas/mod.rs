@@ -0,0 +1,4 @@
+//! Schemas describing the types and methods exposed by the Bevy Remote Protocol.
+
+pub mod json_schema;
+pub mod open_rpc;
@@ -0,0 +1,267 @@
+//! Module with trimmed down `OpenRPC` document structs.
+//! It tries to follow this standard: <https://spec.open-rpc.org>
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+
+use crate::builtin_methods::*;
+
+/// Represents an `OpenRPC` document as defined by the specification.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct OpenRpcDocument {
+    /// The version of the `OpenRPC` specification being used.
+    pub openrpc: String,
+    /// Informational metadata about the document.
+    pub info: InfoObject,
+    /// List of RPC methods defined in the document.
+    pub methods: Vec<MethodObject>,
+}
+
+/// Contains metadata information about the `OpenRPC` document.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct InfoObject {
+    /// The title of the API or document.
+    pub title: String,
+    /// The version of the API.
+    pub version: String,
+    /// An optional description providing additional details about the API.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub description: Option<String>,
+}
+
+impl Default for InfoObject {
+    fn default() -> Self {
+        Self {
+            title: "Bevy Remote Protocol".to_owned(),
+            version: env!("CARGO_PKG_VERSION").to_owned(),
+            description: None,
+        }
+    }
+}
+
+/// Represents an RPC method in the `OpenRPC` document.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq, Default)]
+#[serde(rename_all = "camelCase")]
+pub struct MethodObject {
+    /// The method name (e.g., "/bevy/get")
+    pub name: String,
+    /// An optional short summary of the method.
+    #[serde(skip_serializing_if = "Option::is_none", default)]
+    pub summary: Option<String>,
+    /// Parameters for the RPC method.
+    ///
+    /// These are only known for the built-in methods; custom methods report no parameters.
+    #[serde(default)]
+    pub params: Vec<Parameter>,
+}
+
+/// Represents an RPC method parameter in the `OpenRPC` document.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct Parameter {
+    /// Parameter name.
+    pub name: String,
+    /// Whether the parameter must be provided.
+    #[serde(default)]
+    pub required: bool,
+    /// JSON schema describing the parameter.
+    pub schema: Value,
+}
+
+impl Parameter {
+    fn new(name: &str, required: bool, schema: Value) -> Self {
+        Self {
+            name: name.to_owned(),
+            required,
+            schema,
+        }
+    }
+}
+
+impl MethodObject {
+    /// Returns the [`MethodObject`] describing the method with the given name.
+    ///
+    /// The parameters are filled in for the built-in methods of [`RemotePlugin`](crate::RemotePlugin).
+    pub fn new(name: impl Into<String>) -> Self {
+        let name = name.into();
+        let (summary, params) = builtin_method_info(&name).unwrap_or_default();
+        Self {
+            summary: summary.map(str::to_owned),
+            params,
+            name,
+        }
+    }
+}
+
+/// Returns the summary and parameters of a built-in method.
+fn builtin_method_info(method: &str) -> Option<(Option<&'static str>, Vec<Parameter>)> {
+    let entity = || json!({ "type": "integer", "minimum": 0 });
+    let type_path = || json!({ "type": "string" });
+    let type_paths = || json!({ "type": "array", "items": type_path() });
+    let components = || json!({ "type": "object", "additionalProperties": true });
+
+    let info = match method {
+        BRP_GET_METHOD | BRP_GET_AND_WATCH_METHOD => (
+            "Retrieves one or more components from an entity.",
+            vec![
+                Parameter::new("entity", true, entity()),
+                Parameter::new("components", true, type_paths()),
+                Parameter::new("strict", false, json!({ "type": "boolean" })),
+            ],
+        ),
+        BRP_QUERY_METHOD => (
+            "Performs a query over components in the ECS.",
+            vec![
+                Parameter::new(
+                    "data",
+                    true,
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "components": type_paths(),
+                            "option": type_paths(),
+                            "has": type_paths(),
+                        },
+                    }),
+                ),
+                Parameter::new(
+                    "filter",
+                    false,
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "with": type_paths(),
+                            "without": type_paths(),
+                        },
+                    }),
+                ),
+            ],
+        ),
+        BRP_SPAWN_METHOD => (
+            "Creates a new entity with the given components.",
+            vec![Parameter::new("components", true, components())],
+        ),
+        BRP_INSERT_METHOD => (
+            "Adds one or more components to an entity.",
+            vec![
+                Parameter::new("entity", true, entity()),
+                Parameter::new("components", true, components()),
+            ],
+        ),
+        BRP_REMOVE_METHOD => (
+            "Removes one or more components from an entity.",
+            vec![
+                Parameter::new("entity", true, entity()),
+                Parameter::new("components", true, type_paths()),
+            ],
+        ),
+        BRP_MUTATE_COMPONENT_METHOD => (
+            "Sets the value of a field of a component on an entity.",
+            vec![
+                Parameter::new("entity", true, entity()),
+                Parameter::new("component", true, type_path()),
+                Parameter::new("path", true, json!({ "type": "string" })),
+                Parameter::new("value", true, json!(true)),
+            ],
+        ),
+        BRP_DESTROY_METHOD => (
+            "Despawns an entity.",
+            vec![Parameter::new("entity", true, entity())],
+        ),
+        BRP_REPARENT_METHOD => (
+            "Assigns a new parent to one or more entities.",
+            vec![
+                Parameter::new(
+                    "entities",
+                    true,
+                    json!({ "type": "array", "items": entity() }),
+                ),
+                Parameter::new("parent", false, entity()),
+            ],
+        ),
+        BRP_LIST_METHOD => (
+            "Lists all registered components, or those present on an entity.",
+            vec![Parameter::new("entity", false, entity())],
+        ),
+        BRP_LIST_AND_WATCH_METHOD => (
+            "Watches the components present on an entity.",
+            vec![Parameter::new("entity", true, entity())],
+        ),
+        BRP_GET_RESOURCE_METHOD => (
+            "Retrieves the value of a resource.",
+            vec![Parameter::new("resource", true, type_path())],
+        ),
+        BRP_INSERT_RESOURCE_METHOD => (
+            "Inserts a resource into the world, replacing any existing value.",
+            vec![
+                Parameter::new("resource", true, type_path()),
+                Parameter::new("value", true, json!(true)),
+            ],
+        ),
+        BRP_MUTATE_RESOURCE_METHOD => (
+            "Sets the value of a field of a resource.",
+            vec![
+                Parameter::new("resource", true, type_path()),
+                Parameter::new("path", true, json!({ "type": "string" })),
+                Parameter::new("value", true, json!(true)),
+            ],
+        ),
+        BRP_REMOVE_RESOURCE_METHOD => (
+            "Removes a resource from the world.",
+            vec![Parameter::new("resource", true, type_path())],
+        ),
+        BRP_LIST_RESOURCES_METHOD => ("Lists all registered resources.", vec![]),
+        BRP_REGISTRY_SCHEMA_METHOD => (
+            "Returns the JSON Schema of every reflected type.",
+            vec![
+                Parameter::new("with_crates", false, type_paths()),
+                Parameter::new("without_crates", false, type_paths()),
+                Parameter::new(
+                    "type_limit",
+                    false,
+                    json!({
+                        "type": "object",
+                        "properties": {
+                            "with": type_paths(),
+                            "without": type_paths(),
+                        },
+                    }),
+                ),
+            ],
+        ),
+        BRP_SCHEDULE_GRAPH_METHOD => (
+            "Exports the graph of the systems of a schedule.",
+            vec![
+                Parameter::new("schedule", true, json!({ "type": "string" })),
+                Parameter::new(
+                    "format",
+                    false,
+                    json!({ "type": "string", "enum": ["dot", "mermaid"] }),
+                ),
+                Parameter::new("collapse_sets", false, json!({ "type": "boolean" })),
+                Parameter::new("show_ambiguities", false, json!({ "type": "boolean" })),
+                Parameter::new("color_conditions", false, json!({ "type": "boolean" })),
+            ],
+        ),
+        BRP_STORAGE_STATS_METHOD => (
+            "Reports how entities and components are laid out in memory.",
+            vec![],
+        ),
+        BRP_ASSET_DEPENDENCY_GRAPH_METHOD => (
+            "Exports the graph of the dependencies and dependents of an asset.",
+            vec![
+                Parameter::new("path", true, json!({ "type": "string" })),
+                Parameter::new(
+                    "format",
+                    false,
+                    json!({ "type": "string", "enum": ["json", "dot"] }),
+                ),
+            ],
+        ),
+        RPC_DISCOVER_METHOD => ("Lists every method known to the server.", vec![]),
+        _ => return None,
+    };
+
+    Some((Some(info.0), info.1))
+}
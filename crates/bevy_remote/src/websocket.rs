@@ -0,0 +1,640 @@
+//! The BRP transport using JSON-RPC over WebSocket.
+//!
+//! Adding the [`RemoteWebSocketPlugin`] to your [`App`] causes Bevy to accept
+//! WebSocket connections (by default, on port 15703) while your app is running.
+//! It uses the same [`RemoteMethods`](crate::RemoteMethods) as every other transport.
+//!
+//! Each text or binary message sent by the client is a JSON-RPC request, or a batch of
+//! requests, exactly as it would be sent over HTTP. Responses are sent back as text messages
+//! on the same connection, possibly out of order, so clients should match them up using the
+//! request `id`.
+//!
+//! ## Subscriptions
+//!
+//! A single connection can carry any number of watching methods, such as `bevy/get+watch`.
+//! Instead of streaming their results in the response, the server immediately responds with
+//! the ID of a new subscription:
+//!
+//! ```json
+//! {
+//!     "jsonrpc": "2.0",
+//!     "id": 0,
+//!     "result": {
+//!         "subscription": 0
+//!     }
+//! }
+//! ```
+//!
+//! Every result of the watching method is then pushed to the client as a JSON-RPC
+//! notification, whose `params` contain the subscription ID along with either a `result`
+//! or an `error`:
+//!
+//! ```json
+//! {
+//!     "jsonrpc": "2.0",
+//!     "method": "bevy/subscription",
+//!     "params": {
+//!         "subscription": 0,
+//!         "result": {
+//!             "components": {},
+//!             "removed": []
+//!         }
+//!     }
+//! }
+//! ```
+//!
+//! A subscription is cancelled by sending a `bevy/unsubscribe` request with the
+//! `subscription` ID as its parameter, and all subscriptions of a connection are cancelled
+//! when it is closed.
+
+#![cfg(not(target_family = "wasm"))]
+
+use crate::{
+    error_codes, BrpBatch, BrpError, BrpMessage, BrpPayload, BrpRequest, BrpResponse, BrpResult,
+    BrpSender,
+};
+use anyhow::Result as AnyhowResult;
+use async_channel::{Receiver, Sender};
+use async_io::Async;
+use async_tungstenite::tungstenite::Message;
+use bevy_app::{App, Plugin, Startup};
+use bevy_ecs::system::{Res, Resource};
+use bevy_tasks::{
+    futures_lite::{future, StreamExt},
+    IoTaskPool,
+};
+use bevy_utils::HashMap;
+use core::net::{IpAddr, Ipv4Addr};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::net::{TcpListener, TcpStream};
+
+/// The default port that Bevy will listen on for WebSocket connections.
+///
+/// This is one more than the default HTTP port, so that both transports can be used at once.
+pub const DEFAULT_PORT: u16 = 15703;
+
+/// The default host address that Bevy will use for its WebSocket server.
+pub const DEFAULT_ADDR: IpAddr = IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1));
+
+/// The method of the notifications that carry the results of a subscription.
+pub const BRP_SUBSCRIPTION_NOTIFICATION: &str = "bevy/subscription";
+
+/// The method path for a `bevy/unsubscribe` request, which cancels a subscription.
+pub const BRP_UNSUBSCRIBE_METHOD: &str = "bevy/unsubscribe";
+
+/// The number of results of a single subscription that may be waiting to be sent.
+const SUBSCRIPTION_CHANNEL_SIZE: usize = 8;
+
+/// Add this plugin to your [`App`] to allow remote connections over a WebSocket to inspect and
+/// modify entities. It requires the [`RemotePlugin`](super::RemotePlugin), and can be used
+/// alongside the [`RemoteHttpPlugin`](crate::http::RemoteHttpPlugin).
+///
+/// This BRP transport cannot be used when targeting WASM.
+///
+/// The defaults are:
+/// - [`DEFAULT_ADDR`] : 127.0.0.1.
+/// - [`DEFAULT_PORT`] : 15703.
+pub struct RemoteWebSocketPlugin {
+    /// The address that Bevy will bind to.
+    address: IpAddr,
+    /// The port that Bevy will listen on.
+    port: u16,
+}
+
+impl Default for RemoteWebSocketPlugin {
+    fn default() -> Self {
+        Self {
+            address: DEFAULT_ADDR,
+            port: DEFAULT_PORT,
+        }
+    }
+}
+
+impl Plugin for RemoteWebSocketPlugin {
+    fn build(&self, app: &mut App) {
+        app.insert_resource(WebSocketHostAddress(self.address))
+            .insert_resource(WebSocketHostPort(self.port))
+            .add_systems(Startup, start_websocket_server);
+    }
+}
+
+impl RemoteWebSocketPlugin {
+    /// Set the IP address that the server will use.
+    #[must_use]
+    pub fn with_address(mut self, address: impl Into<IpAddr>) -> Self {
+        self.address = address.into();
+        self
+    }
+
+    /// Set the remote port that the server will listen on.
+    #[must_use]
+    pub fn with_port(mut self, port: u16) -> Self {
+        self.port = port;
+        self
+    }
+}
+
+/// A resource containing the IP address that Bevy will host the WebSocket server on.
+///
+/// Currently, changing this while the application is running has no effect; this merely
+/// reflects the IP address that is set during the setup of the [`RemoteWebSocketPlugin`].
+#[derive(Debug, Resource)]
+pub struct WebSocketHostAddress(pub IpAddr);
+
+/// A resource containing the port number that Bevy will listen on for WebSocket connections.
+///
+/// Currently, changing this while the application is running has no effect; this merely
+/// reflects the host that is set during the setup of the [`RemoteWebSocketPlugin`].
+#[derive(Debug, Resource)]
+pub struct WebSocketHostPort(pub u16);
+
+/// `bevy/unsubscribe`: Cancels a subscription created by a watching method.
+///
+/// The server responds with a null.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpUnsubscribeParams {
+    /// The ID of the subscription to cancel.
+    pub subscription: u64,
+}
+
+/// The response to a watching method sent over a WebSocket.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct BrpSubscribeResponse {
+    /// The ID of the new subscription, which is included in each of its notifications.
+    pub subscription: u64,
+}
+
+/// A JSON-RPC notification that carries a single result of a subscription.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpNotification {
+    /// This field is mandatory and must be set to `"2.0"`.
+    pub jsonrpc: String,
+
+    /// The notification method, which is always [`BRP_SUBSCRIPTION_NOTIFICATION`].
+    pub method: String,
+
+    /// The result of the subscription.
+    pub params: BrpSubscriptionResult,
+}
+
+impl BrpNotification {
+    /// Generates a [`BrpNotification`] from a subscription ID and a `Result`.
+    #[must_use]
+    pub fn new(subscription: u64, result: BrpResult) -> Self {
+        Self {
+            jsonrpc: "2.0".to_owned(),
+            method: BRP_SUBSCRIPTION_NOTIFICATION.to_owned(),
+            params: BrpSubscriptionResult {
+                subscription,
+                payload: BrpPayload::from(result),
+            },
+        }
+    }
+}
+
+/// The `params` of a [`BrpNotification`].
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct BrpSubscriptionResult {
+    /// The ID of the subscription that produced this result.
+    pub subscription: u64,
+
+    /// The result or error produced by the watching method.
+    #[serde(flatten)]
+    pub payload: BrpPayload,
+}
+
+/// A system that starts up the Bevy Remote Protocol WebSocket server.
+fn start_websocket_server(
+    request_sender: Res<BrpSender>,
+    address: Res<WebSocketHostAddress>,
+    remote_port: Res<WebSocketHostPort>,
+) {
+    IoTaskPool::get()
+        .spawn(server_main(
+            address.0,
+            remote_port.0,
+            request_sender.clone(),
+        ))
+        .detach();
+}
+
+/// The Bevy Remote Protocol WebSocket server main loop.
+async fn server_main(
+    address: IpAddr,
+    port: u16,
+    request_sender: Sender<BrpMessage>,
+) -> AnyhowResult<()> {
+    let listener = Async::<TcpListener>::bind((address, port))?;
+    loop {
+        let (client, _) = listener.accept().await?;
+
+        let request_sender = request_sender.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                let _ = handle_client(client, request_sender).await;
+            })
+            .detach();
+    }
+}
+
+/// The state of a single WebSocket connection.
+struct Connection {
+    request_sender: Sender<BrpMessage>,
+    /// Messages that are waiting to be sent to the client.
+    outgoing_sender: Sender<String>,
+    /// The receivers of every active subscription, used to cancel them.
+    subscriptions: HashMap<u64, Receiver<BrpResult>>,
+    next_subscription: u64,
+}
+
+/// An event on a WebSocket connection.
+enum ConnectionEvent {
+    Incoming(Option<Result<Message, async_tungstenite::tungstenite::Error>>),
+    Outgoing(Option<String>),
+}
+
+async fn handle_client(
+    client: Async<TcpStream>,
+    request_sender: Sender<BrpMessage>,
+) -> AnyhowResult<()> {
+    let mut websocket = async_tungstenite::accept_async(client).await?;
+    let (outgoing_sender, outgoing_receiver) = async_channel::unbounded();
+    let mut connection = Connection {
+        request_sender,
+        outgoing_sender,
+        subscriptions: HashMap::default(),
+        next_subscription: 0,
+    };
+
+    let result = async {
+        loop {
+            let event = future::or(
+                async { ConnectionEvent::Incoming(websocket.next().await) },
+                async { ConnectionEvent::Outgoing(outgoing_receiver.recv().await.ok()) },
+            )
+            .await;
+
+            match event {
+                ConnectionEvent::Incoming(Some(message)) => match message? {
+                    Message::Text(text) => connection.process_message(text.as_bytes()).await?,
+                    Message::Binary(bytes) => connection.process_message(&bytes).await?,
+                    Message::Close(_) => break,
+                    // Pings are answered automatically.
+                    Message::Ping(_) | Message::Pong(_) | Message::Frame(_) => {}
+                },
+                ConnectionEvent::Outgoing(Some(text)) => {
+                    websocket.send(Message::text(text)).await?;
+                }
+                ConnectionEvent::Incoming(None) | ConnectionEvent::Outgoing(None) => break,
+            }
+        }
+        AnyhowResult::Ok(())
+    }
+    .await;
+
+    // Cancel all subscriptions of this connection. The `RemotePlugin` stops watching once
+    // the receivers are closed.
+    for (_, receiver) in connection.subscriptions.drain() {
+        receiver.close();
+    }
+
+    result
+}
+
+/// The outcome of a single request on a WebSocket connection.
+enum RequestOutcome {
+    /// The response is already known.
+    Complete(BrpResponse),
+    /// The response will be produced by the world.
+    Pending(Option<Value>, Receiver<BrpResult>),
+}
+
+impl RequestOutcome {
+    async fn into_response(self) -> BrpResponse {
+        match self {
+            Self::Complete(response) => response,
+            Self::Pending(id, receiver) => {
+                let result = receiver.recv().await.unwrap_or_else(|_| {
+                    Err(BrpError::internal(
+                        "The request was dropped without a response",
+                    ))
+                });
+                BrpResponse::new(id, result)
+            }
+        }
+    }
+}
+
+impl Connection {
+    /// Processes a single message from the client, which contains a request or a batch of
+    /// requests.
+    async fn process_message(&mut self, message: &[u8]) -> AnyhowResult<()> {
+        match serde_json::from_slice::<BrpBatch>(message) {
+            Ok(BrpBatch::Single(request)) => {
+                let outcome = self.process_request(request).await;
+                self.respond(async move { serde_json::to_string(&outcome.into_response().await) });
+            }
+            Ok(BrpBatch::Batch(requests)) => {
+                let mut outcomes = Vec::with_capacity(requests.len());
+                for request in requests {
+                    outcomes.push(self.process_request(request).await);
+                }
+                self.respond(async move {
+                    let mut responses = Vec::with_capacity(outcomes.len());
+                    for outcome in outcomes {
+                        responses.push(outcome.into_response().await);
+                    }
+                    serde_json::to_string(&responses)
+                });
+            }
+            Err(err) => {
+                let response = BrpResponse::new(
+                    None,
+                    Err(BrpError {
+                        code: error_codes::INVALID_REQUEST,
+                        message: err.to_string(),
+                        data: None,
+                    }),
+                );
+                self.outgoing_sender
+                    .send(serde_json::to_string(&response)?)
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Sends the serialized response produced by `response` once it's ready, without blocking
+    /// the connection.
+    fn respond(
+        &self,
+        response: impl core::future::Future<Output = serde_json::Result<String>> + Send + 'static,
+    ) {
+        let outgoing_sender = self.outgoing_sender.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                if let Ok(serialized) = response.await {
+                    let _ = outgoing_sender.send(serialized).await;
+                }
+            })
+            .detach();
+    }
+
+    /// Processes a single request, forwarding it to the world unless it is handled by the
+    /// connection itself.
+    async fn process_request(&mut self, request: Value) -> RequestOutcome {
+        // Reach in and get the request ID early so that we can report it even when parsing fails.
+        let id = request.as_object().and_then(|map| map.get("id")).cloned();
+
+        let request: BrpRequest = match serde_json::from_value(request) {
+            Ok(v) => v,
+            Err(err) => {
+                return RequestOutcome::Complete(BrpResponse::new(
+                    id,
+                    Err(BrpError {
+                        code: error_codes::INVALID_REQUEST,
+                        message: err.to_string(),
+                        data: None,
+                    }),
+                ));
+            }
+        };
+
+        if request.jsonrpc != "2.0" {
+            return RequestOutcome::Complete(BrpResponse::new(
+                id,
+                Err(BrpError {
+                    code: error_codes::INVALID_REQUEST,
+                    message: String::from("JSON-RPC request requires `\"jsonrpc\": \"2.0\"`"),
+                    data: None,
+                }),
+            ));
+        }
+
+        if request.method == BRP_UNSUBSCRIBE_METHOD {
+            return RequestOutcome::Complete(BrpResponse::new(
+                request.id,
+                self.unsubscribe(request.params),
+            ));
+        }
+
+        let watch = request.method.contains("+watch");
+        let size = if watch { SUBSCRIPTION_CHANNEL_SIZE } else { 1 };
+        let (result_sender, result_receiver) = async_channel::bounded(size);
+
+        let _ = self
+            .request_sender
+            .send(BrpMessage {
+                method: request.method,
+                params: request.params,
+                sender: result_sender,
+            })
+            .await;
+
+        if !watch {
+            return RequestOutcome::Pending(request.id, result_receiver);
+        }
+
+        let subscription = self.next_subscription;
+        self.next_subscription += 1;
+        self.subscriptions
+            .insert(subscription, result_receiver.clone());
+
+        // Forward every result of the subscription to the client until it is cancelled.
+        let outgoing_sender = self.outgoing_sender.clone();
+        IoTaskPool::get()
+            .spawn(async move {
+                while let Ok(result) = result_receiver.recv().await {
+                    let notification = BrpNotification::new(subscription, result);
+                    let Ok(serialized) = serde_json::to_string(&notification) else {
+                        continue;
+                    };
+                    if outgoing_sender.send(serialized).await.is_err() {
+                        break;
+                    }
+                }
+            })
+            .detach();
+
+        RequestOutcome::Complete(BrpResponse::new(
+            request.id,
+            serde_json::to_value(BrpSubscribeResponse { subscription }).map_err(BrpError::internal),
+        ))
+    }
+
+    /// Handles a `bevy/unsubscribe` request.
+    fn unsubscribe(&mut self, params: Option<Value>) -> BrpResult {
+        let Some(Ok(BrpUnsubscribeParams { subscription })) = params.map(serde_json::from_value)
+        else {
+            return Err(BrpError {
+                code: error_codes::INVALID_PARAMS,
+                message: String::from("Expected the `subscription` to cancel"),
+                data: None,
+            });
+        };
+
+        let Some(receiver) = self.subscriptions.remove(&subscription) else {
+            return Err(BrpError {
+                code: error_codes::INVALID_PARAMS,
+                message: format!("Subscription {subscription} not found"),
+                data: None,
+            });
+        };
+        receiver.close();
+
+        Ok(Value::Null)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_channel::TryRecvError;
+    use bevy_tasks::{block_on, TaskPool};
+    use serde_json::json;
+
+    /// Creates a connection, along with the receivers of the requests it forwards to the world
+    /// and of the messages it sends to the client.
+    fn connection() -> (Connection, Receiver<BrpMessage>, Receiver<String>) {
+        IoTaskPool::get_or_init(TaskPool::default);
+        let (request_sender, request_receiver) = async_channel::unbounded();
+        let (outgoing_sender, outgoing_receiver) = async_channel::unbounded();
+        let connection = Connection {
+            request_sender,
+            outgoing_sender,
+            subscriptions: HashMap::default(),
+            next_subscription: 0,
+        };
+        (connection, request_receiver, outgoing_receiver)
+    }
+
+    fn send(connection: &mut Connection, message: Value) {
+        block_on(connection.process_message(message.to_string().as_bytes())).unwrap();
+    }
+
+    /// Waits for the next message sent to the client.
+    fn next_message(outgoing: &Receiver<String>) -> Value {
+        loop {
+            IoTaskPool::get().with_local_executor(|executor| while executor.try_tick() {});
+            match outgoing.try_recv() {
+                Ok(message) => return serde_json::from_str(&message).unwrap(),
+                Err(TryRecvError::Empty) => std::thread::yield_now(),
+                Err(TryRecvError::Closed) => panic!("the connection was closed"),
+            }
+        }
+    }
+
+    #[test]
+    fn requests_and_batches() {
+        let (mut connection, requests, outgoing) = connection();
+
+        send(
+            &mut connection,
+            json!({ "jsonrpc": "2.0", "id": 1, "method": "bevy/list" }),
+        );
+        let request = requests.try_recv().unwrap();
+        assert_eq!(request.method, "bevy/list");
+        request.sender.try_send(Ok(json!([]))).unwrap();
+        assert_eq!(
+            next_message(&outgoing),
+            json!({ "jsonrpc": "2.0", "id": 1, "result": [] })
+        );
+
+        send(
+            &mut connection,
+            json!([
+                { "jsonrpc": "2.0", "id": 2, "method": "bevy/get" },
+                { "jsonrpc": "1.0", "id": 3, "method": "bevy/get" },
+            ]),
+        );
+        let request = requests.try_recv().unwrap();
+        request
+            .sender
+            .try_send(Err(BrpError::internal("failure")))
+            .unwrap();
+        let responses = next_message(&outgoing);
+        assert_eq!(responses[0]["id"], json!(2));
+        assert_eq!(responses[0]["error"]["message"], json!("failure"));
+        assert_eq!(responses[1]["id"], json!(3));
+        assert_eq!(
+            responses[1]["error"]["code"],
+            json!(error_codes::INVALID_REQUEST)
+        );
+        assert!(requests.is_empty());
+
+        block_on(connection.process_message(b"not json")).unwrap();
+        let response = next_message(&outgoing);
+        assert_eq!(response["id"], Value::Null);
+        assert_eq!(
+            response["error"]["code"],
+            json!(error_codes::INVALID_REQUEST)
+        );
+    }
+
+    #[test]
+    fn subscriptions() {
+        let (mut connection, requests, outgoing) = connection();
+
+        send(
+            &mut connection,
+            json!({ "jsonrpc": "2.0", "id": 1, "method": "bevy/list+watch" }),
+        );
+        assert_eq!(
+            next_message(&outgoing),
+            json!({ "jsonrpc": "2.0", "id": 1, "result": { "subscription": 0 } })
+        );
+        let request = requests.try_recv().unwrap();
+        assert_eq!(request.method, "bevy/list+watch");
+        for value in 0..2 {
+            request.sender.try_send(Ok(json!(value))).unwrap();
+            assert_eq!(
+                next_message(&outgoing),
+                serde_json::to_value(BrpNotification::new(0, Ok(json!(value)))).unwrap()
+            );
+        }
+
+        let unsubscribe = json!({
+            "jsonrpc": "2.0",
+            "id": 2,
+            "method": BRP_UNSUBSCRIBE_METHOD,
+            "params": { "subscription": 0 },
+        });
+        send(&mut connection, unsubscribe.clone());
+        assert_eq!(
+            next_message(&outgoing),
+            json!({ "jsonrpc": "2.0", "id": 2, "result": null })
+        );
+        // The world stops watching once the subscription is cancelled.
+        assert!(request.sender.is_closed());
+
+        send(&mut connection, unsubscribe);
+        assert_eq!(
+            next_message(&outgoing)["error"]["code"],
+            json!(error_codes::INVALID_PARAMS)
+        );
+    }
+
+    #[test]
+    fn notification_serialization() {
+        let notification = BrpNotification::new(3, Ok(Value::Bool(true)));
+        assert_eq!(
+            serde_json::to_value(&notification).unwrap(),
+            serde_json::json!({
+                "jsonrpc": "2.0",
+                "method": BRP_SUBSCRIPTION_NOTIFICATION,
+                "params": { "subscription": 3, "result": true },
+            })
+        );
+
+        let notification = BrpNotification::new(4, Err(BrpError::internal("failure")));
+        assert_eq!(
+            serde_json::to_value(&notification).unwrap()["params"],
+            serde_json::json!({
+                "subscription": 4,
+                "error": { "code": error_codes::INTERNAL_ERROR, "message": "failure" },
+            })
+        );
+    }
+}
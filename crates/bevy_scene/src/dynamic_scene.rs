@@ -1,12 +1,13 @@
-use crate::{ron, DynamicSceneBuilder, Scene, SceneSpawnError};
-use bevy_asset::Asset;
+use crate::{ron, DynamicSceneBuilder, DynamicScenePatch, Scene, SceneSpawnError};
+use bevy_asset::{Asset, AssetPath};
 use bevy_ecs::reflect::ReflectResource;
 use bevy_ecs::{
+    dynamic_component::DynamicComponentSet,
     entity::{Entity, EntityHashMap, SceneEntityMapper},
     reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities},
     world::World,
 };
-use bevy_reflect::{PartialReflect, TypePath, TypeRegistry};
+use bevy_reflect::{FromReflect, PartialReflect, TypePath, TypeRegistry};
 
 #[cfg(feature = "serialize")]
 use crate::serde::SceneSerializer;
@@ -26,6 +27,10 @@ pub struct DynamicScene {
     pub resources: Vec<Box<dyn PartialReflect>>,
     /// Entities contained in the dynamic scene.
     pub entities: Vec<DynamicEntity>,
+    /// Other scenes instantiated by entities of the dynamic scene.
+    ///
+    /// These are only spawned by the [`SceneSpawner`](crate::SceneSpawner).
+    pub instances: Vec<DynamicSceneInstance>,
 }
 
 /// A reflection-powered serializable representation of an entity and its components.
@@ -39,6 +44,23 @@ pub struct DynamicEntity {
     pub components: Vec<Box<dyn PartialReflect>>,
 }
 
+/// An instance of another scene, nested in a [`DynamicScene`] like a prefab.
+///
+/// When the outer scene is spawned by the [`SceneSpawner`](crate::SceneSpawner), the nested scene
+/// is spawned as well, its root entities become children of [`entity`](Self::entity), and the
+/// [`overrides`](Self::overrides) are applied to it. Nested scenes can themselves contain
+/// instances of other scenes, as long as no scene ends up instantiating itself.
+pub struct DynamicSceneInstance {
+    /// The entity of the outer scene that instantiates the nested scene.
+    pub entity: Entity,
+    /// The asset path of the nested scene.
+    pub scene: AssetPath<'static>,
+    /// Changes made to this instance of the nested scene.
+    ///
+    /// Entities in the patch are identified by their id in the nested scene.
+    pub overrides: DynamicScenePatch,
+}
+
 impl DynamicScene {
     /// Create a new dynamic scene from a given scene.
     pub fn from_scene(scene: &Scene) -> Self {
@@ -55,6 +77,8 @@ impl DynamicScene {
 
     /// Write the resources, the dynamic entities, and their corresponding components to the given world.
     ///
+    /// Nested scene [`instances`](Self::instances) are not written.
+    ///
     /// This method will return a [`SceneSpawnError`] if a type either is not registered
     /// in the provided [`AppTypeRegistry`] resource, or doesn't reflect the
     /// [`Component`](bevy_ecs::component::Component) or [`Resource`](bevy_ecs::prelude::Resource) trait.
@@ -111,6 +135,15 @@ impl DynamicScene {
                     });
                 }
 
+                // The dynamic components of the scene may not be registered in this world.
+                if registration.type_id() == core::any::TypeId::of::<DynamicComponentSet>() {
+                    if let Some(set) =
+                        DynamicComponentSet::from_reflect(component.as_partial_reflect())
+                    {
+                        set.validate(world)?;
+                    }
+                }
+
                 reflect_component.apply_or_insert(
                     &mut world.entity_mut(entity),
                     component.as_partial_reflect(),
@@ -181,6 +214,18 @@ impl DynamicScene {
     pub fn serialize(&self, registry: &TypeRegistry) -> Result<String, ron::Error> {
         serialize_ron(SceneSerializer::new(self, registry))
     }
+
+    /// Serialize this dynamic scene into the binary Bevy scene format (`.scn.bin`).
+    ///
+    /// The binary format stores the same data as [`DynamicScene::serialize`] using [postcard],
+    /// which is much faster to load. To deserialize the scene, use the [`BinarySceneLoader`].
+    ///
+    /// [`BinarySceneLoader`]: crate::BinarySceneLoader
+    /// [postcard]: https://crates.io/crates/postcard
+    #[cfg(feature = "serialize")]
+    pub fn serialize_binary(&self, registry: &TypeRegistry) -> Result<Vec<u8>, postcard::Error> {
+        crate::serde::serialize_binary(self, registry)
+    }
 }
 
 /// Serialize a given Rust data structure into rust object notation (ron).
@@ -199,6 +244,9 @@ where
 mod tests {
     use bevy_ecs::{
         component::Component,
+        dynamic_component::{
+            DynamicComponentData, DynamicComponentError, DynamicComponentLayout, DynamicFieldKind,
+        },
         entity::{
             Entity, EntityHashMap, EntityMapper, MapEntities, VisitEntities, VisitEntitiesMut,
         },
@@ -211,6 +259,7 @@ mod tests {
 
     use crate::dynamic_scene::DynamicScene;
     use crate::dynamic_scene_builder::DynamicSceneBuilder;
+    use crate::SceneSpawnError;
 
     #[derive(Resource, Reflect, Debug, VisitEntities, VisitEntitiesMut)]
     #[reflect(Resource, MapEntities)]
@@ -381,4 +430,31 @@ mod tests {
             .write_to_world(&mut dst_world, &mut Default::default())
             .unwrap();
     }
+
+    #[test]
+    fn unregistered_dynamic_components_are_an_error() {
+        let reg = AppTypeRegistry::default();
+        let mut scene_world = World::new();
+        scene_world.insert_resource(reg.clone());
+        scene_world
+            .register_dynamic_component(
+                DynamicComponentLayout::new("Health").with_field("current", DynamicFieldKind::F32),
+            )
+            .unwrap();
+        scene_world
+            .spawn_empty()
+            .insert_dynamic(&DynamicComponentData::new("Health").with("current", 2.5f32))
+            .unwrap();
+        let scene = DynamicScene::from_world(&scene_world);
+
+        let mut dst_world = World::new();
+        dst_world.insert_resource(reg);
+        let result = scene.write_to_world(&mut dst_world, &mut Default::default());
+        assert!(matches!(
+            result,
+            Err(SceneSpawnError::DynamicComponent(
+                DynamicComponentError::UnknownComponent(name)
+            )) if name == "Health"
+        ));
+    }
 }
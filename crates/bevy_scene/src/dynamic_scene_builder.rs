@@ -2,6 +2,7 @@ use crate::{DynamicEntity, DynamicScene, SceneFilter};
 use alloc::collections::BTreeMap;
 use bevy_ecs::{
     component::{Component, ComponentId},
+    dynamic_component::DynamicComponentSet,
     prelude::Entity,
     reflect::{AppTypeRegistry, ReflectComponent, ReflectResource},
     system::Resource,
@@ -59,7 +60,7 @@ pub struct DynamicSceneBuilder<'w> {
     extracted_scene: BTreeMap<Entity, DynamicEntity>,
     component_filter: SceneFilter,
     resource_filter: SceneFilter,
-    original_world: &'w World,
+    pub(crate) original_world: &'w World,
 }
 
 impl<'w> DynamicSceneBuilder<'w> {
@@ -209,6 +210,7 @@ impl<'w> DynamicSceneBuilder<'w> {
         DynamicScene {
             resources: self.extracted_resources.into_values().collect(),
             entities: self.extracted_scene.into_values().collect(),
+            instances: Vec::new(),
         }
     }
 
@@ -262,6 +264,9 @@ impl<'w> DynamicSceneBuilder<'w> {
     ///
     /// Note that components extracted from queried entities must still pass through the filter if one is set.
     ///
+    /// The [dynamic components](bevy_ecs::dynamic_component) of each entity are extracted together
+    /// as a single [`DynamicComponentSet`], which the filter can allow or deny.
+    ///
     /// [`allow`]: Self::allow_component
     /// [`deny`]: Self::deny_component
     #[must_use]
@@ -314,6 +319,14 @@ impl<'w> DynamicSceneBuilder<'w> {
                 };
                 extract_and_push();
             }
+
+            if !self.component_filter.is_denied::<DynamicComponentSet>() {
+                let dynamic_components =
+                    DynamicComponentSet::from_entity(self.original_world, entity);
+                if !dynamic_components.0.is_empty() {
+                    entry.components.push(Box::new(dynamic_components));
+                }
+            }
             self.extracted_scene.insert(entity, entry);
         }
 
@@ -20,6 +20,9 @@ mod dynamic_scene_builder;
 mod scene;
 mod scene_filter;
 mod scene_loader;
+mod scene_patch;
+#[cfg(feature = "serialize")]
+mod scene_saver;
 mod scene_spawner;
 
 #[cfg(feature = "serialize")]
@@ -36,6 +39,9 @@ pub use dynamic_scene_builder::*;
 pub use scene::*;
 pub use scene_filter::*;
 pub use scene_loader::*;
+pub use scene_patch::*;
+#[cfg(feature = "serialize")]
+pub use scene_saver::*;
 pub use scene_spawner::*;
 
 /// The scene prelude.
@@ -52,6 +58,8 @@ pub mod prelude {
 
 use bevy_app::prelude::*;
 use bevy_asset::AssetApp;
+#[cfg(feature = "serialize")]
+use bevy_ecs::world::FromWorld;
 
 /// Plugin that provides scene functionality to an [`App`].
 #[derive(Default)]
@@ -63,11 +71,16 @@ impl Plugin for ScenePlugin {
         app.init_asset::<DynamicScene>()
             .init_asset::<Scene>()
             .init_asset_loader::<SceneLoader>()
+            .init_asset_loader::<BinarySceneLoader>()
             .init_resource::<SceneSpawner>()
             .register_type::<SceneRoot>()
             .register_type::<DynamicSceneRoot>()
             .add_systems(SpawnScene, (scene_spawner, scene_spawner_system).chain());
 
+        // Allow converting text scenes to binary scenes when processing assets
+        let saver = BinarySceneSaver::from_world(app.world_mut());
+        app.register_asset_processor::<BinarySceneProcessor>(saver.into());
+
         // Register component hooks for DynamicSceneRoot
         app.world_mut()
             .register_component_hooks::<DynamicSceneRoot>()
@@ -1,6 +1,7 @@
 use crate::{DynamicScene, SceneSpawnError};
 use bevy_asset::Asset;
 use bevy_ecs::{
+    dynamic_component::DynamicComponentSet,
     entity::{Entity, EntityHashMap, SceneEntityMapper},
     reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities, ReflectResource},
     world::World,
@@ -106,6 +107,16 @@ impl Scene {
                     .expect("should have previously spawned an entity");
 
                 for component_id in archetype.components() {
+                    // Dynamic components have no type, and are copied by name below.
+                    if self
+                        .world
+                        .components()
+                        .get_dynamic_layout(component_id)
+                        .is_some()
+                    {
+                        continue;
+                    }
+
                     let component_info = self
                         .world
                         .components()
@@ -144,6 +155,13 @@ impl Scene {
                         &type_registry,
                     );
                 }
+
+                let dynamic_components =
+                    DynamicComponentSet::from_entity(&self.world, scene_entity.id());
+                if !dynamic_components.0.is_empty() {
+                    dynamic_components.validate(world)?;
+                    dynamic_components.apply_or_insert(&mut world.entity_mut(entity))?;
+                }
             }
         }
 
@@ -38,6 +38,10 @@ pub enum SceneLoaderError {
     /// A [RON Error](ron::error::SpannedError)
     #[error("Could not parse RON: {0}")]
     RonSpannedError(#[from] ron::error::SpannedError),
+    /// A [Postcard Error](postcard::Error)
+    #[cfg(feature = "serialize")]
+    #[error("Could not parse binary scene: {0}")]
+    Postcard(#[from] postcard::Error),
 }
 
 #[cfg(feature = "serialize")]
@@ -67,3 +71,46 @@ impl AssetLoader for SceneLoader {
         &["scn", "scn.ron"]
     }
 }
+
+/// Asset loader for a Bevy dynamic scene in the binary scene format (`.scn.bin`).
+///
+/// The loader handles assets serialized with [`DynamicScene::serialize_binary`],
+/// for example by the [`BinarySceneSaver`](crate::BinarySceneSaver).
+#[derive(Debug)]
+pub struct BinarySceneLoader {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for BinarySceneLoader {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        BinarySceneLoader {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+#[cfg(feature = "serialize")]
+impl AssetLoader for BinarySceneLoader {
+    type Asset = DynamicScene;
+    type Settings = ();
+    type Error = SceneLoaderError;
+
+    async fn load(
+        &self,
+        reader: &mut dyn Reader,
+        _settings: &(),
+        _load_context: &mut LoadContext<'_>,
+    ) -> Result<Self::Asset, Self::Error> {
+        let mut bytes = Vec::new();
+        reader.read_to_end(&mut bytes).await?;
+        Ok(crate::serde::deserialize_binary(
+            &bytes,
+            &self.type_registry.read(),
+        )?)
+    }
+
+    fn extensions(&self) -> &[&str] {
+        &["scn.bin"]
+    }
+}
@@ -0,0 +1,792 @@
+use crate::{DynamicEntity, DynamicScene, DynamicSceneBuilder, SceneSpawnError};
+use bevy_ecs::{
+    component::ComponentInfo,
+    entity::{Entity, EntityHashMap, EntityMapper},
+    reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities, ReflectResource},
+    world::World,
+};
+use bevy_reflect::{PartialReflect, ReflectPath, ReflectRef, TypeInfo, TypeRegistry};
+use bevy_utils::HashSet;
+
+#[cfg(feature = "serialize")]
+use crate::{ron, serde::ScenePatchSerializer, serialize_ron};
+
+/// A set of changes that turns one [`DynamicScene`] into another.
+///
+/// Patches are computed with [`DynamicScenePatch::diff`] (between two scenes) or
+/// [`DynamicScenePatch::from_world`] (between a scene and the entities spawned from it), and are
+/// applied with [`DynamicScenePatch::apply_to_world`].
+///
+/// Entities are identified by their scene [`Entity`] id, the same way [`DynamicScene`] identifies
+/// them. When applying a patch, the entity map that was used to write the original scene to the
+/// world resolves those ids, so the same entities keep being targeted across reloads of the scene
+/// and successive patches.
+#[derive(Default)]
+pub struct DynamicScenePatch {
+    /// Resources that are inserted by the patch, or that replace an existing value.
+    pub resources: Vec<Box<dyn PartialReflect>>,
+    /// Type paths of the resources removed by the patch.
+    pub removed_resources: Vec<String>,
+    /// Entities that are added or modified by the patch.
+    pub entities: Vec<DynamicEntityPatch>,
+    /// Scene ids of the entities removed by the patch.
+    pub removed_entities: Vec<Entity>,
+}
+
+/// The changes made by a [`DynamicScenePatch`] to a single entity.
+pub struct DynamicEntityPatch {
+    /// The scene id of the patched entity.
+    ///
+    /// If no world entity corresponds to this id yet, one is spawned when applying the patch.
+    pub entity: Entity,
+    /// Components that are inserted on the entity, or that replace an existing value.
+    pub components: Vec<Box<dyn PartialReflect>>,
+    /// Individual fields that are changed on components already present on the entity.
+    pub fields: Vec<ComponentFieldPatch>,
+    /// Type paths of the components removed from the entity.
+    pub removed_components: Vec<String>,
+}
+
+/// A new value for a single field of a component.
+pub struct ComponentFieldPatch {
+    /// The type path of the patched component.
+    pub component: String,
+    /// The [reflect path](bevy_reflect::ParsedPath) of the field within the component, such as `.translation.x`.
+    pub path: String,
+    /// The new value of the field.
+    pub value: Box<dyn PartialReflect>,
+}
+
+impl DynamicEntityPatch {
+    /// Create an empty patch for the entity with the given scene id.
+    pub fn new(entity: Entity) -> Self {
+        Self {
+            entity,
+            components: Vec::new(),
+            fields: Vec::new(),
+            removed_components: Vec::new(),
+        }
+    }
+
+    /// Returns `true` if the patch doesn't change the entity.
+    pub fn is_empty(&self) -> bool {
+        self.components.is_empty() && self.fields.is_empty() && self.removed_components.is_empty()
+    }
+}
+
+impl DynamicScenePatch {
+    /// Compute the changes that turn the `from` scene into the `to` scene.
+    ///
+    /// Entities are matched by their scene id, and components and resources by their type path.
+    /// Components present in both scenes are compared field by field, and only the fields that
+    /// differ are recorded. Values that can't be compared field by field, such as enums and lists,
+    /// are replaced as a whole.
+    pub fn diff(from: &DynamicScene, to: &DynamicScene) -> Self {
+        let mut patch = Self::default();
+
+        let (resources, removed_resources) = diff_values(&from.resources, &to.resources);
+        patch.resources = resources;
+        patch.removed_resources = removed_resources;
+
+        let from_entities = from
+            .entities
+            .iter()
+            .map(|entity| (entity.entity, entity))
+            .collect::<EntityHashMap<_>>();
+        let to_entities = to
+            .entities
+            .iter()
+            .map(|entity| entity.entity)
+            .collect::<HashSet<_>>();
+
+        for to_entity in &to.entities {
+            let mut entity_patch = DynamicEntityPatch::new(to_entity.entity);
+            match from_entities.get(&to_entity.entity) {
+                Some(from_entity) => {
+                    for to_component in &to_entity.components {
+                        let component_path = type_path(to_component.as_ref());
+                        let from_component = from_entity
+                            .components
+                            .iter()
+                            .find(|component| type_path(component.as_ref()) == component_path);
+                        let Some(from_component) = from_component else {
+                            entity_patch.components.push(to_component.clone_value());
+                            continue;
+                        };
+
+                        // Field values are applied as-is, so changes that reference entities
+                        // replace the whole component to have them mapped like inserted ones.
+                        let mut fields = Vec::new();
+                        if diff_fields(
+                            "",
+                            from_component.as_ref(),
+                            to_component.as_ref(),
+                            &mut fields,
+                        ) && !fields
+                            .iter()
+                            .any(|(_, value)| contains_entity(value.as_ref()))
+                        {
+                            entity_patch
+                                .fields
+                                .extend(fields.into_iter().map(|(path, value)| {
+                                    ComponentFieldPatch {
+                                        component: component_path.to_string(),
+                                        path,
+                                        value,
+                                    }
+                                }));
+                        } else if !is_equal(from_component.as_ref(), to_component.as_ref()) {
+                            entity_patch.components.push(to_component.clone_value());
+                        }
+                    }
+
+                    entity_patch.removed_components =
+                        removed_type_paths(&from_entity.components, &to_entity.components);
+                }
+                None => {
+                    entity_patch.components = to_entity
+                        .components
+                        .iter()
+                        .map(|component| component.clone_value())
+                        .collect();
+                }
+            }
+
+            // Entities that are new to the scene are kept even without components,
+            // so that applying the patch spawns them.
+            if !entity_patch.is_empty() || !from_entities.contains_key(&to_entity.entity) {
+                patch.entities.push(entity_patch);
+            }
+        }
+
+        patch.removed_entities = from
+            .entities
+            .iter()
+            .map(|entity| entity.entity)
+            .filter(|entity| !to_entities.contains(entity))
+            .collect();
+
+        patch
+    }
+
+    /// Compute the changes that turn `scene` into the current state of the entities that were
+    /// spawned from it.
+    ///
+    /// `entity_map` is the map that was used to write `scene` to the world: world entities that
+    /// are not in it are not part of the patch, and scene entities whose world entity has been
+    /// despawned are removed by the patch. Entity references in the extracted components are
+    /// mapped back to scene ids.
+    ///
+    /// The filters of `builder` decide which components and resources are compared.
+    pub fn from_world(
+        scene: &DynamicScene,
+        builder: DynamicSceneBuilder,
+        entity_map: &EntityHashMap<Entity>,
+    ) -> Self {
+        let world = builder.original_world;
+        let type_registry = world.resource::<AppTypeRegistry>().read();
+
+        let mut current = builder
+            .extract_entities(
+                entity_map
+                    .values()
+                    .copied()
+                    .filter(|&entity| world.get_entity(entity).is_ok()),
+            )
+            .extract_resources()
+            .build();
+
+        let mut mapper = ReverseEntityMapper::new(entity_map);
+        for entity in &mut current.entities {
+            entity.entity = mapper.map_entity(entity.entity);
+            map_entities(&mut entity.components, &type_registry, &mut mapper);
+        }
+        map_entities(&mut current.resources, &type_registry, &mut mapper);
+
+        Self::diff(scene, &current)
+    }
+
+    /// Returns `true` if applying the patch doesn't change anything.
+    pub fn is_empty(&self) -> bool {
+        self.resources.is_empty()
+            && self.removed_resources.is_empty()
+            && self.entities.is_empty()
+            && self.removed_entities.is_empty()
+    }
+
+    /// Apply the changes of this patch to the given world.
+    ///
+    /// `entity_map` maps the scene ids of the patched entities to world entities, as it does for
+    /// [`DynamicScene::write_to_world_with`]. It is updated with the entities spawned and
+    /// despawned by the patch, so it can be reused to apply further patches.
+    ///
+    /// Entity references in inserted components and resources are mapped to world entities.
+    /// Field values are applied as-is: [`DynamicScenePatch::diff`] replaces whole components
+    /// instead of recording fields that reference entities. Fields of immutable components are
+    /// applied by replacing the whole component.
+    ///
+    /// This method will return a [`SceneSpawnError`] if a type either is not registered
+    /// in the provided [`AppTypeRegistry`] resource, doesn't reflect the
+    /// [`Component`](bevy_ecs::component::Component) or [`Resource`](bevy_ecs::prelude::Resource)
+    /// trait, or if a field can't be found on the patched entity.
+    pub fn apply_to_world_with(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+        type_registry: &AppTypeRegistry,
+    ) -> Result<(), SceneSpawnError> {
+        for scene_entity in &self.removed_entities {
+            if let Some(entity) = entity_map.remove(scene_entity) {
+                world.despawn(entity);
+            }
+        }
+
+        // Inserting whole components and resources is exactly what writing a scene does.
+        let inserted = DynamicScene {
+            resources: self
+                .resources
+                .iter()
+                .map(|resource| resource.clone_value())
+                .collect(),
+            entities: self
+                .entities
+                .iter()
+                .map(|entity| DynamicEntity {
+                    entity: entity.entity,
+                    components: entity
+                        .components
+                        .iter()
+                        .map(|component| component.clone_value())
+                        .collect(),
+                })
+                .collect(),
+            instances: Vec::new(),
+        };
+        inserted.write_to_world_with(world, entity_map, type_registry)?;
+
+        let type_registry = type_registry.read();
+
+        for entity_patch in &self.entities {
+            let entity = *entity_map
+                .get(&entity_patch.entity)
+                .expect("should have previously spawned an entity");
+
+            for field in &entity_patch.fields {
+                let reflect_component = get_reflect_component(&type_registry, &field.component)?;
+                let not_present = || SceneSpawnError::InvalidPatchField {
+                    type_path: field.component.clone(),
+                    path: field.path.clone(),
+                    reason: "the component is not present on the entity".to_string(),
+                };
+                let apply_field = |component: &mut dyn PartialReflect| {
+                    field
+                        .path
+                        .as_str()
+                        .reflect_element_mut(component)
+                        .map_err(|error| error.to_string())
+                        .and_then(|target| {
+                            target
+                                .try_apply(field.value.as_ref())
+                                .map_err(|error| error.to_string())
+                        })
+                        .map_err(|reason| SceneSpawnError::InvalidPatchField {
+                            type_path: field.component.clone(),
+                            path: field.path.clone(),
+                            reason,
+                        })
+                };
+
+                if is_mutable(world, &type_registry, &field.component) {
+                    let mut entity_mut = world.entity_mut(entity);
+                    let mut component = reflect_component
+                        .reflect_mut(&mut entity_mut)
+                        .ok_or_else(not_present)?;
+                    apply_field(component.as_partial_reflect_mut())?;
+                } else {
+                    // Immutable components can't be changed in place, so the patched field is
+                    // applied to a copy that then replaces the whole component.
+                    let mut component = reflect_component
+                        .reflect(world.entity(entity))
+                        .ok_or_else(not_present)?
+                        .clone_value();
+                    apply_field(component.as_mut())?;
+                    reflect_component.insert(
+                        &mut world.entity_mut(entity),
+                        component.as_ref(),
+                        &type_registry,
+                    );
+                }
+            }
+
+            for type_path in &entity_patch.removed_components {
+                let reflect_component = get_reflect_component(&type_registry, type_path)?;
+                reflect_component.remove(&mut world.entity_mut(entity));
+            }
+        }
+
+        for type_path in &self.removed_resources {
+            let reflect_resource = type_registry
+                .get_with_type_path(type_path)
+                .ok_or_else(|| SceneSpawnError::UnregisteredButReflectedType {
+                    type_path: type_path.clone(),
+                })?
+                .data::<ReflectResource>()
+                .ok_or_else(|| SceneSpawnError::UnregisteredResource {
+                    type_path: type_path.clone(),
+                })?;
+            reflect_resource.remove(world);
+        }
+
+        Ok(())
+    }
+
+    /// Apply the changes of this patch to the given world.
+    ///
+    /// This method will return a [`SceneSpawnError`] if a type either is not registered
+    /// in the world's [`AppTypeRegistry`] resource, doesn't reflect the
+    /// [`Component`](bevy_ecs::component::Component) trait, or if a field can't be found on the
+    /// patched entity.
+    pub fn apply_to_world(
+        &self,
+        world: &mut World,
+        entity_map: &mut EntityHashMap<Entity>,
+    ) -> Result<(), SceneSpawnError> {
+        let registry = world.resource::<AppTypeRegistry>().clone();
+        self.apply_to_world_with(world, entity_map, &registry)
+    }
+
+    /// Serialize this patch into the Bevy scene patch format (`.scn.patch` / `.scn.patch.ron`).
+    ///
+    /// Like scenes, patches are written in [Rusty Object Notation (RON)].
+    /// To deserialize a patch, use the [`ScenePatchDeserializer`].
+    ///
+    /// [`ScenePatchDeserializer`]: crate::serde::ScenePatchDeserializer
+    /// [Rusty Object Notation (RON)]: https://crates.io/crates/ron
+    #[cfg(feature = "serialize")]
+    pub fn serialize(&self, registry: &TypeRegistry) -> Result<String, ron::Error> {
+        serialize_ron(ScenePatchSerializer::new(self, registry))
+    }
+}
+
+impl DynamicScene {
+    /// Compute the changes that turn this scene into `other`.
+    ///
+    /// See [`DynamicScenePatch::diff`].
+    pub fn diff(&self, other: &DynamicScene) -> DynamicScenePatch {
+        DynamicScenePatch::diff(self, other)
+    }
+}
+
+/// Maps world entities back to the scene entities they were spawned from.
+struct ReverseEntityMapper {
+    map: EntityHashMap<Entity>,
+}
+
+impl ReverseEntityMapper {
+    fn new(entity_map: &EntityHashMap<Entity>) -> Self {
+        Self {
+            map: entity_map
+                .iter()
+                .map(|(&scene_entity, &world_entity)| (world_entity, scene_entity))
+                .collect(),
+        }
+    }
+}
+
+impl EntityMapper for ReverseEntityMapper {
+    fn map_entity(&mut self, entity: Entity) -> Entity {
+        self.map.get(&entity).copied().unwrap_or(entity)
+    }
+}
+
+fn map_entities(
+    values: &mut [Box<dyn PartialReflect>],
+    type_registry: &TypeRegistry,
+    mapper: &mut ReverseEntityMapper,
+) {
+    for value in values {
+        let map_entities = value
+            .get_represented_type_info()
+            .and_then(|type_info| type_registry.get(type_info.type_id()))
+            .and_then(|registration| registration.data::<ReflectMapEntities>());
+        if let Some(map_entities) = map_entities {
+            map_entities.map_entities(value.as_partial_reflect_mut(), mapper);
+        }
+    }
+}
+
+/// Returns `false` if the component with the given type path is immutable.
+fn is_mutable(world: &World, type_registry: &TypeRegistry, type_path: &str) -> bool {
+    type_registry
+        .get_with_type_path(type_path)
+        .and_then(|registration| world.components().get_id(registration.type_id()))
+        .and_then(|component_id| world.components().get_info(component_id))
+        .is_none_or(ComponentInfo::mutable)
+}
+
+fn get_reflect_component<'a>(
+    type_registry: &'a TypeRegistry,
+    type_path: &str,
+) -> Result<&'a ReflectComponent, SceneSpawnError> {
+    type_registry
+        .get_with_type_path(type_path)
+        .ok_or_else(|| SceneSpawnError::UnregisteredButReflectedType {
+            type_path: type_path.to_string(),
+        })?
+        .data::<ReflectComponent>()
+        .ok_or_else(|| SceneSpawnError::UnregisteredComponent {
+            type_path: type_path.to_string(),
+        })
+}
+
+/// The type path of the type represented by a value, which is how scenes identify
+/// components and resources.
+fn type_path(value: &dyn PartialReflect) -> &str {
+    value
+        .get_represented_type_info()
+        .map(TypeInfo::type_path)
+        .unwrap_or_else(|| value.reflect_type_path())
+}
+
+fn is_equal(a: &dyn PartialReflect, b: &dyn PartialReflect) -> bool {
+    a.reflect_partial_eq(b).unwrap_or(false)
+}
+
+/// Returns the values of `to` that are missing from or different in `from`,
+/// and the type paths of the values of `from` missing from `to`.
+fn diff_values(
+    from: &[Box<dyn PartialReflect>],
+    to: &[Box<dyn PartialReflect>],
+) -> (Vec<Box<dyn PartialReflect>>, Vec<String>) {
+    let changed = to
+        .iter()
+        .filter(|to_value| {
+            let value_path = type_path(to_value.as_ref());
+            !from.iter().any(|from_value| {
+                type_path(from_value.as_ref()) == value_path
+                    && is_equal(from_value.as_ref(), to_value.as_ref())
+            })
+        })
+        .map(|value| value.clone_value())
+        .collect();
+
+    (changed, removed_type_paths(from, to))
+}
+
+fn removed_type_paths(
+    from: &[Box<dyn PartialReflect>],
+    to: &[Box<dyn PartialReflect>],
+) -> Vec<String> {
+    from.iter()
+        .map(|value| type_path(value.as_ref()))
+        .filter(|&from_path| {
+            !to.iter()
+                .any(|value| type_path(value.as_ref()) == from_path)
+        })
+        .map(str::to_string)
+        .collect()
+}
+
+/// Records the fields that differ between two values of the same structured type in `changes`,
+/// as pairs of reflect paths (prefixed with `prefix`) and new values.
+///
+/// Returns `false` if the values can't be compared field by field.
+fn diff_fields(
+    prefix: &str,
+    from: &dyn PartialReflect,
+    to: &dyn PartialReflect,
+    changes: &mut Vec<(String, Box<dyn PartialReflect>)>,
+) -> bool {
+    if type_path(from) != type_path(to) {
+        return false;
+    }
+
+    match (from.reflect_ref(), to.reflect_ref()) {
+        (ReflectRef::Struct(from), ReflectRef::Struct(to)) => {
+            if from.field_len() != to.field_len() {
+                return false;
+            }
+            for (index, to_field) in to.iter_fields().enumerate() {
+                let name = to.name_at(index).expect("field index should be in bounds");
+                let Some(from_field) = from.field(name) else {
+                    return false;
+                };
+                diff_field(format!("{prefix}.{name}"), from_field, to_field, changes);
+            }
+            true
+        }
+        (ReflectRef::TupleStruct(from), ReflectRef::TupleStruct(to)) => {
+            if from.field_len() != to.field_len() {
+                return false;
+            }
+            for (index, (from_field, to_field)) in
+                from.iter_fields().zip(to.iter_fields()).enumerate()
+            {
+                diff_field(format!("{prefix}.{index}"), from_field, to_field, changes);
+            }
+            true
+        }
+        (ReflectRef::Tuple(from), ReflectRef::Tuple(to)) => {
+            if from.field_len() != to.field_len() {
+                return false;
+            }
+            for (index, (from_field, to_field)) in
+                from.iter_fields().zip(to.iter_fields()).enumerate()
+            {
+                diff_field(format!("{prefix}.{index}"), from_field, to_field, changes);
+            }
+            true
+        }
+        _ => false,
+    }
+}
+
+fn diff_field(
+    path: String,
+    from: &dyn PartialReflect,
+    to: &dyn PartialReflect,
+    changes: &mut Vec<(String, Box<dyn PartialReflect>)>,
+) {
+    if is_equal(from, to) {
+        return;
+    }
+
+    let mut nested = Vec::new();
+    if diff_fields(&path, from, to, &mut nested) {
+        changes.append(&mut nested);
+    } else {
+        changes.push((path, to.clone_value()));
+    }
+}
+
+/// Returns `true` if the value is or contains an [`Entity`].
+fn contains_entity(value: &dyn PartialReflect) -> bool {
+    if value.try_downcast_ref::<Entity>().is_some() {
+        return true;
+    }
+
+    match value.reflect_ref() {
+        ReflectRef::Struct(value) => value.iter_fields().any(contains_entity),
+        ReflectRef::TupleStruct(value) => value.iter_fields().any(contains_entity),
+        ReflectRef::Tuple(value) => value.iter_fields().any(contains_entity),
+        ReflectRef::List(value) => value.iter().any(contains_entity),
+        ReflectRef::Array(value) => value.iter().any(contains_entity),
+        ReflectRef::Map(value) => value
+            .iter()
+            .any(|(key, value)| contains_entity(key) || contains_entity(value)),
+        ReflectRef::Set(value) => value.iter().any(contains_entity),
+        ReflectRef::Enum(value) => value
+            .iter_fields()
+            .any(|field| contains_entity(field.value())),
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::{
+        component::Component,
+        entity::{Entity, EntityHashMap, VisitEntities, VisitEntitiesMut},
+        reflect::{AppTypeRegistry, ReflectComponent, ReflectMapEntities, ReflectResource},
+        system::Resource,
+        world::World,
+    };
+    use bevy_reflect::{Reflect, TypePath};
+
+    use crate::{
+        ComponentFieldPatch, DynamicEntityPatch, DynamicScene, DynamicSceneBuilder,
+        DynamicScenePatch,
+    };
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Position {
+        x: f32,
+        y: f32,
+    }
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Health(u32);
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Component)]
+    struct Marker;
+
+    #[derive(Component, Reflect, Default, Debug, PartialEq)]
+    #[component(immutable)]
+    #[reflect(Component)]
+    struct Level(u32);
+
+    #[derive(Component, Reflect, Debug, PartialEq, VisitEntities, VisitEntitiesMut)]
+    #[reflect(Component, MapEntities)]
+    struct Target(Entity);
+
+    #[derive(Resource, Reflect, Default, Debug, PartialEq)]
+    #[reflect(Resource)]
+    struct Score(u32);
+
+    fn create_world() -> World {
+        let mut world = World::new();
+        let registry = AppTypeRegistry::default();
+        {
+            let mut registry = registry.write();
+            registry.register::<Position>();
+            registry.register::<Health>();
+            registry.register::<Marker>();
+            registry.register::<Level>();
+            registry.register::<Target>();
+            registry.register::<Score>();
+        }
+        world.insert_resource(registry);
+        world
+    }
+
+    #[test]
+    fn diff_and_apply() {
+        let mut world = create_world();
+        let a = world.spawn((Position { x: 1.0, y: 2.0 }, Health(10))).id();
+        let b = world.spawn(Marker).id();
+        world.insert_resource(Score(1));
+        let before = DynamicScene::from_world(&world);
+
+        world.entity_mut(a).insert(Position { x: 1.0, y: 5.0 });
+        world.entity_mut(a).remove::<Health>();
+        world.entity_mut(a).insert(Marker);
+        world.despawn(b);
+        let c = world.spawn(Health(3)).id();
+        world.insert_resource(Score(2));
+        let after = DynamicScene::from_world(&world);
+
+        let patch = before.diff(&after);
+        assert_eq!(1, patch.resources.len());
+        assert_eq!(vec![b], patch.removed_entities);
+        assert_eq!(2, patch.entities.len());
+
+        let entity_patch = patch.entities.iter().find(|e| e.entity == a).unwrap();
+        assert_eq!(1, entity_patch.fields.len());
+        assert_eq!(".y", entity_patch.fields[0].path);
+        assert_eq!(1, entity_patch.components.len());
+        assert_eq!(
+            vec![core::any::type_name::<Health>().to_string()],
+            entity_patch.removed_components
+        );
+
+        // Apply the patch to a copy of the original scene.
+        let mut dst_world = create_world();
+        let mut entity_map = EntityHashMap::default();
+        before
+            .write_to_world(&mut dst_world, &mut entity_map)
+            .unwrap();
+        patch
+            .apply_to_world(&mut dst_world, &mut entity_map)
+            .unwrap();
+
+        let dst_a = dst_world.entity(entity_map[&a]);
+        assert_eq!(Some(&Position { x: 1.0, y: 5.0 }), dst_a.get::<Position>());
+        assert!(dst_a.get::<Health>().is_none());
+        assert!(dst_a.contains::<Marker>());
+        assert!(!entity_map.contains_key(&b));
+        assert_eq!(
+            Some(&Health(3)),
+            dst_world.entity(entity_map[&c]).get::<Health>()
+        );
+        assert_eq!(&Score(2), dst_world.resource::<Score>());
+
+        // The patched world now matches the target scene.
+        let builder = DynamicSceneBuilder::from_world(&dst_world);
+        assert!(DynamicScenePatch::from_world(&after, builder, &entity_map).is_empty());
+    }
+
+    #[test]
+    fn patch_from_world_keeps_scene_ids() {
+        let mut world = create_world();
+        let a = world.spawn(Position { x: 0.0, y: 0.0 }).id();
+        let scene = DynamicScene::from_world(&world);
+
+        let mut dst_world = create_world();
+        // Offset the entity ids of the destination world.
+        dst_world.spawn_empty();
+        let mut entity_map = EntityHashMap::default();
+        scene
+            .write_to_world(&mut dst_world, &mut entity_map)
+            .unwrap();
+        dst_world
+            .entity_mut(entity_map[&a])
+            .insert(Position { x: 4.0, y: 0.0 });
+
+        let builder = DynamicSceneBuilder::from_world(&dst_world).deny_all_resources();
+        let patch = DynamicScenePatch::from_world(&scene, builder, &entity_map);
+        assert_eq!(1, patch.entities.len());
+        assert_eq!(a, patch.entities[0].entity);
+        assert_eq!(".x", patch.entities[0].fields[0].path);
+
+        // Reapplying the patch after reloading the scene targets the same entity.
+        scene
+            .write_to_world(&mut dst_world, &mut entity_map)
+            .unwrap();
+        patch
+            .apply_to_world(&mut dst_world, &mut entity_map)
+            .unwrap();
+        assert_eq!(
+            Some(&Position { x: 4.0, y: 0.0 }),
+            dst_world.entity(entity_map[&a]).get::<Position>()
+        );
+    }
+
+    #[test]
+    fn entity_fields_are_mapped() {
+        let mut world = create_world();
+        let a = world.spawn_empty().id();
+        let b = world.spawn_empty().id();
+        world.entity_mut(a).insert(Target(b));
+        let before = DynamicScene::from_world(&world);
+
+        let c = world.spawn_empty().id();
+        world.entity_mut(a).insert(Target(c));
+        let after = DynamicScene::from_world(&world);
+
+        // The changed entity reference replaces the whole component.
+        let patch = before.diff(&after);
+        let entity_patch = patch.entities.iter().find(|e| e.entity == a).unwrap();
+        assert!(entity_patch.fields.is_empty());
+        assert_eq!(1, entity_patch.components.len());
+
+        let mut dst_world = create_world();
+        // Offset the entity ids of the destination world.
+        dst_world.spawn_empty();
+        let mut entity_map = EntityHashMap::default();
+        before
+            .write_to_world(&mut dst_world, &mut entity_map)
+            .unwrap();
+        patch
+            .apply_to_world(&mut dst_world, &mut entity_map)
+            .unwrap();
+        assert_eq!(
+            Some(&Target(entity_map[&c])),
+            dst_world.entity(entity_map[&a]).get::<Target>()
+        );
+    }
+
+    #[test]
+    fn patch_immutable_component_field() {
+        let mut world = create_world();
+        let scene_entity = Entity::from_raw(0);
+        let entity = world.spawn(Level(1)).id();
+        let mut entity_map = EntityHashMap::from_iter([(scene_entity, entity)]);
+
+        let mut entity_patch = DynamicEntityPatch::new(scene_entity);
+        entity_patch.fields.push(ComponentFieldPatch {
+            component: Level::type_path().to_string(),
+            path: ".0".to_string(),
+            value: Box::new(2u32),
+        });
+        let patch = DynamicScenePatch {
+            entities: vec![entity_patch],
+            ..Default::default()
+        };
+        patch.apply_to_world(&mut world, &mut entity_map).unwrap();
+
+        assert_eq!(Some(&Level(2)), world.get::<Level>(entity));
+    }
+}
@@ -0,0 +1,77 @@
+use crate::{BinarySceneLoader, DynamicScene, SceneLoader};
+use bevy_asset::{
+    io::Writer,
+    processor::LoadTransformAndSave,
+    saver::{AssetSaver, SavedAsset},
+    transformer::IdentityAssetTransformer,
+    AsyncWriteExt,
+};
+use bevy_ecs::{
+    reflect::AppTypeRegistry,
+    world::{FromWorld, World},
+};
+use bevy_reflect::TypeRegistryArc;
+use thiserror::Error;
+
+/// Asset processor that converts text scenes (`.scn` / `.scn.ron`) into the binary scene format.
+///
+/// It is registered by the [`ScenePlugin`](crate::ScenePlugin) when asset processing is enabled,
+/// but isn't used by default. Select it in the `.meta` file of a scene, or make it the default
+/// processor of text scenes:
+///
+/// ```no_run
+/// # use bevy_app::App;
+/// # use bevy_asset::AssetApp;
+/// # use bevy_scene::BinarySceneProcessor;
+/// # let mut app = App::new();
+/// app.set_default_asset_processor::<BinarySceneProcessor>("scn.ron");
+/// ```
+pub type BinarySceneProcessor =
+    LoadTransformAndSave<SceneLoader, IdentityAssetTransformer<DynamicScene>, BinarySceneSaver>;
+
+/// Asset saver for a Bevy dynamic scene in the binary scene format (`.scn.bin`).
+///
+/// Saved scenes are loaded with the [`BinarySceneLoader`].
+#[derive(Debug)]
+pub struct BinarySceneSaver {
+    type_registry: TypeRegistryArc,
+}
+
+impl FromWorld for BinarySceneSaver {
+    fn from_world(world: &mut World) -> Self {
+        let type_registry = world.resource::<AppTypeRegistry>();
+        BinarySceneSaver {
+            type_registry: type_registry.0.clone(),
+        }
+    }
+}
+
+/// Possible errors that can be produced by [`BinarySceneSaver`]
+#[non_exhaustive]
+#[derive(Debug, Error)]
+pub enum SceneSaverError {
+    /// An [IO Error](std::io::Error)
+    #[error("Error while trying to write the scene file: {0}")]
+    Io(#[from] std::io::Error),
+    /// A [Postcard Error](postcard::Error)
+    #[error("Could not serialize binary scene: {0}")]
+    Postcard(#[from] postcard::Error),
+}
+
+impl AssetSaver for BinarySceneSaver {
+    type Asset = DynamicScene;
+    type Settings = ();
+    type OutputLoader = BinarySceneLoader;
+    type Error = SceneSaverError;
+
+    async fn save(
+        &self,
+        writer: &mut Writer,
+        asset: SavedAsset<'_, Self::Asset>,
+        _settings: &Self::Settings,
+    ) -> Result<(), Self::Error> {
+        let bytes = asset.serialize_binary(&self.type_registry.read())?;
+        writer.write_all(&bytes).await?;
+        Ok(())
+    }
+}
@@ -1,6 +1,7 @@
 use crate::{DynamicScene, Scene};
-use bevy_asset::{AssetEvent, AssetId, Assets, Handle};
+use bevy_asset::{AssetEvent, AssetId, AssetServer, Assets, Handle};
 use bevy_ecs::{
+    dynamic_component::DynamicComponentError,
     entity::{Entity, EntityHashMap},
     event::{Event, EventCursor, Events},
     reflect::AppTypeRegistry,
@@ -9,7 +10,7 @@ use bevy_ecs::{
 };
 use bevy_hierarchy::{AddChild, BuildChildren, DespawnRecursiveExt, Parent};
 use bevy_reflect::Reflect;
-use bevy_utils::{HashMap, HashSet};
+use bevy_utils::{hashbrown::hash_map::Entry, tracing::error, HashMap, HashSet};
 use thiserror::Error;
 use uuid::Uuid;
 
@@ -26,10 +27,50 @@ pub struct SceneInstanceReady {
 }
 
 /// Information about a scene instance.
-#[derive(Debug)]
+#[derive(Debug, Default)]
 pub struct InstanceInfo {
     /// Mapping of entities from the scene world to the instance world.
     pub entity_map: EntityHashMap<Entity>,
+    /// Instances of other scenes nested in this instance, keyed by the scene entity that
+    /// instantiates them.
+    ///
+    /// See [`DynamicSceneInstance`](crate::DynamicSceneInstance).
+    pub nested_instances: EntityHashMap<NestedInstanceInfo>,
+}
+
+/// Information about a scene instance nested in another scene instance.
+#[derive(Debug)]
+pub struct NestedInstanceInfo {
+    /// Handle to the nested scene, which keeps it loaded while the instance exists.
+    pub scene: Handle<DynamicScene>,
+    /// The nested instance.
+    ///
+    /// Its entity map is empty until the nested scene has been loaded.
+    pub instance: InstanceInfo,
+}
+
+impl InstanceInfo {
+    fn from_entity_map(entity_map: EntityHashMap<Entity>) -> Self {
+        Self {
+            entity_map,
+            ..Default::default()
+        }
+    }
+
+    /// Collects the entities of this instance, including those of nested instances.
+    fn collect_entities(&self, entities: &mut Vec<Entity>) {
+        entities.extend(self.entity_map.values());
+        for nested in self.nested_instances.values() {
+            nested.instance.collect_entities(entities);
+        }
+    }
+
+    /// Returns `true` if the scene with the given id is nested in this instance, at any depth.
+    fn contains_nested_scene(&self, id: AssetId<DynamicScene>) -> bool {
+        self.nested_instances
+            .values()
+            .any(|nested| nested.scene.id() == id || nested.instance.contains_nested_scene(id))
+    }
 }
 
 /// Unique id identifying a scene instance.
@@ -126,6 +167,31 @@ pub enum SceneSpawnError {
         /// Id of the non-existent scene.
         id: AssetId<Scene>,
     },
+    /// Scene contains an instance of another scene, but there is no [`AssetServer`] to load it.
+    #[error("scene `{path}` is nested in a scene, but there is no `AssetServer` to load it")]
+    NoAssetServer {
+        /// Path of the nested scene.
+        path: String,
+    },
+    /// Scene contains an instance of itself, possibly through other nested scenes.
+    #[error("scene `{path}` is nested in itself")]
+    RecursiveScene {
+        /// Path of the scene that is nested in itself.
+        path: String,
+    },
+    /// Scene patch changes a field that could not be set on the patched entity.
+    #[error("scene patch could not set `{path}` on the component `{type_path}`: {reason}")]
+    InvalidPatchField {
+        /// Type of the patched component.
+        type_path: String,
+        /// Reflect path of the patched field.
+        path: String,
+        /// Why the field could not be set.
+        reason: String,
+    },
+    /// Scene contains a dynamic component that could not be inserted.
+    #[error("scene contains a dynamic component that could not be inserted: {0}")]
+    DynamicComponent(#[from] DynamicComponentError),
 }
 
 impl SceneSpawner {
@@ -193,12 +259,18 @@ impl SceneSpawner {
     /// Immediately despawns a scene instance, removing all its entities from the world.
     pub fn despawn_instance_sync(&mut self, world: &mut World, instance_id: &InstanceId) {
         if let Some(instance) = self.spawned_instances.remove(instance_id) {
-            for &entity in instance.entity_map.values() {
-                if let Ok(mut entity_mut) = world.get_entity_mut(entity) {
-                    entity_mut.remove_parent();
-                    entity_mut.despawn_recursive();
-                };
-            }
+            Self::despawn_instance_entities(world, &instance);
+        }
+    }
+
+    fn despawn_instance_entities(world: &mut World, instance: &InstanceInfo) {
+        let mut entities = Vec::new();
+        instance.collect_entities(&mut entities);
+        for entity in entities {
+            if let Ok(mut entity_mut) = world.get_entity_mut(entity) {
+                entity_mut.remove_parent();
+                entity_mut.despawn_recursive();
+            };
         }
     }
 
@@ -208,12 +280,15 @@ impl SceneSpawner {
         world: &mut World,
         id: impl Into<AssetId<DynamicScene>>,
     ) -> Result<InstanceId, SceneSpawnError> {
-        let mut entity_map = EntityHashMap::default();
+        let mut instance = InstanceInfo::default();
         let id = id.into();
-        Self::spawn_dynamic_internal(world, id, &mut entity_map)?;
+        if let Err(err) = Self::spawn_dynamic_internal(world, id, &mut instance) {
+            // Don't leave the part of the scene that was already written in the world.
+            Self::despawn_instance_entities(world, &instance);
+            return Err(err);
+        }
         let instance_id = InstanceId::new();
-        self.spawned_instances
-            .insert(instance_id, InstanceInfo { entity_map });
+        self.spawned_instances.insert(instance_id, instance);
         let spawned = self.spawned_dynamic_scenes.entry(id).or_default();
         spawned.insert(instance_id);
         Ok(instance_id)
@@ -222,17 +297,111 @@ impl SceneSpawner {
     fn spawn_dynamic_internal(
         world: &mut World,
         id: AssetId<DynamicScene>,
-        entity_map: &mut EntityHashMap<Entity>,
+        instance: &mut InstanceInfo,
     ) -> Result<(), SceneSpawnError> {
         world.resource_scope(|world, scenes: Mut<Assets<DynamicScene>>| {
-            let scene = scenes
-                .get(id)
-                .ok_or(SceneSpawnError::NonExistentScene { id })?;
-
-            scene.write_to_world(world, entity_map)
+            Self::write_dynamic_instance(world, &scenes, id, instance, &mut Vec::new())
         })
     }
 
+    /// Writes a dynamic scene and the scenes nested in it to the world.
+    ///
+    /// `ancestors` are the scenes this instance is nested in, used to detect cycles.
+    fn write_dynamic_instance(
+        world: &mut World,
+        scenes: &Assets<DynamicScene>,
+        id: AssetId<DynamicScene>,
+        instance: &mut InstanceInfo,
+        ancestors: &mut Vec<AssetId<DynamicScene>>,
+    ) -> Result<(), SceneSpawnError> {
+        let scene = scenes
+            .get(id)
+            .ok_or(SceneSpawnError::NonExistentScene { id })?;
+
+        scene.write_to_world(world, &mut instance.entity_map)?;
+
+        // Despawn the instances of the scenes that are no longer nested in this one.
+        let removed_instances: Vec<_> = instance
+            .nested_instances
+            .keys()
+            .filter(|&&entity| !scene.instances.iter().any(|nested| nested.entity == entity))
+            .copied()
+            .collect();
+        for entity in removed_instances {
+            if let Some(info) = instance.nested_instances.remove(&entity) {
+                Self::despawn_instance_entities(world, &info.instance);
+            }
+        }
+
+        ancestors.push(id);
+        for nested in &scene.instances {
+            // If the scene entity now instantiates another scene, replace the previous instance.
+            let is_stale = instance
+                .nested_instances
+                .get(&nested.entity)
+                .is_some_and(|info| info.scene.path() != Some(&nested.scene));
+            if is_stale {
+                if let Some(info) = instance.nested_instances.remove(&nested.entity) {
+                    Self::despawn_instance_entities(world, &info.instance);
+                }
+            }
+
+            let info = match instance.nested_instances.entry(nested.entity) {
+                Entry::Occupied(entry) => entry.into_mut(),
+                Entry::Vacant(entry) => {
+                    let asset_server = world.get_resource::<AssetServer>().ok_or_else(|| {
+                        SceneSpawnError::NoAssetServer {
+                            path: nested.scene.to_string(),
+                        }
+                    })?;
+                    entry.insert(NestedInstanceInfo {
+                        scene: asset_server.load(&nested.scene),
+                        instance: InstanceInfo::default(),
+                    })
+                }
+            };
+
+            let nested_id = info.scene.id();
+            if ancestors.contains(&nested_id) {
+                return Err(SceneSpawnError::RecursiveScene {
+                    path: nested.scene.to_string(),
+                });
+            }
+
+            // Nested scenes that are still loading are spawned by `scene_spawner_system`
+            // once they are added.
+            if !scenes.contains(nested_id) {
+                continue;
+            }
+
+            Self::write_dynamic_instance(world, scenes, nested_id, &mut info.instance, ancestors)?;
+            nested
+                .overrides
+                .apply_to_world(world, &mut info.instance.entity_map)?;
+
+            let parent = *instance
+                .entity_map
+                .entry(nested.entity)
+                .or_insert_with(|| world.spawn_empty().id());
+            for &entity in info.instance.entity_map.values() {
+                // Only the roots of the nested scene don't have a parent yet.
+                if world
+                    .get_entity(entity)
+                    .is_ok_and(|entity| !entity.contains::<Parent>())
+                {
+                    AddChild {
+                        parent,
+                        child: entity,
+                    }
+                    .apply(world);
+                }
+            }
+        }
+        ancestors.pop();
+
+        Ok(())
+    }
+
     /// Immediately spawns a new instance of the provided scene.
     pub fn spawn_sync(
         &mut self,
@@ -244,7 +413,7 @@ impl SceneSpawner {
         Self::spawn_sync_internal(world, id, &mut entity_map)?;
         let instance_id = InstanceId::new();
         self.spawned_instances
-            .insert(instance_id, InstanceInfo { entity_map });
+            .insert(instance_id, InstanceInfo::from_entity_map(entity_map));
         Ok(instance_id)
     }
 
@@ -269,21 +438,41 @@ impl SceneSpawner {
     /// Iterate through all instances of the provided scenes and update those immediately.
     ///
     /// Useful for updating already spawned scene instances after their corresponding scene has been modified.
+    ///
+    /// Instances that fail to update are despawned, and the first error is returned once the
+    /// other instances are updated.
     pub fn update_spawned_scenes(
         &mut self,
         world: &mut World,
         scene_ids: &[AssetId<DynamicScene>],
     ) -> Result<(), SceneSpawnError> {
+        first_error(|on_error| self.update_spawned_scenes_with(world, scene_ids, on_error))
+    }
+
+    fn update_spawned_scenes_with(
+        &mut self,
+        world: &mut World,
+        scene_ids: &[AssetId<DynamicScene>],
+        mut on_error: impl FnMut(SceneSpawnError),
+    ) {
         for id in scene_ids {
-            if let Some(spawned_instances) = self.spawned_dynamic_scenes.get(id) {
-                for instance_id in spawned_instances {
-                    if let Some(instance_info) = self.spawned_instances.get_mut(instance_id) {
-                        Self::spawn_dynamic_internal(world, *id, &mut instance_info.entity_map)?;
-                    }
+            let Some(spawned_instances) = self.spawned_dynamic_scenes.get_mut(id) else {
+                continue;
+            };
+            spawned_instances.retain(|instance_id| {
+                let Some(instance_info) = self.spawned_instances.get_mut(instance_id) else {
+                    return true;
+                };
+                let Err(err) = Self::spawn_dynamic_internal(world, *id, instance_info) else {
+                    return true;
+                };
+                on_error(err);
+                if let Some(instance) = self.spawned_instances.remove(instance_id) {
+                    Self::despawn_instance_entities(world, &instance);
                 }
-            }
+                false
+            });
         }
-        Ok(())
     }
 
     /// Immediately despawns all scenes scheduled for despawn by despawning their instances.
@@ -306,16 +495,26 @@ impl SceneSpawner {
     }
 
     /// Immediately spawns all scenes scheduled for spawn.
+    ///
+    /// Instances that fail to spawn are dropped, and the first error is returned once the other
+    /// instances are spawned.
     pub fn spawn_queued_scenes(&mut self, world: &mut World) -> Result<(), SceneSpawnError> {
+        first_error(|on_error| self.spawn_queued_scenes_with(world, on_error))
+    }
+
+    fn spawn_queued_scenes_with(
+        &mut self,
+        world: &mut World,
+        mut on_error: impl FnMut(SceneSpawnError),
+    ) {
         let scenes_to_spawn = core::mem::take(&mut self.dynamic_scenes_to_spawn);
 
         for (handle, instance_id, parent) in scenes_to_spawn {
-            let mut entity_map = EntityHashMap::default();
+            let mut instance = InstanceInfo::default();
 
-            match Self::spawn_dynamic_internal(world, handle.id(), &mut entity_map) {
+            match Self::spawn_dynamic_internal(world, handle.id(), &mut instance) {
                 Ok(_) => {
-                    self.spawned_instances
-                        .insert(instance_id, InstanceInfo { entity_map });
+                    self.spawned_instances.insert(instance_id, instance);
                     let spawned = self
                         .spawned_dynamic_scenes
                         .entry(handle.id())
@@ -333,7 +532,11 @@ impl SceneSpawner {
                     self.dynamic_scenes_to_spawn
                         .push((handle, instance_id, parent));
                 }
-                Err(err) => return Err(err),
+                Err(err) => {
+                    Self::despawn_instance_entities(world, &instance);
+                    self.scenes_with_parent.retain(|(id, _)| *id != instance_id);
+                    on_error(err);
+                }
             }
         }
 
@@ -345,7 +548,7 @@ impl SceneSpawner {
             match Self::spawn_sync_internal(world, scene_handle.id(), &mut entity_map) {
                 Ok(_) => {
                     self.spawned_instances
-                        .insert(instance_id, InstanceInfo { entity_map });
+                        .insert(instance_id, InstanceInfo::from_entity_map(entity_map));
 
                     // Scenes with parents need more setup before they are ready.
                     // See `set_scene_instance_parent_sync()`.
@@ -358,11 +561,12 @@ impl SceneSpawner {
                     self.scenes_to_spawn
                         .push((scene_handle, instance_id, parent));
                 }
-                Err(err) => return Err(err),
+                Err(err) => {
+                    self.scenes_with_parent.retain(|(id, _)| *id != instance_id);
+                    on_error(err);
+                }
             }
         }
-
-        Ok(())
     }
 
     pub(crate) fn set_scene_instance_parent_sync(&mut self, world: &mut World) {
@@ -407,18 +611,19 @@ impl SceneSpawner {
 
     /// Get an iterator over the entities in an instance, once it's spawned.
     ///
+    /// This includes the entities of the scenes nested in the instance.
+    ///
     /// Before the scene is spawned, the iterator will be empty. Use [`Self::instance_is_ready`]
     /// to check if the instance is ready.
     pub fn iter_instance_entities(
         &'_ self,
         instance_id: InstanceId,
     ) -> impl Iterator<Item = Entity> + '_ {
-        self.spawned_instances
-            .get(&instance_id)
-            .map(|instance| instance.entity_map.values())
-            .into_iter()
-            .flatten()
-            .copied()
+        let mut entities = Vec::new();
+        if let Some(instance) = self.spawned_instances.get(&instance_id) {
+            instance.collect_entities(&mut entities);
+        }
+        entities.into_iter()
     }
 }
 
@@ -448,6 +653,7 @@ pub fn scene_spawner_system(world: &mut World) {
         let scene_asset_events = world.resource::<Events<AssetEvent<DynamicScene>>>();
 
         let mut updated_spawned_scenes = Vec::new();
+        let mut changed_scenes = Vec::new();
         let scene_spawner = &mut *scene_spawner;
         for event in scene_spawner
             .scene_asset_event_reader
@@ -458,24 +664,62 @@ pub fn scene_spawner_system(world: &mut World) {
                     updated_spawned_scenes.push(*id);
                 }
             }
+            // Nested scenes are spawned once they are loaded, and respawned when they change.
+            if let AssetEvent::Added { id } | AssetEvent::Modified { id } = event {
+                changed_scenes.push(*id);
+            }
+        }
+
+        // Update the whole outer scene, so that the overrides of its instances are reapplied.
+        for (id, instance_ids) in &scene_spawner.spawned_dynamic_scenes {
+            let contains_changed_scene = instance_ids.iter().any(|instance_id| {
+                scene_spawner
+                    .spawned_instances
+                    .get(instance_id)
+                    .is_some_and(|instance| {
+                        changed_scenes
+                            .iter()
+                            .any(|&changed| instance.contains_nested_scene(changed))
+                    })
+            });
+            if contains_changed_scene && !updated_spawned_scenes.contains(id) {
+                updated_spawned_scenes.push(*id);
+            }
         }
 
         scene_spawner.despawn_queued_scenes(world).unwrap();
         scene_spawner.despawn_queued_instances(world);
-        scene_spawner
-            .spawn_queued_scenes(world)
-            .unwrap_or_else(|err| panic!("{}", err));
-        scene_spawner
-            .update_spawned_scenes(world, &updated_spawned_scenes)
-            .unwrap();
+        // Scene data can be invalid, so errors only drop the instances they come from.
+        scene_spawner.spawn_queued_scenes_with(world, |err| {
+            error!("Failed to spawn a scene instance: {err}");
+        });
+        scene_spawner.update_spawned_scenes_with(world, &updated_spawned_scenes, |err| {
+            error!("Failed to update a scene instance, it was despawned: {err}");
+        });
         scene_spawner.set_scene_instance_parent_sync(world);
     });
 }
 
+/// Runs `f` with a callback reporting errors, and returns the first reported error.
+fn first_error(f: impl FnOnce(&mut dyn FnMut(SceneSpawnError))) -> Result<(), SceneSpawnError> {
+    let mut first = None;
+    f(&mut |err| {
+        first.get_or_insert(err);
+    });
+    first.map_or(Ok(()), Err)
+}
+
 #[cfg(test)]
 mod tests {
     use bevy_app::App;
-    use bevy_asset::{AssetPlugin, AssetServer, Handle};
+    use bevy_asset::{
+        io::{
+            memory::{Dir, MemoryAssetReader},
+            AssetSource, AssetSourceId,
+        },
+        AssetApp, AssetPlugin, AssetServer, Handle,
+    };
+    use bevy_core::TaskPoolPlugin;
     use bevy_ecs::{
         component::Component,
         observer::Trigger,
@@ -484,8 +728,11 @@ mod tests {
         system::{Commands, Query, Res, ResMut, RunSystemOnce},
     };
     use bevy_reflect::Reflect;
+    use std::path::Path;
 
-    use crate::{DynamicSceneBuilder, DynamicSceneRoot, ScenePlugin};
+    use crate::{
+        DynamicEntity, DynamicSceneBuilder, DynamicSceneInstance, DynamicSceneRoot, ScenePlugin,
+    };
 
     use super::*;
 
@@ -749,4 +996,297 @@ mod tests {
         app.update();
         check(app.world_mut(), 0);
     }
+
+    fn memory_app(files: &[(&str, &str)]) -> App {
+        let dir = Dir::default();
+        for (path, contents) in files {
+            dir.insert_asset_text(Path::new(path), contents);
+        }
+
+        let mut app = App::new();
+        let reader = MemoryAssetReader { root: dir };
+        app.register_asset_source(
+            AssetSourceId::Default,
+            AssetSource::build().with_reader(move || Box::new(reader.clone())),
+        )
+        .add_plugins((
+            TaskPoolPlugin::default(),
+            AssetPlugin::default(),
+            ScenePlugin,
+        ))
+        .register_type::<A>()
+        .register_type::<ComponentA>();
+        app
+    }
+
+    fn update_until(app: &mut App, mut predicate: impl FnMut(&mut World) -> bool) {
+        for _ in 0..100 {
+            app.update();
+            if predicate(app.world_mut()) {
+                return;
+            }
+        }
+        panic!("condition was not met after 100 updates");
+    }
+
+    #[test]
+    fn nested_scene_instances() {
+        let inner = r#"(
+  resources: {},
+  entities: {
+    4294967296: (
+      components: {
+        "bevy_scene::scene_spawner::tests::A": (1),
+      },
+    ),
+  },
+)"#;
+        let outer = r#"(
+  resources: {},
+  entities: {
+    4294967296: (
+      components: {
+        "bevy_scene::scene_spawner::tests::ComponentA": (),
+      },
+    ),
+  },
+  instances: {
+    4294967296: (
+      scene: "inner.scn.ron",
+      overrides: (
+        resources: {},
+        removed_resources: [],
+        entities: {
+          4294967296: (
+            components: {},
+            fields: {
+              "bevy_scene::scene_spawner::tests::A": {
+                ".0": {
+                  "usize": 5,
+                },
+              },
+            },
+            removed_components: [],
+          ),
+        },
+        removed_entities: [],
+      ),
+    ),
+  },
+)"#;
+        let mut app = memory_app(&[("inner.scn.ron", inner), ("outer.scn.ron", outer)]);
+
+        let outer = app.world().resource::<AssetServer>().load("outer.scn.ron");
+        app.world_mut().spawn(DynamicSceneRoot(outer));
+
+        // The nested scene is spawned once it is loaded.
+        update_until(&mut app, |world| world.query::<&A>().iter(world).len() == 1);
+
+        let (a, parent) = app.world_mut().query::<(&A, &Parent)>().single(app.world());
+        assert_eq!(&A(5), a, "overrides should apply to the nested scene");
+        assert!(app.world().entity(parent.get()).contains::<ComponentA>());
+
+        // Changing the nested scene updates its instances, and keeps the overrides.
+        let inner = app
+            .world()
+            .resource::<AssetServer>()
+            .get_handle::<DynamicScene>("inner.scn.ron")
+            .unwrap();
+        let mut scene = DynamicScene::default();
+        scene.entities.push(DynamicEntity {
+            entity: Entity::from_raw(0),
+            components: vec![Box::new(A(1)), Box::new(ComponentA)],
+        });
+        app.world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .insert(&inner, scene);
+
+        update_until(&mut app, |world| {
+            world
+                .query_filtered::<&A, With<ComponentA>>()
+                .iter(world)
+                .len()
+                == 1
+        });
+        let a = app
+            .world_mut()
+            .query_filtered::<&A, With<ComponentA>>()
+            .single(app.world());
+        assert_eq!(&A(5), a);
+
+        // Removing the instance from the outer scene despawns it.
+        let outer = app
+            .world()
+            .resource::<AssetServer>()
+            .get_handle::<DynamicScene>("outer.scn.ron")
+            .unwrap();
+        let mut scene = DynamicScene::default();
+        scene.entities.push(DynamicEntity {
+            entity: Entity::from_raw(0),
+            components: vec![Box::new(ComponentA)],
+        });
+        app.world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .insert(&outer, scene);
+
+        update_until(&mut app, |world| world.query::<&A>().iter(world).len() == 0);
+        assert_eq!(
+            1,
+            app.world_mut()
+                .query::<&ComponentA>()
+                .iter(app.world())
+                .len()
+        );
+    }
+
+    #[test]
+    fn recursive_scene_instances() {
+        let scene = r#"(
+  resources: {},
+  entities: {
+    4294967296: (
+      components: {},
+    ),
+  },
+  instances: {
+    4294967296: (
+      scene: "recursive.scn.ron",
+      overrides: (
+        resources: {},
+        removed_resources: [],
+        entities: {},
+        removed_entities: [],
+      ),
+    ),
+  },
+)"#;
+        let mut app = memory_app(&[("recursive.scn.ron", scene)]);
+
+        let scene = app
+            .world()
+            .resource::<AssetServer>()
+            .load::<DynamicScene>("recursive.scn.ron");
+        let id = scene.id();
+        update_until(&mut app, |world| {
+            world.resource::<Assets<DynamicScene>>().contains(id)
+        });
+
+        let entity_count = app.world().entities().len();
+        let result =
+            app.world_mut()
+                .resource_scope(|world, mut scene_spawner: Mut<SceneSpawner>| {
+                    scene_spawner.spawn_dynamic_sync(world, &scene)
+                });
+        assert!(matches!(
+            result,
+            Err(SceneSpawnError::RecursiveScene { .. })
+        ));
+        assert_eq!(
+            entity_count,
+            app.world().entities().len(),
+            "entities spawned before the error should be despawned"
+        );
+    }
+
+    #[test]
+    fn invalid_scene_instances_are_dropped() {
+        let recursive = r#"(
+  resources: {},
+  entities: {
+    4294967296: (
+      components: {},
+    ),
+  },
+  instances: {
+    4294967296: (
+      scene: "recursive.scn.ron",
+      overrides: (
+        resources: {},
+        removed_resources: [],
+        entities: {},
+        removed_entities: [],
+      ),
+    ),
+  },
+)"#;
+        let mut app = memory_app(&[("recursive.scn.ron", recursive)]);
+        let recursive = app
+            .world()
+            .resource::<AssetServer>()
+            .load::<DynamicScene>("recursive.scn.ron");
+        let recursive_id = recursive.id();
+        update_until(&mut app, |world| {
+            world
+                .resource::<Assets<DynamicScene>>()
+                .contains(recursive_id)
+        });
+
+        let mut scene = DynamicScene::default();
+        scene.entities.push(DynamicEntity {
+            entity: Entity::from_raw(0),
+            components: vec![Box::new(A(1))],
+        });
+        let valid = app
+            .world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .add(scene);
+        let mut scene_spawner = app.world_mut().resource_mut::<SceneSpawner>();
+        let invalid_instance = scene_spawner.spawn_dynamic(recursive);
+        let valid_instance = scene_spawner.spawn_dynamic(valid.clone());
+
+        // The invalid instance is dropped without stopping the other one from being spawned.
+        app.update();
+        let scene_spawner = app.world().resource::<SceneSpawner>();
+        assert!(!scene_spawner.instance_is_ready(invalid_instance));
+        assert!(scene_spawner.instance_is_ready(valid_instance));
+        assert!(scene_spawner.dynamic_scenes_to_spawn.is_empty());
+        assert_eq!(1, app.world_mut().query::<&A>().iter(app.world()).len());
+
+        // An instance that becomes invalid when its scene changes is despawned.
+        let mut scene = DynamicScene::default();
+        scene.entities.push(DynamicEntity {
+            entity: Entity::from_raw(0),
+            components: vec![Box::new(A(1))],
+        });
+        scene.instances.push(DynamicSceneInstance {
+            entity: Entity::from_raw(0),
+            scene: "recursive.scn.ron".into(),
+            overrides: Default::default(),
+        });
+        app.world_mut()
+            .resource_mut::<Assets<DynamicScene>>()
+            .insert(&valid, scene);
+        update_until(&mut app, |world| {
+            !world
+                .resource::<SceneSpawner>()
+                .instance_is_ready(valid_instance)
+        });
+        assert_eq!(0, app.world_mut().query::<&A>().iter(app.world()).len());
+    }
+
+    #[test]
+    fn nested_scene_instances_without_asset_server() {
+        let mut world = World::new();
+        world.init_resource::<AppTypeRegistry>();
+        world.init_resource::<SceneSpawner>();
+        let mut scenes = Assets::<DynamicScene>::default();
+        let mut scene = DynamicScene::default();
+        scene.entities.push(DynamicEntity {
+            entity: Entity::from_raw(0),
+            components: Vec::new(),
+        });
+        scene.instances.push(DynamicSceneInstance {
+            entity: Entity::from_raw(0),
+            scene: "inner.scn.ron".into(),
+            overrides: Default::default(),
+        });
+        let scene = scenes.add(scene);
+        world.insert_resource(scenes);
+
+        let result = world.resource_scope(|world, mut scene_spawner: Mut<SceneSpawner>| {
+            scene_spawner.spawn_dynamic_sync(world, &scene)
+        });
+        assert!(matches!(result, Err(SceneSpawnError::NoAssetServer { .. })));
+        assert_eq!(0, world.entities().len());
+    }
 }
@@ -1,11 +1,14 @@
 //! `serde` serialization and deserialization implementation for Bevy scenes.
 
-use crate::{DynamicEntity, DynamicScene};
+use crate::{
+    ComponentFieldPatch, DynamicEntity, DynamicEntityPatch, DynamicScene, DynamicSceneInstance,
+    DynamicScenePatch,
+};
 use bevy_ecs::entity::Entity;
 use bevy_reflect::{
     serde::{
-        ReflectDeserializer, TypeRegistrationDeserializer, TypedReflectDeserializer,
-        TypedReflectSerializer,
+        ReflectDeserializer, ReflectSerializer, TypeRegistrationDeserializer,
+        TypedReflectDeserializer, TypedReflectSerializer,
     },
     PartialReflect, ReflectFromReflect, TypeRegistry,
 };
@@ -23,12 +26,35 @@ pub const SCENE_STRUCT: &str = "Scene";
 pub const SCENE_RESOURCES: &str = "resources";
 /// Name of the serialized entities field in a scene struct.
 pub const SCENE_ENTITIES: &str = "entities";
+/// Name of the serialized nested scene instances field in a scene struct.
+pub const SCENE_INSTANCES: &str = "instances";
 
 /// Name of the serialized entity struct type.
 pub const ENTITY_STRUCT: &str = "Entity";
 /// Name of the serialized component field in an entity struct.
 pub const ENTITY_FIELD_COMPONENTS: &str = "components";
 
+/// Name of the serialized nested scene instance struct type.
+pub const INSTANCE_STRUCT: &str = "Instance";
+/// Name of the serialized scene path field in a nested scene instance struct.
+pub const INSTANCE_SCENE: &str = "scene";
+/// Name of the serialized overrides field in a nested scene instance struct.
+pub const INSTANCE_OVERRIDES: &str = "overrides";
+
+/// Name of the serialized scene patch struct type.
+pub const SCENE_PATCH_STRUCT: &str = "ScenePatch";
+/// Name of the serialized removed resources field in a scene patch struct.
+pub const SCENE_PATCH_REMOVED_RESOURCES: &str = "removed_resources";
+/// Name of the serialized removed entities field in a scene patch struct.
+pub const SCENE_PATCH_REMOVED_ENTITIES: &str = "removed_entities";
+
+/// Name of the serialized entity patch struct type.
+pub const ENTITY_PATCH_STRUCT: &str = "EntityPatch";
+/// Name of the serialized changed fields field in an entity patch struct.
+pub const ENTITY_PATCH_FIELDS: &str = "fields";
+/// Name of the serialized removed components field in an entity patch struct.
+pub const ENTITY_PATCH_REMOVED_COMPONENTS: &str = "removed_components";
+
 /// Serializer for a [`DynamicScene`].
 ///
 /// Helper object defining Bevy's serialize format for a [`DynamicScene`] and implementing
@@ -78,7 +104,27 @@ impl<'a> Serialize for SceneSerializer<'a> {
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_struct(SCENE_STRUCT, 2)?;
+        // Formats that aren't self-describing can't tell whether the trailing `instances` field
+        // is present, so only human-readable formats store it in the scene struct.
+        if !self.scene.instances.is_empty() && !serializer.is_human_readable() {
+            return Err(serde::ser::Error::custom(
+                "nested scene instances can only be serialized to human-readable formats, \
+                use `DynamicScene::serialize_binary` to serialize them to the binary scene format",
+            ));
+        }
+        self.serialize_fields(serializer, !self.scene.instances.is_empty())
+    }
+}
+
+impl<'a> SceneSerializer<'a> {
+    /// Scenes without nested instances are written the same way they were before nested
+    /// instances existed.
+    fn serialize_fields<S>(&self, serializer: S, with_instances: bool) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let len = if with_instances { 3 } else { 2 };
+        let mut state = serializer.serialize_struct(SCENE_STRUCT, len)?;
         state.serialize_field(
             SCENE_RESOURCES,
             &SceneMapSerializer {
@@ -93,10 +139,72 @@ impl<'a> Serialize for SceneSerializer<'a> {
                 registry: self.registry,
             },
         )?;
+        if with_instances {
+            state.serialize_field(
+                SCENE_INSTANCES,
+                &InstancesSerializer {
+                    instances: &self.scene.instances,
+                    registry: self.registry,
+                },
+            )?;
+        } else {
+            state.skip_field(SCENE_INSTANCES)?;
+        }
         state.end()
     }
 }
 
+/// Serializes the resources and entities of a scene, leaving out its nested instances.
+struct SceneWithoutInstancesSerializer<'a>(SceneSerializer<'a>);
+
+impl<'a> Serialize for SceneWithoutInstancesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        self.0.serialize_fields(serializer, false)
+    }
+}
+
+/// Serializes a scene into the binary scene format.
+///
+/// The scene struct is written with postcard exactly like [`SceneSerializer`] does for scenes
+/// without nested instances. The nested instances, if any, are written after it, since postcard
+/// can't tell whether a trailing struct field is present.
+pub(crate) fn serialize_binary(
+    scene: &DynamicScene,
+    registry: &TypeRegistry,
+) -> Result<Vec<u8>, postcard::Error> {
+    let bytes = postcard::to_allocvec(&SceneWithoutInstancesSerializer(SceneSerializer::new(
+        scene, registry,
+    )))?;
+    if scene.instances.is_empty() {
+        return Ok(bytes);
+    }
+    postcard::to_extend(
+        &InstancesSerializer {
+            instances: &scene.instances,
+            registry,
+        },
+        bytes,
+    )
+}
+
+/// Deserializes a scene written by [`serialize_binary`].
+pub(crate) fn deserialize_binary(
+    bytes: &[u8],
+    type_registry: &TypeRegistry,
+) -> Result<DynamicScene, postcard::Error> {
+    let mut deserializer = postcard::Deserializer::from_bytes(bytes);
+    let mut scene = SceneDeserializer { type_registry }.deserialize(&mut deserializer)?;
+    let remainder = deserializer.finalize()?;
+    if !remainder.is_empty() {
+        scene.instances = InstancesDeserializer { type_registry }
+            .deserialize(&mut postcard::Deserializer::from_bytes(remainder))?;
+    }
+    Ok(scene)
+}
+
 /// Handles serialization of multiple entities as a map of entity id to serialized entity.
 pub struct EntitiesSerializer<'a> {
     /// The entities to serialize.
@@ -124,6 +232,56 @@ impl<'a> Serialize for EntitiesSerializer<'a> {
     }
 }
 
+/// Handles serialization of nested scene instances as a map of entity id to serialized instance.
+pub struct InstancesSerializer<'a> {
+    /// The nested scene instances to serialize.
+    pub instances: &'a [DynamicSceneInstance],
+    /// Type registry in which the types used by the instance overrides are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for InstancesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.instances.len()))?;
+        for instance in self.instances {
+            state.serialize_entry(
+                &instance.entity,
+                &InstanceSerializer {
+                    instance,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+/// Handles serialization of a nested scene instance as the path of the scene and its overrides.
+pub struct InstanceSerializer<'a> {
+    /// The nested scene instance to serialize.
+    pub instance: &'a DynamicSceneInstance,
+    /// Type registry in which the types used by the instance overrides are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for InstanceSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(INSTANCE_STRUCT, 2)?;
+        state.serialize_field(INSTANCE_SCENE, &self.instance.scene)?;
+        state.serialize_field(
+            INSTANCE_OVERRIDES,
+            &ScenePatchSerializer::new(&self.instance.overrides, self.registry),
+        )?;
+        state.end()
+    }
+}
+
 /// Handles entity serialization as a map of component type to component value.
 pub struct EntitySerializer<'a> {
     /// The entity to serialize.
@@ -151,43 +309,701 @@ impl<'a> Serialize for EntitySerializer<'a> {
 
 /// Handles serializing a list of values with a unique type as a map of type to value.
 ///
-/// Used to serialize scene resources in [`SceneSerializer`] and entity components in [`EntitySerializer`].
-/// Note that having several entries of the same type in `entries` will lead to an error when using the RON format and
-/// deserializing through [`SceneMapDeserializer`].
+/// Used to serialize scene resources in [`SceneSerializer`] and entity components in [`EntitySerializer`].
+/// Note that having several entries of the same type in `entries` will lead to an error when using the RON format and
+/// deserializing through [`SceneMapDeserializer`].
+///
+/// Note: The entries are sorted by type path before they're serialized.
+pub struct SceneMapSerializer<'a> {
+    /// List of boxed values of unique type to serialize.
+    pub entries: &'a [Box<dyn PartialReflect>],
+    /// Type registry in which the types used in `entries` are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for SceneMapSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.entries.len()))?;
+        let sorted_entries = {
+            let mut entries = self
+                .entries
+                .iter()
+                .map(|entry| {
+                    (
+                        entry.get_represented_type_info().unwrap().type_path(),
+                        entry.as_partial_reflect(),
+                    )
+                })
+                .collect::<Vec<_>>();
+            entries.sort_by_key(|(type_path, _partial_reflect)| *type_path);
+            entries
+        };
+
+        for (type_path, partial_reflect) in sorted_entries {
+            state.serialize_entry(
+                type_path,
+                &TypedReflectSerializer::new(partial_reflect, self.registry),
+            )?;
+        }
+        state.end()
+    }
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum SceneField {
+    Resources,
+    Entities,
+    Instances,
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum EntityField {
+    Components,
+}
+
+#[derive(Deserialize)]
+#[serde(field_identifier, rename_all = "lowercase")]
+enum InstanceField {
+    Scene,
+    Overrides,
+}
+
+/// Handles scene deserialization.
+pub struct SceneDeserializer<'a> {
+    /// Type registry in which the components and resources types used in the scene to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
+    type Value = DynamicScene;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        // Only human-readable formats store nested instances in the scene struct, see
+        // `SceneSerializer`.
+        let fields: &'static [&'static str] = if deserializer.is_human_readable() {
+            &[SCENE_RESOURCES, SCENE_ENTITIES, SCENE_INSTANCES]
+        } else {
+            &[SCENE_RESOURCES, SCENE_ENTITIES]
+        };
+        deserializer.deserialize_struct(
+            SCENE_STRUCT,
+            fields,
+            SceneVisitor {
+                type_registry: self.type_registry,
+            },
+        )
+    }
+}
+
+struct SceneVisitor<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
+    type Value = DynamicScene;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("scene struct")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let resources = seq
+            .next_element_seed(SceneMapDeserializer {
+                registry: self.type_registry,
+            })?
+            .ok_or_else(|| Error::missing_field(SCENE_RESOURCES))?;
+
+        let entities = seq
+            .next_element_seed(SceneEntitiesDeserializer {
+                type_registry: self.type_registry,
+            })?
+            .ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
+
+        // Scenes without nested instances omit the trailing field.
+        let instances = seq
+            .next_element_seed(InstancesDeserializer {
+                type_registry: self.type_registry,
+            })?
+            .unwrap_or_default();
+
+        Ok(DynamicScene {
+            resources,
+            entities,
+            instances,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut resources = None;
+        let mut entities = None;
+        let mut instances = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                SceneField::Resources => {
+                    if resources.is_some() {
+                        return Err(Error::duplicate_field(SCENE_RESOURCES));
+                    }
+                    resources = Some(map.next_value_seed(SceneMapDeserializer {
+                        registry: self.type_registry,
+                    })?);
+                }
+                SceneField::Entities => {
+                    if entities.is_some() {
+                        return Err(Error::duplicate_field(SCENE_ENTITIES));
+                    }
+                    entities = Some(map.next_value_seed(SceneEntitiesDeserializer {
+                        type_registry: self.type_registry,
+                    })?);
+                }
+                SceneField::Instances => {
+                    if instances.is_some() {
+                        return Err(Error::duplicate_field(SCENE_INSTANCES));
+                    }
+                    instances = Some(map.next_value_seed(InstancesDeserializer {
+                        type_registry: self.type_registry,
+                    })?);
+                }
+            }
+        }
+
+        let resources = resources.ok_or_else(|| Error::missing_field(SCENE_RESOURCES))?;
+        let entities = entities.ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
+        // Scenes without nested instances may omit the field.
+        let instances = instances.unwrap_or_default();
+
+        Ok(DynamicScene {
+            resources,
+            entities,
+            instances,
+        })
+    }
+}
+
+/// Handles deserialization for a collection of entities.
+pub struct SceneEntitiesDeserializer<'a> {
+    /// Type registry in which the component types used by the entities to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneEntitiesDeserializer<'a> {
+    type Value = Vec<DynamicEntity>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(SceneEntitiesVisitor {
+            type_registry: self.type_registry,
+        })
+    }
+}
+
+struct SceneEntitiesVisitor<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for SceneEntitiesVisitor<'a> {
+    type Value = Vec<DynamicEntity>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("map of entities")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut entities = Vec::new();
+        while let Some(entity) = map.next_key::<Entity>()? {
+            let entity = map.next_value_seed(SceneEntityDeserializer {
+                entity,
+                type_registry: self.type_registry,
+            })?;
+            entities.push(entity);
+        }
+
+        Ok(entities)
+    }
+}
+
+/// Handle deserialization of an entity and its components.
+pub struct SceneEntityDeserializer<'a> {
+    /// Id of the deserialized entity.
+    pub entity: Entity,
+    /// Type registry in which the component types used by the entity to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneEntityDeserializer<'a> {
+    type Value = DynamicEntity;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            ENTITY_STRUCT,
+            &[ENTITY_FIELD_COMPONENTS],
+            SceneEntityVisitor {
+                entity: self.entity,
+                registry: self.type_registry,
+            },
+        )
+    }
+}
+
+struct SceneEntityVisitor<'a> {
+    pub entity: Entity,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
+    type Value = DynamicEntity;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("entities")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let components = seq
+            .next_element_seed(SceneMapDeserializer {
+                registry: self.registry,
+            })?
+            .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
+
+        Ok(DynamicEntity {
+            entity: self.entity,
+            components,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut components = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                EntityField::Components => {
+                    if components.is_some() {
+                        return Err(Error::duplicate_field(ENTITY_FIELD_COMPONENTS));
+                    }
+
+                    components = Some(map.next_value_seed(SceneMapDeserializer {
+                        registry: self.registry,
+                    })?);
+                }
+            }
+        }
+
+        let components = components
+            .take()
+            .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
+        Ok(DynamicEntity {
+            entity: self.entity,
+            components,
+        })
+    }
+}
+
+/// Handles deserialization for a collection of nested scene instances.
+pub struct InstancesDeserializer<'a> {
+    /// Type registry in which the types used by the instance overrides to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for InstancesDeserializer<'a> {
+    type Value = Vec<DynamicSceneInstance>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(InstancesVisitor {
+            type_registry: self.type_registry,
+        })
+    }
+}
+
+struct InstancesVisitor<'a> {
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for InstancesVisitor<'a> {
+    type Value = Vec<DynamicSceneInstance>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("map of scene instances")
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut instances = Vec::new();
+        while let Some(entity) = map.next_key::<Entity>()? {
+            let instance = map.next_value_seed(InstanceDeserializer {
+                entity,
+                type_registry: self.type_registry,
+            })?;
+            instances.push(instance);
+        }
+
+        Ok(instances)
+    }
+}
+
+/// Handle deserialization of a nested scene instance.
+pub struct InstanceDeserializer<'a> {
+    /// Id of the entity instantiating the nested scene.
+    pub entity: Entity,
+    /// Type registry in which the types used by the instance overrides to deserialize are registered.
+    pub type_registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for InstanceDeserializer<'a> {
+    type Value = DynamicSceneInstance;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_struct(
+            INSTANCE_STRUCT,
+            &[INSTANCE_SCENE, INSTANCE_OVERRIDES],
+            InstanceVisitor {
+                entity: self.entity,
+                registry: self.type_registry,
+            },
+        )
+    }
+}
+
+struct InstanceVisitor<'a> {
+    pub entity: Entity,
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for InstanceVisitor<'a> {
+    type Value = DynamicSceneInstance;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("scene instance")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let scene = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(INSTANCE_SCENE))?;
+
+        let overrides = seq
+            .next_element_seed(ScenePatchDeserializer {
+                type_registry: self.registry,
+            })?
+            .ok_or_else(|| Error::missing_field(INSTANCE_OVERRIDES))?;
+
+        Ok(DynamicSceneInstance {
+            entity: self.entity,
+            scene,
+            overrides,
+        })
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut scene = None;
+        let mut overrides = None;
+        while let Some(key) = map.next_key()? {
+            match key {
+                InstanceField::Scene => {
+                    if scene.is_some() {
+                        return Err(Error::duplicate_field(INSTANCE_SCENE));
+                    }
+                    scene = Some(map.next_value()?);
+                }
+                InstanceField::Overrides => {
+                    if overrides.is_some() {
+                        return Err(Error::duplicate_field(INSTANCE_OVERRIDES));
+                    }
+                    overrides = Some(map.next_value_seed(ScenePatchDeserializer {
+                        type_registry: self.registry,
+                    })?);
+                }
+            }
+        }
+
+        let scene = scene.ok_or_else(|| Error::missing_field(INSTANCE_SCENE))?;
+        let overrides = overrides.ok_or_else(|| Error::missing_field(INSTANCE_OVERRIDES))?;
+
+        Ok(DynamicSceneInstance {
+            entity: self.entity,
+            scene,
+            overrides,
+        })
+    }
+}
+
+/// Handles deserialization of a sequence of values with unique types.
+pub struct SceneMapDeserializer<'a> {
+    /// Type registry in which the types of the values to deserialize are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for SceneMapDeserializer<'a> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        deserializer.deserialize_map(SceneMapVisitor {
+            registry: self.registry,
+        })
+    }
+}
+
+struct SceneMapVisitor<'a> {
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
+    type Value = Vec<Box<dyn PartialReflect>>;
+
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("map of reflect types")
+    }
+
+    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    where
+        A: SeqAccess<'de>,
+    {
+        let mut dynamic_properties = Vec::new();
+        while let Some(entity) = seq.next_element_seed(ReflectDeserializer::new(self.registry))? {
+            dynamic_properties.push(entity);
+        }
+
+        Ok(dynamic_properties)
+    }
+
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        let mut added = <HashSet<_>>::default();
+        let mut entries = Vec::new();
+        while let Some(registration) =
+            map.next_key_seed(TypeRegistrationDeserializer::new(self.registry))?
+        {
+            if !added.insert(registration.type_id()) {
+                return Err(Error::custom(format_args!(
+                    "duplicate reflect type: `{}`",
+                    registration.type_info().type_path(),
+                )));
+            }
+
+            let value =
+                map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?;
+
+            // Attempt to convert using FromReflect.
+            let value = self
+                .registry
+                .get(registration.type_id())
+                .and_then(|tr| tr.data::<ReflectFromReflect>())
+                .and_then(|fr| fr.from_reflect(value.as_partial_reflect()))
+                .map(PartialReflect::into_partial_reflect)
+                .unwrap_or(value);
+
+            entries.push(value);
+        }
+
+        Ok(entries)
+    }
+}
+
+/// Serializer for a [`DynamicScenePatch`].
+///
+/// Helper object defining Bevy's serialize format for a [`DynamicScenePatch`] and implementing
+/// the [`Serialize`] trait for use with Serde. Inserted components and resources are written
+/// the same way [`SceneSerializer`] writes them.
+pub struct ScenePatchSerializer<'a> {
+    /// The patch to serialize.
+    pub patch: &'a DynamicScenePatch,
+    /// The type registry containing the types present in the patch.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> ScenePatchSerializer<'a> {
+    /// Create a new serializer from a [`DynamicScenePatch`] and an associated [`TypeRegistry`].
+    ///
+    /// The type registry must contain all types present in the patch, including the types of
+    /// the changed fields.
+    pub fn new(patch: &'a DynamicScenePatch, registry: &'a TypeRegistry) -> Self {
+        ScenePatchSerializer { patch, registry }
+    }
+}
+
+impl<'a> Serialize for ScenePatchSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(SCENE_PATCH_STRUCT, 4)?;
+        state.serialize_field(
+            SCENE_RESOURCES,
+            &SceneMapSerializer {
+                entries: &self.patch.resources,
+                registry: self.registry,
+            },
+        )?;
+        state.serialize_field(SCENE_PATCH_REMOVED_RESOURCES, &self.patch.removed_resources)?;
+        state.serialize_field(
+            SCENE_ENTITIES,
+            &EntityPatchesSerializer {
+                entities: &self.patch.entities,
+                registry: self.registry,
+            },
+        )?;
+        state.serialize_field(SCENE_PATCH_REMOVED_ENTITIES, &self.patch.removed_entities)?;
+        state.end()
+    }
+}
+
+/// Handles serialization of multiple entity patches as a map of entity id to serialized patch.
+pub struct EntityPatchesSerializer<'a> {
+    /// The entity patches to serialize.
+    pub entities: &'a [DynamicEntityPatch],
+    /// Type registry in which the types used by the entity patches are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for EntityPatchesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.entities.len()))?;
+        for entity in self.entities {
+            state.serialize_entry(
+                &entity.entity,
+                &EntityPatchSerializer {
+                    entity,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+/// Handles serialization of the changes made to a single entity.
+pub struct EntityPatchSerializer<'a> {
+    /// The entity patch to serialize.
+    pub entity: &'a DynamicEntityPatch,
+    /// Type registry in which the types used by the entity patch are registered.
+    pub registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for EntityPatchSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_struct(ENTITY_PATCH_STRUCT, 3)?;
+        state.serialize_field(
+            ENTITY_FIELD_COMPONENTS,
+            &SceneMapSerializer {
+                entries: &self.entity.components,
+                registry: self.registry,
+            },
+        )?;
+        state.serialize_field(
+            ENTITY_PATCH_FIELDS,
+            &ComponentFieldsSerializer {
+                fields: &self.entity.fields,
+                registry: self.registry,
+            },
+        )?;
+        state.serialize_field(
+            ENTITY_PATCH_REMOVED_COMPONENTS,
+            &self.entity.removed_components,
+        )?;
+        state.end()
+    }
+}
+
+/// Handles serialization of changed component fields as a map of component type to a map of
+/// field path to new value.
+///
+/// Field values are serialized along with their type path, as with [`ReflectSerializer`].
 ///
-/// Note: The entries are sorted by type path before they're serialized.
-pub struct SceneMapSerializer<'a> {
-    /// List of boxed values of unique type to serialize.
-    pub entries: &'a [Box<dyn PartialReflect>],
-    /// Type registry in which the types used in `entries` are registered.
+/// Note: The fields are sorted by component type path and field path before they're serialized.
+pub struct ComponentFieldsSerializer<'a> {
+    /// The changed fields to serialize.
+    pub fields: &'a [ComponentFieldPatch],
+    /// Type registry in which the types of the field values are registered.
     pub registry: &'a TypeRegistry,
 }
 
-impl<'a> Serialize for SceneMapSerializer<'a> {
+impl<'a> Serialize for ComponentFieldsSerializer<'a> {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: Serializer,
     {
-        let mut state = serializer.serialize_map(Some(self.entries.len()))?;
-        let sorted_entries = {
-            let mut entries = self
-                .entries
-                .iter()
-                .map(|entry| {
-                    (
-                        entry.get_represented_type_info().unwrap().type_path(),
-                        entry.as_partial_reflect(),
-                    )
-                })
-                .collect::<Vec<_>>();
-            entries.sort_by_key(|(type_path, _partial_reflect)| *type_path);
-            entries
-        };
+        let mut sorted_fields = self.fields.iter().collect::<Vec<_>>();
+        sorted_fields.sort_by(|a, b| (&a.component, &a.path).cmp(&(&b.component, &b.path)));
 
-        for (type_path, partial_reflect) in sorted_entries {
+        let components = sorted_fields.chunk_by(|a, b| a.component == b.component);
+        let mut state = serializer.serialize_map(Some(components.clone().count()))?;
+        for fields in components {
             state.serialize_entry(
-                type_path,
-                &TypedReflectSerializer::new(partial_reflect, self.registry),
+                &fields[0].component,
+                &FieldValuesSerializer {
+                    fields,
+                    registry: self.registry,
+                },
+            )?;
+        }
+        state.end()
+    }
+}
+
+struct FieldValuesSerializer<'a> {
+    fields: &'a [&'a ComponentFieldPatch],
+    registry: &'a TypeRegistry,
+}
+
+impl<'a> Serialize for FieldValuesSerializer<'a> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut state = serializer.serialize_map(Some(self.fields.len()))?;
+        for field in self.fields {
+            state.serialize_entry(
+                &field.path,
+                &ReflectSerializer::new(field.value.as_ref(), self.registry),
             )?;
         }
         state.end()
@@ -195,50 +1011,59 @@ impl<'a> Serialize for SceneMapSerializer<'a> {
 }
 
 #[derive(Deserialize)]
-#[serde(field_identifier, rename_all = "lowercase")]
-enum SceneField {
+#[serde(field_identifier, rename_all = "snake_case")]
+enum ScenePatchField {
     Resources,
+    RemovedResources,
     Entities,
+    RemovedEntities,
 }
 
 #[derive(Deserialize)]
-#[serde(field_identifier, rename_all = "lowercase")]
-enum EntityField {
+#[serde(field_identifier, rename_all = "snake_case")]
+enum EntityPatchField {
     Components,
+    Fields,
+    RemovedComponents,
 }
 
-/// Handles scene deserialization.
-pub struct SceneDeserializer<'a> {
-    /// Type registry in which the components and resources types used in the scene to deserialize are registered.
+/// Handles scene patch deserialization.
+pub struct ScenePatchDeserializer<'a> {
+    /// Type registry in which the types used in the patch to deserialize are registered.
     pub type_registry: &'a TypeRegistry,
 }
 
-impl<'a, 'de> DeserializeSeed<'de> for SceneDeserializer<'a> {
-    type Value = DynamicScene;
+impl<'a, 'de> DeserializeSeed<'de> for ScenePatchDeserializer<'a> {
+    type Value = DynamicScenePatch;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
         D: Deserializer<'de>,
     {
         deserializer.deserialize_struct(
-            SCENE_STRUCT,
-            &[SCENE_RESOURCES, SCENE_ENTITIES],
-            SceneVisitor {
+            SCENE_PATCH_STRUCT,
+            &[
+                SCENE_RESOURCES,
+                SCENE_PATCH_REMOVED_RESOURCES,
+                SCENE_ENTITIES,
+                SCENE_PATCH_REMOVED_ENTITIES,
+            ],
+            ScenePatchVisitor {
                 type_registry: self.type_registry,
             },
         )
     }
 }
 
-struct SceneVisitor<'a> {
+struct ScenePatchVisitor<'a> {
     pub type_registry: &'a TypeRegistry,
 }
 
-impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
-    type Value = DynamicScene;
+impl<'a, 'de> Visitor<'de> for ScenePatchVisitor<'a> {
+    type Value = DynamicScenePatch;
 
     fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
-        formatter.write_str("scene struct")
+        formatter.write_str("scene patch struct")
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
@@ -251,15 +1076,25 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
             })?
             .ok_or_else(|| Error::missing_field(SCENE_RESOURCES))?;
 
+        let removed_resources = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(SCENE_PATCH_REMOVED_RESOURCES))?;
+
         let entities = seq
-            .next_element_seed(SceneEntitiesDeserializer {
+            .next_element_seed(EntityPatchesDeserializer {
                 type_registry: self.type_registry,
             })?
             .ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
 
-        Ok(DynamicScene {
+        let removed_entities = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(SCENE_PATCH_REMOVED_ENTITIES))?;
+
+        Ok(DynamicScenePatch {
             resources,
+            removed_resources,
             entities,
+            removed_entities,
         })
     }
 
@@ -268,10 +1103,12 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
         A: MapAccess<'de>,
     {
         let mut resources = None;
+        let mut removed_resources = None;
         let mut entities = None;
+        let mut removed_entities = None;
         while let Some(key) = map.next_key()? {
             match key {
-                SceneField::Resources => {
+                ScenePatchField::Resources => {
                     if resources.is_some() {
                         return Err(Error::duplicate_field(SCENE_RESOURCES));
                     }
@@ -279,55 +1116,73 @@ impl<'a, 'de> Visitor<'de> for SceneVisitor<'a> {
                         registry: self.type_registry,
                     })?);
                 }
-                SceneField::Entities => {
+                ScenePatchField::RemovedResources => {
+                    if removed_resources.is_some() {
+                        return Err(Error::duplicate_field(SCENE_PATCH_REMOVED_RESOURCES));
+                    }
+                    removed_resources = Some(map.next_value()?);
+                }
+                ScenePatchField::Entities => {
                     if entities.is_some() {
                         return Err(Error::duplicate_field(SCENE_ENTITIES));
                     }
-                    entities = Some(map.next_value_seed(SceneEntitiesDeserializer {
+                    entities = Some(map.next_value_seed(EntityPatchesDeserializer {
                         type_registry: self.type_registry,
                     })?);
                 }
+                ScenePatchField::RemovedEntities => {
+                    if removed_entities.is_some() {
+                        return Err(Error::duplicate_field(SCENE_PATCH_REMOVED_ENTITIES));
+                    }
+                    removed_entities = Some(map.next_value()?);
+                }
             }
         }
 
         let resources = resources.ok_or_else(|| Error::missing_field(SCENE_RESOURCES))?;
+        let removed_resources =
+            removed_resources.ok_or_else(|| Error::missing_field(SCENE_PATCH_REMOVED_RESOURCES))?;
         let entities = entities.ok_or_else(|| Error::missing_field(SCENE_ENTITIES))?;
+        let removed_entities =
+            removed_entities.ok_or_else(|| Error::missing_field(SCENE_PATCH_REMOVED_ENTITIES))?;
 
-        Ok(DynamicScene {
+        Ok(DynamicScenePatch {
             resources,
+            removed_resources,
             entities,
+            removed_entities,
         })
     }
 }
 
-/// Handles deserialization for a collection of entities.
-pub struct SceneEntitiesDeserializer<'a> {
-    /// Type registry in which the component types used by the entities to deserialize are registered.
+/// Handles deserialization for a collection of entity patches.
+pub struct EntityPatchesDeserializer<'a> {
+    /// Type registry in which the types used by the entity patches to deserialize are registered.
     pub type_registry: &'a TypeRegistry,
 }
 
-impl<'a, 'de> DeserializeSeed<'de> for SceneEntitiesDeserializer<'a> {
-    type Value = Vec<DynamicEntity>;
+impl<'a, 'de> DeserializeSeed<'de> for EntityPatchesDeserializer<'a> {
+    type Value = Vec<DynamicEntityPatch>;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_map(SceneEntitiesVisitor {
+        deserializer.deserialize_map(EntityPatchesVisitor {
             type_registry: self.type_registry,
         })
     }
 }
 
-struct SceneEntitiesVisitor<'a> {
+struct EntityPatchesVisitor<'a> {
     pub type_registry: &'a TypeRegistry,
 }
 
-impl<'a, 'de> Visitor<'de> for SceneEntitiesVisitor<'a> {
-    type Value = Vec<DynamicEntity>;
+impl<'a, 'de> Visitor<'de> for EntityPatchesVisitor<'a> {
+    type Value = Vec<DynamicEntityPatch>;
 
     fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
-        formatter.write_str("map of entities")
+        formatter.write_str("map of entity patches")
     }
 
     fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
@@ -336,7 +1191,7 @@ impl<'a, 'de> Visitor<'de> for SceneEntitiesVisitor<'a> {
     {
         let mut entities = Vec::new();
         while let Some(entity) = map.next_key::<Entity>()? {
-            let entity = map.next_value_seed(SceneEntityDeserializer {
+            let entity = map.next_value_seed(EntityPatchDeserializer {
                 entity,
                 type_registry: self.type_registry,
             })?;
@@ -347,25 +1202,29 @@ impl<'a, 'de> Visitor<'de> for SceneEntitiesVisitor<'a> {
     }
 }
 
-/// Handle deserialization of an entity and its components.
-pub struct SceneEntityDeserializer<'a> {
-    /// Id of the deserialized entity.
+/// Handle deserialization of the changes made to a single entity.
+pub struct EntityPatchDeserializer<'a> {
+    /// Id of the patched entity.
     pub entity: Entity,
-    /// Type registry in which the component types used by the entity to deserialize are registered.
+    /// Type registry in which the types used by the entity patch to deserialize are registered.
     pub type_registry: &'a TypeRegistry,
 }
 
-impl<'a, 'de> DeserializeSeed<'de> for SceneEntityDeserializer<'a> {
-    type Value = DynamicEntity;
+impl<'a, 'de> DeserializeSeed<'de> for EntityPatchDeserializer<'a> {
+    type Value = DynamicEntityPatch;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
         D: Deserializer<'de>,
     {
         deserializer.deserialize_struct(
-            ENTITY_STRUCT,
-            &[ENTITY_FIELD_COMPONENTS],
-            SceneEntityVisitor {
+            ENTITY_PATCH_STRUCT,
+            &[
+                ENTITY_FIELD_COMPONENTS,
+                ENTITY_PATCH_FIELDS,
+                ENTITY_PATCH_REMOVED_COMPONENTS,
+            ],
+            EntityPatchVisitor {
                 entity: self.entity,
                 registry: self.type_registry,
             },
@@ -373,16 +1232,16 @@ impl<'a, 'de> DeserializeSeed<'de> for SceneEntityDeserializer<'a> {
     }
 }
 
-struct SceneEntityVisitor<'a> {
+struct EntityPatchVisitor<'a> {
     pub entity: Entity,
     pub registry: &'a TypeRegistry,
 }
 
-impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
-    type Value = DynamicEntity;
+impl<'a, 'de> Visitor<'de> for EntityPatchVisitor<'a> {
+    type Value = DynamicEntityPatch;
 
     fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
-        formatter.write_str("entities")
+        formatter.write_str("entity patch")
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
@@ -395,9 +1254,21 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
             })?
             .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
 
-        Ok(DynamicEntity {
+        let fields = seq
+            .next_element_seed(ComponentFieldsDeserializer {
+                registry: self.registry,
+            })?
+            .ok_or_else(|| Error::missing_field(ENTITY_PATCH_FIELDS))?;
+
+        let removed_components = seq
+            .next_element()?
+            .ok_or_else(|| Error::missing_field(ENTITY_PATCH_REMOVED_COMPONENTS))?;
+
+        Ok(DynamicEntityPatch {
             entity: self.entity,
             components,
+            fields,
+            removed_components,
         })
     }
 
@@ -406,104 +1277,134 @@ impl<'a, 'de> Visitor<'de> for SceneEntityVisitor<'a> {
         A: MapAccess<'de>,
     {
         let mut components = None;
+        let mut fields = None;
+        let mut removed_components = None;
         while let Some(key) = map.next_key()? {
             match key {
-                EntityField::Components => {
+                EntityPatchField::Components => {
                     if components.is_some() {
                         return Err(Error::duplicate_field(ENTITY_FIELD_COMPONENTS));
                     }
-
                     components = Some(map.next_value_seed(SceneMapDeserializer {
                         registry: self.registry,
                     })?);
                 }
+                EntityPatchField::Fields => {
+                    if fields.is_some() {
+                        return Err(Error::duplicate_field(ENTITY_PATCH_FIELDS));
+                    }
+                    fields = Some(map.next_value_seed(ComponentFieldsDeserializer {
+                        registry: self.registry,
+                    })?);
+                }
+                EntityPatchField::RemovedComponents => {
+                    if removed_components.is_some() {
+                        return Err(Error::duplicate_field(ENTITY_PATCH_REMOVED_COMPONENTS));
+                    }
+                    removed_components = Some(map.next_value()?);
+                }
             }
         }
 
-        let components = components
-            .take()
-            .ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
-        Ok(DynamicEntity {
+        let components = components.ok_or_else(|| Error::missing_field(ENTITY_FIELD_COMPONENTS))?;
+        let fields = fields.ok_or_else(|| Error::missing_field(ENTITY_PATCH_FIELDS))?;
+        let removed_components = removed_components
+            .ok_or_else(|| Error::missing_field(ENTITY_PATCH_REMOVED_COMPONENTS))?;
+
+        Ok(DynamicEntityPatch {
             entity: self.entity,
             components,
+            fields,
+            removed_components,
         })
     }
 }
 
-/// Handles deserialization of a sequence of values with unique types.
-pub struct SceneMapDeserializer<'a> {
-    /// Type registry in which the types of the values to deserialize are registered.
+/// Handles deserialization of changed component fields, as written by [`ComponentFieldsSerializer`].
+pub struct ComponentFieldsDeserializer<'a> {
+    /// Type registry in which the types of the field values to deserialize are registered.
     pub registry: &'a TypeRegistry,
 }
 
-impl<'a, 'de> DeserializeSeed<'de> for SceneMapDeserializer<'a> {
-    type Value = Vec<Box<dyn PartialReflect>>;
+impl<'a, 'de> DeserializeSeed<'de> for ComponentFieldsDeserializer<'a> {
+    type Value = Vec<ComponentFieldPatch>;
 
     fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
         D: Deserializer<'de>,
     {
-        deserializer.deserialize_map(SceneMapVisitor {
+        deserializer.deserialize_map(ComponentFieldsVisitor {
             registry: self.registry,
         })
     }
 }
 
-struct SceneMapVisitor<'a> {
+struct ComponentFieldsVisitor<'a> {
     pub registry: &'a TypeRegistry,
 }
 
-impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
-    type Value = Vec<Box<dyn PartialReflect>>;
+impl<'a, 'de> Visitor<'de> for ComponentFieldsVisitor<'a> {
+    type Value = Vec<ComponentFieldPatch>;
 
     fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
-        formatter.write_str("map of reflect types")
+        formatter.write_str("map of component fields")
     }
 
-    fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
     where
-        A: SeqAccess<'de>,
+        A: MapAccess<'de>,
     {
-        let mut dynamic_properties = Vec::new();
-        while let Some(entity) = seq.next_element_seed(ReflectDeserializer::new(self.registry))? {
-            dynamic_properties.push(entity);
+        let mut fields = Vec::new();
+        while let Some(component) = map.next_key::<String>()? {
+            map.next_value_seed(FieldValuesDeserializer {
+                component,
+                fields: &mut fields,
+                registry: self.registry,
+            })?;
         }
 
-        Ok(dynamic_properties)
+        Ok(fields)
     }
+}
 
-    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+struct FieldValuesDeserializer<'a> {
+    component: String,
+    fields: &'a mut Vec<ComponentFieldPatch>,
+    registry: &'a TypeRegistry,
+}
+
+impl<'a, 'de> DeserializeSeed<'de> for FieldValuesDeserializer<'a> {
+    type Value = ();
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
     where
-        A: MapAccess<'de>,
+        D: Deserializer<'de>,
     {
-        let mut added = <HashSet<_>>::default();
-        let mut entries = Vec::new();
-        while let Some(registration) =
-            map.next_key_seed(TypeRegistrationDeserializer::new(self.registry))?
-        {
-            if !added.insert(registration.type_id()) {
-                return Err(Error::custom(format_args!(
-                    "duplicate reflect type: `{}`",
-                    registration.type_info().type_path(),
-                )));
-            }
+        deserializer.deserialize_map(self)
+    }
+}
 
-            let value =
-                map.next_value_seed(TypedReflectDeserializer::new(registration, self.registry))?;
+impl<'a, 'de> Visitor<'de> for FieldValuesDeserializer<'a> {
+    type Value = ();
 
-            // Attempt to convert using FromReflect.
-            let value = self
-                .registry
-                .get(registration.type_id())
-                .and_then(|tr| tr.data::<ReflectFromReflect>())
-                .and_then(|fr| fr.from_reflect(value.as_partial_reflect()))
-                .map(PartialReflect::into_partial_reflect)
-                .unwrap_or(value);
+    fn expecting(&self, formatter: &mut Formatter) -> core::fmt::Result {
+        formatter.write_str("map of field paths to values")
+    }
 
-            entries.push(value);
+    fn visit_map<A>(self, mut map: A) -> Result<Self::Value, A::Error>
+    where
+        A: MapAccess<'de>,
+    {
+        while let Some(path) = map.next_key::<String>()? {
+            let value = map.next_value_seed(ReflectDeserializer::new(self.registry))?;
+            self.fields.push(ComponentFieldPatch {
+                component: self.component.clone(),
+                path,
+                value,
+            });
         }
 
-        Ok(entries)
+        Ok(())
     }
 }
 
@@ -511,10 +1412,11 @@ impl<'a, 'de> Visitor<'de> for SceneMapVisitor<'a> {
 mod tests {
     use crate::{
         ron,
-        serde::{SceneDeserializer, SceneSerializer},
-        DynamicScene, DynamicSceneBuilder,
+        serde::{deserialize_binary, SceneDeserializer, ScenePatchDeserializer, SceneSerializer},
+        DynamicScene, DynamicSceneBuilder, DynamicSceneInstance, DynamicScenePatch,
     };
     use bevy_ecs::{
+        dynamic_component::{DynamicComponentData, DynamicComponentLayout, DynamicFieldKind},
         entity::{Entity, EntityHashMap, VisitEntities, VisitEntitiesMut},
         prelude::{Component, ReflectComponent, ReflectResource, Resource, World},
         query::{With, Without},
@@ -796,6 +1698,39 @@ mod tests {
         assert_eq!(&qux, world.query::<&Qux>().single(&world));
     }
 
+    #[test]
+    fn should_roundtrip_dynamic_components() {
+        let layout = || {
+            DynamicComponentLayout::new("Health")
+                .with_field("current", DynamicFieldKind::F32)
+                .with_field("dead", DynamicFieldKind::Bool)
+        };
+
+        let mut world = create_world();
+        world.register_dynamic_component(layout()).unwrap();
+        world
+            .spawn(Foo(123))
+            .insert_dynamic(&DynamicComponentData::new("Health").with("current", 2.5f32))
+            .unwrap();
+
+        let (scene, deserialized_scene) = roundtrip_ron(&world);
+        assert_eq!(1, deserialized_scene.entities.len());
+        assert_scene_eq(&scene, &deserialized_scene);
+
+        let mut world = create_world();
+        let health = world.register_dynamic_component(layout()).unwrap();
+        deserialized_scene
+            .write_to_world(&mut world, &mut EntityHashMap::default())
+            .unwrap();
+        let entity = world.query_filtered::<Entity, With<Foo>>().single(&world);
+        assert_eq!(
+            world.get_dynamic(entity, health).unwrap().to_data(),
+            DynamicComponentData::new("Health")
+                .with("current", 2.5f32)
+                .with("dead", false)
+        );
+    }
+
     #[test]
     fn should_roundtrip_postcard() {
         let mut world = create_world();
@@ -835,6 +1770,55 @@ mod tests {
         assert_scene_eq(&scene, &deserialized_scene);
     }
 
+    #[test]
+    fn should_roundtrip_binary_scene() {
+        let mut world = create_world();
+
+        world.spawn((Foo(123), Bar(345)));
+        world.spawn(MyComponent {
+            foo: [1, 2, 3],
+            bar: (1.3, 3.7),
+            baz: MyEnum::Struct { value: 42 },
+        });
+        world.insert_resource(MyResource { foo: 7 });
+
+        let registry = world.resource::<AppTypeRegistry>();
+        let registry = &registry.read();
+
+        let mut scene = DynamicScene::from_world(&world);
+        let serialized_scene = scene.serialize_binary(registry).unwrap();
+
+        // Scenes without nested instances are plain postcard scenes.
+        let scene_deserializer = SceneDeserializer {
+            type_registry: registry,
+        };
+        let deserialized_scene = scene_deserializer
+            .deserialize(&mut postcard::Deserializer::from_bytes(&serialized_scene))
+            .unwrap();
+
+        assert_eq!(1, deserialized_scene.resources.len());
+        assert_scene_eq(&scene, &deserialized_scene);
+
+        scene.instances.push(DynamicSceneInstance {
+            entity: Entity::from_raw(7),
+            scene: "nested.scn.ron".into(),
+            overrides: DynamicScenePatch::default(),
+        });
+        let serialized_scene = scene.serialize_binary(registry).unwrap();
+        let deserialized_scene = deserialize_binary(&serialized_scene, registry).unwrap();
+
+        assert_scene_eq(&scene, &deserialized_scene);
+        assert_eq!(1, deserialized_scene.instances.len());
+        assert_eq!(Entity::from_raw(7), deserialized_scene.instances[0].entity);
+        assert_eq!(
+            "nested.scn.ron",
+            deserialized_scene.instances[0].scene.to_string()
+        );
+
+        // Other non-human-readable formats can't store nested instances.
+        assert!(postcard::to_allocvec(&SceneSerializer::new(&scene, registry)).is_err());
+    }
+
     #[test]
     fn should_roundtrip_messagepack() {
         let mut world = create_world();
@@ -922,6 +1906,101 @@ mod tests {
         assert_scene_eq(&scene, &deserialized_scene);
     }
 
+    #[test]
+    fn should_roundtrip_scene_patch() {
+        let mut world = create_world();
+        let a = world
+            .spawn((
+                Foo(1),
+                MyComponent {
+                    foo: [1, 2, 3],
+                    bar: (1.0, 2.0),
+                    baz: MyEnum::Unit,
+                },
+            ))
+            .id();
+        let b = world.spawn(Bar(2)).id();
+        let before = DynamicScene::from_world(&world);
+
+        world.entity_mut(a).insert((
+            Bar(3),
+            MyComponent {
+                foo: [1, 2, 3],
+                bar: (1.0, 4.0),
+                baz: MyEnum::Struct { value: 5 },
+            },
+        ));
+        world.entity_mut(a).remove::<Foo>();
+        world.despawn(b);
+        world.insert_resource(MyResource { foo: 7 });
+        let after = DynamicScene::from_world(&world);
+
+        let registry = world.resource::<AppTypeRegistry>().read();
+        let patch = before.diff(&after);
+        let serialized = patch.serialize(&registry).unwrap();
+
+        let expected = r#"(
+  resources: {
+    "bevy_scene::serde::tests::MyResource": (
+      foo: 7,
+    ),
+  },
+  removed_resources: [],
+  entities: {
+    4294967296: (
+      components: {
+        "bevy_scene::serde::tests::Bar": (3),
+      },
+      fields: {
+        "bevy_scene::serde::tests::MyComponent": {
+          ".bar.1": {
+            "f32": 4.0,
+          },
+          ".baz": {
+            "bevy_scene::serde::tests::MyEnum": Struct(
+              value: 5,
+            ),
+          },
+        },
+      },
+      removed_components: [
+        "bevy_scene::serde::tests::Foo",
+      ],
+    ),
+  },
+  removed_entities: [
+    4294967297,
+  ],
+)"#;
+        assert_eq!(expected, serialized);
+
+        let mut deserializer = ron::de::Deserializer::from_str(&serialized).unwrap();
+        let patch_deserializer = ScenePatchDeserializer {
+            type_registry: &registry,
+        };
+        let deserialized_patch = patch_deserializer.deserialize(&mut deserializer).unwrap();
+
+        let mut dst_world = create_world();
+        let mut entity_map = EntityHashMap::default();
+        before
+            .write_to_world(&mut dst_world, &mut entity_map)
+            .unwrap();
+        deserialized_patch
+            .apply_to_world(&mut dst_world, &mut entity_map)
+            .unwrap();
+
+        let patched = DynamicScene::from_world(&dst_world);
+        assert!(dst_world.get_entity(entity_map[&a]).is_ok());
+        assert!(!entity_map.contains_key(&b));
+        assert_eq!(1, patched.entities.len());
+        let component = dst_world.get::<MyComponent>(entity_map[&a]).unwrap();
+        assert_eq!((1.0, 4.0), component.bar);
+        assert!(matches!(component.baz, MyEnum::Struct { value: 5 }));
+        assert_eq!(3, dst_world.get::<Bar>(entity_map[&a]).unwrap().0);
+        assert!(dst_world.get::<Foo>(entity_map[&a]).is_none());
+        assert_eq!(7, dst_world.resource::<MyResource>().foo);
+    }
+
     /// A crude equality checker for [`DynamicScene`], used solely for testing purposes.
     fn assert_scene_eq(expected: &DynamicScene, received: &DynamicScene) {
         assert_eq!(